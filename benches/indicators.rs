@@ -1,11 +1,22 @@
 use bencher::{benchmark_group, benchmark_main, black_box, Bencher};
 use rand::Rng;
 use tam::indicators::{
-    AverageTrueRange, BollingerBands, ChandelierExit, CommodityChannelIndex, EfficiencyRatio,
-    ExponentialMovingAverage, FastStochastic, KeltnerChannel, Maximum, MeanAbsoluteDeviation,
-    Minimum, MoneyFlowIndex, MovingAverageConvergenceDivergence, OnBalanceVolume,
-    PercentagePriceOscillator, RateOfChange, RelativeStrengthIndex, SimpleMovingAverage,
-    SlowStochastic, StandardDeviation, TrueRange, WeightedMovingAverage,
+    Alligator, Alma, AnchoredVwap, AverageTrueRange, BollingerBands, BollingerBandwidth,
+    BollingerPercentB, Breakout, CenterOfGravity, ChaikinMoneyFlow, ChaikinVolatility,
+    ChandeMomentumOscillator, ChandelierExit, CommodityChannelIndex, DamianiVolatmeter, Decycler,
+    DecyclerOscillator, DisparityIndex, DoubleExponentialMovingAverage, EfficiencyRatio,
+    ElderImpulse, ExponentialMovingAverage, FastStochastic, FibonacciWeightedMovingAverage,
+    Fractal, GannHiLo, GatorOscillator, Gmma, GmmaScore, HurstExponent, KeltnerChannel,
+    KlingerOscillator, Kurtosis, LaguerreFilter, LaguerreRsi, LinearRegression, LogReturn,
+    MaRibbon, MaxDrawdown, Maximum, McginleyDynamic, MeanAbsoluteDeviation, Median, Minimum,
+    MoneyFlowIndex, MovingAverage, MovingAverageConvergenceDivergence, NegativeVolumeIndex,
+    OnBalanceVolume, PercentTrailingStop, PercentagePriceOscillator, PercentageVolumeOscillator,
+    PositiveVolumeIndex, PriceVolumeTrend, QStick, Rainbow, RateOfChange, RelativeMomentumIndex,
+    RelativeStrengthIndex, RelativeVigorIndex, Return, SafeZoneStop, SchaffTrendCycle,
+    SimpleMovingAverage, SineWave, Skewness, SlowStochastic, SmoothedMovingAverage,
+    SqueezeMomentum, StandardDeviation, SuperTrend, TimeSeriesForecast, TrendStreak,
+    TripleExponentialMovingAverage, TrueRange, UlcerIndex, Vidya, VolumeProfile,
+    WeightedMovingAverage, ZScore, ZeroLagEma,
 };
 use tam::{DataItem, Next};
 
@@ -52,6 +63,8 @@ macro_rules! bench_indicators {
 }
 
 bench_indicators!(
+    Alligator,
+    GatorOscillator,
     AverageTrueRange,
     ExponentialMovingAverage,
     MeanAbsoluteDeviation,
@@ -59,19 +72,76 @@ bench_indicators!(
     ChandelierExit,
     EfficiencyRatio,
     FastStochastic,
+    Fractal,
     KeltnerChannel,
+    MaRibbon,
     Maximum,
     Minimum,
     MoneyFlowIndex,
     MovingAverageConvergenceDivergence,
     OnBalanceVolume,
     PercentagePriceOscillator,
+    PriceVolumeTrend,
     CommodityChannelIndex,
+    DamianiVolatmeter,
     RateOfChange,
     RelativeStrengthIndex,
     SimpleMovingAverage,
     SlowStochastic,
     StandardDeviation,
     TrueRange,
-    WeightedMovingAverage
+    VolumeProfile,
+    WeightedMovingAverage,
+    Gmma,
+    GmmaScore,
+    SqueezeMomentum,
+    HurstExponent,
+    LaguerreFilter,
+    LaguerreRsi,
+    CenterOfGravity,
+    Decycler,
+    DecyclerOscillator,
+    FibonacciWeightedMovingAverage,
+    SineWave,
+    PercentTrailingStop,
+    ChandeMomentumOscillator,
+    Vidya,
+    SuperTrend,
+    LinearRegression,
+    TrendStreak,
+    RelativeMomentumIndex,
+    BollingerPercentB,
+    BollingerBandwidth,
+    ChaikinVolatility,
+    ZScore,
+    MovingAverage,
+    UlcerIndex,
+    SafeZoneStop,
+    SchaffTrendCycle,
+    ZeroLagEma,
+    Alma,
+    PercentageVolumeOscillator,
+    NegativeVolumeIndex,
+    PositiveVolumeIndex,
+    KlingerOscillator,
+    Median,
+    Return,
+    LogReturn,
+    MaxDrawdown,
+    ChaikinMoneyFlow,
+    AnchoredVwap,
+    Breakout,
+    GannHiLo,
+    SmoothedMovingAverage,
+    McginleyDynamic,
+    QStick,
+    ElderImpulse,
+    DisparityIndex,
+    TimeSeriesForecast,
+    Skewness,
+    Kurtosis,
+    DoubleExponentialMovingAverage,
+    TripleExponentialMovingAverage,
+    Rainbow,
+    RelativeVigorIndex
 );
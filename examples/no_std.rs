@@ -0,0 +1,17 @@
+// Checks that the indicators can be used from a `no_std` crate:
+//
+//     cargo build --no-default-features --example no_std
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use tam::indicators::{RelativeStrengthIndex, SimpleMovingAverage};
+use tam::Next;
+
+/// RSI of the prices smoothed by an SMA.
+pub fn smoothed_rsi(prices: &[f64], sma_period: usize, rsi_period: usize) -> Vec<f64> {
+    let mut sma = SimpleMovingAverage::new(sma_period).unwrap();
+    let mut rsi = RelativeStrengthIndex::new(rsi_period).unwrap();
+    prices.iter().map(|&p| rsi.next(sma.next(p))).collect()
+}
@@ -0,0 +1,302 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, DataItem, High, Low, Next, Open, Reset, Volume};
+use serde::{Deserialize, Serialize};
+
+/// Builds bars of a higher timeframe out of every `factor` consecutive input bars.
+///
+/// Feeds of ticks or minute bars can be turned into e.g. 5 minute bars before being given to
+/// the indicators. Every `factor` inputs a [DataItem](struct.DataItem.html) is emitted, which
+/// opens with the first input, closes with the last one, spans their highest high and lowest
+/// low and sums their volume. The other inputs return `None`. The buy and sell volumes of
+/// [ClassifiedBar](trait.ClassifiedBar.html) are not aggregated.
+///
+/// [MultiTimeframe](struct.MultiTimeframe.html) aggregates the same way to run an indicator
+/// on several timeframes at once.
+///
+/// # Parameters
+///
+/// * _factor_ - number of input bars in an output bar (integer greater than 0)
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::SimpleMovingAverage;
+/// use tam::{BarAggregator, DataItem, Next};
+///
+/// let minute = |close: f64| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(close + 0.5)
+///         .low(close - 0.5)
+///         .close(close)
+///         .volume(100.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut five_minutes = BarAggregator::new(5).unwrap();
+/// let mut sma = SimpleMovingAverage::new(20).unwrap();
+/// for i in 0..10 {
+///     if let Some(bar) = five_minutes.next(&minute(10.0 + i as f64)) {
+///         sma.next(&bar);
+///     }
+/// }
+/// // the closes of the two 5 minute bars, 14 and 19
+/// assert_eq!(sma.next(0.0), 11.0);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BarAggregator {
+    factor: usize,
+    count: usize,
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+    volume: f64,
+}
+
+impl BarAggregator {
+    pub fn new(factor: usize) -> Result<Self> {
+        match factor {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                factor,
+                count: 0,
+                open: 0.0,
+                high: 0.0,
+                low: 0.0,
+                close: 0.0,
+                volume: 0.0,
+            }),
+        }
+    }
+
+    pub fn factor(&self) -> usize {
+        self.factor
+    }
+
+    /// Number of inputs in the bar being built, from 0 to `factor - 1`.
+    pub fn pending(&self) -> usize {
+        self.count
+    }
+}
+
+impl<T: Open + High + Low + Close + Volume> Next<&T> for BarAggregator {
+    type Output = Option<DataItem>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        if self.count == 0 {
+            self.open = input.open();
+            self.high = input.high();
+            self.low = input.low();
+            self.volume = 0.0;
+        } else {
+            self.high = self.high.max(input.high());
+            self.low = self.low.min(input.low());
+        }
+        self.close = input.close();
+        self.volume += input.volume();
+        self.count += 1;
+
+        if self.count < self.factor {
+            return None;
+        }
+        self.count = 0;
+        Some(DataItem::from_parts(
+            self.open,
+            self.high,
+            self.low,
+            self.close,
+            self.volume,
+        ))
+    }
+}
+
+impl Reset for BarAggregator {
+    fn reset(&mut self) {
+        self.count = 0;
+    }
+}
+
+impl fmt::Display for BarAggregator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AGG({})", self.factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(BarAggregator::new(0).is_err());
+        assert!(BarAggregator::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_five_minutes() {
+        let minutes = [
+            Bar::new()
+                .open(10.0)
+                .high(10.5)
+                .low(9.75)
+                .close(10.25)
+                .volume(100.0),
+            Bar::new()
+                .open(10.25)
+                .high(11.0)
+                .low(10.0)
+                .close(10.75)
+                .volume(150.0),
+            Bar::new()
+                .open(10.75)
+                .high(10.75)
+                .low(9.5)
+                .close(9.75)
+                .volume(80.0),
+            Bar::new()
+                .open(9.75)
+                .high(10.25)
+                .low(9.25)
+                .close(10.0)
+                .volume(120.0),
+            Bar::new()
+                .open(10.0)
+                .high(10.5)
+                .low(9.75)
+                .close(10.5)
+                .volume(50.0),
+            Bar::new()
+                .open(10.5)
+                .high(12.0)
+                .low(10.5)
+                .close(11.75)
+                .volume(300.0),
+            Bar::new()
+                .open(11.75)
+                .high(11.75)
+                .low(11.0)
+                .close(11.25)
+                .volume(200.0),
+            Bar::new()
+                .open(11.25)
+                .high(11.5)
+                .low(10.25)
+                .close(10.5)
+                .volume(250.0),
+            Bar::new()
+                .open(10.5)
+                .high(11.0)
+                .low(10.5)
+                .close(10.75)
+                .volume(75.0),
+            Bar::new()
+                .open(10.75)
+                .high(11.25)
+                .low(10.5)
+                .close(11.0)
+                .volume(25.0),
+        ];
+
+        let mut agg = BarAggregator::new(5).unwrap();
+        let mut bars = Vec::new();
+        for (i, minute) in minutes.iter().enumerate() {
+            match agg.next(minute) {
+                Some(bar) => bars.push(bar),
+                None => assert_ne!(i % 5, 4),
+            }
+            assert_eq!(agg.pending(), (i + 1) % 5);
+        }
+
+        assert_eq!(
+            bars,
+            vec![
+                DataItem::from_parts(10.0, 11.0, 9.25, 10.5, 500.0),
+                DataItem::from_parts(10.5, 12.0, 10.25, 11.0, 850.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_factor_1() {
+        let mut agg = BarAggregator::new(1).unwrap();
+        let minute = Bar::new()
+            .open(10.0)
+            .high(10.5)
+            .low(9.75)
+            .close(10.25)
+            .volume(100.0);
+        assert_eq!(
+            agg.next(&minute),
+            Some(DataItem::from_parts(10.0, 10.5, 9.75, 10.25, 100.0))
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut agg = BarAggregator::new(2).unwrap();
+        agg.next(
+            &Bar::new()
+                .open(10.0)
+                .high(12.0)
+                .low(8.0)
+                .close(11.0)
+                .volume(100.0),
+        );
+
+        agg.reset();
+        assert_eq!(agg.pending(), 0);
+        assert_eq!(
+            agg.next(
+                &Bar::new()
+                    .open(11.0)
+                    .high(11.5)
+                    .low(10.5)
+                    .close(11.0)
+                    .volume(10.0)
+            ),
+            None
+        );
+        assert_eq!(
+            agg.next(
+                &Bar::new()
+                    .open(11.0)
+                    .high(11.25)
+                    .low(10.75)
+                    .close(11.25)
+                    .volume(20.0)
+            ),
+            Some(DataItem::from_parts(11.0, 11.5, 10.5, 11.25, 30.0))
+        );
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut agg = BarAggregator::new(2).unwrap();
+        agg.next(
+            &Bar::new()
+                .open(10.0)
+                .high(12.0)
+                .low(8.0)
+                .close(11.0)
+                .volume(100.0),
+        );
+        let mut restored = serde_round_trip(&agg);
+        let b = Bar::new()
+            .open(11.0)
+            .high(13.0)
+            .low(10.0)
+            .close(12.0)
+            .volume(50.0);
+        assert_eq!(restored.next(&b), agg.next(&b));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", BarAggregator::new(5).unwrap()), "AGG(5)");
+    }
+}
@@ -0,0 +1,181 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{Next, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Collects the ready outputs of an indicator until they are drained.
+///
+/// `Buffered` forwards every input to the wrapped indicator and keeps its outputs once the
+/// indicator is warmed up (the first [lookback](trait.Warmup.html) outputs are skipped).
+/// A consumer pulling results in batches calls [drain](#method.drain) at its own pace and gets
+/// everything produced since the previous call, so the feed rate and the consume rate are
+/// independent. Each output is still returned by `next` as well.
+///
+/// # Parameters
+///
+/// * _inner_ - the wrapped indicator
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::SimpleMovingAverage;
+/// use tam::{Buffered, Next};
+///
+/// let mut buffered = Buffered::new(SimpleMovingAverage::new(2).unwrap());
+/// for price in [1.0, 3.0, 5.0, 7.0] {
+///     buffered.next(price);
+/// }
+/// assert_eq!(buffered.drain(), vec![2.0, 4.0, 6.0]);
+/// assert!(buffered.drain().is_empty());
+///
+/// buffered.next(9.0);
+/// assert_eq!(buffered.drain(), vec![8.0]);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Buffered<I, O> {
+    inner: I,
+    count: usize,
+    ready: Vec<O>,
+}
+
+impl<I, O> Buffered<I, O> {
+    pub fn new(inner: I) -> Self {
+        Self {
+            inner,
+            count: 0,
+            ready: Vec::new(),
+        }
+    }
+
+    /// Returns the outputs produced since the last drain, the oldest first, and forgets them.
+    pub fn drain(&mut self) -> Vec<O> {
+        core::mem::take(&mut self.ready)
+    }
+
+    /// Number of outputs waiting to be drained.
+    pub fn len(&self) -> usize {
+        self.ready.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ready.is_empty()
+    }
+
+    pub fn inner(&self) -> &I {
+        &self.inner
+    }
+}
+
+impl<I, O, T> Next<T> for Buffered<I, O>
+where
+    I: Next<T, Output = O> + Warmup,
+    O: Clone,
+{
+    type Output = O;
+
+    fn next(&mut self, input: T) -> Self::Output {
+        let output = self.inner.next(input);
+        if self.count < self.inner.lookback() {
+            self.count += 1;
+        } else {
+            self.ready.push(output.clone());
+        }
+        output
+    }
+}
+
+impl<I: Warmup, O> Warmup for Buffered<I, O> {
+    fn lookback(&self) -> usize {
+        self.inner.lookback()
+    }
+}
+
+impl<I: Reset, O> Reset for Buffered<I, O> {
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.count = 0;
+        self.ready.clear();
+    }
+}
+
+impl<I: fmt::Display, O> fmt::Display for Buffered<I, O> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BUFFERED({})", self.inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{LinearRegression, RelativeStrengthIndex as Rsi};
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_drain() {
+        let mut buffered = Buffered::new(Rsi::new(14).unwrap());
+        for i in 0..100 {
+            buffered.next(&Bar::new().close(100.0 + (i as f64 * 0.3).sin()));
+        }
+
+        let lookback = buffered.lookback();
+        assert_eq!(buffered.len(), 100 - lookback);
+        let drained = buffered.drain();
+        assert_eq!(drained.len(), 100 - lookback);
+        assert!(drained.iter().all(|v| !v.is_nan()));
+        assert!(buffered.is_empty());
+    }
+
+    #[test]
+    fn test_batches() {
+        let inputs: Vec<f64> = (0..30).map(|i| (i * i % 7) as f64).collect();
+        let mut rsi = Rsi::new(5).unwrap();
+        let expected: Vec<f64> = inputs.iter().map(|&p| rsi.next(p)).skip(5).collect();
+
+        // draining at irregular intervals loses and repeats nothing
+        let mut buffered = Buffered::new(Rsi::new(5).unwrap());
+        let mut drained = Vec::new();
+        for (i, &p) in inputs.iter().enumerate() {
+            buffered.next(p);
+            if i % 4 == 3 || i % 7 == 6 {
+                drained.extend(buffered.drain());
+            }
+        }
+        drained.extend(buffered.drain());
+        assert_eq!(drained, expected);
+    }
+
+    #[test]
+    fn test_struct_output() {
+        let mut buffered = Buffered::new(LinearRegression::new(3).unwrap());
+        for &p in [1.0, 2.0, 3.0, 5.0].iter() {
+            buffered.next(p);
+        }
+        let drained = buffered.drain();
+        assert_eq!(drained.len(), 2);
+        assert_eq!(drained[0].slope, 1.0);
+        assert_eq!(drained[1].slope, 1.5);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut buffered = Buffered::new(Rsi::new(2).unwrap());
+        for &p in [1.0, 2.0, 3.0, 4.0].iter() {
+            buffered.next(p);
+        }
+
+        buffered.reset();
+        assert!(buffered.is_empty());
+        buffered.next(1.0);
+        buffered.next(2.0);
+        assert!(buffered.is_empty());
+        buffered.next(3.0);
+        assert_eq!(buffered.len(), 1);
+    }
+
+    #[test]
+    fn test_display() {
+        let buffered: Buffered<Rsi, f64> = Buffered::new(Rsi::new(14).unwrap());
+        assert_eq!(format!("{}", buffered), "BUFFERED(RSI(14))");
+    }
+}
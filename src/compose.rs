@@ -0,0 +1,195 @@
+use core::fmt;
+
+use crate::{Next, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Chains two indicators, skipping the NaN outputs of the first one.
+///
+/// The outputs of the first indicator are the inputs of the second one, except NaN: a NaN is
+/// returned as is and the second indicator never sees it, so its window only holds valid
+/// values. Unlike [Pipeline](struct.Pipeline.html), which counts the warmup inputs of the
+/// first stage, the first indicator does not need a [lookback](trait.Warmup.html), and a NaN
+/// in the middle of the series (e.g. a [Skewness](indicators/struct.Skewness.html) of a flat
+/// window) is skipped as well.
+///
+/// The outputs of the second indicator are NaN until it has seen as many valid values as its
+/// own [lookback](trait.Warmup.html), like in a pipeline, so the lookback of a chain is the
+/// sum of the lookbacks of its stages as long as the first one only returns NaN during its
+/// warmup. Chains can be nested to compose more indicators.
+///
+/// # Parameters
+///
+/// * _first_ - indicator consuming the inputs, its output must be `f64`
+/// * _second_ - indicator consuming the valid outputs of _first_
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::{ExponentialMovingAverage as Ema, RelativeStrengthIndex as Rsi};
+/// use tam::{Chain, Next, Warmup};
+///
+/// // EMA(9) of the RSI(14)
+/// let mut chain = Chain::new(Rsi::new(14).unwrap(), Ema::new(9).unwrap());
+/// assert_eq!(chain.lookback(), 14);
+/// for i in 0..14 {
+///     assert!(chain.next(i as f64).is_nan());
+/// }
+/// // the EMA starts with the first valid RSI
+/// assert_eq!(chain.next(14.0), 100.0);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Chain<A, B> {
+    first: A,
+    second: B,
+    /// Valid values fed to the second indicator, up to its lookback.
+    count: usize,
+}
+
+impl<A, B> Chain<A, B> {
+    pub fn new(first: A, second: B) -> Self {
+        Self {
+            first,
+            second,
+            count: 0,
+        }
+    }
+
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+}
+
+impl<A: Warmup, B: Warmup> Warmup for Chain<A, B> {
+    fn lookback(&self) -> usize {
+        self.first.lookback() + self.second.lookback()
+    }
+}
+
+impl<A, B, T> Next<T> for Chain<A, B>
+where
+    A: Next<T, Output = f64>,
+    B: Next<f64, Output = f64> + Warmup,
+{
+    type Output = f64;
+
+    fn next(&mut self, input: T) -> Self::Output {
+        let value = self.first.next(input);
+        if value.is_nan() {
+            return f64::NAN;
+        }
+
+        let value = self.second.next(value);
+        if self.count < self.second.lookback() {
+            self.count += 1;
+            return f64::NAN;
+        }
+        value
+    }
+}
+
+impl<A: Reset, B: Reset> Reset for Chain<A, B> {
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+        self.count = 0;
+    }
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for Chain<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CHAIN({}, {})", self.first, self.second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{
+        DisparityIndex, ExponentialMovingAverage as Ema, RelativeStrengthIndex as Rsi,
+        SimpleMovingAverage as Sma,
+    };
+    use crate::test_helper::*;
+
+    fn prices(n: usize) -> Vec<f64> {
+        (0..n)
+            .map(|i| 100.0 + 10.0 * (i as f64 * 0.4).sin() + i as f64 * 0.1)
+            .collect()
+    }
+
+    #[test]
+    fn test_rsi_sma() {
+        let mut chain = Chain::new(Rsi::new(3).unwrap(), Sma::new(2).unwrap());
+        let mut rsi = Rsi::new(3).unwrap();
+        let mut sma = Sma::new(2).unwrap();
+
+        for (i, p) in prices(20).into_iter().enumerate() {
+            let value = chain.next(&Bar::new().close(p));
+            let rsi = rsi.next(p);
+            if rsi.is_nan() {
+                assert!(value.is_nan());
+                continue;
+            }
+            // the SMA only holds valid RSI values
+            let sma = sma.next(rsi);
+            if i < chain.lookback() {
+                assert!(value.is_nan());
+            } else {
+                assert_eq!(value, sma);
+                assert!(value.is_finite());
+            }
+        }
+    }
+
+    #[test]
+    fn test_skip_nan() {
+        // the disparity is NaN when its SMA is 0
+        let mut chain = Chain::new(DisparityIndex::new(2).unwrap(), Sma::new(2).unwrap());
+        // the first disparity, 0, only warms up the SMA
+        assert!(chain.next(-1.0).is_nan());
+        assert!(chain.next(1.0).is_nan());
+        // averages 0 and 50, the NaN is not in the window
+        assert_eq!(chain.next(3.0), 25.0);
+    }
+
+    #[test]
+    fn test_nested() {
+        let inner = Chain::new(Rsi::new(14).unwrap(), Sma::new(5).unwrap());
+        let mut chain = Chain::new(inner, Ema::new(3).unwrap());
+        assert_eq!(chain.lookback(), 18);
+
+        let outputs: Vec<f64> = prices(40).iter().map(|&p| chain.next(p)).collect();
+        assert_eq!(warmup_nan_count(&outputs), chain.lookback());
+        assert!(outputs[18..].iter().all(|v| v.is_finite()));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut chain = Chain::new(Rsi::new(3).unwrap(), Sma::new(2).unwrap());
+        let first: Vec<f64> = prices(10).iter().map(|&p| chain.next(p)).collect();
+
+        chain.reset();
+        let second: Vec<f64> = prices(10).iter().map(|&p| chain.next(p)).collect();
+        assert_eq!(first[4..], second[4..]);
+        assert!(second[..4].iter().all(|v| v.is_nan()));
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut chain = Chain::new(Rsi::new(3).unwrap(), Sma::new(2).unwrap());
+        for p in prices(5) {
+            chain.next(p);
+        }
+        let mut restored = serde_round_trip(&chain);
+        assert_eq!(round(restored.next(101.0)), round(chain.next(101.0)));
+    }
+
+    #[test]
+    fn test_display() {
+        let chain = Chain::new(Rsi::new(14).unwrap(), Ema::new(9).unwrap());
+        assert_eq!(format!("{}", chain), "CHAIN(RSI(14), EMA(9))");
+    }
+}
@@ -0,0 +1,302 @@
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float;
+use crate::{Next, Reset};
+use serde::{Deserialize, Serialize};
+
+/// An indicator with `f64` output that can be boxed into a [CompositeScore](struct.CompositeScore.html).
+trait ScoreInput<T>: for<'a> Next<&'a T, Output = f64> + Reset {}
+
+impl<T, I> ScoreInput<T> for I where I: for<'a> Next<&'a T, Output = f64> + Reset {}
+
+/// Configuration of one indicator of a [CompositeScore](struct.CompositeScore.html).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoreWeight {
+    /// The indicator, as displayed (e.g. `RSI(14)`).
+    pub name: String,
+    pub weight: f64,
+    /// Whether the output is z-score normalized before weighting.
+    pub normalize: bool,
+}
+
+/// Running mean and variance (Welford's algorithm).
+#[derive(Debug, Clone, Default)]
+struct ZScore {
+    count: usize,
+    mean: f64,
+    m2: f64,
+}
+
+impl ZScore {
+    fn next(&mut self, value: f64) -> f64 {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+
+        let sd = (self.m2 / self.count as f64).sqrt();
+        if sd > 0.0 {
+            (value - self.mean) / sd
+        } else {
+            0.0
+        }
+    }
+}
+
+struct Component<T> {
+    indicator: Box<dyn ScoreInput<T>>,
+    config: ScoreWeight,
+    zscore: ZScore,
+}
+
+/// Weighted sum of several indicators fed with the same input.
+///
+/// Combines oscillators into a single score for a strategy. Each indicator is added with a
+/// weight and can optionally be z-score normalized first, so indicators with different
+/// scales (e.g. RSI in [0, 100] and a price based oscillator) contribute comparably. The
+/// normalization uses the mean and standard deviation of all the outputs of the indicator
+/// seen so far.
+///
+/// An indicator does not contribute while its output is NaN (warmup). The score is NaN when
+/// no indicator contributes.
+///
+/// The indicators are boxed, so the score itself is not serializable, but its configuration
+/// is (see [weights](#method.weights)).
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::{FastStochastic, RelativeStrengthIndex};
+/// use tam::{CompositeScore, DataItem, Next};
+///
+/// let mut score = CompositeScore::new()
+///     .add(RelativeStrengthIndex::new(14).unwrap(), 0.6, false)
+///     .add(FastStochastic::new(14).unwrap(), 0.4, false);
+///
+/// let bar = DataItem::builder()
+///     .open(10.0).high(12.0).low(8.0).close(11.0).volume(1.0).build().unwrap();
+///
+/// // the RSI is still warming up, only the stochastic contributes
+/// assert_eq!(score.next(&bar), 0.4 * 75.0);
+/// ```
+pub struct CompositeScore<T> {
+    components: Vec<Component<T>>,
+}
+
+impl<T> CompositeScore<T> {
+    pub fn new() -> Self {
+        Self {
+            components: Vec::new(),
+        }
+    }
+
+    /// Adds `indicator` with the given `weight`. If `normalize` is true, its output is z-score
+    /// normalized before it is weighted.
+    pub fn add<I>(mut self, indicator: I, weight: f64, normalize: bool) -> Self
+    where
+        I: for<'a> Next<&'a T, Output = f64> + Reset + fmt::Display + 'static,
+    {
+        self.components.push(Component {
+            config: ScoreWeight {
+                name: indicator.to_string(),
+                weight,
+                normalize,
+            },
+            indicator: Box::new(indicator),
+            zscore: ZScore::default(),
+        });
+        self
+    }
+
+    /// Configuration of the indicators, in the order they were added.
+    pub fn weights(&self) -> Vec<ScoreWeight> {
+        self.components.iter().map(|c| c.config.clone()).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+}
+
+impl<T> Next<&T> for CompositeScore<T> {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let mut score = f64::NAN;
+
+        for component in self.components.iter_mut() {
+            let mut value = component.indicator.next(input);
+            if value.is_nan() {
+                continue;
+            }
+            if component.config.normalize {
+                value = component.zscore.next(value);
+            }
+
+            let contribution = component.config.weight * value;
+            score = if score.is_nan() {
+                contribution
+            } else {
+                score + contribution
+            };
+        }
+
+        score
+    }
+}
+
+impl<T> Reset for CompositeScore<T> {
+    fn reset(&mut self) {
+        for component in self.components.iter_mut() {
+            component.indicator.reset();
+            component.zscore = ZScore::default();
+        }
+    }
+}
+
+impl<T> Default for CompositeScore<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for CompositeScore<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CompositeScore")
+            .field("weights", &self.weights())
+            .finish()
+    }
+}
+
+impl<T> fmt::Display for CompositeScore<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SCORE(")?;
+        for (i, component) in self.components.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            let config = &component.config;
+            write!(f, "{} * {}", config.name, config.weight)?;
+            if config.normalize {
+                write!(f, " (z)")?;
+            }
+        }
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{FastStochastic, RelativeStrengthIndex as Rsi};
+    use crate::test_helper::*;
+
+    fn bar(i: usize) -> Bar {
+        let close = 100.0 + 10.0 * (i as f64 / 3.0).sin();
+        Bar::new().high(close + 1.0).low(close - 1.0).close(close)
+    }
+
+    #[test]
+    fn test_next() {
+        let mut score = CompositeScore::new()
+            .add(Rsi::new(3).unwrap(), 0.6, false)
+            .add(FastStochastic::new(5).unwrap(), 0.4, false);
+        let mut rsi = Rsi::new(3).unwrap();
+        let mut stoch = FastStochastic::new(5).unwrap();
+
+        for i in 0..30 {
+            let (r, s) = (rsi.next(&bar(i)), stoch.next(&bar(i)));
+            let expected = if r.is_nan() {
+                0.4 * s
+            } else {
+                0.6 * r + 0.4 * s
+            };
+            assert_eq!(round(score.next(&bar(i))), round(expected), "bar {}", i);
+        }
+    }
+
+    #[test]
+    fn test_warmup() {
+        let mut score = CompositeScore::new().add(Rsi::new(3).unwrap(), 1.0, false);
+        assert!(score.next(&bar(0)).is_nan());
+
+        let mut empty: CompositeScore<Bar> = CompositeScore::new();
+        assert!(empty.is_empty());
+        assert!(empty.next(&bar(0)).is_nan());
+    }
+
+    #[test]
+    fn test_normalize() {
+        let mut score = CompositeScore::new()
+            .add(Rsi::new(3).unwrap(), 0.6, true)
+            .add(FastStochastic::new(5).unwrap(), 0.4, true);
+
+        let mut values = Vec::new();
+        for i in 0..200 {
+            values.push(score.next(&bar(i)));
+        }
+        // z-scores of oscillators in [0, 100] stay within a few units
+        assert!(values[50..].iter().all(|v| v.abs() < 3.0));
+        assert!(values[50..].iter().any(|v| v.abs() > 0.5));
+    }
+
+    #[test]
+    fn test_zscore() {
+        let mut z = ZScore::default();
+        assert_eq!(z.next(5.0), 0.0);
+        assert_eq!(z.next(7.0), 1.0);
+        assert_eq!(z.next(6.0), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut score = CompositeScore::new()
+            .add(Rsi::new(3).unwrap(), 0.6, true)
+            .add(FastStochastic::new(5).unwrap(), 0.4, false);
+        let first: Vec<f64> = (0..10).map(|i| score.next(&bar(i))).collect();
+
+        score.reset();
+        let second: Vec<f64> = (0..10).map(|i| score.next(&bar(i))).collect();
+        assert_eq!(format!("{:?}", first), format!("{:?}", second));
+    }
+
+    #[test]
+    fn test_weights() {
+        let score: CompositeScore<Bar> = CompositeScore::new()
+            .add(Rsi::new(14).unwrap(), 0.6, false)
+            .add(FastStochastic::new(14).unwrap(), 0.4, true);
+
+        let weights = score.weights();
+        assert_eq!(weights.len(), 2);
+        assert_eq!(weights[0].name, "RSI(14)");
+        assert_eq!(weights[1].weight, 0.4);
+        assert!(weights[1].normalize);
+
+        let json = serde_json::to_string(&weights[0]).unwrap();
+        assert_eq!(json, r#"{"name":"RSI(14)","weight":0.6,"normalize":false}"#);
+        let config: ScoreWeight = serde_json::from_str(&json).unwrap();
+        assert_eq!(config, weights[0]);
+    }
+
+    #[test]
+    fn test_display() {
+        let score: CompositeScore<Bar> = CompositeScore::new()
+            .add(Rsi::new(14).unwrap(), 0.6, false)
+            .add(FastStochastic::new(14).unwrap(), 0.4, true);
+        assert_eq!(
+            format!("{}", score),
+            "SCORE(RSI(14) * 0.6, FAST_STOCH(14) * 0.4 (z))"
+        );
+    }
+}
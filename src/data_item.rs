@@ -1,5 +1,5 @@
 use crate::errors::*;
-use crate::traits::{Close, High, Low, Open, Volume};
+use crate::traits::{ClassifiedBar, Close, High, Low, Open, Volume};
 use serde::{Deserialize, Serialize};
 
 /// Data item is used as an input for indicators.
@@ -26,6 +26,27 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(item.volume(), 7500.0);
 /// ```
 ///
+/// The volume can optionally be split into buy and sell volume (see
+/// [ClassifiedBar](trait.ClassifiedBar.html)):
+///
+/// ```
+/// use tam::{ClassifiedBar, DataItem};
+///
+/// let item = DataItem::builder()
+///     .open(20.0)
+///     .high(25.0)
+///     .low(15.0)
+///     .close(21.0)
+///     .volume(7500.0)
+///     .buy_volume(5000.0)
+///     .sell_volume(2500.0)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(item.buy_volume(), Some(5000.0));
+/// assert_eq!(item.sell_volume(), Some(2500.0));
+/// ```
+///
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct DataItem {
     open: f64,
@@ -33,12 +54,29 @@ pub struct DataItem {
     low: f64,
     close: f64,
     volume: f64,
+    #[serde(default)]
+    buy_volume: Option<f64>,
+    #[serde(default)]
+    sell_volume: Option<f64>,
 }
 
 impl DataItem {
     pub fn builder() -> DataItemBuilder {
         DataItemBuilder::new()
     }
+
+    /// Creates a data item without validation, for bars derived from already validated ones.
+    pub(crate) fn from_parts(open: f64, high: f64, low: f64, close: f64, volume: f64) -> Self {
+        Self {
+            open,
+            high,
+            low,
+            close,
+            volume,
+            buy_volume: None,
+            sell_volume: None,
+        }
+    }
 }
 
 impl Open for DataItem {
@@ -71,12 +109,24 @@ impl Volume for DataItem {
     }
 }
 
+impl ClassifiedBar for DataItem {
+    fn buy_volume(&self) -> Option<f64> {
+        self.buy_volume
+    }
+
+    fn sell_volume(&self) -> Option<f64> {
+        self.sell_volume
+    }
+}
+
 pub struct DataItemBuilder {
     open: Option<f64>,
     high: Option<f64>,
     low: Option<f64>,
     close: Option<f64>,
     volume: Option<f64>,
+    buy_volume: Option<f64>,
+    sell_volume: Option<f64>,
 }
 
 impl DataItemBuilder {
@@ -87,6 +137,8 @@ impl DataItemBuilder {
             low: None,
             close: None,
             volume: None,
+            buy_volume: None,
+            sell_volume: None,
         }
     }
 
@@ -115,6 +167,16 @@ impl DataItemBuilder {
         self
     }
 
+    pub fn buy_volume(mut self, val: f64) -> Self {
+        self.buy_volume = Some(val);
+        self
+    }
+
+    pub fn sell_volume(mut self, val: f64) -> Self {
+        self.sell_volume = Some(val);
+        self
+    }
+
     pub fn build(self) -> Result<DataItem> {
         if let (Some(open), Some(high), Some(low), Some(close), Some(volume)) =
             (self.open, self.high, self.low, self.close, self.volume)
@@ -126,6 +188,8 @@ impl DataItemBuilder {
                 && high >= open
                 && high >= close
                 && volume >= 0.0
+                && self.buy_volume.is_none_or(|v| v >= 0.0)
+                && self.sell_volume.is_none_or(|v| v >= 0.0)
             {
                 let item = DataItem {
                     open,
@@ -133,6 +197,8 @@ impl DataItemBuilder {
                     low,
                     close,
                     volume,
+                    buy_volume: self.buy_volume,
+                    sell_volume: self.sell_volume,
                 };
                 Ok(item)
             } else {
@@ -147,6 +213,7 @@ impl DataItemBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec;
 
     #[test]
     fn test_builder() {
@@ -199,4 +266,33 @@ mod tests {
             assert_invalid(record)
         }
     }
+
+    #[test]
+    fn test_builder_classified() {
+        let builder = || {
+            DataItem::builder()
+                .open(20.0)
+                .high(25.0)
+                .low(15.0)
+                .close(21.0)
+                .volume(7500.0)
+        };
+
+        let item = builder().build().unwrap();
+        assert_eq!(item.buy_volume(), None);
+        assert_eq!(item.sell_volume(), None);
+
+        let item = builder().buy_volume(7000.0).build().unwrap();
+        assert_eq!(item.buy_volume(), Some(7000.0));
+        assert_eq!(item.sell_volume(), None);
+
+        assert_eq!(
+            builder().sell_volume(-1.0).build(),
+            Err(TaError::DataItemInvalid)
+        );
+        assert_eq!(
+            builder().buy_volume(-1.0).build(),
+            Err(TaError::DataItemInvalid)
+        );
+    }
 }
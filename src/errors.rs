@@ -1,21 +1,27 @@
-use std::error::Error;
-use std::fmt::{Display, Formatter};
+use core::error::Error;
+use core::fmt::{Display, Formatter};
 
-pub type Result<T> = std::result::Result<T, TaError>;
+pub type Result<T> = core::result::Result<T, TaError>;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum TaError {
     InvalidParameter,
     DataItemIncomplete,
     DataItemInvalid,
+    InsufficientData { needed: usize, got: usize },
+    DeserializeError,
 }
 
 impl Display for TaError {
-    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
         match *self {
             TaError::InvalidParameter => write!(f, "invalid parameter"),
             TaError::DataItemIncomplete => write!(f, "data item is incomplete"),
             TaError::DataItemInvalid => write!(f, "data item is invalid"),
+            TaError::InsufficientData { needed, got } => {
+                write!(f, "insufficient data: needed {}, got {}", needed, got)
+            }
+            TaError::DeserializeError => write!(f, "malformed serialized indicator"),
         }
     }
 }
@@ -26,6 +32,8 @@ impl Error for TaError {
             TaError::InvalidParameter => None,
             TaError::DataItemIncomplete => None,
             TaError::DataItemInvalid => None,
+            TaError::InsufficientData { .. } => None,
+            TaError::DeserializeError => None,
         }
     }
 }
@@ -1,16 +1,196 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float;
+
+/// Implements [ApproxEq](../trait.ApproxEq.html) for an output struct by comparing the given
+/// fields.
+macro_rules! impl_approx_eq {
+    ($type:ty, $($field:ident),+) => {
+        impl crate::ApproxEq for $type {
+            fn approx_eq(&self, other: &Self, tol: f64) -> bool {
+                $(crate::ApproxEq::approx_eq(&self.$field, &other.$field, tol))&&+
+            }
+        }
+    };
+}
+
+/// Implements [ApproxEq](../trait.ApproxEq.html) and [NamedOutput](../trait.NamedOutput.html)
+/// for an output struct whose fields are all `f64` or `bool`.
+macro_rules! impl_output {
+    ($type:ty, $($field:ident),+) => {
+        impl_approx_eq!($type, $($field),+);
+
+        impl crate::NamedOutput for $type {
+            fn field_names() -> &'static [&'static str] {
+                &[$(stringify!($field)),+]
+            }
+
+            fn to_named_f64(&self) -> alloc::collections::BTreeMap<&'static str, f64> {
+                let mut map = alloc::collections::BTreeMap::new();
+                $(map.insert(stringify!($field), f64::from(self.$field));)+
+                map
+            }
+        }
+    };
+}
+
+/// Implements `Next<f64>` and `Next<Option<f64>>` for an indicator with a private
+/// `next_value(&mut self, f64) -> f64` method and a `last_output: Option<f64>` field.
+///
+/// `Next<f64>` calls `next_value` and remembers its output, so every input reaching it (directly,
+/// as `Some` or through a `Next<&T>` forwarding the close) updates `last_output`. `None` returns
+/// the remembered output (NaN if there is none) without touching the indicator. A `Next<&T>` not
+/// going through `Next<f64>` must update `last_output` itself.
+macro_rules! impl_next_option {
+    ($type:ty) => {
+        impl crate::Next<f64> for $type {
+            type Output = f64;
+
+            fn next(&mut self, input: f64) -> Self::Output {
+                let output = self.next_value(input);
+                self.last_output = Some(output);
+                output
+            }
+        }
+
+        impl crate::Next<Option<f64>> for $type {
+            type Output = f64;
+
+            fn next(&mut self, input: Option<f64>) -> Self::Output {
+                match input {
+                    Some(value) => crate::Next::<f64>::next(self, value),
+                    None => self.last_output.unwrap_or(f64::NAN),
+                }
+            }
+        }
+    };
+}
+
 /// Returns the largest of 3 given numbers.
 pub fn max3(a: f64, b: f64, c: f64) -> f64 {
     a.max(b).max(c)
 }
 
+/// Sums `values` strictly from left to right.
+///
+/// Used by the `deterministic` feature: the result only depends on the values and their order,
+/// never on the history of a running sum. Rust never contracts `a * b + c` into a fused
+/// multiply-add on its own (only [f64::mul_add] does), so a fixed summation order is enough
+/// for bit-identical results across platforms.
+#[cfg(feature = "deterministic")]
+pub fn ordered_sum<I: IntoIterator<Item = f64>>(values: I) -> f64 {
+    values.into_iter().fold(0.0, |acc, value| acc + value)
+}
+
+/// Rounds `value` to `decimals` decimal places, half away from zero like `f64::round`, or
+/// returns it unchanged if `decimals` is `None`.
+///
+/// The precision of the indicators having a `with_decimals` option, e.g. to compare with
+/// TA-Lib outputs printed with a fixed number of decimals. A value too large to be scaled is
+/// returned unchanged.
+pub fn round_decimals(value: f64, decimals: Option<u32>) -> f64 {
+    let decimals = match decimals {
+        Some(decimals) => decimals,
+        None => return value,
+    };
+    let factor = 10f64.powi(decimals.min(i32::MAX as u32) as i32);
+    let scaled = value * factor;
+    if scaled.is_finite() {
+        scaled.round() / factor
+    } else {
+        value
+    }
+}
+
+/// Feeds every item of `inputs` to `next` and collects the outputs, the first `lookback` of
+/// them replaced with NaN.
+pub fn batch<T>(lookback: usize, inputs: &[T], mut next: impl FnMut(&T) -> f64) -> Vec<f64> {
+    inputs
+        .iter()
+        .enumerate()
+        .map(|(i, input)| {
+            let output = next(input);
+            if i < lookback {
+                f64::NAN
+            } else {
+                output
+            }
+        })
+        .collect()
+}
+
+/// Returns the last `needed` items of `warmup`, or `TaError::InsufficientData` if there are
+/// fewer than `needed` items.
+pub fn warmup_tail<T>(warmup: &[T], needed: usize) -> Result<&[T]> {
+    if warmup.len() < needed {
+        return Err(TaError::InsufficientData {
+            needed,
+            got: warmup.len(),
+        });
+    }
+    Ok(&warmup[warmup.len() - needed..])
+}
+
+/// Writes `=<current value>` after the name of an indicator when the alternate flag is set
+/// (`{:#}`), with the precision of the formatter if any (`{:#.2}`). The value is NaN before
+/// the first one is known. Writes nothing without the flag.
+pub fn fmt_current(f: &mut fmt::Formatter, current: Option<f64>) -> fmt::Result {
+    if !f.alternate() {
+        return Ok(());
+    }
+    let value = current.unwrap_or(f64::NAN);
+    match f.precision() {
+        Some(precision) => write!(f, "={:.*}", precision, value),
+        None => write!(f, "={}", value),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_round_decimals() {
+        assert_eq!(round_decimals(12.3456, None), 12.3456);
+        assert_eq!(round_decimals(12.3456, Some(0)), 12.0);
+        assert_eq!(round_decimals(12.3456, Some(2)), 12.35);
+        assert_eq!(round_decimals(-12.3456, Some(3)), -12.346);
+        assert_eq!(round_decimals(0.125, Some(2)), 0.13);
+        assert!(round_decimals(f64::NAN, Some(2)).is_nan());
+        assert_eq!(round_decimals(f64::INFINITY, Some(2)), f64::INFINITY);
+        // too many decimals to scale
+        assert_eq!(round_decimals(1e300, Some(20)), 1e300);
+        assert_eq!(round_decimals(0.1, Some(400)), 0.1);
+    }
+
     #[test]
     fn test_max3() {
         assert_eq!(max3(3.0, 2.0, 1.0), 3.0);
         assert_eq!(max3(2.0, 3.0, 1.0), 3.0);
         assert_eq!(max3(2.0, 1.0, 3.0), 3.0);
     }
+
+    #[test]
+    fn test_warmup_tail() {
+        assert_eq!(warmup_tail(&[1, 2, 3], 2), Ok(&[2, 3][..]));
+        assert_eq!(warmup_tail(&[1, 2, 3], 0), Ok(&[][..]));
+        assert_eq!(
+            warmup_tail(&[1], 2),
+            Err(TaError::InsufficientData { needed: 2, got: 1 })
+        );
+    }
+
+    #[cfg(feature = "deterministic")]
+    #[test]
+    fn test_ordered_sum() {
+        assert_eq!(ordered_sum(vec![]), 0.0);
+        assert_eq!(ordered_sum(vec![1.0, 2.0, 3.5]), 6.5);
+        // left to right: (1e16 + 1) + 1 loses both ones
+        assert_eq!(ordered_sum(vec![1e16, 1.0, 1.0]), 1e16);
+        assert_eq!(ordered_sum(vec![1.0, 1.0, 1e16]), 1e16 + 2.0);
+    }
 }
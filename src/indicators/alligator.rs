@@ -0,0 +1,299 @@
+use alloc::collections::VecDeque;
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{High, Low, Next, Periods, Reset};
+use serde::{Deserialize, Serialize};
+
+/// Bill Williams' Alligator.
+///
+/// Three smoothed moving averages of the median price, each shifted forward by a number of
+/// bars. The lines are called the jaw (slowest), the teeth and the lips (fastest). When the
+/// lines are intertwined the alligator "sleeps" and the market is ranging, when they fan out
+/// in order the market is trending.
+///
+/// # Formula
+///
+/// Median = (High + Low) / 2
+///
+/// Jaw<sub>t</sub> = SMMA(Median, _jaw_)<sub>t-8</sub>
+///
+/// Teeth<sub>t</sub> = SMMA(Median, _teeth_)<sub>t-5</sub>
+///
+/// Lips<sub>t</sub> = SMMA(Median, _lips_)<sub>t-3</sub>
+///
+/// Where SMMA is the smoothed (Wilder's) moving average, seeded with the simple average of the
+/// first _period_ values. The shifts can be changed with
+/// [with_shifts](struct.Alligator.html#method.with_shifts).
+///
+/// # Parameters
+///
+/// * _jaw_ - period of the jaw line (integer greater than 0). Default is 13.
+/// * _teeth_ - period of the teeth line (integer greater than 0). Default is 8.
+/// * _lips_ - period of the lips line (integer greater than 0). Default is 5.
+///
+/// Every line is NaN until its average is filled and shifted.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::Alligator;
+/// use tam::Next;
+///
+/// let mut alligator = Alligator::new(3, 2, 1).unwrap().with_shifts(0, 0, 0).unwrap();
+///
+/// let out = alligator.next(2.0);
+/// assert!(out.jaw.is_nan());
+/// assert!(out.teeth.is_nan());
+/// assert_eq!(out.lips, 2.0);
+///
+/// alligator.next(4.0);
+/// let out = alligator.next(6.0);
+/// assert_eq!(out.jaw, 4.0);
+/// assert_eq!(out.teeth, 4.5);
+/// assert_eq!(out.lips, 6.0);
+/// ```
+///
+/// # Links
+///
+/// * [Alligator, Investopedia](https://www.investopedia.com/articles/trading/072115/exploring-williams-alligator-indicator.asp)
+///
+#[doc(alias = "ALLIGATOR")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Alligator {
+    jaw: ShiftedLine,
+    teeth: ShiftedLine,
+    lips: ShiftedLine,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlligatorOutput {
+    pub jaw: f64,
+    pub teeth: f64,
+    pub lips: f64,
+}
+
+impl From<AlligatorOutput> for (f64, f64, f64) {
+    fn from(out: AlligatorOutput) -> Self {
+        (out.jaw, out.teeth, out.lips)
+    }
+}
+
+impl_output!(AlligatorOutput, jaw, teeth, lips);
+
+impl Alligator {
+    pub fn new(jaw: usize, teeth: usize, lips: usize) -> Result<Self> {
+        Ok(Self {
+            jaw: ShiftedLine::new(jaw, 8)?,
+            teeth: ShiftedLine::new(teeth, 5)?,
+            lips: ShiftedLine::new(lips, 3)?,
+        })
+    }
+
+    /// Sets how many bars each line is shifted forward (8, 5 and 3 by default).
+    pub fn with_shifts(self, jaw: usize, teeth: usize, lips: usize) -> Result<Self> {
+        Ok(Self {
+            jaw: ShiftedLine::new(self.jaw.period, jaw)?,
+            teeth: ShiftedLine::new(self.teeth.period, teeth)?,
+            lips: ShiftedLine::new(self.lips.period, lips)?,
+        })
+    }
+}
+
+impl Periods for Alligator {
+    fn periods(&self) -> Vec<usize> {
+        vec![self.jaw.period, self.teeth.period, self.lips.period]
+    }
+}
+
+impl Next<f64> for Alligator {
+    type Output = AlligatorOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        AlligatorOutput {
+            jaw: self.jaw.next(input),
+            teeth: self.teeth.next(input),
+            lips: self.lips.next(input),
+        }
+    }
+}
+
+impl<T: High + Low> Next<&T> for Alligator {
+    type Output = AlligatorOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next((input.high() + input.low()) / 2.0)
+    }
+}
+
+impl Reset for Alligator {
+    fn reset(&mut self) {
+        self.jaw.reset();
+        self.teeth.reset();
+        self.lips.reset();
+    }
+}
+
+impl Default for Alligator {
+    fn default() -> Self {
+        Self::new(13, 8, 5).unwrap()
+    }
+}
+
+impl fmt::Display for Alligator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ALLIGATOR({}, {}, {})",
+            self.jaw.period, self.teeth.period, self.lips.period
+        )
+    }
+}
+
+/// Smoothed moving average whose output is delayed by `shift` bars.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct ShiftedLine {
+    period: usize,
+    shift: usize,
+    count: usize,
+    sum: f64,
+    current: f64,
+    shifted: VecDeque<f64>,
+}
+
+impl ShiftedLine {
+    fn new(period: usize, shift: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                shift,
+                count: 0,
+                sum: 0.0,
+                current: f64::NAN,
+                shifted: VecDeque::with_capacity(shift + 1),
+            }),
+        }
+    }
+
+    fn next(&mut self, input: f64) -> f64 {
+        if self.count < self.period {
+            self.count += 1;
+            self.sum += input;
+            if self.count == self.period {
+                self.current = self.sum / self.period as f64;
+            }
+        } else {
+            self.current += (input - self.current) / self.period as f64;
+        }
+
+        if self.shifted.len() > self.shift {
+            self.shifted.pop_front();
+        }
+        self.shifted.push_back(self.current);
+
+        if self.shifted.len() > self.shift {
+            self.shifted[0]
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.sum = 0.0;
+        self.current = f64::NAN;
+        self.shifted.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Alligator::new(0, 8, 5).is_err());
+        assert!(Alligator::new(13, 0, 5).is_err());
+        assert!(Alligator::new(13, 8, 0).is_err());
+        assert!(Alligator::new(13, 8, 5).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut alligator = Alligator::new(3, 2, 1)
+            .unwrap()
+            .with_shifts(2, 1, 0)
+            .unwrap();
+
+        let out = alligator.next(2.0);
+        assert!(out.jaw.is_nan());
+        assert!(out.teeth.is_nan());
+        assert_eq!(out.lips, 2.0);
+
+        let out = alligator.next(4.0);
+        assert!(out.jaw.is_nan());
+        assert!(out.teeth.is_nan());
+        assert_eq!(out.lips, 4.0);
+
+        let out = alligator.next(6.0);
+        assert!(out.jaw.is_nan());
+        assert_eq!(out.teeth, 3.0);
+        assert_eq!(out.lips, 6.0);
+
+        alligator.next(8.0);
+        let out = alligator.next(10.0);
+        assert_eq!(out.jaw, 4.0);
+        assert_eq!(out.teeth, 6.25);
+        assert_eq!(out.lips, 10.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut alligator = Alligator::new(2, 2, 2)
+            .unwrap()
+            .with_shifts(0, 0, 0)
+            .unwrap();
+
+        alligator.next(&Bar::new().high(12).low(8));
+        let out = alligator.next(&Bar::new().high(14).low(10));
+        assert_eq!(out.jaw, 11.0);
+        assert_eq!(out.teeth, 11.0);
+        assert_eq!(out.lips, 11.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut alligator = Alligator::new(2, 2, 1)
+            .unwrap()
+            .with_shifts(1, 1, 0)
+            .unwrap();
+        alligator.next(2.0);
+        alligator.next(4.0);
+        alligator.next(6.0);
+
+        alligator.reset();
+        let out = alligator.next(10.0);
+        assert!(out.jaw.is_nan());
+        assert!(out.teeth.is_nan());
+        assert_eq!(out.lips, 10.0);
+    }
+
+    #[test]
+    fn test_periods() {
+        assert_eq!(Alligator::new(13, 8, 5).unwrap().periods(), vec![13, 8, 5]);
+    }
+
+    #[test]
+    fn test_default() {
+        Alligator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let alligator = Alligator::default();
+        assert_eq!(format!("{}", alligator), "ALLIGATOR(13, 8, 5)");
+    }
+}
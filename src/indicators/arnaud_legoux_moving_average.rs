@@ -0,0 +1,263 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float;
+use crate::{Close, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Arnaud Legoux Moving Average (ALMA).
+///
+/// Developed by Arnaud Legoux and Dimitrios Kouzis-Loukas, weights the window by a Gaussian
+/// curve. Centering the curve near the latest values reduces the lag, its width sets how
+/// smooth the average is.
+///
+/// # Formula
+///
+/// w<sub>i</sub> = exp(-(i - m)<sup>2</sup> / (2 * s<sup>2</sup>)), with
+/// m = _offset_ * (_period_ - 1) and s = _period_ / _sigma_
+///
+/// ALMA = (w<sub>0</sub> * p<sub>t-n+1</sub> + ... + w<sub>n-1</sub> * p<sub>t</sub>) / (w<sub>0</sub> + ... + w<sub>n-1</sub>)
+///
+/// Where _n_ is the period and _i_ goes from the oldest value of the window (0) to the latest
+/// one (_n_ - 1). The weights only depend on the parameters, they are computed once. The
+/// output is NaN until the window is full.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+/// * _offset_ - position of the center of the curve in the window, from the oldest value (0)
+///   to the latest one (1). Default is 0.85.
+/// * _sigma_ - sharpness of the curve, the larger the narrower (number greater than 0).
+///   Default is 6.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::Alma;
+/// use tam::Next;
+///
+/// // a curve centered on the middle of the window: weights 0.607, 1, 0.607
+/// let mut alma = Alma::new(3, 0.5, 3.0).unwrap();
+/// assert!(alma.next(1.0).is_nan());
+/// assert!(alma.next(2.0).is_nan());
+/// assert_eq!((alma.next(3.0) * 1000.0).round(), 2000.0);
+/// assert_eq!((alma.next(6.0) * 1000.0).round(), 3548.0);
+/// ```
+///
+/// # Links
+///
+/// * [Arnaud Legoux Moving Average, TradingView](https://www.tradingview.com/support/solutions/43000594683-arnaud-legoux-moving-average/)
+///
+#[doc(alias = "ALMA")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Alma {
+    period: usize,
+    offset: f64,
+    sigma: f64,
+    index: usize,
+    count: usize,
+    weights: Box<[f64]>,
+    deque: Box<[f64]>,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl Alma {
+    pub fn new(period: usize, offset: f64, sigma: f64) -> Result<Self> {
+        if period == 0 || !(0.0..=1.0).contains(&offset) || sigma.is_nan() || sigma <= 0.0 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            offset,
+            sigma,
+            index: 0,
+            count: 0,
+            weights: gaussian_weights(period, offset, sigma).into_boxed_slice(),
+            deque: vec![0.0; period].into_boxed_slice(),
+            last_output: None,
+        })
+    }
+
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
+
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+}
+
+/// Returns the weights of the window, the oldest first, normalized to a sum of 1.
+fn gaussian_weights(period: usize, offset: f64, sigma: f64) -> Vec<f64> {
+    let m = offset * (period - 1) as f64;
+    let s = period as f64 / sigma;
+    let mut weights: Vec<f64> = (0..period)
+        .map(|i| (-(i as f64 - m).powi(2) / (2.0 * s * s)).exp())
+        .collect();
+    let sum: f64 = weights.iter().sum();
+    for w in weights.iter_mut() {
+        *w /= sum;
+    }
+    weights
+}
+
+impl Period for Alma {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Warmup for Alma {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl Alma {
+    fn next_value(&mut self, input: f64) -> f64 {
+        self.deque[self.index] = input;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+        if self.count < self.period {
+            self.count += 1;
+            if self.count < self.period {
+                return f64::NAN;
+            }
+        }
+
+        // the oldest value is at the next index to write
+        self.weights
+            .iter()
+            .enumerate()
+            .map(|(i, &weight)| weight * self.deque[(self.index + i) % self.period])
+            .sum()
+    }
+}
+
+impl_next_option!(Alma);
+
+impl<T: Close> Next<&T> for Alma {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for Alma {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+        self.last_output = None;
+    }
+}
+
+impl Default for Alma {
+    fn default() -> Self {
+        Self::new(9, 0.85, 6.0).unwrap()
+    }
+}
+
+impl fmt::Display for Alma {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ALMA({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Alma::new(0, 0.85, 6.0).is_err());
+        assert!(Alma::new(9, -0.1, 6.0).is_err());
+        assert!(Alma::new(9, 1.1, 6.0).is_err());
+        assert!(Alma::new(9, 0.85, 0.0).is_err());
+        assert!(Alma::new(9, 0.85, f64::NAN).is_err());
+        assert!(Alma::new(1, 0.0, 1.0).is_ok());
+        assert!(Alma::new(9, 1.0, 6.0).is_ok());
+    }
+
+    #[test]
+    fn test_weights() {
+        for &(period, offset, sigma) in [(1, 0.85, 6.0), (9, 0.85, 6.0), (50, 0.2, 1.5)].iter() {
+            let weights = gaussian_weights(period, offset, sigma);
+            assert_eq!(weights.len(), period);
+            assert_eq!(round(weights.iter().sum()), 1.0);
+        }
+
+        // the largest weight is at offset * (period - 1)
+        let weights = gaussian_weights(9, 0.85, 6.0);
+        let max = weights.iter().cloned().fold(f64::MIN, f64::max);
+        assert_eq!(weights[7], max);
+        assert!(weights[0] < weights[8]);
+    }
+
+    #[test]
+    fn test_next() {
+        let mut alma = Alma::new(3, 1.0, 2.0).unwrap();
+        assert_eq!(alma.lookback(), 2);
+
+        // weights exp(-8/9), exp(-2/9), 1 normalized: 0.186, 0.362, 0.452
+        assert!(alma.next(4.0).is_nan());
+        assert!(alma.next(&Bar::new().close(8)).is_nan());
+        assert_eq!(round(alma.next(2.0)), 4.544);
+        assert_eq!(round(alma.next(6.0)), 4.924);
+    }
+
+    #[test]
+    fn test_constant() {
+        let mut alma = Alma::default();
+        for i in 0..30 {
+            let value = alma.next(42.0);
+            if i < 8 {
+                assert!(value.is_nan());
+            } else {
+                assert_eq!(round(value), 42.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut alma = Alma::new(2, 0.5, 1.0).unwrap();
+        alma.next(4.0);
+        alma.next(8.0);
+
+        alma.reset();
+        assert!(alma.next(5.0).is_nan());
+        assert_eq!(alma.next(7.0), 6.0);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut alma = Alma::default();
+        for price in 10..19 {
+            alma.next(price as f64);
+        }
+        let restored = serde_round_trip(&alma);
+        assert_eq!(restored, alma);
+    }
+
+    #[test]
+    fn test_default() {
+        Alma::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Alma::default()), "ALMA(9)");
+    }
+}
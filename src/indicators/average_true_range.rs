@@ -1,8 +1,10 @@
-use std::fmt;
+use alloc::vec::Vec;
+use core::fmt;
 
 use crate::errors::Result;
+use crate::helpers::{batch, warmup_tail};
 use crate::indicators::{ExponentialMovingAverage, TrueRange};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::{Close, High, Low, Next, Period, Reset, Warmup};
 use serde::{Deserialize, Serialize};
 
 /// Average true range (ATR).
@@ -59,6 +61,8 @@ use serde::{Deserialize, Serialize};
 pub struct AverageTrueRange {
     true_range: TrueRange,
     ema: ExponentialMovingAverage,
+    #[serde(default)]
+    last_output: Option<f64>,
 }
 
 impl AverageTrueRange {
@@ -66,8 +70,35 @@ impl AverageTrueRange {
         Ok(Self {
             true_range: TrueRange::new(),
             ema: ExponentialMovingAverage::new(period)?,
+            last_output: None,
         })
     }
+
+    /// Creates an ATR fed with every bar of `warmup`.
+    ///
+    /// Like its EMA, the ATR remembers all of its inputs, so the whole of `warmup` is used.
+    /// The EMA is seeded with the first true range, so any warmup is enough, even an empty one.
+    ///
+    /// Returns `TaError::InsufficientData` if `warmup` has fewer bars than the lookback.
+    pub fn ready<T: High + Low + Close>(period: usize, warmup: &[T]) -> Result<Self> {
+        let mut atr = Self::new(period)?;
+        warmup_tail(warmup, atr.lookback())?;
+        for bar in warmup {
+            atr.next(bar);
+        }
+        Ok(atr)
+    }
+
+    /// Computes the ATR of every bar of `inputs`.
+    ///
+    /// The EMA is seeded with the first true range, so there is no NaN warmup (see
+    /// [Warmup](../trait.Warmup.html)) and the outputs are those of `next`.
+    ///
+    /// Returns `TaError::InvalidParameter` if `period` is 0.
+    pub fn calculate<T: High + Low + Close>(period: usize, inputs: &[T]) -> Result<Vec<f64>> {
+        let mut atr = Self::new(period)?;
+        Ok(batch(atr.lookback(), inputs, |bar| atr.next(bar)))
+    }
 }
 
 impl Period for AverageTrueRange {
@@ -76,19 +107,27 @@ impl Period for AverageTrueRange {
     }
 }
 
-impl Next<f64> for AverageTrueRange {
-    type Output = f64;
+impl Warmup for AverageTrueRange {
+    fn lookback(&self) -> usize {
+        self.ema.lookback()
+    }
+}
 
-    fn next(&mut self, input: f64) -> Self::Output {
+impl AverageTrueRange {
+    fn next_value(&mut self, input: f64) -> f64 {
         self.ema.next(self.true_range.next(input))
     }
 }
 
+impl_next_option!(AverageTrueRange);
+
 impl<T: High + Low + Close> Next<&T> for AverageTrueRange {
     type Output = f64;
 
     fn next(&mut self, input: &T) -> Self::Output {
-        self.ema.next(self.true_range.next(input))
+        let output = self.ema.next(self.true_range.next(input));
+        self.last_output = Some(output);
+        output
     }
 }
 
@@ -96,6 +135,7 @@ impl Reset for AverageTrueRange {
     fn reset(&mut self) {
         self.true_range.reset();
         self.ema.reset();
+        self.last_output = None;
     }
 }
 
@@ -136,6 +176,43 @@ mod tests {
         assert_eq!(atr.next(&bar3), 3.375);
     }
 
+    #[test]
+    fn test_calculate() {
+        let bars: Vec<Bar> = (0..5)
+            .map(|i| Bar::new().high(i + 2).low(i).close(i + 1))
+            .collect();
+        assert_eq!(AverageTrueRange::new(3).unwrap().lookback(), 0);
+
+        let out = AverageTrueRange::calculate(3, &bars).unwrap();
+        assert_eq!(out, [2.0, 2.0, 2.0, 2.0, 2.0]);
+
+        // the same outputs as next, from the first bar
+        let mut atr = AverageTrueRange::new(3).unwrap();
+        let outputs: Vec<f64> = bars.iter().map(|bar| atr.next(bar)).collect();
+        assert_eq!(out, outputs);
+
+        assert!(AverageTrueRange::calculate(0, &bars).is_err());
+    }
+
+    #[test]
+    fn test_ready() {
+        use crate::errors::TaError;
+
+        let bar1 = Bar::new().high(10).low(7.5).close(9);
+        let bar2 = Bar::new().high(11).low(9).close(9.5);
+        let bar3 = Bar::new().high(9).low(5).close(8);
+
+        assert_eq!(
+            AverageTrueRange::ready::<Bar>(0, &[]),
+            Err(TaError::InvalidParameter)
+        );
+        assert!(AverageTrueRange::ready::<Bar>(2, &[]).is_ok());
+
+        // the whole warmup is used
+        let mut atr = AverageTrueRange::ready(3, &[bar1, bar2]).unwrap();
+        assert_eq!(atr.next(&bar3), 3.375);
+    }
+
     #[test]
     fn test_reset() {
         let mut atr = AverageTrueRange::new(9).unwrap();
@@ -0,0 +1,207 @@
+use alloc::{boxed::Box, vec};
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{PairIndicator, Period, Reset};
+use serde::{Deserialize, Serialize};
+
+/// Beta.
+///
+/// Sensitivity of a series _x_ to a benchmark _y_ over the last _period_ points: the slope of
+/// the least squares regression of _x_ on _y_. In pairs trading it is the hedge ratio, the
+/// number of units of _y_ that offsets one unit of _x_.
+///
+/// # Formula
+///
+/// Beta = cov(x, y) / var(y)
+///
+/// The output is NaN until _period_ points are known, and while _y_ is constant over the
+/// window.
+///
+/// # Parameters
+///
+/// * _period_ - number of points (integer greater than 1). Default is 30.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::Beta;
+/// use tam::Next;
+///
+/// let mut beta = Beta::new(3).unwrap();
+/// assert!(beta.next((2.0, 1.0)).is_nan());
+/// assert!(beta.next((4.0, 2.0)).is_nan());
+/// assert_eq!(beta.next((6.0, 3.0)), 2.0);
+/// ```
+///
+/// # Links
+///
+/// * [Beta (finance), Wikipedia](https://en.wikipedia.org/wiki/Beta_(finance))
+///
+#[doc(alias = "BETA")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Beta {
+    period: usize,
+    index: usize,
+    count: usize,
+    sum_x: f64,
+    sum_y: f64,
+    sum_xy: f64,
+    sum_y2: f64,
+    values_x: Box<[f64]>,
+    values_y: Box<[f64]>,
+}
+
+impl Beta {
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 2 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            index: 0,
+            count: 0,
+            sum_x: 0.0,
+            sum_y: 0.0,
+            sum_xy: 0.0,
+            sum_y2: 0.0,
+            values_x: vec![0.0; period].into_boxed_slice(),
+            values_y: vec![0.0; period].into_boxed_slice(),
+        })
+    }
+}
+
+impl Period for Beta {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl PairIndicator for Beta {
+    fn next_pair(&mut self, x: f64, y: f64) -> f64 {
+        let (old_x, old_y) = (self.values_x[self.index], self.values_y[self.index]);
+        self.values_x[self.index] = x;
+        self.values_y[self.index] = y;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        // the buffers start with zeros, which add nothing to the sums
+        self.sum_x += x - old_x;
+        self.sum_y += y - old_y;
+        self.sum_xy += x * y - old_x * old_y;
+        self.sum_y2 += y * y - old_y * old_y;
+
+        if self.count < self.period {
+            self.count += 1;
+        }
+        if self.count < self.period {
+            return f64::NAN;
+        }
+
+        let n = self.period as f64;
+        let covariance = self.sum_xy - self.sum_x * self.sum_y / n;
+        let variance = self.sum_y2 - self.sum_y * self.sum_y / n;
+        if variance <= 0.0 {
+            return f64::NAN;
+        }
+        covariance / variance
+    }
+}
+
+impl Reset for Beta {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sum_x = 0.0;
+        self.sum_y = 0.0;
+        self.sum_xy = 0.0;
+        self.sum_y2 = 0.0;
+        for i in 0..self.period {
+            self.values_x[i] = 0.0;
+            self.values_y[i] = 0.0;
+        }
+    }
+}
+
+impl Default for Beta {
+    fn default() -> Self {
+        Self::new(30).unwrap()
+    }
+}
+
+impl fmt::Display for Beta {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BETA({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use crate::Next;
+
+    #[test]
+    fn test_new() {
+        assert!(Beta::new(0).is_err());
+        assert!(Beta::new(1).is_err());
+        assert!(Beta::new(2).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut beta = Beta::new(3).unwrap();
+
+        assert!(beta.next((2.0, 1.0)).is_nan());
+        assert!(beta.next((3.0, 3.0)).is_nan());
+        // x: 2, 3, 5 and y: 1, 3, 2, co-deviation 1 and y deviation 2
+        assert_eq!(round(beta.next_pair(5.0, 2.0)), 0.5);
+        // x: 3, 5, 11 and y: 3, 2, 6, co-deviation 15.333 and y deviation 8.667
+        assert_eq!(round(beta.next((11.0, 6.0))), 1.769);
+    }
+
+    #[test]
+    fn test_hedge_ratio() {
+        // x moves 1.5 times as much as y, plus an offset
+        let mut beta = Beta::new(5).unwrap();
+        for i in 0..20 {
+            let y = 10.0 + (i as f64 * 0.9).sin();
+            let value = beta.next((3.0 + 1.5 * y, y));
+            if i >= 4 {
+                assert_eq!(round(value), 1.5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_constant_benchmark() {
+        let mut beta = Beta::new(2).unwrap();
+        beta.next((1.0, 4.0));
+        assert!(beta.next((2.0, 4.0)).is_nan());
+        assert_eq!(beta.next((4.0, 5.0)), 2.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut beta = Beta::new(2).unwrap();
+        beta.next((1.0, 1.0));
+        beta.next((2.0, 3.0));
+
+        beta.reset();
+        assert!(beta.next((1.0, 1.0)).is_nan());
+        assert_eq!(beta.next((4.0, 2.0)), 3.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Beta::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Beta::new(30).unwrap()), "BETA(30)");
+    }
+}
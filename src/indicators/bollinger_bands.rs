@@ -1,4 +1,4 @@
-use std::fmt;
+use core::fmt;
 
 use crate::errors::Result;
 use crate::indicators::StandardDeviation as Sd;
@@ -52,13 +52,15 @@ pub struct BollingerBands {
     sd: Sd,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BollingerBandsOutput {
     pub average: f64,
     pub upper: f64,
     pub lower: f64,
 }
 
+impl_output!(BollingerBandsOutput, average, upper, lower);
+
 impl BollingerBands {
     pub fn new(period: usize, multiplier: f64) -> Result<Self> {
         Ok(Self {
@@ -134,6 +136,23 @@ mod tests {
         assert!(BollingerBands::new(2, 2_f64).is_ok());
     }
 
+    #[test]
+    fn test_named_output() {
+        use crate::NamedOutput;
+
+        let mut bb = BollingerBands::new(3, 2.0_f64).unwrap();
+        bb.next(2.0);
+        let out = bb.next(5.0);
+        let map = out.to_named_f64();
+
+        let mut names = BollingerBandsOutput::field_names().to_vec();
+        names.sort();
+        assert_eq!(map.keys().cloned().collect::<Vec<_>>(), names);
+        assert_eq!(map["average"], out.average);
+        assert_eq!(map["upper"], out.upper);
+        assert_eq!(map["lower"], out.lower);
+    }
+
     #[test]
     fn test_next() {
         let mut bb = BollingerBands::new(3, 2.0_f64).unwrap();
@@ -0,0 +1,173 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::BollingerBands;
+use crate::{Close, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Bollinger Bandwidth.
+///
+/// Width of the [Bollinger Bands](struct.BollingerBands.html) relative to the middle band. A
+/// low bandwidth marks a volatility contraction (the "squeeze").
+///
+/// # Formula
+///
+/// BBW = (BB<sub>Upper</sub> - BB<sub>Lower</sub>) / BB<sub>Middle</sub>
+///
+/// The output is NaN until _period_ prices are known, and 0 when the middle band is 0.
+///
+/// # Parameters
+///
+/// * _period_ - period of the bands (integer greater than 0). Default is 20.
+/// * _multiplier_ - number of standard deviations of the bands. Default is 2.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::BollingerBandwidth;
+/// use tam::Next;
+///
+/// let mut bandwidth = BollingerBandwidth::new(2, 1.0).unwrap();
+/// assert!(bandwidth.next(10.0).is_nan());
+/// // the bands are 10 and 12 around 11
+/// assert_eq!(bandwidth.next(12.0), 2.0 / 11.0);
+/// ```
+///
+/// # Links
+///
+/// * [Bollinger BandWidth, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:bollinger_band_width)
+///
+#[doc(alias = "BBW")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BollingerBandwidth {
+    bb: BollingerBands,
+    count: usize,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl BollingerBandwidth {
+    pub fn new(period: usize, multiplier: f64) -> Result<Self> {
+        Ok(Self {
+            bb: BollingerBands::new(period, multiplier)?,
+            count: 0,
+            last_output: None,
+        })
+    }
+}
+
+impl Period for BollingerBandwidth {
+    fn period(&self) -> usize {
+        self.bb.period()
+    }
+}
+
+impl Warmup for BollingerBandwidth {
+    fn lookback(&self) -> usize {
+        self.bb.period() - 1
+    }
+}
+
+impl BollingerBandwidth {
+    fn next_value(&mut self, input: f64) -> f64 {
+        let bb = self.bb.next(input);
+        if self.count < self.lookback() {
+            self.count += 1;
+            return f64::NAN;
+        }
+
+        if bb.average == 0.0 {
+            return 0.0;
+        }
+        (bb.upper - bb.lower) / bb.average
+    }
+}
+
+impl_next_option!(BollingerBandwidth);
+
+impl<T: Close> Next<&T> for BollingerBandwidth {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for BollingerBandwidth {
+    fn reset(&mut self) {
+        self.bb.reset();
+        self.count = 0;
+        self.last_output = None;
+    }
+}
+
+impl Default for BollingerBandwidth {
+    fn default() -> Self {
+        Self::new(20, 2.0).unwrap()
+    }
+}
+
+impl fmt::Display for BollingerBandwidth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BBW({})", self.bb.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(BollingerBandwidth::new(0, 2.0).is_err());
+        assert!(BollingerBandwidth::new(1, 2.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut bandwidth = BollingerBandwidth::new(3, 2.0).unwrap();
+
+        assert!(bandwidth.next(2.0).is_nan());
+        assert!(bandwidth.next(5.0).is_nan());
+        // bands -0.733 and 6.066 around 2.667
+        assert_eq!(round(bandwidth.next(&Bar::new().close(1))), 2.55);
+        // bands -0.395 and 8.562 around 4.083
+        assert_eq!(round(bandwidth.next(6.25)), 2.193);
+    }
+
+    #[test]
+    fn test_zero() {
+        // no width
+        let mut bandwidth = BollingerBandwidth::new(2, 2.0).unwrap();
+        bandwidth.next(4.0);
+        assert_eq!(bandwidth.next(4.0), 0.0);
+
+        // zero middle band
+        let mut bandwidth = BollingerBandwidth::new(2, 2.0).unwrap();
+        bandwidth.next(-1.0);
+        assert_eq!(bandwidth.next(1.0), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut bandwidth = BollingerBandwidth::new(2, 1.0).unwrap();
+        bandwidth.next(10.0);
+        bandwidth.next(12.0);
+
+        bandwidth.reset();
+        assert!(bandwidth.next(3.0).is_nan());
+        assert_eq!(bandwidth.next(5.0), 0.5);
+    }
+
+    #[test]
+    fn test_default() {
+        BollingerBandwidth::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let bandwidth = BollingerBandwidth::new(20, 2.0).unwrap();
+        assert_eq!(format!("{}", bandwidth), "BBW(20)");
+    }
+}
@@ -0,0 +1,183 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::BollingerBands;
+use crate::{Close, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Bollinger %B.
+///
+/// Position of the price within the [Bollinger Bands](struct.BollingerBands.html): 0 at the
+/// lower band, 1 at the upper band, above 1 or below 0 outside of the bands.
+///
+/// # Formula
+///
+/// %B = (Price - BB<sub>Lower</sub>) / (BB<sub>Upper</sub> - BB<sub>Lower</sub>)
+///
+/// The output is NaN until _period_ prices are known, and 0 when the bands have no width.
+///
+/// # Parameters
+///
+/// * _period_ - period of the bands (integer greater than 0). Default is 20.
+/// * _multiplier_ - number of standard deviations of the bands. Default is 2.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::BollingerPercentB;
+/// use tam::Next;
+///
+/// let mut percent_b = BollingerPercentB::new(2, 1.0).unwrap();
+/// assert!(percent_b.next(10.0).is_nan());
+/// // the bands are 10 and 12
+/// assert_eq!(percent_b.next(12.0), 1.0);
+/// ```
+///
+/// # Links
+///
+/// * [%B Indicator, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:bollinger_band_perce)
+///
+#[doc(alias = "%B")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BollingerPercentB {
+    bb: BollingerBands,
+    count: usize,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl BollingerPercentB {
+    pub fn new(period: usize, multiplier: f64) -> Result<Self> {
+        Ok(Self {
+            bb: BollingerBands::new(period, multiplier)?,
+            count: 0,
+            last_output: None,
+        })
+    }
+}
+
+impl Period for BollingerPercentB {
+    fn period(&self) -> usize {
+        self.bb.period()
+    }
+}
+
+impl Warmup for BollingerPercentB {
+    fn lookback(&self) -> usize {
+        self.bb.period() - 1
+    }
+}
+
+impl BollingerPercentB {
+    fn next_value(&mut self, input: f64) -> f64 {
+        let bb = self.bb.next(input);
+        if self.count < self.lookback() {
+            self.count += 1;
+            return f64::NAN;
+        }
+
+        let width = bb.upper - bb.lower;
+        if width == 0.0 {
+            return 0.0;
+        }
+        (input - bb.lower) / width
+    }
+}
+
+impl_next_option!(BollingerPercentB);
+
+impl<T: Close> Next<&T> for BollingerPercentB {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for BollingerPercentB {
+    fn reset(&mut self) {
+        self.bb.reset();
+        self.count = 0;
+        self.last_output = None;
+    }
+}
+
+impl Default for BollingerPercentB {
+    fn default() -> Self {
+        Self::new(20, 2.0).unwrap()
+    }
+}
+
+impl fmt::Display for BollingerPercentB {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "%B({})", self.bb.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(BollingerPercentB::new(0, 2.0).is_err());
+        assert!(BollingerPercentB::new(1, 2.0).is_ok());
+    }
+
+    #[test]
+    fn test_bands() {
+        // with 2 prices and a multiplier of 1 the bands are the two prices
+        let mut percent_b = BollingerPercentB::new(2, 1.0).unwrap();
+        assert!(percent_b.next(10.0).is_nan());
+
+        // at the upper band
+        assert_eq!(percent_b.next(12.0), 1.0);
+        // at the lower band
+        assert_eq!(percent_b.next(&Bar::new().close(8)), 0.0);
+        assert_eq!(percent_b.next(9.0), 1.0);
+    }
+
+    #[test]
+    fn test_next() {
+        let mut percent_b = BollingerPercentB::new(3, 2.0).unwrap();
+
+        assert!(percent_b.next(2.0).is_nan());
+        assert!(percent_b.next(5.0).is_nan());
+        // bands -0.733 and 6.066
+        assert_eq!(round(percent_b.next(1.0)), 0.255);
+        // bands -0.395 and 8.562
+        assert_eq!(round(percent_b.next(6.25)), 0.742);
+    }
+
+    #[test]
+    fn test_flat() {
+        let mut percent_b = BollingerPercentB::new(3, 2.0).unwrap();
+        for _ in 0..3 {
+            percent_b.next(4.0);
+        }
+        assert_eq!(percent_b.next(4.0), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut percent_b = BollingerPercentB::new(2, 1.0).unwrap();
+        percent_b.next(10.0);
+        percent_b.next(12.0);
+
+        percent_b.reset();
+        assert!(percent_b.next(12.0).is_nan());
+        assert_eq!(percent_b.next(10.0), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        BollingerPercentB::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let percent_b = BollingerPercentB::new(20, 2.0).unwrap();
+        assert_eq!(format!("{}", percent_b), "%B(20)");
+    }
+}
@@ -0,0 +1,275 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{Maximum, Minimum};
+use crate::{Close, High, Low, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Signal emitted by [Breakout](struct.Breakout.html).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum BreakoutSignal {
+    /// The close stayed within the channel.
+    None,
+    /// The close rose above the upper band of the channel.
+    UpperBreak,
+    /// The close fell below the lower band of the channel.
+    LowerBreak,
+}
+
+/// Donchian channel breakout.
+///
+/// Signals a close outside the Donchian channel, the highest high and the lowest low of the
+/// last _period_ bars, as in the Turtle trading rules.
+///
+/// # Prior bar's channel
+///
+/// The close is compared with the channel of the _period_ bars **before** the current one.
+/// Using the channel of the current bar is a subtle lookahead bug: it already contains the
+/// high and low of the bar being evaluated, so the close can never break it, and a breakout
+/// tested on the high compares the bar with itself. The first signal is therefore available
+/// at the input at index _period_.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars of the channel (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::{Breakout, BreakoutSignal};
+/// use tam::{DataItem, Next};
+///
+/// let bar = |high, low, close| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut breakout = Breakout::new(2).unwrap();
+/// breakout.next(&bar(11.0, 9.0, 10.0));
+/// breakout.next(&bar(12.0, 10.0, 11.0));
+/// // channel 9..12
+/// assert_eq!(breakout.next(&bar(12.5, 11.0, 12.5)), BreakoutSignal::UpperBreak);
+/// assert_eq!(breakout.next(&bar(12.5, 10.5, 11.0)), BreakoutSignal::None);
+/// ```
+///
+/// # Links
+///
+/// * [Donchian channel, Wikipedia](https://en.wikipedia.org/wiki/Donchian_channel)
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Breakout {
+    highest: Maximum,
+    lowest: Minimum,
+    count: usize,
+    /// Lower and upper bands of the channel up to the previous bar, once it spans _period_
+    /// bars.
+    channel: Option<(f64, f64)>,
+}
+
+impl Breakout {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            highest: Maximum::new(period)?,
+            lowest: Minimum::new(period)?,
+            count: 0,
+            channel: None,
+        })
+    }
+
+    /// Lower and upper bands the next close is compared with, `None` during the warmup.
+    pub fn channel(&self) -> Option<(f64, f64)> {
+        self.channel
+    }
+}
+
+impl Period for Breakout {
+    fn period(&self) -> usize {
+        self.highest.period()
+    }
+}
+
+impl Warmup for Breakout {
+    fn lookback(&self) -> usize {
+        self.highest.period()
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for Breakout {
+    type Output = BreakoutSignal;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let close = input.close();
+        let signal = match self.channel {
+            Some((_, upper)) if close > upper => BreakoutSignal::UpperBreak,
+            Some((lower, _)) if close < lower => BreakoutSignal::LowerBreak,
+            _ => BreakoutSignal::None,
+        };
+
+        // the channel of the next bar includes this one
+        let upper = self.highest.next(input.high());
+        let lower = self.lowest.next(input.low());
+        if self.count < self.period() {
+            self.count += 1;
+        }
+        if self.count == self.period() {
+            self.channel = Some((lower, upper));
+        }
+
+        signal
+    }
+}
+
+impl Reset for Breakout {
+    fn reset(&mut self) {
+        self.highest.reset();
+        self.lowest.reset();
+        self.count = 0;
+        self.channel = None;
+    }
+}
+
+impl Default for Breakout {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for Breakout {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BREAKOUT({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Breakout::new(0).is_err());
+        assert!(Breakout::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_upper_break() {
+        let mut breakout = Breakout::new(3).unwrap();
+        assert_eq!(breakout.lookback(), 3);
+
+        // far outside, but the channel is not complete yet
+        assert_eq!(
+            breakout.next(&Bar::new().high(11.0).low(9.0).close(10.0)),
+            BreakoutSignal::None
+        );
+        assert_eq!(
+            breakout.next(&Bar::new().high(20.0).low(10.0).close(20.0)),
+            BreakoutSignal::None
+        );
+        assert_eq!(
+            breakout.next(&Bar::new().high(12.0).low(10.0).close(11.0)),
+            BreakoutSignal::None
+        );
+        assert_eq!(breakout.channel(), Some((9.0, 20.0)));
+
+        assert_eq!(
+            breakout.next(&Bar::new().high(19.0).low(15.0).close(19.0)),
+            BreakoutSignal::None
+        );
+        assert_eq!(
+            breakout.next(&Bar::new().high(19.5).low(18.0).close(19.5)),
+            BreakoutSignal::None
+        );
+        // the 20 high has left the channel
+        assert_eq!(breakout.channel(), Some((10.0, 19.5)));
+        assert_eq!(
+            breakout.next(&Bar::new().high(20.0).low(18.0).close(20.0)),
+            BreakoutSignal::UpperBreak
+        );
+        // a close equal to the band is not a break
+        assert_eq!(breakout.channel(), Some((15.0, 20.0)));
+        assert_eq!(
+            breakout.next(&Bar::new().high(20.0).low(18.0).close(20.0)),
+            BreakoutSignal::None
+        );
+    }
+
+    #[test]
+    fn test_lower_break() {
+        let mut breakout = Breakout::new(2).unwrap();
+        breakout.next(&Bar::new().high(11.0).low(9.0).close(10.0));
+        breakout.next(&Bar::new().high(10.0).low(8.0).close(9.0));
+        // the close is above the low of its own bar, only the prior channel counts
+        assert_eq!(
+            breakout.next(&Bar::new().high(9.0).low(7.0).close(7.5)),
+            BreakoutSignal::LowerBreak
+        );
+        assert_eq!(
+            breakout.next(&Bar::new().high(8.0).low(7.0).close(7.5)),
+            BreakoutSignal::None
+        );
+        assert_eq!(
+            breakout.next(&Bar::new().high(7.5).low(6.0).close(6.5)),
+            BreakoutSignal::LowerBreak
+        );
+    }
+
+    #[test]
+    fn test_no_lookahead() {
+        // closing at a new high breaks the prior channel every time, while it would never be
+        // above a channel including the bar
+        let mut breakout = Breakout::new(2).unwrap();
+        breakout.next(&Bar::new().high(10.0).low(9.0).close(10.0));
+        breakout.next(&Bar::new().high(10.0).low(9.0).close(10.0));
+        let signals: Vec<BreakoutSignal> = (1..=5)
+            .map(|i| {
+                let high = 10.0 + i as f64;
+                breakout.next(&Bar::new().high(high).low(high - 1.0).close(high))
+            })
+            .collect();
+        assert!(signals.iter().all(|&s| s == BreakoutSignal::UpperBreak));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut breakout = Breakout::new(1).unwrap();
+        breakout.next(&Bar::new().high(11.0).low(9.0).close(10.0));
+        assert_eq!(
+            breakout.next(&Bar::new().high(13.0).low(9.0).close(12.0)),
+            BreakoutSignal::UpperBreak
+        );
+
+        breakout.reset();
+        assert_eq!(breakout.channel(), None);
+        assert_eq!(
+            breakout.next(&Bar::new().high(13.0).low(9.0).close(12.0)),
+            BreakoutSignal::None
+        );
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut breakout = Breakout::new(2).unwrap();
+        breakout.next(&Bar::new().high(11.0).low(9.0).close(10.0));
+        breakout.next(&Bar::new().high(12.0).low(10.0).close(11.0));
+        let mut restored = serde_round_trip(&breakout);
+        let b = Bar::new().high(13.0).low(11.0).close(12.5);
+        assert_eq!(restored.next(&b), breakout.next(&b));
+        assert_eq!(restored.channel(), breakout.channel());
+    }
+
+    #[test]
+    fn test_default() {
+        Breakout::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Breakout::default()), "BREAKOUT(20)");
+    }
+}
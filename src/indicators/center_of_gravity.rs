@@ -0,0 +1,178 @@
+use alloc::collections::VecDeque;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Reset};
+use serde::{Deserialize, Serialize};
+
+/// Center of Gravity (COG).
+///
+/// John Ehlers' oscillator that locates the balance point of the prices in the window, the
+/// most recent price having the lowest weight. It has essentially zero lag and turns ahead of
+/// the price, which makes its crossover with its own previous value a turning point signal
+/// (see [CogCross](../signals/struct.CogCross.html)).
+///
+/// # Formula
+///
+/// COG = -(1 * p<sub>t</sub> + 2 * p<sub>t-1</sub> + ... + _n_ * p<sub>t-n+1</sub>) /
+/// (p<sub>t</sub> + p<sub>t-1</sub> + ... + p<sub>t-n+1</sub>)
+///
+/// Where _n_ is the period. The values oscillate around -(_n_ + 1) / 2. A window whose prices
+/// sum to 0 gives 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 10.
+///
+/// The output is NaN until the window is filled.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::CenterOfGravity;
+/// use tam::Next;
+///
+/// let mut cog = CenterOfGravity::new(3).unwrap();
+/// assert!(cog.next(10.0).is_nan());
+/// assert!(cog.next(10.0).is_nan());
+/// assert_eq!(cog.next(10.0), -2.0);
+/// ```
+///
+/// # Links
+///
+/// * [The CG Oscillator, John Ehlers](https://www.mesasoftware.com/papers/TheCGOscillator.pdf)
+///
+#[doc(alias = "COG")]
+#[doc(alias = "CG")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CenterOfGravity {
+    period: usize,
+    window: VecDeque<f64>,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl CenterOfGravity {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                window: VecDeque::with_capacity(period),
+                last_output: None,
+            }),
+        }
+    }
+}
+
+impl Period for CenterOfGravity {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl CenterOfGravity {
+    fn next_value(&mut self, input: f64) -> f64 {
+        if self.window.len() == self.period {
+            self.window.pop_back();
+        }
+        self.window.push_front(input);
+
+        if self.window.len() < self.period {
+            return f64::NAN;
+        }
+
+        let (mut num, mut den) = (0.0, 0.0);
+        for (i, &price) in self.window.iter().enumerate() {
+            num += (i + 1) as f64 * price;
+            den += price;
+        }
+
+        if den == 0.0 {
+            0.0
+        } else {
+            -num / den
+        }
+    }
+}
+
+impl_next_option!(CenterOfGravity);
+
+impl<T: Close> Next<&T> for CenterOfGravity {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for CenterOfGravity {
+    fn reset(&mut self) {
+        self.window.clear();
+        self.last_output = None;
+    }
+}
+
+impl Default for CenterOfGravity {
+    fn default() -> Self {
+        Self::new(10).unwrap()
+    }
+}
+
+impl fmt::Display for CenterOfGravity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "COG({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(CenterOfGravity::new(0).is_err());
+        assert!(CenterOfGravity::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut cog = CenterOfGravity::new(3).unwrap();
+
+        assert!(cog.next(1.0).is_nan());
+        assert!(cog.next(&Bar::new().close(2)).is_nan());
+        // -(1 * 3 + 2 * 2 + 3 * 1) / 6
+        assert_eq!(round(cog.next(3.0)), -1.667);
+        // -(1 * 1 + 2 * 3 + 3 * 2) / 6
+        assert_eq!(round(cog.next(1.0)), -2.167);
+    }
+
+    #[test]
+    fn test_zero_sum() {
+        let mut cog = CenterOfGravity::new(2).unwrap();
+        cog.next(-1.0);
+        assert_eq!(cog.next(1.0), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut cog = CenterOfGravity::new(2).unwrap();
+        cog.next(1.0);
+        assert!(!cog.next(2.0).is_nan());
+
+        cog.reset();
+        assert!(cog.next(2.0).is_nan());
+    }
+
+    #[test]
+    fn test_default() {
+        CenterOfGravity::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let cog = CenterOfGravity::new(8).unwrap();
+        assert_eq!(format!("{}", cog), "COG(8)");
+    }
+}
@@ -0,0 +1,277 @@
+use alloc::{boxed::Box, vec};
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, High, Low, Next, Period, Reset, Volume, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Chaikin Money Flow (CMF).
+///
+/// The volume-weighted average of where the closes sit in their bars' ranges over the last
+/// _period_ bars. It oscillates between -1 and 1: positive values show buying pressure
+/// (closes near the highs on volume), negative values selling pressure.
+///
+/// # Formula
+///
+/// Money Flow Multiplier (MFM) = ((Close - Low) - (High - Close)) / (High - Low)
+///
+/// Money Flow Volume (MFV) = MFM * Volume
+///
+/// CMF = sum(MFV, _period_) / sum(Volume, _period_)
+///
+/// The multiplier is the one of the Accumulation/Distribution line. A bar without range (High
+/// = Low) has a multiplier of 0, and the CMF is 0 when the window has no volume. The output is
+/// NaN until the window is full.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::ChaikinMoneyFlow;
+/// use tam::{DataItem, Next};
+///
+/// let mut cmf = ChaikinMoneyFlow::new(2).unwrap();
+/// let bar = |high, low, close, volume| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(volume)
+///         .build()
+///         .unwrap()
+/// };
+/// assert!(cmf.next(&bar(12.0, 10.0, 12.0, 100.0)).is_nan());
+/// // (1 * 100 - 0.5 * 300) / 400
+/// assert_eq!(cmf.next(&bar(12.0, 8.0, 9.0, 300.0)), -0.125);
+/// ```
+///
+/// # Links
+///
+/// * [Chaikin Money Flow, StockCharts](https://chartschool.stockcharts.com/table-of-contents/technical-indicators-and-overlays/technical-indicators/chaikin-money-flow-cmf)
+///
+#[doc(alias = "CMF")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ChaikinMoneyFlow {
+    period: usize,
+    index: usize,
+    count: usize,
+    sum_flow_volume: f64,
+    sum_volume: f64,
+    /// Money flow volume and volume of the bars in the window.
+    deque: Box<[(f64, f64)]>,
+}
+
+impl ChaikinMoneyFlow {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                sum_flow_volume: 0.0,
+                sum_volume: 0.0,
+                deque: vec![(0.0, 0.0); period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+/// Position of the close in the range of the bar, from -1 at the low to 1 at the high.
+fn money_flow_multiplier(high: f64, low: f64, close: f64) -> f64 {
+    let range = high - low;
+    if range > 0.0 {
+        ((close - low) - (high - close)) / range
+    } else {
+        0.0
+    }
+}
+
+impl Period for ChaikinMoneyFlow {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Warmup for ChaikinMoneyFlow {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl<T: High + Low + Close + Volume> Next<&T> for ChaikinMoneyFlow {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let volume = input.volume();
+        let flow_volume = money_flow_multiplier(input.high(), input.low(), input.close()) * volume;
+
+        let (old_flow_volume, old_volume) = self.deque[self.index];
+        self.deque[self.index] = (flow_volume, volume);
+        self.sum_flow_volume += flow_volume - old_flow_volume;
+        self.sum_volume += volume - old_volume;
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count < self.period {
+            self.count += 1;
+        }
+        if self.count < self.period {
+            return f64::NAN;
+        }
+
+        if self.sum_volume > 0.0 {
+            self.sum_flow_volume / self.sum_volume
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Reset for ChaikinMoneyFlow {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sum_flow_volume = 0.0;
+        self.sum_volume = 0.0;
+        for i in 0..self.period {
+            self.deque[i] = (0.0, 0.0);
+        }
+    }
+}
+
+impl Default for ChaikinMoneyFlow {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for ChaikinMoneyFlow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CMF({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(ChaikinMoneyFlow::new(0).is_err());
+        assert!(ChaikinMoneyFlow::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_multiplier() {
+        assert_eq!(money_flow_multiplier(12.0, 10.0, 12.0), 1.0);
+        assert_eq!(money_flow_multiplier(12.0, 10.0, 10.0), -1.0);
+        assert_eq!(money_flow_multiplier(12.0, 10.0, 11.0), 0.0);
+        assert_eq!(money_flow_multiplier(12.0, 8.0, 9.0), -0.5);
+        assert_eq!(money_flow_multiplier(10.0, 10.0, 10.0), 0.0);
+    }
+
+    #[test]
+    fn test_ground_truth() {
+        // high, low, close, volume
+        let bars = [
+            (62.34, 61.37, 62.15, 7849.0),
+            (62.05, 60.69, 60.81, 11692.0),
+            (62.27, 60.10, 60.45, 10575.0),
+            (60.79, 58.61, 59.18, 13059.0),
+            (59.93, 58.71, 59.24, 20734.0),
+            (61.75, 59.86, 60.20, 29630.0),
+        ];
+        let mut cmf = ChaikinMoneyFlow::new(4).unwrap();
+        assert_eq!(cmf.lookback(), 3);
+
+        let outputs: Vec<f64> = bars
+            .iter()
+            .map(|&(h, l, c, v)| round(cmf.next(&Bar::new().high(h).low(l).close(c).volume(v))))
+            .collect();
+        assert_eq!(warmup_nan_count(&outputs), 3);
+        assert_eq!(&outputs[3..], &[-0.423, -0.459, -0.474]);
+    }
+
+    #[test]
+    fn test_range() {
+        let mut cmf = ChaikinMoneyFlow::new(3).unwrap();
+        for _ in 0..5 {
+            cmf.next(&Bar::new().high(12).low(10).close(12).volume(100.0));
+        }
+        assert_eq!(
+            cmf.next(&Bar::new().high(12).low(10).close(12).volume(50.0)),
+            1.0
+        );
+
+        for _ in 0..3 {
+            cmf.next(&Bar::new().high(12).low(10).close(10).volume(100.0));
+        }
+        assert_eq!(
+            cmf.next(&Bar::new().high(9).low(8).close(8).volume(1.0)),
+            -1.0
+        );
+    }
+
+    #[test]
+    fn test_no_volume_or_range() {
+        let mut cmf = ChaikinMoneyFlow::new(2).unwrap();
+        cmf.next(&Bar::new().high(12).low(10).close(12).volume(0.0));
+        assert_eq!(
+            cmf.next(&Bar::new().high(12).low(10).close(10).volume(0.0)),
+            0.0
+        );
+
+        let mut cmf = ChaikinMoneyFlow::new(2).unwrap();
+        cmf.next(&Bar::new().high(10).low(10).close(10).volume(100.0));
+        assert_eq!(
+            cmf.next(&Bar::new().high(10).low(10).close(10).volume(100.0)),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut cmf = ChaikinMoneyFlow::new(2).unwrap();
+        cmf.next(&Bar::new().high(12).low(10).close(12).volume(100.0));
+        cmf.next(&Bar::new().high(12).low(10).close(10).volume(300.0));
+
+        cmf.reset();
+        assert!(cmf
+            .next(&Bar::new().high(12).low(10).close(12).volume(100.0))
+            .is_nan());
+        assert_eq!(
+            cmf.next(&Bar::new().high(12).low(10).close(12).volume(100.0)),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut cmf = ChaikinMoneyFlow::new(3).unwrap();
+        cmf.next(&Bar::new().high(12).low(10).close(11.5).volume(100.0));
+        cmf.next(&Bar::new().high(12).low(9).close(9.5).volume(200.0));
+        let mut restored = serde_round_trip(&cmf);
+        let bar = Bar::new().high(11).low(10).close(10.8).volume(150.0);
+        assert_eq!(restored.next(&bar), cmf.next(&bar));
+    }
+
+    #[test]
+    fn test_default() {
+        ChaikinMoneyFlow::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", ChaikinMoneyFlow::default()), "CMF(20)");
+    }
+}
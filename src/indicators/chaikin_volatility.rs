@@ -0,0 +1,220 @@
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{ExponentialMovingAverage as Ema, RateOfChange as Roc};
+use crate::{High, Low, Next, Period, Periods, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Chaikin Volatility.
+///
+/// Developed by Marc Chaikin, measures volatility as the rate of change of the average
+/// high-low range: a rising value means the ranges widen, a falling one that they narrow.
+///
+/// # Formula
+///
+/// Chaikin Volatility = ROC(EMA(High - Low, _ema_period_), _roc_period_)
+///
+/// Where ROC is the [Rate of Change](struct.RateOfChange.html) in percent. The output is NaN
+/// until _ema_period_ ranges are averaged and _roc_period_ more bars are known, and 0 when
+/// the EMA _roc_period_ bars ago is 0.
+///
+/// # Parameters
+///
+/// * _ema_period_ - period of the EMA of the range (integer greater than 0). Default is 10.
+/// * _roc_period_ - number of bars of the rate of change (integer greater than 0). Default
+///   is 10.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::ChaikinVolatility;
+/// use tam::{DataItem, Next};
+///
+/// let mut volatility = ChaikinVolatility::new(1, 1).unwrap();
+/// let bar = |range: f64| {
+///     DataItem::builder()
+///         .open(10.0).high(10.0 + range).low(10.0).close(10.0).volume(1.0)
+///         .build().unwrap()
+/// };
+///
+/// assert!(volatility.next(&bar(2.0)).is_nan());
+/// assert_eq!(volatility.next(&bar(3.0)), 50.0);
+/// ```
+///
+/// # Links
+///
+/// * [Chaikin Volatility, MetaStock](https://www.metastock.com/customer/resources/taaz/?p=120)
+///
+#[doc(alias = "CHAIKINVOL")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ChaikinVolatility {
+    ema: Ema,
+    roc: Roc,
+    count: usize,
+}
+
+impl ChaikinVolatility {
+    pub fn new(ema_period: usize, roc_period: usize) -> Result<Self> {
+        Ok(Self {
+            ema: Ema::new(ema_period)?,
+            roc: Roc::new(roc_period)?,
+            count: 0,
+        })
+    }
+}
+
+impl Periods for ChaikinVolatility {
+    fn periods(&self) -> Vec<usize> {
+        vec![self.ema.period(), self.roc.period()]
+    }
+}
+
+impl Warmup for ChaikinVolatility {
+    fn lookback(&self) -> usize {
+        self.ema.period() - 1 + self.roc.period()
+    }
+}
+
+impl<T: High + Low> Next<&T> for ChaikinVolatility {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let ema = self.ema.next(input.high() - input.low());
+        // the EMA is seeded with the first range, but only counts as warm after its period
+        if self.count < self.ema.period() - 1 {
+            self.count += 1;
+            return f64::NAN;
+        }
+
+        // the rate of change starts at the first warmed up EMA value
+        let roc = self.roc.next(ema);
+        if self.count < self.lookback() {
+            self.count += 1;
+            return f64::NAN;
+        }
+        if roc.is_finite() {
+            roc
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Reset for ChaikinVolatility {
+    fn reset(&mut self) {
+        self.ema.reset();
+        self.roc.reset();
+        self.count = 0;
+    }
+}
+
+impl Default for ChaikinVolatility {
+    fn default() -> Self {
+        Self::new(10, 10).unwrap()
+    }
+}
+
+impl fmt::Display for ChaikinVolatility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CHAIKINVOL({}, {})",
+            self.ema.period(),
+            self.roc.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(ChaikinVolatility::new(0, 10).is_err());
+        assert!(ChaikinVolatility::new(10, 0).is_err());
+        assert!(ChaikinVolatility::new(1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        // EMA(2) of the ranges: 2, 3, 3.667, 2.556, 3.185
+        let mut volatility = ChaikinVolatility::new(2, 2).unwrap();
+
+        assert!(volatility.next(&Bar::new().high(102.0).low(100.0)).is_nan());
+        assert!(volatility.next(&Bar::new().high(103.5).low(100.0)).is_nan());
+        assert!(volatility.next(&Bar::new().high(104.0).low(100.0)).is_nan());
+        assert_eq!(
+            round(volatility.next(&Bar::new().high(102.0).low(100.0))),
+            -14.815
+        );
+        assert_eq!(
+            round(volatility.next(&Bar::new().high(103.5).low(100.0))),
+            -13.131
+        );
+    }
+
+    #[test]
+    fn test_warmup() {
+        let mut volatility = ChaikinVolatility::new(3, 4).unwrap();
+        assert_eq!(volatility.lookback(), 6);
+        for _ in 0..6 {
+            assert!(volatility.next(&Bar::new().high(101.0).low(100.0)).is_nan());
+        }
+        assert_eq!(volatility.next(&Bar::new().high(101.0).low(100.0)), 0.0);
+    }
+
+    #[test]
+    fn test_expanding_range() {
+        let mut volatility = ChaikinVolatility::new(3, 2).unwrap();
+        for _ in 0..5 {
+            volatility.next(&Bar::new().high(101.0).low(100.0));
+        }
+        assert_eq!(volatility.next(&Bar::new().high(101.0).low(100.0)), 0.0);
+
+        // the ranges widen faster and faster: the volatility keeps rising
+        let mut prev = 0.0;
+        for i in 1..10 {
+            let value = volatility.next(
+                &Bar::new()
+                    .high(100.0 + (0.05 * (i * i) as f64).exp())
+                    .low(100.0),
+            );
+            assert!(value > prev, "{} <= {}", value, prev);
+            prev = value;
+        }
+    }
+
+    #[test]
+    fn test_zero_range() {
+        let mut volatility = ChaikinVolatility::new(1, 1).unwrap();
+        volatility.next(&Bar::new().high(100.0).low(100.0));
+        assert_eq!(volatility.next(&Bar::new().high(100.0).low(100.0)), 0.0);
+        assert_eq!(volatility.next(&Bar::new().high(102.0).low(100.0)), 0.0);
+        assert_eq!(volatility.next(&Bar::new().high(103.0).low(100.0)), 50.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut volatility = ChaikinVolatility::new(1, 1).unwrap();
+        volatility.next(&Bar::new().high(102.0).low(100.0));
+        volatility.next(&Bar::new().high(104.0).low(100.0));
+
+        volatility.reset();
+        assert!(volatility.next(&Bar::new().high(104.0).low(100.0)).is_nan());
+        assert_eq!(volatility.next(&Bar::new().high(103.0).low(100.0)), -25.0);
+    }
+
+    #[test]
+    fn test_default() {
+        ChaikinVolatility::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let volatility = ChaikinVolatility::new(10, 12).unwrap();
+        assert_eq!(format!("{}", volatility), "CHAIKINVOL(10, 12)");
+    }
+}
@@ -0,0 +1,219 @@
+use alloc::{boxed::Box, vec};
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Chande Momentum Oscillator (CMO).
+///
+/// Developed by Tushar Chande, a momentum oscillator in [-100, 100] comparing the sum of the
+/// gains with the sum of the losses over the period. Unlike the RSI, the sums are not
+/// smoothed.
+///
+/// # Formula
+///
+/// CMO = 100 * (Up - Down) / (Up + Down)
+///
+/// Where _Up_ (_Down_) is the sum of the price increases (decreases) of the last _period_
+/// changes. The output is NaN until _period_ changes are known (_period_ + 1 inputs), and 0
+/// when the price did not change over the period.
+///
+/// # Parameters
+///
+/// * _period_ - number of price changes (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::ChandeMomentumOscillator;
+/// use tam::Next;
+///
+/// let mut cmo = ChandeMomentumOscillator::new(3).unwrap();
+/// assert!(cmo.next(10.0).is_nan());
+/// assert!(cmo.next(11.0).is_nan());
+/// assert!(cmo.next(13.0).is_nan());
+/// assert_eq!(cmo.next(12.0), 50.0); // (1 + 2 - 1) / (1 + 2 + 1)
+/// ```
+///
+/// # Links
+///
+/// * [Chande Momentum Oscillator, Investopedia](https://www.investopedia.com/terms/c/chandemomentumoscillator.asp)
+///
+#[doc(alias = "CMO")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ChandeMomentumOscillator {
+    period: usize,
+    index: usize,
+    count: usize,
+    prev: f64,
+    up: f64,
+    down: f64,
+    changes: Box<[f64]>,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl ChandeMomentumOscillator {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                prev: 0.0,
+                up: 0.0,
+                down: 0.0,
+                changes: vec![0.0; period].into_boxed_slice(),
+                last_output: None,
+            }),
+        }
+    }
+}
+
+impl Period for ChandeMomentumOscillator {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Warmup for ChandeMomentumOscillator {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+}
+
+impl ChandeMomentumOscillator {
+    fn next_value(&mut self, input: f64) -> f64 {
+        self.count += 1;
+        let prev = self.prev;
+        self.prev = input;
+        if self.count == 1 {
+            return f64::NAN;
+        }
+
+        let old = self.changes[self.index];
+        if old > 0.0 {
+            self.up -= old;
+        } else {
+            self.down += old;
+        }
+
+        let change = input - prev;
+        self.changes[self.index] = change;
+        if change > 0.0 {
+            self.up += change;
+        } else {
+            self.down -= change;
+        }
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count <= self.period {
+            return f64::NAN;
+        }
+        let total = self.up + self.down;
+        if total <= 0.0 {
+            0.0
+        } else {
+            100.0 * (self.up - self.down) / total
+        }
+    }
+}
+
+impl_next_option!(ChandeMomentumOscillator);
+
+impl<T: Close> Next<&T> for ChandeMomentumOscillator {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for ChandeMomentumOscillator {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.prev = 0.0;
+        self.up = 0.0;
+        self.down = 0.0;
+        for i in 0..self.period {
+            self.changes[i] = 0.0;
+        }
+        self.last_output = None;
+    }
+}
+
+impl Default for ChandeMomentumOscillator {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for ChandeMomentumOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CMO({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(ChandeMomentumOscillator::new(0).is_err());
+        assert!(ChandeMomentumOscillator::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut cmo = ChandeMomentumOscillator::new(3).unwrap();
+
+        assert!(cmo.next(10.0).is_nan());
+        assert!(cmo.next(11.0).is_nan());
+        assert!(cmo.next(13.0).is_nan());
+        assert_eq!(cmo.next(12.0), 50.0); // (1 + 2 - 1) / 4
+        assert_eq!(round(cmo.next(&Bar::new().close(9))), -33.333); // (2 - 1 - 3) / 6
+        assert_eq!(cmo.next(6.0), -100.0); // (-1 - 3 - 3) / 7
+        assert_eq!(cmo.next(10.0), -20.0); // (4 - 3 - 3) / 10
+    }
+
+    #[test]
+    fn test_flat() {
+        let mut cmo = ChandeMomentumOscillator::new(2).unwrap();
+        cmo.next(5.0);
+        cmo.next(5.0);
+        assert_eq!(cmo.next(5.0), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut cmo = ChandeMomentumOscillator::new(2).unwrap();
+        cmo.next(5.0);
+        cmo.next(6.0);
+        cmo.next(7.0);
+
+        cmo.reset();
+        assert!(cmo.next(7.0).is_nan());
+        assert!(cmo.next(6.0).is_nan());
+        assert_eq!(cmo.next(5.0), -100.0);
+    }
+
+    #[test]
+    fn test_default() {
+        ChandeMomentumOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let cmo = ChandeMomentumOscillator::new(9).unwrap();
+        assert_eq!(format!("{}", cmo), "CMO(9)");
+    }
+}
@@ -1,4 +1,4 @@
-use std::fmt;
+use core::fmt;
 use serde::{Deserialize, Serialize};
 
 use crate::errors::Result;
@@ -72,7 +72,7 @@ impl ChandelierExit {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ChandelierExitOutput {
     pub long: f64,
     pub short: f64,
@@ -84,6 +84,8 @@ impl From<ChandelierExitOutput> for (f64, f64) {
     }
 }
 
+impl_output!(ChandelierExitOutput, long, short);
+
 impl Period for ChandelierExit {
     fn period(&self) -> usize {
         self.atr.period()
@@ -169,6 +171,40 @@ mod tests {
         assert_eq!(round(ce.next(&bar6).into()), (2.92, 7.08));
     }
 
+    #[test]
+    fn test_widen_with_atr() {
+        let mut ce = Ce::new(3, 2.0).unwrap();
+        let mut atr = AverageTrueRange::new(3).unwrap();
+
+        // same center, growing ranges: the ATR rises and the stops move away
+        let mut widths = Vec::new();
+        for &range in [1.0, 1.0, 1.0, 2.0, 4.0, 6.0].iter() {
+            let bar = Bar::new().high(10.0 + range).low(10.0 - range).close(10.0);
+            let out = ce.next(&bar);
+            let width = 2.0 * atr.next(&bar);
+
+            // the highest high and the lowest low of the window are those of this bar
+            assert_eq!(out.long, 10.0 + range - width);
+            assert_eq!(out.short, 10.0 - range + width);
+            widths.push(width);
+        }
+
+        assert_eq!(widths[1], widths[2]);
+        for pair in widths[2..].windows(2) {
+            assert!(pair[1] > pair[0]);
+        }
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut ce = Ce::new(2, 2.0).unwrap();
+        ce.next(&Bar::new().high(2).low(1).close(1.5));
+        ce.next(&Bar::new().high(5).low(3).close(4));
+        let mut restored = serde_round_trip(&ce);
+        let bar = Bar::new().high(6).low(4).close(5);
+        assert_eq!(restored.next(&bar), ce.next(&bar));
+    }
+
     #[test]
     fn test_reset() {
         let mut ce = Ce::new(5, 2.0).unwrap();
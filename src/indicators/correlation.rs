@@ -1,7 +1,15 @@
-use std::fmt;
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::cmp::Ordering;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Next, Period, Reset};
+#[cfg(feature = "deterministic")]
+use crate::helpers::ordered_sum;
+use crate::helpers::{fmt_current, round_decimals};
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float;
+use crate::{Current, PairIndicator, Period, Reset, WarmupPolicy};
 use serde::{Deserialize, Serialize};
 
 /// Pearson's Correlation Coefficient (r).
@@ -24,6 +32,30 @@ use serde::{Deserialize, Serialize};
 ///
 /// * _period_ - number of periods (integer greater than 0). Default value is 30.
 ///
+/// With the `deterministic` feature the sums are recomputed over the window, oldest point
+/// first, on every input instead of being updated incrementally, so the output only depends
+/// on the points in the window.
+///
+/// # Undefined correlation
+///
+/// With less than 2 points, or when one of the series is constant over the window, the
+/// correlation is undefined. It is reported as 0 by default, which cannot be told apart from
+/// an actual absence of linear relationship; use
+/// [undefined_as_nan](#method.undefined_as_nan) to get NaN instead.
+///
+/// # Spearman's rank correlation
+///
+/// [with_spearman](#method.with_spearman) correlates the ranks of the values in the window
+/// instead of the values themselves, which measures a monotonic rather than a linear
+/// relationship: y = x³ is perfectly rank correlated with x, not perfectly linearly
+/// correlated. Tied values get the average of the ranks they span. The ranks are recomputed
+/// on every input, O(_period_ log _period_).
+///
+/// # Precision
+///
+/// [with_decimals](#method.with_decimals) rounds the outputs to a number of decimal places;
+/// the sums keep their full precision.
+///
 /// # Example
 ///
 /// ```
@@ -46,8 +78,34 @@ pub struct Correlation {
     sum_xy: f64,
     sum_x2: f64,
     sum_y2: f64,
-    values_x: Box<[f64]>,
-    values_y: Box<[f64]>,
+    /// Ring buffers written twice, at `i` and `i + period`, so the window is always a
+    /// contiguous slice.
+    buffer_x: Box<[f64]>,
+    buffer_y: Box<[f64]>,
+    #[serde(default)]
+    undefined_as_nan: bool,
+    #[serde(default)]
+    spearman: bool,
+    #[serde(default)]
+    last_correlation: Option<f64>,
+    /// Output for the first point, the undefined value if `None`.
+    #[serde(default)]
+    warmup_policy: Option<WarmupPolicy>,
+    #[serde(default)]
+    decimals: Option<u32>,
+}
+
+/// Configuration of a [Correlation](struct.Correlation.html), without its state.
+///
+/// Unlike the indicator it is `Eq` and `Hash`, e.g. to key a cache of instances.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct CorrelationConfig {
+    pub period: usize,
+    pub undefined_as_nan: bool,
+    pub spearman: bool,
+    pub warmup_policy: Option<WarmupPolicy>,
+    #[serde(default)]
+    pub decimals: Option<u32>,
 }
 
 impl Correlation {
@@ -63,11 +121,89 @@ impl Correlation {
                 sum_xy: 0.0,
                 sum_x2: 0.0,
                 sum_y2: 0.0,
-                values_x: vec![0.0; period].into_boxed_slice(),
-                values_y: vec![0.0; period].into_boxed_slice(),
+                buffer_x: vec![0.0; 2 * period].into_boxed_slice(),
+                buffer_y: vec![0.0; 2 * period].into_boxed_slice(),
+                undefined_as_nan: false,
+                spearman: false,
+                last_correlation: None,
+                warmup_policy: None,
+                decimals: None,
             }),
         }
     }
+
+    /// Outputs NaN instead of 0 when the correlation is undefined, see
+    /// [Undefined correlation](#undefined-correlation).
+    pub fn undefined_as_nan(mut self) -> Self {
+        self.undefined_as_nan = true;
+        self
+    }
+
+    /// Computes Spearman's rank correlation instead of Pearson's, see
+    /// [Spearman's rank correlation](#spearmans-rank-correlation).
+    pub fn with_spearman(mut self) -> Self {
+        self.spearman = true;
+        self
+    }
+
+    /// Changes the output for the first point, which has no correlation. Without a policy it
+    /// is the [undefined](#undefined-correlation) value.
+    pub fn with_warmup_policy(mut self, policy: WarmupPolicy) -> Self {
+        self.warmup_policy = Some(policy);
+        self
+    }
+
+    /// Rounds the outputs to `decimals` decimal places, see [Precision](#precision).
+    pub fn with_decimals(mut self, decimals: u32) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+
+    /// Decimals of the outputs, `None` for full precision.
+    pub fn decimals(&self) -> Option<u32> {
+        self.decimals
+    }
+
+    pub fn is_spearman(&self) -> bool {
+        self.spearman
+    }
+
+    /// The parameters the correlation was created with, whatever it has been fed since.
+    pub fn config(&self) -> CorrelationConfig {
+        CorrelationConfig {
+            period: self.period,
+            undefined_as_nan: self.undefined_as_nan,
+            spearman: self.spearman,
+            warmup_policy: self.warmup_policy,
+            decimals: self.decimals,
+        }
+    }
+
+    fn undefined(&self) -> f64 {
+        if self.undefined_as_nan {
+            f64::NAN
+        } else {
+            0.0
+        }
+    }
+
+    /// Values of x and of y in the window from the oldest to the newest, fewer than `period`
+    /// of them during the warmup.
+    pub fn window(&self) -> (&[f64], &[f64]) {
+        let range = if self.count < self.period {
+            0..self.count
+        } else {
+            self.index..self.index + self.period
+        };
+        (&self.buffer_x[range.clone()], &self.buffer_y[range])
+    }
+
+    /// Points of the window from the oldest to the newest.
+    #[cfg(feature = "deterministic")]
+    fn points(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        let (xs, ys) = self.window();
+        xs.iter().copied().zip(ys.iter().copied())
+    }
 }
 
 impl Period for Correlation {
@@ -76,19 +212,38 @@ impl Period for Correlation {
     }
 }
 
-impl Next<(f64, f64)> for Correlation {
+impl PairIndicator for Correlation {
+    fn next_pair(&mut self, input_x: f64, input_y: f64) -> f64 {
+        let correlation = round_decimals(self.update(input_x, input_y), self.decimals);
+        if self.count >= 2 {
+            self.last_correlation = Some(correlation);
+        }
+        correlation
+    }
+}
+
+impl Current for Correlation {
     type Output = f64;
 
-    fn next(&mut self, input: (f64, f64)) -> Self::Output {
-        let (input_x, input_y) = input;
-        
+    fn current(&self) -> Option<f64> {
+        self.last_correlation
+    }
+}
+
+impl Correlation {
+    /// Adds a point to the window and computes the correlation.
+    fn update(&mut self, input_x: f64, input_y: f64) -> f64 {
         // Store the trailing values before we overwrite them
-        let trailing_x = self.values_x[self.index];
-        let trailing_y = self.values_y[self.index];
+        #[cfg(not(feature = "deterministic"))]
+        let trailing_x = self.buffer_x[self.index];
+        #[cfg(not(feature = "deterministic"))]
+        let trailing_y = self.buffer_y[self.index];
         
         // Add new values to the buffers
-        self.values_x[self.index] = input_x;
-        self.values_y[self.index] = input_y;
+        self.buffer_x[self.index] = input_x;
+        self.buffer_y[self.index] = input_y;
+        self.buffer_x[self.index + self.period] = input_x;
+        self.buffer_y[self.index + self.period] = input_y;
         
         // Update index for next iteration
         self.index = if self.index + 1 < self.period {
@@ -98,6 +253,7 @@ impl Next<(f64, f64)> for Correlation {
         };
         
         // Update count of points (up to period)
+        #[cfg(not(feature = "deterministic"))]
         if self.count < self.period {
             self.count += 1;
             
@@ -115,11 +271,31 @@ impl Next<(f64, f64)> for Correlation {
             self.sum_x2 = self.sum_x2 - (trailing_x * trailing_x) + (input_x * input_x);
             self.sum_y2 = self.sum_y2 - (trailing_y * trailing_y) + (input_y * input_y);
         }
+
+        // Recompute the sums over the window, oldest point first
+        #[cfg(feature = "deterministic")]
+        {
+            if self.count < self.period {
+                self.count += 1;
+            }
+            self.sum_x = ordered_sum(self.points().map(|(x, _)| x));
+            self.sum_y = ordered_sum(self.points().map(|(_, y)| y));
+            self.sum_xy = ordered_sum(self.points().map(|(x, y)| x * y));
+            self.sum_x2 = ordered_sum(self.points().map(|(x, _)| x * x));
+            self.sum_y2 = ordered_sum(self.points().map(|(_, y)| y * y));
+        }
         
         // Calculate correlation coefficient
         if self.count < 2 {
             // Need at least 2 points for correlation
-            return 0.0;
+            return match self.warmup_policy {
+                Some(policy) => policy.warmup_value(),
+                None => self.undefined(),
+            };
+        }
+
+        if self.spearman {
+            return self.rank_correlation();
         }
         
         let n = self.count as f64;
@@ -130,11 +306,56 @@ impl Next<(f64, f64)> for Correlation {
         
         // Check for division by zero or negative under sqrt
         if denominator <= 0.0 {
-            return 0.0;
+            return self.undefined();
         }
         
         numerator / denominator.sqrt()
     }
+
+    /// Pearson's correlation of the ranks of the points of the window.
+    fn rank_correlation(&self) -> f64 {
+        let (xs, ys) = self.window();
+        let (rx, ry) = (ranks(xs), ranks(ys));
+
+        // ranks are 1..=n, so their mean is known and the two-pass formula is exact enough
+        let mean = (self.count as f64 + 1.0) / 2.0;
+        let (mut cov, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+        for (x, y) in rx.iter().zip(ry.iter()) {
+            cov += (x - mean) * (y - mean);
+            var_x += (x - mean) * (x - mean);
+            var_y += (y - mean) * (y - mean);
+        }
+
+        // all the values of one series are tied
+        if var_x == 0.0 || var_y == 0.0 {
+            return self.undefined();
+        }
+        cov / (var_x * var_y).sqrt()
+    }
+}
+
+/// Ranks of `values` from 1, tied values getting the average of the ranks they span.
+fn ranks(values: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+
+    let mut ranks = vec![0.0; values.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len()
+            && values[order[end]].total_cmp(&values[order[start]]) == Ordering::Equal
+        {
+            end += 1;
+        }
+        // positions start..end hold ranks start + 1 ..= end
+        let rank = (start + end + 1) as f64 / 2.0;
+        for &i in &order[start..end] {
+            ranks[i] = rank;
+        }
+        start = end;
+    }
+    ranks
 }
 
 impl Reset for Correlation {
@@ -147,10 +368,9 @@ impl Reset for Correlation {
         self.sum_x2 = 0.0;
         self.sum_y2 = 0.0;
         
-        for i in 0..self.period {
-            self.values_x[i] = 0.0;
-            self.values_y[i] = 0.0;
-        }
+        self.buffer_x.fill(0.0);
+        self.buffer_y.fill(0.0);
+        self.last_correlation = None;
     }
 }
 
@@ -162,7 +382,12 @@ impl Default for Correlation {
 
 impl fmt::Display for Correlation {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "CORREL({})", self.period)
+        if self.spearman {
+            write!(f, "SPEARMAN({})", self.period)?;
+        } else {
+            write!(f, "CORREL({})", self.period)?;
+        }
+        fmt_current(f, self.current())
     }
 }
 
@@ -170,6 +395,7 @@ impl fmt::Display for Correlation {
 mod tests {
     use super::*;
     use crate::test_helper::*;
+    use crate::Next;
     
     #[test]
     fn test_new() {
@@ -188,7 +414,6 @@ mod tests {
     ///   2   | (3.0, 2.0) |    -1.0000000000000000 |    -1.0000000000000000 | ✓
     ///   3   | (6.0, 1.0) |    -0.9607689228305228 |    -0.9607689228305226 | ✓
     ///   4   | (5.0, 2.0) |    -0.7559289460184537 |    -0.7559289460184546 | ✓
-
     fn test_next() {
         let mut corr = Correlation::new(3).unwrap();
         
@@ -205,6 +430,111 @@ mod tests {
         assert_eq!(corr.next((5.0, 2.0)), -0.7559289460184537);
     }
     
+    #[test]
+    fn test_undefined_as_nan() {
+        let mut corr = Correlation::new(3).unwrap().undefined_as_nan();
+
+        assert!(corr.next((4.0, 1.0)).is_nan());
+        // constant x series
+        assert!(corr.next((4.0, 2.0)).is_nan());
+        assert!(corr.next((4.0, 7.0)).is_nan());
+        // x moves again
+        assert_eq!(round(corr.next((5.0, 3.0))), -0.327);
+
+        // the default still reports 0
+        let mut corr = Correlation::new(3).unwrap();
+        for &y in [1.0, 2.0, 7.0].iter() {
+            assert_eq!(corr.next((4.0, y)), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_warmup_policy() {
+        let mut corr = Correlation::new(3)
+            .unwrap()
+            .undefined_as_nan()
+            .with_warmup_policy(WarmupPolicy::Zero);
+        assert_eq!(corr.next((2.0, 3.0)), 0.0);
+        assert_eq!(corr.next((3.0, 2.0)), -1.0);
+        // an undefined correlation after the warmup is still NaN
+        corr.next((3.0, 2.0));
+        assert!(corr.next((3.0, 2.0)).is_nan());
+
+        let mut corr = Correlation::new(3)
+            .unwrap()
+            .with_warmup_policy(WarmupPolicy::Nan);
+        assert!(corr.next((2.0, 3.0)).is_nan());
+    }
+
+    #[test]
+    fn test_current() {
+        let mut corr = Correlation::new(3).unwrap();
+        corr.next((2.0, 3.0));
+        assert_eq!(corr.current(), None);
+
+        for &point in [(3.0, 2.0), (6.0, 1.0), (4.0, 4.0)].iter() {
+            let value = corr.next(point);
+            assert_eq!(corr.current(), Some(value));
+        }
+
+        corr.reset();
+        assert_eq!(corr.current(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_config() {
+        use std::collections::HashSet;
+
+        let mut fed = Correlation::new(5).unwrap();
+        for &point in [(2.0, 3.0), (3.0, 2.0), (6.0, 1.0)].iter() {
+            fed.next(point);
+        }
+        let fresh = Correlation::new(5).unwrap();
+        assert_ne!(fed, fresh);
+        assert_eq!(fed.config(), fresh.config());
+        assert_eq!(
+            fresh.config(),
+            CorrelationConfig {
+                period: 5,
+                undefined_as_nan: false,
+                spearman: false,
+                warmup_policy: None,
+                decimals: None
+            }
+        );
+
+        let nan = Correlation::new(5).unwrap().undefined_as_nan();
+        let other_period = Correlation::new(6).unwrap();
+        let rounded = Correlation::new(5).unwrap().with_decimals(3);
+        let configs: HashSet<CorrelationConfig> = [
+            fed.config(),
+            fresh.config(),
+            nan.config(),
+            other_period.config(),
+            rounded.config(),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(configs.len(), 4);
+    }
+
+    #[test]
+    fn test_decimals() {
+        let mut corr = Correlation::new(3).unwrap().with_decimals(3);
+        assert_eq!(corr.next((2.0, 3.0)), 0.0);
+        assert_eq!(corr.next((3.0, 2.0)), -1.0);
+        // -0.9607689228305228
+        assert_eq!(corr.next((6.0, 1.0)), -0.961);
+        assert_eq!(corr.current(), Some(-0.961));
+
+        corr.reset();
+        assert_eq!(corr.decimals(), Some(3));
+        corr.next((2.0, 3.0));
+        corr.next((3.0, 2.0));
+        assert_eq!(corr.next((6.0, 1.0)), -0.961);
+    }
+
     #[test]
     fn test_reset() {
         let mut corr = Correlation::new(3).unwrap();
@@ -225,5 +555,129 @@ mod tests {
     fn test_display() {
         let indicator = Correlation::new(10).unwrap();
         assert_eq!(format!("{}", indicator), "CORREL(10)");
+        assert_eq!(format!("{}", indicator.with_spearman()), "SPEARMAN(10)");
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_display_alternate() {
+        let mut corr = Correlation::new(3).unwrap();
+        corr.next((2.0, 3.0));
+        assert_eq!(format!("{:#}", corr), "CORREL(3)=NaN");
+
+        corr.next((3.0, 2.0));
+        assert_eq!(format!("{}", corr), "CORREL(3)");
+        assert_eq!(format!("{:#}", corr), "CORREL(3)=-1");
+
+        let mut spearman = Correlation::new(3).unwrap().with_spearman();
+        assert_eq!(format!("{:#}", spearman), "SPEARMAN(3)=NaN");
+        spearman.next((2.0, 3.0));
+        spearman.next((3.0, 2.0));
+        assert_eq!(format!("{:#.1}", spearman), "SPEARMAN(3)=-1.0");
+    }
+
+    #[test]
+    fn test_window() {
+        let mut corr = Correlation::new(3).unwrap();
+        let (xs, ys) = corr.window();
+        assert!(xs.is_empty() && ys.is_empty());
+
+        corr.next((1.0, 10.0));
+        assert_eq!(corr.window(), (&[1.0][..], &[10.0][..]));
+
+        for i in 2..8 {
+            corr.next((i as f64, 10.0 * i as f64));
+        }
+        assert_eq!(
+            corr.window(),
+            (&[5.0, 6.0, 7.0][..], &[50.0, 60.0, 70.0][..])
+        );
+        corr.next((8.0, 80.0));
+        assert_eq!(
+            corr.window(),
+            (&[6.0, 7.0, 8.0][..], &[60.0, 70.0, 80.0][..])
+        );
+    }
+
+    #[test]
+    fn test_ranks() {
+        assert_eq!(ranks(&[]), Vec::<f64>::new());
+        assert_eq!(ranks(&[3.0, 1.0, 2.0]), vec![3.0, 1.0, 2.0]);
+        assert_eq!(ranks(&[10.0, 20.0, 20.0, 30.0]), vec![1.0, 2.5, 2.5, 4.0]);
+        assert_eq!(ranks(&[5.0, 5.0, 5.0]), vec![2.0, 2.0, 2.0]);
+    }
+
+    #[test]
+    fn test_spearman_vs_pearson() {
+        let mut pearson = Correlation::new(6).unwrap();
+        let mut spearman = Correlation::new(6).unwrap().with_spearman();
+        assert!(spearman.is_spearman());
+
+        // monotonic but far from linear
+        let (mut r_pearson, mut r_spearman) = (0.0, 0.0);
+        for i in 0..6 {
+            let x = i as f64;
+            let point = (x, x.exp());
+            r_pearson = pearson.next(point);
+            r_spearman = spearman.next(point);
+        }
+        assert_eq!(round(r_pearson), 0.847);
+        assert_eq!(r_spearman, 1.0);
+
+        // rho = 1 - 6 * sum(d²) / (n * (n² - 1)) = 1 - 6 * 4 / 120
+        let mut spearman = Correlation::new(5).unwrap().with_spearman();
+        let points = [(1.0, 20.0), (2.0, 10.0), (3.0, 40.0), (4.0, 30.0), (5.0, 50.0)];
+        let r = points.iter().map(|&p| spearman.next(p)).last().unwrap();
+        assert_eq!(round(r), 0.8);
+
+        // the window moves: (2, 10), (3, 40), (4, 30), (5, 50), (6, 0)
+        assert_eq!(round(spearman.next((6.0, 0.0))), -0.1);
+    }
+
+    #[test]
+    fn test_spearman_ties() {
+        let mut spearman = Correlation::new(4).unwrap().with_spearman();
+        // ranks x: 1 2.5 2.5 4, y: 1 2 3 4
+        for &point in [(1.0, 1.0), (2.0, 2.0), (2.0, 3.0)].iter() {
+            spearman.next(point);
+        }
+        assert_eq!(round(spearman.next((3.0, 4.0))), 0.949);
+
+        // a series made of ties only is undefined
+        let mut spearman = Correlation::new(3).unwrap().with_spearman().undefined_as_nan();
+        for &point in [(1.0, 4.0), (1.0, 2.0), (1.0, 3.0)].iter() {
+            assert!(spearman.next(point).is_nan());
+        }
+    }
+
+    #[cfg(feature = "deterministic")]
+    #[test]
+    fn test_deterministic() {
+        let points: Vec<(f64, f64)> = (0..40)
+            .map(|i| {
+                let x = i as f64;
+                (1e6 * (x * 0.7).sin(), 0.1 * x + (x * 1.3).cos())
+            })
+            .collect();
+        let mut corr = Correlation::new(6).unwrap();
+
+        for (i, &point) in points.iter().enumerate() {
+            let window = &points[(i + 1).saturating_sub(6)..=i];
+            let (mut sx, mut sy, mut sxy, mut sx2, mut sy2) = (0.0, 0.0, 0.0, 0.0, 0.0);
+            for &(x, y) in window {
+                sx += x;
+                sy += y;
+                sxy += x * y;
+                sx2 += x * x;
+                sy2 += y * y;
+            }
+            let n = window.len() as f64;
+            let denominator = (sx2 - sx * sx / n) * (sy2 - sy * sy / n);
+            let expected = if window.len() < 2 || denominator <= 0.0 {
+                0.0
+            } else {
+                (sxy - sx * sy / n) / denominator.sqrt()
+            };
+            assert_eq!(corr.next(point).to_bits(), expected.to_bits());
+        }
+    }
+}
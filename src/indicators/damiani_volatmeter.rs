@@ -0,0 +1,269 @@
+use alloc::collections::VecDeque;
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{AverageTrueRange, StandardDeviation};
+use crate::{Close, High, Low, Next, Period, Periods, Reset};
+use serde::{Deserialize, Serialize};
+
+const LAG_SUPPRESSOR: f64 = 0.5;
+
+/// Damiani Volatmeter.
+///
+/// Distinguishes trending (volatile) markets from choppy ones by comparing short-term
+/// volatility to long-term volatility, measured both with ATR and with standard deviation.
+/// The market is considered to be trending when `vol` is above `anti_threshold`.
+///
+/// # Formula
+///
+/// vol<sub>t</sub> = ATR<sub>fast</sub> / ATR<sub>slow</sub> + 0.5 * (vol<sub>t-1</sub> - vol<sub>t-3</sub>)
+///
+/// anti_threshold<sub>t</sub> = _threshold_ - SD<sub>fast</sub> / SD<sub>slow</sub>
+///
+/// The second term of `vol` is the lag suppressor from the original formula, it is applied
+/// once three previous values are known. A zero slow ATR or slow SD yields a ratio of 0.
+///
+/// # Parameters
+///
+/// * _atr_fast_ - period of the fast ATR. Default is 13.
+/// * _atr_slow_ - period of the slow ATR, must be greater than _atr_fast_. Default is 40.
+/// * _std_fast_ - period of the fast SD. Default is 20.
+/// * _std_slow_ - period of the slow SD, must be greater than _std_fast_. Default is 100.
+/// * _threshold_ - threshold level. Default is 1.4.
+///
+/// Outputs are NaN until both slow windows are filled.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::DamianiVolatmeter;
+/// use tam::{DataItem, Next};
+///
+/// let mut damiani = DamianiVolatmeter::new(2, 3, 2, 3, 1.4).unwrap();
+/// let bar = DataItem::builder()
+///     .open(10.0).high(11.0).low(9.0).close(10.0).volume(1.0).build().unwrap();
+///
+/// assert!(damiani.next(&bar).vol.is_nan());
+/// assert!(damiani.next(&bar).vol.is_nan());
+/// let out = damiani.next(&bar);
+/// assert_eq!(out.vol, 1.0);
+/// assert_eq!(out.anti_threshold, 1.4);
+/// ```
+///
+/// # Links
+///
+/// * [Damiani Volatmeter, TradingView](https://www.tradingview.com/script/pTfwC8Xt-Damiani-Volatmeter/)
+///
+#[doc(alias = "DAMIANI")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DamianiVolatmeter {
+    atr_fast: AverageTrueRange,
+    atr_slow: AverageTrueRange,
+    std_fast: StandardDeviation,
+    std_slow: StandardDeviation,
+    threshold: f64,
+    warmup: usize,
+    count: usize,
+    prev_vols: VecDeque<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DamianiOutput {
+    pub vol: f64,
+    pub anti_threshold: f64,
+}
+
+impl From<DamianiOutput> for (f64, f64) {
+    fn from(out: DamianiOutput) -> Self {
+        (out.vol, out.anti_threshold)
+    }
+}
+
+impl_output!(DamianiOutput, vol, anti_threshold);
+
+impl DamianiVolatmeter {
+    pub fn new(
+        atr_fast: usize,
+        atr_slow: usize,
+        std_fast: usize,
+        std_slow: usize,
+        threshold: f64,
+    ) -> Result<Self> {
+        if atr_fast >= atr_slow || std_fast >= std_slow {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            atr_fast: AverageTrueRange::new(atr_fast)?,
+            atr_slow: AverageTrueRange::new(atr_slow)?,
+            std_fast: StandardDeviation::new(std_fast)?,
+            std_slow: StandardDeviation::new(std_slow)?,
+            threshold,
+            warmup: atr_slow.max(std_slow),
+            count: 0,
+            prev_vols: VecDeque::with_capacity(3),
+        })
+    }
+
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+}
+
+impl Periods for DamianiVolatmeter {
+    fn periods(&self) -> Vec<usize> {
+        vec![
+            self.atr_fast.period(),
+            self.atr_slow.period(),
+            self.std_fast.period(),
+            self.std_slow.period(),
+        ]
+    }
+}
+
+fn ratio(fast: f64, slow: f64) -> f64 {
+    if slow == 0.0 {
+        0.0
+    } else {
+        fast / slow
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for DamianiVolatmeter {
+    type Output = DamianiOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let atr_ratio = ratio(self.atr_fast.next(input), self.atr_slow.next(input));
+        let std_ratio = ratio(self.std_fast.next(input), self.std_slow.next(input));
+
+        // prev_vols holds vol[t-3], vol[t-2], vol[t-1]
+        let vol = if self.prev_vols.len() == 3 {
+            atr_ratio + LAG_SUPPRESSOR * (self.prev_vols[2] - self.prev_vols[0])
+        } else {
+            atr_ratio
+        };
+
+        if self.prev_vols.len() == 3 {
+            self.prev_vols.pop_front();
+        }
+        self.prev_vols.push_back(vol);
+
+        if self.count < self.warmup {
+            self.count += 1;
+        }
+        if self.count < self.warmup {
+            return DamianiOutput {
+                vol: f64::NAN,
+                anti_threshold: f64::NAN,
+            };
+        }
+
+        DamianiOutput {
+            vol,
+            anti_threshold: self.threshold - std_ratio,
+        }
+    }
+}
+
+impl Reset for DamianiVolatmeter {
+    fn reset(&mut self) {
+        self.atr_fast.reset();
+        self.atr_slow.reset();
+        self.std_fast.reset();
+        self.std_slow.reset();
+        self.count = 0;
+        self.prev_vols.clear();
+    }
+}
+
+impl Default for DamianiVolatmeter {
+    fn default() -> Self {
+        Self::new(13, 40, 20, 100, 1.4).unwrap()
+    }
+}
+
+impl fmt::Display for DamianiVolatmeter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DAMIANI")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(DamianiVolatmeter::new(0, 3, 2, 3, 1.4).is_err());
+        assert!(DamianiVolatmeter::new(3, 3, 2, 3, 1.4).is_err());
+        assert!(DamianiVolatmeter::new(2, 3, 3, 3, 1.4).is_err());
+        assert!(DamianiVolatmeter::new(2, 3, 2, 3, 1.4).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut damiani = DamianiVolatmeter::new(2, 3, 2, 4, 1.4).unwrap();
+
+        // constant range: both ATRs are equal and the lag term is 0
+        for _ in 0..3 {
+            let out = damiani.next(&Bar::new().high(11.0).low(9.0).close(10.0));
+            assert!(out.vol.is_nan());
+            assert!(out.anti_threshold.is_nan());
+        }
+        let out = damiani.next(&Bar::new().high(11.0).low(9.0).close(10.0));
+        assert_eq!(out.vol, 1.0);
+        assert_eq!(out.anti_threshold, 1.4);
+
+        // expanding range pushes the fast ATR above the slow one
+        let out = damiani.next(&Bar::new().high(14.0).low(6.0).close(12.0));
+        assert!(out.vol > 1.0);
+        assert!(out.anti_threshold < 1.4);
+    }
+
+    #[test]
+    fn test_lag_suppressor() {
+        let mut damiani = DamianiVolatmeter::new(1, 2, 1, 2, 1.0).unwrap();
+        let bars = [
+            Bar::new().high(11.0).low(9.0).close(10.0),
+            Bar::new().high(11.0).low(9.0).close(10.0),
+            Bar::new().high(12.0).low(8.0).close(10.0),
+            Bar::new().high(11.0).low(9.0).close(10.0),
+            Bar::new().high(11.0).low(9.0).close(10.0),
+        ];
+        let vols: Vec<f64> = bars.iter().map(|b| damiani.next(b).vol).collect();
+
+        // ATR(1) = TR, ATR(2): k = 2/3
+        assert_eq!(vols[1], 1.0);
+        assert_eq!(round(vols[2]), 1.2); // 4 / 3.333
+        assert_eq!(round(vols[3]), 0.918); // 2 / 2.444 + 0.5 * (1.2 - 1.0)
+        assert_eq!(round(vols[4]), 0.89); // 2 / 2.148 + 0.5 * (0.918 - 1.0)
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut damiani = DamianiVolatmeter::new(2, 3, 2, 3, 1.4).unwrap();
+        let bars = [
+            Bar::new().high(11.0).low(9.0).close(10.0),
+            Bar::new().high(12.0).low(9.0).close(11.0),
+            Bar::new().high(13.0).low(10.0).close(12.0),
+            Bar::new().high(12.0).low(8.0).close(9.0),
+        ];
+        let first: Vec<DamianiOutput> = bars.iter().map(|b| damiani.next(b)).collect();
+
+        damiani.reset();
+        let second: Vec<DamianiOutput> = bars.iter().map(|b| damiani.next(b)).collect();
+        assert_eq!(first[3], second[3]);
+    }
+
+    #[test]
+    fn test_default() {
+        DamianiVolatmeter::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let damiani = DamianiVolatmeter::default();
+        assert_eq!(format!("{}", damiani), "DAMIANI");
+    }
+}
@@ -0,0 +1,333 @@
+use alloc::{vec, vec::Vec};
+use core::f64::consts::PI;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float;
+use crate::{Close, Next, Period, Periods, Reset};
+use serde::{Deserialize, Serialize};
+
+/// Decycler.
+///
+/// John Ehlers' trendline: the price minus its high-pass filtered component. Cycles shorter
+/// than the period are removed with very little lag, the trend passes through.
+///
+/// # Formula
+///
+/// α = (cos(2π / _period_) + sin(2π / _period_) - 1) / cos(2π / _period_)
+///
+/// HP<sub>t</sub> = (1 - α / 2) * (p<sub>t</sub> - p<sub>t-1</sub>) + (1 - α) * HP<sub>t-1</sub>
+///
+/// Decycler<sub>t</sub> = p<sub>t</sub> - HP<sub>t</sub>
+///
+/// The high-pass filter starts at 0, so the first output is the first input.
+///
+/// # Parameters
+///
+/// * _period_ - cutoff period of the high-pass filter (integer greater than 4). Default is 125.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::Decycler;
+/// use tam::Next;
+///
+/// let mut decycler = Decycler::new(20).unwrap();
+/// assert_eq!(decycler.next(10.0), 10.0);
+/// assert_eq!(decycler.next(10.0), 10.0);
+/// ```
+///
+/// # Links
+///
+/// * [Decyclers, John Ehlers](https://www.mesasoftware.com/papers/Decyclers.pdf)
+///
+#[doc(alias = "DECYCLER")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Decycler {
+    period: usize,
+    alpha: f64,
+    high_pass: f64,
+    prev: f64,
+    is_new: bool,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl Decycler {
+    pub fn new(period: usize) -> Result<Self> {
+        if period <= 4 {
+            return Err(TaError::InvalidParameter);
+        }
+        let angle = 2.0 * PI / period as f64;
+        Ok(Self {
+            period,
+            alpha: (angle.cos() + angle.sin() - 1.0) / angle.cos(),
+            high_pass: 0.0,
+            prev: 0.0,
+            is_new: true,
+            last_output: None,
+        })
+    }
+}
+
+impl Period for Decycler {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Decycler {
+    fn next_value(&mut self, input: f64) -> f64 {
+        if self.is_new {
+            self.is_new = false;
+        } else {
+            self.high_pass = (1.0 - self.alpha / 2.0) * (input - self.prev)
+                + (1.0 - self.alpha) * self.high_pass;
+        }
+        self.prev = input;
+        input - self.high_pass
+    }
+}
+
+impl_next_option!(Decycler);
+
+impl<T: Close> Next<&T> for Decycler {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for Decycler {
+    fn reset(&mut self) {
+        self.high_pass = 0.0;
+        self.prev = 0.0;
+        self.is_new = true;
+        self.last_output = None;
+    }
+}
+
+impl Default for Decycler {
+    fn default() -> Self {
+        Self::new(125).unwrap()
+    }
+}
+
+impl fmt::Display for Decycler {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DECYCLER({})", self.period)
+    }
+}
+
+/// Decycler Oscillator.
+///
+/// Percentage difference between a fast and a slow [Decycler](struct.Decycler.html). It is
+/// positive in uptrends and negative in downtrends, and crosses zero when the trend turns.
+///
+/// # Formula
+///
+/// Oscillator = 100 * (Decycler<sub>short</sub> - Decycler<sub>long</sub>) / Decycler<sub>long</sub>
+///
+/// A slow decycler of 0 gives 0.
+///
+/// # Parameters
+///
+/// * _short_ - period of the fast decycler (integer greater than 4). Default is 100.
+/// * _long_ - period of the slow decycler (integer greater than _short_). Default is 125.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::DecyclerOscillator;
+/// use tam::Next;
+///
+/// let mut osc = DecyclerOscillator::new(10, 20).unwrap();
+/// assert_eq!(osc.next(10.0), 0.0);
+/// assert!(osc.next(11.0) > 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Decyclers, John Ehlers](https://www.mesasoftware.com/papers/Decyclers.pdf)
+///
+#[doc(alias = "DECOSC")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DecyclerOscillator {
+    short: Decycler,
+    long: Decycler,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl DecyclerOscillator {
+    pub fn new(short: usize, long: usize) -> Result<Self> {
+        if short >= long {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            short: Decycler::new(short)?,
+            long: Decycler::new(long)?,
+            last_output: None,
+        })
+    }
+}
+
+impl Periods for DecyclerOscillator {
+    fn periods(&self) -> Vec<usize> {
+        vec![self.short.period, self.long.period]
+    }
+}
+
+impl DecyclerOscillator {
+    fn next_value(&mut self, input: f64) -> f64 {
+        let short = self.short.next(input);
+        let long = self.long.next(input);
+        if long == 0.0 {
+            0.0
+        } else {
+            100.0 * (short - long) / long
+        }
+    }
+}
+
+impl_next_option!(DecyclerOscillator);
+
+impl<T: Close> Next<&T> for DecyclerOscillator {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for DecyclerOscillator {
+    fn reset(&mut self) {
+        self.short.reset();
+        self.long.reset();
+        self.last_output = None;
+    }
+}
+
+impl Default for DecyclerOscillator {
+    fn default() -> Self {
+        Self::new(100, 125).unwrap()
+    }
+}
+
+impl fmt::Display for DecyclerOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DECOSC({}, {})", self.short.period, self.long.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Decycler::new(0).is_err());
+        assert!(Decycler::new(4).is_err());
+        assert!(Decycler::new(5).is_ok());
+
+        assert!(DecyclerOscillator::new(4, 10).is_err());
+        assert!(DecyclerOscillator::new(10, 10).is_err());
+        assert!(DecyclerOscillator::new(20, 10).is_err());
+        assert!(DecyclerOscillator::new(10, 20).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut decycler = Decycler::new(10).unwrap();
+        assert_eq!(decycler.next(10.0), 10.0);
+        assert_eq!(round(decycler.next(12.0)), 10.49);
+        assert_eq!(round(decycler.next(&Bar::new().close(11))), 10.986);
+    }
+
+    #[test]
+    fn test_tracks_trend() {
+        // a trend of 0.5 per bar with a 10 bar cycle of amplitude 5
+        let mut decycler = Decycler::new(60).unwrap();
+        let mut residuals = Vec::new();
+        for i in 0..400 {
+            let trend = 100.0 + 0.5 * i as f64;
+            let price = trend + 5.0 * (2.0 * PI * i as f64 / 10.0).sin();
+            let value = decycler.next(price);
+            if i >= 300 {
+                residuals.push(trend - value);
+            }
+        }
+
+        // the decycler lags the trend by a constant ~4.8...
+        let mean = residuals.iter().sum::<f64>() / residuals.len() as f64;
+        assert!(mean > 4.0 && mean < 5.5, "lag {}", mean);
+
+        // ...while the cycle is damped from 10 to less than 2 peak to peak
+        let max = residuals.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let min = residuals.iter().cloned().fold(f64::INFINITY, f64::min);
+        assert!(max - min < 2.0, "peak to peak {}", max - min);
+    }
+
+    #[test]
+    fn test_oscillator() {
+        let mut osc = DecyclerOscillator::new(10, 30).unwrap();
+
+        let mut value = 0.0;
+        for i in 0..100 {
+            value = osc.next(100.0 + i as f64);
+        }
+        assert!(value > 0.0);
+
+        for i in 0..100 {
+            value = osc.next(&Bar::new().close(200.0 - i as f64));
+        }
+        assert!(value < 0.0);
+
+        let mut osc = DecyclerOscillator::new(10, 30).unwrap();
+        for _ in 0..10 {
+            assert_eq!(osc.next(0.0), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut decycler = Decycler::new(10).unwrap();
+        decycler.next(10.0);
+        decycler.next(12.0);
+        decycler.reset();
+        assert_eq!(decycler.next(5.0), 5.0);
+
+        let mut osc = DecyclerOscillator::new(10, 20).unwrap();
+        osc.next(10.0);
+        osc.next(12.0);
+        osc.reset();
+        assert_eq!(osc.next(5.0), 0.0);
+    }
+
+    #[test]
+    fn test_periods() {
+        assert_eq!(
+            DecyclerOscillator::new(10, 20).unwrap().periods(),
+            vec![10, 20]
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        Decycler::default();
+        DecyclerOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Decycler::new(60).unwrap()), "DECYCLER(60)");
+        assert_eq!(
+            format!("{}", DecyclerOscillator::default()),
+            "DECOSC(100, 125)"
+        );
+    }
+}
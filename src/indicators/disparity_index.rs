@@ -0,0 +1,184 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{Close, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Disparity Index.
+///
+/// The distance of the price from its moving average, in percent of the average. Positive
+/// values show a price above the average, and the further from 0, the more stretched the
+/// move.
+///
+/// # Formula
+///
+/// Disparity = 100 * (Price - SMA(Price, _period_)) / SMA(Price, _period_)
+///
+/// The output is NaN when the SMA is 0. Like the [SMA](struct.SimpleMovingAverage.html), the
+/// first _period_ - 1 outputs use the average of the inputs seen so far.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods of the SMA (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::DisparityIndex;
+/// use tam::Next;
+///
+/// let mut disparity = DisparityIndex::new(2).unwrap();
+/// assert_eq!(disparity.next(10.0), 0.0);
+/// // 100 * (15 - 12.5) / 12.5
+/// assert_eq!(disparity.next(15.0), 20.0);
+/// ```
+///
+/// # Links
+///
+/// * [Disparity Index, Investopedia](https://www.investopedia.com/terms/d/disparityindex.asp)
+///
+#[doc(alias = "DISPARITY")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DisparityIndex {
+    sma: Sma,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl DisparityIndex {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            sma: Sma::new(period)?,
+            last_output: None,
+        })
+    }
+}
+
+impl Period for DisparityIndex {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl Warmup for DisparityIndex {
+    fn lookback(&self) -> usize {
+        self.sma.lookback()
+    }
+}
+
+impl DisparityIndex {
+    fn next_value(&mut self, input: f64) -> f64 {
+        let sma = self.sma.next(input);
+        if sma == 0.0 {
+            return f64::NAN;
+        }
+        100.0 * (input - sma) / sma
+    }
+}
+
+impl_next_option!(DisparityIndex);
+
+impl<T: Close> Next<&T> for DisparityIndex {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for DisparityIndex {
+    fn reset(&mut self) {
+        self.sma.reset();
+        self.last_output = None;
+    }
+}
+
+impl Default for DisparityIndex {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for DisparityIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DISPARITY({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(DisparityIndex);
+
+    #[test]
+    fn test_new() {
+        assert!(DisparityIndex::new(0).is_err());
+        assert!(DisparityIndex::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_flat() {
+        let mut disparity = DisparityIndex::new(3).unwrap();
+        for _ in 0..5 {
+            assert_eq!(disparity.next(42.0), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_trend() {
+        let mut disparity = DisparityIndex::new(3).unwrap();
+        assert_eq!(disparity.lookback(), 2);
+
+        // once the window is full the SMA lags the price by 1
+        let outputs: Vec<f64> = (1..=6)
+            .map(|p| round(disparity.next(&Bar::new().close(p as f64))))
+            .collect();
+        assert_eq!(outputs, vec![0.0, 33.333, 50.0, 33.333, 25.0, 20.0]);
+
+        // a downtrend is below its average
+        for p in (1..=5).rev() {
+            disparity.next(p as f64);
+        }
+        assert!(disparity.next(0.5) < 0.0);
+    }
+
+    #[test]
+    fn test_zero_sma() {
+        let mut disparity = DisparityIndex::new(2).unwrap();
+        disparity.next(-1.0);
+        assert!(disparity.next(1.0).is_nan());
+        assert_eq!(disparity.next(3.0), 50.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut disparity = DisparityIndex::new(2).unwrap();
+        disparity.next(10.0);
+        disparity.next(15.0);
+
+        disparity.reset();
+        assert_eq!(disparity.next(15.0), 0.0);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut disparity = DisparityIndex::new(3).unwrap();
+        disparity.next(10.0);
+        disparity.next(12.0);
+        let mut restored = serde_round_trip(&disparity);
+        assert_eq!(restored.next(14.0), disparity.next(14.0));
+    }
+
+    #[test]
+    fn test_default() {
+        DisparityIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", DisparityIndex::default()), "DISPARITY(14)");
+    }
+}
@@ -0,0 +1,180 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Double Exponential Moving Average (DEMA).
+///
+/// Patrick Mulloy's low-lag average: twice an EMA minus the EMA of that EMA. The second EMA
+/// measures the lag of the first one, which is then subtracted, so the average follows the
+/// price more closely than an EMA of the same period.
+///
+/// # Formula
+///
+/// EMA<sub>1</sub> = EMA(p<sub>t</sub>, _period_)
+///
+/// DEMA = 2 * EMA<sub>1</sub> - EMA(EMA<sub>1</sub>, _period_)
+///
+/// Both EMAs are seeded with their first input, so the first output is the first price.
+///
+/// # Parameters
+///
+/// * _period_ - period of the EMAs (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::DoubleExponentialMovingAverage as Dema;
+/// use tam::Next;
+///
+/// let mut dema = Dema::new(3).unwrap();
+/// assert_eq!(dema.next(2.0), 2.0);
+/// // EMA 3.5, EMA of the EMA 2.75
+/// assert_eq!(dema.next(5.0), 4.25);
+/// ```
+///
+/// # Links
+///
+/// * [Double exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Double_exponential_moving_average)
+///
+#[doc(alias = "DEMA")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DoubleExponentialMovingAverage {
+    ema: Ema,
+    ema_ema: Ema,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl DoubleExponentialMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            ema: Ema::new(period)?,
+            ema_ema: Ema::new(period)?,
+            last_output: None,
+        })
+    }
+}
+
+impl Period for DoubleExponentialMovingAverage {
+    fn period(&self) -> usize {
+        self.ema.period()
+    }
+}
+
+impl Warmup for DoubleExponentialMovingAverage {
+    fn lookback(&self) -> usize {
+        2 * self.ema.lookback()
+    }
+}
+
+impl DoubleExponentialMovingAverage {
+    fn next_value(&mut self, input: f64) -> f64 {
+        let ema = self.ema.next(input);
+        2.0 * ema - self.ema_ema.next(ema)
+    }
+}
+
+impl_next_option!(DoubleExponentialMovingAverage);
+
+impl<T: Close> Next<&T> for DoubleExponentialMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for DoubleExponentialMovingAverage {
+    fn reset(&mut self) {
+        self.ema.reset();
+        self.ema_ema.reset();
+        self.last_output = None;
+    }
+}
+
+impl Default for DoubleExponentialMovingAverage {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for DoubleExponentialMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DEMA({})", self.ema.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(DoubleExponentialMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(DoubleExponentialMovingAverage::new(0).is_err());
+        assert!(DoubleExponentialMovingAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut dema = DoubleExponentialMovingAverage::new(3).unwrap();
+        assert_eq!(dema.lookback(), 0);
+
+        let outputs: Vec<f64> = [2.0, 5.0, 1.0, 6.25]
+            .iter()
+            .map(|&p| dema.next(&Bar::new().close(p)))
+            .collect();
+        // EMA 2, 3.5, 2.25, 4.25 and EMA of the EMA 2, 2.75, 2.5, 3.375
+        assert_eq!(outputs, vec![2.0, 4.25, 2.0, 5.125]);
+    }
+
+    #[test]
+    fn test_lag() {
+        // on a linear trend the DEMA catches up with the price, the EMA stays behind
+        let mut dema = DoubleExponentialMovingAverage::new(5).unwrap();
+        let mut ema = Ema::new(5).unwrap();
+        let (mut dema_lag, mut ema_lag) = (0.0, 0.0);
+        for i in 0..60 {
+            let price = i as f64;
+            dema_lag = price - dema.next(price);
+            ema_lag = price - ema.next(price);
+        }
+        assert_eq!(round(ema_lag), 2.0);
+        assert_eq!(round(dema_lag), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut dema = DoubleExponentialMovingAverage::new(3).unwrap();
+        dema.next(2.0);
+        dema.next(5.0);
+
+        dema.reset();
+        assert_eq!(dema.next(5.0), 5.0);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut dema = DoubleExponentialMovingAverage::new(3).unwrap();
+        dema.next(2.0);
+        dema.next(5.0);
+        let mut restored = serde_round_trip(&dema);
+        assert_eq!(restored.next(1.0), dema.next(1.0));
+    }
+
+    #[test]
+    fn test_default() {
+        DoubleExponentialMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let dema = DoubleExponentialMovingAverage::new(9).unwrap();
+        assert_eq!(format!("{}", dema), "DEMA(9)");
+    }
+}
@@ -0,0 +1,190 @@
+use core::fmt;
+
+use crate::{Close, Next, Reset};
+use serde::{Deserialize, Serialize};
+
+/// Maximum drawdown.
+///
+/// Tracks the running peak of an equity curve or a price series and returns how far below it
+/// the current value is, as a fraction of the peak. The worst drawdown seen so far is
+/// available with [max_drawdown](#method.max_drawdown). Both are cumulative: there is no
+/// window, the peak is the highest value since the start or the last reset.
+///
+/// # Formula
+///
+/// Peak<sub>t</sub> = max(p<sub>0</sub>, ..., p<sub>t</sub>)
+///
+/// DD<sub>t</sub> = (Peak<sub>t</sub> - p<sub>t</sub>) / Peak<sub>t</sub>
+///
+/// MaxDD<sub>t</sub> = max(DD<sub>0</sub>, ..., DD<sub>t</sub>)
+///
+/// The first value sets the peak and yields 0. While the peak is not greater than 0, the
+/// drawdown is 0.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::MaxDrawdown;
+/// use tam::Next;
+///
+/// let mut dd = MaxDrawdown::new();
+/// assert_eq!(dd.next(100.0), 0.0);
+/// assert_eq!(dd.next(80.0), 0.2);
+/// assert_eq!(dd.next(90.0), 0.1);
+/// assert_eq!(dd.max_drawdown(), 0.2);
+/// ```
+///
+/// # Links
+///
+/// * [Drawdown (economics), Wikipedia](https://en.wikipedia.org/wiki/Drawdown_(economics))
+///
+#[doc(alias = "MAXDD")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MaxDrawdown {
+    peak: Option<f64>,
+    max_drawdown: f64,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl MaxDrawdown {
+    pub fn new() -> Self {
+        Self {
+            peak: None,
+            max_drawdown: 0.0,
+            last_output: None,
+        }
+    }
+
+    /// Largest drawdown since the start or the last reset, 0 before the first value.
+    pub fn max_drawdown(&self) -> f64 {
+        self.max_drawdown
+    }
+
+    /// Highest value since the start or the last reset.
+    pub fn peak(&self) -> Option<f64> {
+        self.peak
+    }
+}
+
+impl MaxDrawdown {
+    fn next_value(&mut self, input: f64) -> f64 {
+        let peak = match self.peak {
+            Some(peak) if peak >= input => peak,
+            _ => input,
+        };
+        self.peak = Some(peak);
+
+        let drawdown = if peak > 0.0 {
+            (peak - input) / peak
+        } else {
+            0.0
+        };
+        self.max_drawdown = self.max_drawdown.max(drawdown);
+        drawdown
+    }
+}
+
+impl_next_option!(MaxDrawdown);
+
+impl<T: Close> Next<&T> for MaxDrawdown {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for MaxDrawdown {
+    fn reset(&mut self) {
+        self.peak = None;
+        self.max_drawdown = 0.0;
+        self.last_output = None;
+    }
+}
+
+impl Default for MaxDrawdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for MaxDrawdown {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MAXDD")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(MaxDrawdown);
+
+    #[test]
+    fn test_peak_trough_recovery() {
+        let mut dd = MaxDrawdown::new();
+
+        let equity = [100.0, 110.0, 99.0, 88.0, 104.5, 121.0, 114.95];
+        let drawdowns: Vec<f64> = equity.iter().map(|&e| round(dd.next(e))).collect();
+        // trough of 20% below 110, recovered to a new peak, then 5% below it
+        assert_eq!(drawdowns, vec![0.0, 0.0, 0.1, 0.2, 0.05, 0.0, 0.05]);
+        assert_eq!(round(dd.max_drawdown()), 0.2);
+        assert_eq!(dd.peak(), Some(121.0));
+    }
+
+    #[test]
+    fn test_max_drawdown_is_kept() {
+        let mut dd = MaxDrawdown::new();
+        dd.next(&Bar::new().close(50));
+        dd.next(&Bar::new().close(25));
+        assert_eq!(dd.max_drawdown(), 0.5);
+
+        // a smaller drawdown later does not replace it
+        dd.next(200.0);
+        assert_eq!(dd.next(150.0), 0.25);
+        assert_eq!(dd.max_drawdown(), 0.5);
+    }
+
+    #[test]
+    fn test_non_positive_peak() {
+        let mut dd = MaxDrawdown::new();
+        assert_eq!(dd.next(0.0), 0.0);
+        assert_eq!(dd.next(-10.0), 0.0);
+        assert_eq!(dd.next(10.0), 0.0);
+        assert_eq!(dd.next(5.0), 0.5);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut dd = MaxDrawdown::new();
+        dd.next(100.0);
+        dd.next(50.0);
+
+        dd.reset();
+        assert_eq!(dd.max_drawdown(), 0.0);
+        assert_eq!(dd.peak(), None);
+        assert_eq!(dd.next(40.0), 0.0);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut dd = MaxDrawdown::new();
+        dd.next(100.0);
+        dd.next(70.0);
+        let mut restored = serde_round_trip(&dd);
+        assert_eq!(restored.max_drawdown(), dd.max_drawdown());
+        assert_eq!(restored.next(90.0), dd.next(90.0));
+    }
+
+    #[test]
+    fn test_default() {
+        MaxDrawdown::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", MaxDrawdown::new()), "MAXDD");
+    }
+}
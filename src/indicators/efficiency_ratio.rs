@@ -1,4 +1,5 @@
-use std::fmt;
+use alloc::{boxed::Box, vec};
+use core::fmt;
 
 use crate::errors::{Result, TaError};
 use crate::traits::{Close, Next, Period, Reset};
@@ -27,7 +28,6 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(er.next(18.0), 0.8);
 /// assert_eq!(er.next(19.0), 0.75);
 /// ```
-
 #[doc(alias = "ER")]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct EfficiencyRatio {
@@ -35,6 +35,8 @@ pub struct EfficiencyRatio {
     index: usize,
     count: usize,
     deque: Box<[f64]>,
+    #[serde(default)]
+    last_output: Option<f64>,
 }
 
 impl EfficiencyRatio {
@@ -46,6 +48,7 @@ impl EfficiencyRatio {
                 index: 0,
                 count: 0,
                 deque: vec![0.0; period].into_boxed_slice(),
+                last_output: None,
             }),
         }
     }
@@ -57,10 +60,8 @@ impl Period for EfficiencyRatio {
     }
 }
 
-impl Next<f64> for EfficiencyRatio {
-    type Output = f64;
-
-    fn next(&mut self, input: f64) -> f64 {
+impl EfficiencyRatio {
+    fn next_value(&mut self, input: f64) -> f64 {
         let first = if self.count >= self.period {
             self.deque[self.index]
         } else {
@@ -90,6 +91,8 @@ impl Next<f64> for EfficiencyRatio {
     }
 }
 
+impl_next_option!(EfficiencyRatio);
+
 impl<T: Close> Next<&T> for EfficiencyRatio {
     type Output = f64;
 
@@ -105,6 +108,7 @@ impl Reset for EfficiencyRatio {
         for i in 0..self.period {
             self.deque[i] = 0.0;
         }
+        self.last_output = None;
     }
 }
 
@@ -0,0 +1,221 @@
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{
+    ExponentialMovingAverage as Ema, MovingAverageConvergenceDivergence as Macd,
+};
+use crate::{Close, Next, Period, Periods, Reset};
+use serde::{Deserialize, Serialize};
+
+/// Color of a bar in the [Elder Impulse System](struct.ElderImpulse.html).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Impulse {
+    /// The EMA and the MACD histogram are both rising.
+    Green,
+    /// The EMA and the MACD histogram are both falling.
+    Red,
+    /// The EMA and the MACD histogram disagree, or one of them is flat.
+    Blue,
+}
+
+/// Elder Impulse System.
+///
+/// Alexander Elder's bar coloring combining inertia, the slope of an EMA, and momentum, the
+/// slope of the MACD histogram:
+///
+/// * _Green_ - both are rising: buying is allowed, shorting is not
+/// * _Red_ - both are falling: shorting is allowed, buying is not
+/// * _Blue_ - otherwise
+///
+/// The slopes compare each value with the one of the previous bar, so the first bar is
+/// always blue.
+///
+/// # Parameters
+///
+/// * _ema_period_ - period of the EMA (integer greater than 0). Default is 13.
+/// * _macd_fast_ - period of the fast EMA of the MACD. Default is 12.
+/// * _macd_slow_ - period of the slow EMA of the MACD. Default is 26.
+/// * _macd_signal_ - period of the signal EMA of the MACD. Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::{ElderImpulse, Impulse};
+/// use tam::Next;
+///
+/// let mut impulse = ElderImpulse::new(3, 2, 4, 2).unwrap();
+/// assert_eq!(impulse.next(10.0), Impulse::Blue);
+/// assert_eq!(impulse.next(11.0), Impulse::Green);
+/// assert_eq!(impulse.next(12.0), Impulse::Green);
+/// assert_eq!(impulse.next(9.0), Impulse::Red);
+/// ```
+///
+/// # Links
+///
+/// * [Elder Impulse System, StockCharts](https://chartschool.stockcharts.com/table-of-contents/chart-analysis/chart-types/elder-impulse-system)
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ElderImpulse {
+    ema: Ema,
+    macd: Macd,
+    /// EMA and MACD histogram of the previous bar.
+    prev: Option<(f64, f64)>,
+}
+
+impl ElderImpulse {
+    pub fn new(
+        ema_period: usize,
+        macd_fast: usize,
+        macd_slow: usize,
+        macd_signal: usize,
+    ) -> Result<Self> {
+        Ok(Self {
+            ema: Ema::new(ema_period)?,
+            macd: Macd::new(macd_fast, macd_slow, macd_signal)?,
+            prev: None,
+        })
+    }
+}
+
+impl Periods for ElderImpulse {
+    fn periods(&self) -> Vec<usize> {
+        let mut periods = vec![self.ema.period()];
+        periods.extend(self.macd.periods());
+        periods
+    }
+}
+
+impl Next<f64> for ElderImpulse {
+    type Output = Impulse;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let ema = self.ema.next(input);
+        let histogram = self.macd.next(input).histogram;
+
+        match self.prev.replace((ema, histogram)) {
+            Some((prev_ema, prev_histogram)) if ema > prev_ema && histogram > prev_histogram => {
+                Impulse::Green
+            }
+            Some((prev_ema, prev_histogram)) if ema < prev_ema && histogram < prev_histogram => {
+                Impulse::Red
+            }
+            _ => Impulse::Blue,
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for ElderImpulse {
+    type Output = Impulse;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for ElderImpulse {
+    fn reset(&mut self) {
+        self.ema.reset();
+        self.macd.reset();
+        self.prev = None;
+    }
+}
+
+impl Default for ElderImpulse {
+    fn default() -> Self {
+        Self::new(13, 12, 26, 9).unwrap()
+    }
+}
+
+impl fmt::Display for ElderImpulse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let periods = self.periods();
+        write!(
+            f,
+            "IMPULSE({}, {}, {}, {})",
+            periods[0], periods[1], periods[2], periods[3]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(ElderImpulse::new(0, 12, 26, 9).is_err());
+        assert!(ElderImpulse::new(13, 0, 26, 9).is_err());
+        assert!(ElderImpulse::new(13, 12, 0, 9).is_err());
+        assert!(ElderImpulse::new(13, 12, 26, 0).is_err());
+        assert!(ElderImpulse::new(1, 1, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_transitions() {
+        let mut impulse = ElderImpulse::new(3, 2, 4, 2).unwrap();
+        let colors: Vec<Impulse> = [10.0, 11.0, 12.0, 12.5, 12.6, 9.0, 8.0, 8.5, 10.0]
+            .iter()
+            .map(|&close| impulse.next(&Bar::new().close(close)))
+            .collect();
+        assert_eq!(
+            colors,
+            vec![
+                Impulse::Blue,
+                Impulse::Green,
+                Impulse::Green,
+                // the rally slows down: the EMA still rises, the histogram falls
+                Impulse::Blue,
+                Impulse::Blue,
+                Impulse::Red,
+                // the decline slows down
+                Impulse::Blue,
+                Impulse::Blue,
+                Impulse::Green,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_flat() {
+        let mut impulse = ElderImpulse::new(3, 2, 4, 2).unwrap();
+        for _ in 0..5 {
+            assert_eq!(impulse.next(10.0), Impulse::Blue);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut impulse = ElderImpulse::new(3, 2, 4, 2).unwrap();
+        impulse.next(10.0);
+        assert_eq!(impulse.next(11.0), Impulse::Green);
+
+        impulse.reset();
+        assert_eq!(impulse.next(11.0), Impulse::Blue);
+        assert_eq!(impulse.next(9.0), Impulse::Red);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut impulse = ElderImpulse::new(3, 2, 4, 2).unwrap();
+        impulse.next(10.0);
+        impulse.next(11.0);
+        let mut restored = serde_round_trip(&impulse);
+        assert_eq!(restored, impulse);
+        assert_eq!(restored.next(11.5), impulse.next(11.5));
+    }
+
+    #[test]
+    fn test_default() {
+        ElderImpulse::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            format!("{}", ElderImpulse::default()),
+            "IMPULSE(13, 12, 26, 9)"
+        );
+    }
+}
@@ -0,0 +1,245 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float;
+use crate::{PairIndicator, Reset};
+use serde::{Deserialize, Serialize};
+
+/// Exponentially weighted correlation.
+///
+/// The decay weighted analog of [Correlation](struct.Correlation.html): instead of a fixed
+/// window, every point is kept with a weight that decays geometrically with its age, so
+/// recent observations count more and a regime change shows up quickly.
+///
+/// # Formula
+///
+/// With α = 1 - _decay_ and d<sub>x</sub> = x<sub>t</sub> - mean<sub>x,t-1</sub> (likewise for y):
+///
+/// mean<sub>x,t</sub> = mean<sub>x,t-1</sub> + α * d<sub>x</sub>
+///
+/// var<sub>x,t</sub> = _decay_ * (var<sub>x,t-1</sub> + α * d<sub>x</sub>²)
+///
+/// cov<sub>t</sub> = _decay_ * (cov<sub>t-1</sub> + α * d<sub>x</sub> * d<sub>y</sub>)
+///
+/// Correlation = cov<sub>t</sub> / sqrt(var<sub>x,t</sub> * var<sub>y,t</sub>)
+///
+/// The means start at the first point. The output is 0 until there are 2 points, or while
+/// one of the variances is 0, and is clamped to [-1, 1].
+///
+/// # Parameters
+///
+/// * _decay_ - weight of the previous state (number in (0, 1)). Default is 0.94.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::ExponentialCorrelation;
+/// use tam::Next;
+///
+/// let mut corr = ExponentialCorrelation::new(0.9).unwrap();
+/// assert_eq!(corr.next((2.0, 3.0)), 0.0);
+/// assert_eq!(corr.next((3.0, 2.0)), -1.0);
+/// assert!(corr.next((6.0, 1.0)) < -0.9);
+/// ```
+///
+/// # Links
+///
+/// * [Incremental calculation of weighted mean and variance, Tony Finch](https://fanf2.user.srcf.net/hermes/doc/antiforgery/stats.pdf)
+///
+#[doc(alias = "EWCORR")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExponentialCorrelation {
+    decay: f64,
+    is_new: bool,
+    mean_x: f64,
+    mean_y: f64,
+    var_x: f64,
+    var_y: f64,
+    cov: f64,
+}
+
+impl ExponentialCorrelation {
+    pub fn new(decay: f64) -> Result<Self> {
+        if !(decay > 0.0 && decay < 1.0) {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            decay,
+            is_new: true,
+            mean_x: 0.0,
+            mean_y: 0.0,
+            var_x: 0.0,
+            var_y: 0.0,
+            cov: 0.0,
+        })
+    }
+}
+
+impl PairIndicator for ExponentialCorrelation {
+    fn next_pair(&mut self, x: f64, y: f64) -> f64 {
+        if self.is_new {
+            self.is_new = false;
+            self.mean_x = x;
+            self.mean_y = y;
+            return 0.0;
+        }
+
+        let alpha = 1.0 - self.decay;
+        let dx = x - self.mean_x;
+        let dy = y - self.mean_y;
+        self.mean_x += alpha * dx;
+        self.mean_y += alpha * dy;
+        self.var_x = self.decay * (self.var_x + alpha * dx * dx);
+        self.var_y = self.decay * (self.var_y + alpha * dy * dy);
+        self.cov = self.decay * (self.cov + alpha * dx * dy);
+
+        let denominator = self.var_x * self.var_y;
+        if denominator <= 0.0 {
+            return 0.0;
+        }
+        (self.cov / denominator.sqrt()).clamp(-1.0, 1.0)
+    }
+}
+
+impl Reset for ExponentialCorrelation {
+    fn reset(&mut self) {
+        self.is_new = true;
+        self.mean_x = 0.0;
+        self.mean_y = 0.0;
+        self.var_x = 0.0;
+        self.var_y = 0.0;
+        self.cov = 0.0;
+    }
+}
+
+impl Default for ExponentialCorrelation {
+    fn default() -> Self {
+        Self::new(0.94).unwrap()
+    }
+}
+
+impl fmt::Display for ExponentialCorrelation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "EWCORR({})", self.decay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Next;
+    use alloc::{format, vec::Vec};
+
+    /// Correlation with explicit weights: the first point has weight decay^(n-1), the point
+    /// of age k < n - 1 has weight (1 - decay) * decay^k.
+    fn manual(decay: f64, points: &[(f64, f64)]) -> f64 {
+        let n = points.len();
+        let weights: Vec<f64> = (0..n)
+            .map(|i| {
+                let age = (n - 1 - i) as i32;
+                if i == 0 {
+                    decay.powi(age)
+                } else {
+                    (1.0 - decay) * decay.powi(age)
+                }
+            })
+            .collect();
+
+        let weighted = |f: &dyn Fn(f64, f64) -> f64| -> f64 {
+            points
+                .iter()
+                .zip(weights.iter())
+                .map(|(&(x, y), w)| w * f(x, y))
+                .sum()
+        };
+        let mean_x = weighted(&|x, _| x);
+        let mean_y = weighted(&|_, y| y);
+        let var_x = weighted(&|x, _| (x - mean_x).powi(2));
+        let var_y = weighted(&|_, y| (y - mean_y).powi(2));
+        let cov = weighted(&|x, y| (x - mean_x) * (y - mean_y));
+        cov / (var_x * var_y).sqrt()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(ExponentialCorrelation::new(0.0).is_err());
+        assert!(ExponentialCorrelation::new(1.0).is_err());
+        assert!(ExponentialCorrelation::new(f64::NAN).is_err());
+        assert!(ExponentialCorrelation::new(0.5).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let points = [
+            (2.0, 3.0),
+            (3.0, 2.0),
+            (6.0, 1.0),
+            (5.0, 2.0),
+            (7.0, 4.0),
+            (4.0, 4.5),
+            (8.0, 6.0),
+        ];
+        let mut corr = ExponentialCorrelation::new(0.8).unwrap();
+
+        assert_eq!(corr.next(points[0]), 0.0);
+        for n in 2..=points.len() {
+            let value = corr.next(points[n - 1]);
+            let expected = manual(0.8, &points[..n]);
+            assert!(
+                (value - expected).abs() < 1e-12,
+                "{} != {}",
+                value,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_regime_change() {
+        // recent points dominate: after a long positive correlation, a short negative stretch
+        // already turns the correlation negative
+        let mut corr = ExponentialCorrelation::new(0.8).unwrap();
+        for i in 0..100 {
+            let x = (i as f64 / 2.0).sin();
+            corr.next((x, x));
+        }
+        let mut value = 0.0;
+        for i in 0..15 {
+            let x = (i as f64 / 2.0).sin();
+            value = corr.next((x, -x));
+        }
+        assert!(value < -0.8, "{}", value);
+    }
+
+    #[test]
+    fn test_constant() {
+        let mut corr = ExponentialCorrelation::new(0.9).unwrap();
+        for _ in 0..5 {
+            assert_eq!(corr.next((1.0, 2.0)), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut corr = ExponentialCorrelation::new(0.9).unwrap();
+        corr.next((2.0, 3.0));
+        corr.next((3.0, 2.0));
+
+        corr.reset();
+        assert_eq!(corr.next((8.0, 9.0)), 0.0);
+        assert_eq!(corr.next((9.0, 10.0)), 1.0);
+    }
+
+    #[test]
+    fn test_default() {
+        ExponentialCorrelation::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let corr = ExponentialCorrelation::new(0.9).unwrap();
+        assert_eq!(format!("{}", corr), "EWCORR(0.9)");
+    }
+}
@@ -1,7 +1,12 @@
-use std::fmt;
+use alloc::vec::Vec;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::helpers::{batch, warmup_tail};
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float;
+use crate::{Close, Next, Period, Reset, Warmup};
 use serde::{Deserialize, Serialize};
 
 /// An exponential moving average (EMA), also known as an exponentially weighted moving average
@@ -33,6 +38,22 @@ use serde::{Deserialize, Serialize};
 ///
 /// * _period_ - number of periods (integer greater than 0)
 ///
+/// Some strategies give _α_ rather than a period: [with_custom_alpha](#method.with_custom_alpha)
+/// sets it directly.
+///
+/// # Seeding
+///
+/// The recursion needs an initial value, and libraries disagree on it:
+///
+/// * [EmaSeed::FirstValue](enum.EmaSeed.html) - the first input is used as is, so a value is
+///   emitted from the very first bar. This is `EmaSeed::default()`, what `new` does.
+/// * [EmaSeed::Sma](enum.EmaSeed.html) - the first `period` inputs are averaged, the first
+///   `period - 1` outputs are NaN. This matches TA-Lib.
+///
+/// The two seeds produce different values while the influence of the seed decays, roughly by
+/// a factor of `1 - α` per bar, so after a few multiples of `period` bars they agree to many
+/// decimals. Use [with_seed](#method.with_seed) to cross-validate against another library.
+///
 /// # Example
 ///
 /// ```
@@ -50,7 +71,6 @@ use serde::{Deserialize, Serialize};
 ///
 /// * [Exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Moving_average#Exponential_moving_average)
 ///
-
 #[doc(alias = "EMA")]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ExponentialMovingAverage {
@@ -58,6 +78,29 @@ pub struct ExponentialMovingAverage {
     k: f64,
     current: f64,
     is_new: bool,
+    #[serde(default = "first_value")]
+    seed: EmaSeed,
+    #[serde(default)]
+    count: usize,
+    #[serde(default)]
+    sum: f64,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+/// Initial value of an [EMA](struct.ExponentialMovingAverage.html).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum EmaSeed {
+    /// Simple average of the first `period` inputs (TA-Lib).
+    Sma,
+    /// The first input.
+    #[default]
+    FirstValue,
+}
+
+// EMAs serialized before seeding was configurable used the first value.
+fn first_value() -> EmaSeed {
+    EmaSeed::FirstValue
 }
 
 impl ExponentialMovingAverage {
@@ -69,9 +112,97 @@ impl ExponentialMovingAverage {
                 k: 2.0 / (period + 1) as f64,
                 current: 0.0,
                 is_new: true,
+                seed: EmaSeed::default(),
+                count: 0,
+                sum: 0.0,
+                last_output: None,
             }),
         }
     }
+
+    /// Creates Wilder's smoothing (RMA): α = 1 / _period_ and the SMA seed.
+    pub(crate) fn wilder(period: usize) -> Result<Self> {
+        let mut ema = Self::new(period)?.with_seed(EmaSeed::Sma);
+        ema.k = 1.0 / period as f64;
+        Ok(ema)
+    }
+
+    /// Changes how the EMA is seeded, see [Seeding](#seeding).
+    pub fn with_seed(mut self, seed: EmaSeed) -> Self {
+        self.seed = seed;
+        self.reset();
+        self
+    }
+
+    pub fn seed(&self) -> EmaSeed {
+        self.seed
+    }
+
+    /// Sets the smoothing factor _α_ directly instead of deriving it from the period.
+    ///
+    /// The period becomes the equivalent `2 / α - 1`, rounded, which is what
+    /// [period](../trait.Period.html) reports and how many inputs the SMA seed averages.
+    ///
+    /// Returns `TaError::InvalidParameter` unless `0 < alpha <= 1`.
+    pub fn with_custom_alpha(mut self, alpha: f64) -> Result<Self> {
+        if !(alpha > 0.0 && alpha <= 1.0) {
+            return Err(TaError::InvalidParameter);
+        }
+        self.k = alpha;
+        self.period = (2.0 / alpha - 1.0).round() as usize;
+        self.reset();
+        Ok(self)
+    }
+
+    /// Smoothing factor _α_.
+    pub fn alpha(&self) -> f64 {
+        self.k
+    }
+
+    /// Creates an EMA fed with every value of `warmup`.
+    ///
+    /// The EMA remembers all of its inputs, so the whole of `warmup` is used, not only the
+    /// last [lookback](../trait.Warmup.html) values. With the first value seed of `new` any
+    /// warmup is enough, even an empty one.
+    ///
+    /// Returns `TaError::InsufficientData` if `warmup` has fewer values than the lookback.
+    pub fn ready(period: usize, warmup: &[f64]) -> Result<Self> {
+        let mut ema = Self::new(period)?;
+        warmup_tail(warmup, ema.lookback())?;
+        for &value in warmup {
+            ema.next(value);
+        }
+        Ok(ema)
+    }
+
+    /// Computes the EMA of every value of `inputs`.
+    ///
+    /// The first [lookback](../trait.Warmup.html) outputs are NaN: none with the first value
+    /// seed of `new`, so the outputs are those of `next`. See [Seeding](#seeding).
+    ///
+    /// Returns `TaError::InvalidParameter` if `period` is 0.
+    pub fn calculate(period: usize, inputs: &[f64]) -> Result<Vec<f64>> {
+        let mut ema = Self::new(period)?;
+        Ok(batch(ema.lookback(), inputs, |&value| ema.next(value)))
+    }
+
+    /// Recomputes the smoothed value from `recent` history, discarding the accumulated state.
+    ///
+    /// After months of continuous updates the running value can carry rounding error that the
+    /// recursion never fully washes out. Calling this periodically (e.g. once per session, with
+    /// at least the last few multiples of `period` inputs) re-seeds the EMA with the first value
+    /// of `recent` and replays the rest, so the result only depends on that window.
+    ///
+    /// Does nothing if `recent` is empty.
+    pub fn recalibrate(&mut self, recent: &[f64]) {
+        if recent.is_empty() {
+            return;
+        }
+        self.reset();
+        for &value in recent {
+            self.next(value);
+        }
+    }
 }
 
 impl Period for ExponentialMovingAverage {
@@ -80,20 +211,43 @@ impl Period for ExponentialMovingAverage {
     }
 }
 
-impl Next<f64> for ExponentialMovingAverage {
-    type Output = f64;
+impl Warmup for ExponentialMovingAverage {
+    fn lookback(&self) -> usize {
+        match self.seed {
+            EmaSeed::FirstValue => 0,
+            EmaSeed::Sma => self.period - 1,
+        }
+    }
+}
 
-    fn next(&mut self, input: f64) -> Self::Output {
+impl ExponentialMovingAverage {
+    fn next_value(&mut self, input: f64) -> f64 {
         if self.is_new {
+            match self.seed {
+                EmaSeed::FirstValue => self.current = input,
+                EmaSeed::Sma => {
+                    self.count += 1;
+                    self.sum += input;
+                    if self.count < self.period {
+                        return f64::NAN;
+                    }
+                    self.current = self.sum / self.period as f64;
+                }
+            }
             self.is_new = false;
-            self.current = input;
         } else {
             self.current = self.k * input + (1.0 - self.k) * self.current;
+            // flush denormals to zero, they only slow down further computation
+            if self.current.is_subnormal() {
+                self.current = 0.0;
+            }
         }
         self.current
     }
 }
 
+impl_next_option!(ExponentialMovingAverage);
+
 impl<T: Close> Next<&T> for ExponentialMovingAverage {
     type Output = f64;
 
@@ -106,6 +260,9 @@ impl Reset for ExponentialMovingAverage {
     fn reset(&mut self) {
         self.current = 0.0;
         self.is_new = true;
+        self.count = 0;
+        self.sum = 0.0;
+        self.last_output = None;
     }
 }
 
@@ -134,6 +291,43 @@ mod tests {
         assert!(ExponentialMovingAverage::new(1).is_ok());
     }
 
+    #[test]
+    fn test_calculate() {
+        let inputs = [2.0, 5.0, 1.0, 6.25];
+        let out = ExponentialMovingAverage::calculate(3, &inputs).unwrap();
+        assert_eq!(out, [2.0, 3.5, 2.25, 4.25]);
+
+        // the same outputs as next, from the first bar
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+        let outputs: Vec<f64> = inputs.iter().map(|&x| ema.next(x)).collect();
+        assert_eq!(out, outputs);
+
+        assert!(ExponentialMovingAverage::calculate(0, &[1.0]).is_err());
+    }
+
+    #[test]
+    fn test_lookback() {
+        let ema = ExponentialMovingAverage::new(3).unwrap();
+        assert_eq!(ema.lookback(), 0);
+        assert_eq!(ema.with_seed(EmaSeed::Sma).lookback(), 2);
+    }
+
+    #[test]
+    fn test_ready() {
+        assert_eq!(
+            ExponentialMovingAverage::ready(0, &[]),
+            Err(TaError::InvalidParameter)
+        );
+
+        // the first value seed needs no warmup
+        let mut ema = ExponentialMovingAverage::ready(3, &[]).unwrap();
+        assert_eq!(ema.next(2.0), 2.0);
+
+        // the whole warmup is used
+        let mut ema = ExponentialMovingAverage::ready(3, &[2.0, 5.0]).unwrap();
+        assert_eq!(ema.next(1.0), 2.25);
+    }
+
     #[test]
     fn test_next() {
         let mut ema = ExponentialMovingAverage::new(3).unwrap();
@@ -150,6 +344,147 @@ mod tests {
         assert_eq!(ema.next(&bar2), 3.5);
     }
 
+    #[test]
+    fn test_seed_first_value() {
+        assert_eq!(EmaSeed::default(), EmaSeed::FirstValue);
+        assert_eq!(
+            ExponentialMovingAverage::new(3).unwrap().seed(),
+            EmaSeed::default()
+        );
+
+        let mut ema = ExponentialMovingAverage::new(3)
+            .unwrap()
+            .with_seed(EmaSeed::FirstValue);
+        let outputs: Vec<f64> = [2.0, 5.0, 1.0, 6.25, 4.0]
+            .iter()
+            .map(|&x| ema.next(x))
+            .collect();
+        assert_eq!(outputs, vec![2.0, 3.5, 2.25, 4.25, 4.125]);
+    }
+
+    #[test]
+    fn test_seed_sma() {
+        let mut ema = ExponentialMovingAverage::new(3)
+            .unwrap()
+            .with_seed(EmaSeed::Sma);
+        let outputs: Vec<f64> = [2.0, 5.0, 1.0, 6.25, 4.0, 7.0]
+            .iter()
+            .map(|&x| round(ema.next(x)))
+            .collect();
+        assert!(outputs[0].is_nan());
+        assert!(outputs[1].is_nan());
+        assert_eq!(&outputs[2..], &[2.667, 4.458, 4.229, 5.615]);
+
+        ema.reset();
+        assert!(ema.next(2.0).is_nan());
+    }
+
+    #[test]
+    fn test_seeds_converge() {
+        let mut first = ExponentialMovingAverage::new(5).unwrap();
+        let mut sma = ExponentialMovingAverage::new(5)
+            .unwrap()
+            .with_seed(EmaSeed::Sma);
+
+        let mut diffs = Vec::new();
+        for i in 0..100 {
+            let x = 100.0 + 5.0 * i as f64 + (i as f64 * 0.7).sin();
+            diffs.push((first.next(x) - sma.next(x)).abs());
+        }
+        assert!(diffs[4] > 1.0);
+        assert!(diffs[99] < 1e-9);
+    }
+
+    #[test]
+    fn test_custom_alpha() {
+        let ema = || ExponentialMovingAverage::new(9).unwrap();
+        for &alpha in [0.0, -0.1, 1.01, f64::NAN, f64::INFINITY].iter() {
+            assert_eq!(
+                ema().with_custom_alpha(alpha),
+                Err(TaError::InvalidParameter)
+            );
+        }
+        assert!(ema().with_custom_alpha(1.0).is_ok());
+        assert!(ema().with_custom_alpha(1e-6).is_ok());
+
+        let mut ema = ema().with_custom_alpha(0.25).unwrap();
+        assert_eq!(ema.alpha(), 0.25);
+        assert_eq!(ema.next(2.0), 2.0);
+        assert_eq!(ema.next(6.0), 3.0);
+    }
+
+    #[test]
+    fn test_custom_alpha_period() {
+        let period = |alpha| {
+            ExponentialMovingAverage::default()
+                .with_custom_alpha(alpha)
+                .unwrap()
+                .period()
+        };
+        assert_eq!(period(0.5), 3);
+        assert_eq!(period(0.1), 19);
+        assert_eq!(period(1.0), 1);
+        // 2 / 0.3 - 1 = 5.667
+        assert_eq!(period(0.3), 6);
+        assert_eq!(period(0.9), 1);
+
+        // the same α as the period
+        let ema = ExponentialMovingAverage::new(7).unwrap();
+        assert_eq!(
+            ExponentialMovingAverage::default()
+                .with_custom_alpha(ema.alpha())
+                .unwrap(),
+            ema
+        );
+
+        // the SMA seed averages the equivalent period
+        let mut ema = ExponentialMovingAverage::default()
+            .with_seed(EmaSeed::Sma)
+            .with_custom_alpha(0.5)
+            .unwrap();
+        assert_eq!(ema.lookback(), 2);
+        assert!(ema.next(2.0).is_nan());
+        assert!(ema.next(4.0).is_nan());
+        assert_eq!(ema.next(6.0), 4.0);
+    }
+
+    #[test]
+    fn test_recalibrate() {
+        let mut ema = ExponentialMovingAverage::new(10).unwrap();
+        ema.next(1e9);
+        for _ in 0..1_000_000 {
+            ema.next(0.1);
+        }
+        // the rounding error of the spike is never washed out
+        let mut drifted = ema.clone();
+        assert_ne!(drifted.next(0.1), 0.1);
+
+        ema.recalibrate(&[0.1; 30]);
+        assert_eq!(ema.next(0.1), 0.1);
+        assert_ne!(ema, drifted);
+
+        // empty history keeps the current state
+        ema.recalibrate(&[]);
+        assert_eq!(ema.next(0.1), 0.1);
+
+        let mut fresh = ExponentialMovingAverage::new(10).unwrap();
+        ema.recalibrate(&[2.0, 5.0, 1.0]);
+        fresh.next(2.0);
+        fresh.next(5.0);
+        fresh.next(1.0);
+        assert_eq!(ema, fresh);
+    }
+
+    #[test]
+    fn test_subnormal() {
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+        ema.next(1e-300);
+        for _ in 0..100 {
+            assert!(!ema.next(0.0).is_subnormal());
+        }
+        assert_eq!(ema.next(0.0), 0.0);
+    }
+
     #[test]
     fn test_reset() {
         let mut ema = ExponentialMovingAverage::new(5).unwrap();
@@ -1,4 +1,4 @@
-use std::fmt;
+use core::fmt;
 
 use crate::errors::Result;
 use crate::indicators::{Maximum, Minimum};
@@ -21,6 +21,23 @@ use serde::{Deserialize, Serialize};
 /// * L<sub>n</sub> - lowest price for the last _n_ periods
 /// * H<sub>n</sub> - highest price for the last _n_ periods
 ///
+/// # Modes
+///
+/// Charting platforms disagree on the numerator and denominator, the convention is chosen with
+/// [with_mode](#method.with_mode):
+///
+/// * [StochMode::FastK](enum.StochMode.html) - the formula above, from 0 to 100. This is
+///   what `new` does.
+/// * [StochMode::SlowK](enum.StochMode.html) - the numerators and the denominators are summed
+///   over the last 3 bars before dividing:
+///   100 * Σ(C<sub>t</sub> - L<sub>n</sub>) / Σ(H<sub>n</sub> - L<sub>n</sub>), from 0 to 100.
+///   Unlike an average of %K, a bar with a wide range weighs more than a quiet one.
+/// * [StochMode::Midpoint](enum.StochMode.html) - the distance of the close from the middle of
+///   the range, relative to half the range:
+///   100 * (C<sub>t</sub> - (H<sub>n</sub> + L<sub>n</sub>) / 2) / ((H<sub>n</sub> - L<sub>n</sub>) / 2),
+///   from -100 to 100. It is 2 * %K - 100.
+///
+/// With an empty range the output is 50 (0 in the midpoint mode).
 ///
 /// # Parameters
 ///
@@ -44,16 +61,92 @@ pub struct FastStochastic {
     period: usize,
     minimum: Minimum,
     maximum: Maximum,
+    #[serde(default)]
+    last_output: Option<f64>,
+    #[serde(default)]
+    mode: StochMode,
+    /// Numerators and denominators of the last bars, for the slow %K mode.
+    #[serde(default)]
+    ranges: [(f64, f64); SLOW_K_BARS],
+    #[serde(default)]
+    index: usize,
+}
+
+/// Numerator and denominator convention of a [FastStochastic](struct.FastStochastic.html),
+/// see [Modes](struct.FastStochastic.html#modes).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum StochMode {
+    /// Close relative to the range of the last _period_ bars.
+    #[default]
+    FastK,
+    /// Sums of the fast %K numerators and denominators over the last 3 bars.
+    SlowK,
+    /// Close relative to the middle of the range, from -100 to 100.
+    Midpoint,
 }
 
+/// Number of bars summed by [StochMode::SlowK](enum.StochMode.html).
+const SLOW_K_BARS: usize = 3;
+
 impl FastStochastic {
     pub fn new(period: usize) -> Result<Self> {
         Ok(Self {
             period,
             minimum: Minimum::new(period)?,
             maximum: Maximum::new(period)?,
+            last_output: None,
+            mode: StochMode::FastK,
+            ranges: [(0.0, 0.0); SLOW_K_BARS],
+            index: 0,
         })
     }
+
+    /// Changes the numerator and denominator convention, see [Modes](#modes).
+    pub fn with_mode(mut self, mode: StochMode) -> Self {
+        self.mode = mode;
+        self.reset();
+        self
+    }
+
+    pub fn mode(&self) -> StochMode {
+        self.mode
+    }
+
+    fn stochastic(&mut self, close: f64, highest: f64, lowest: f64) -> f64 {
+        match self.mode {
+            StochMode::FastK => {
+                if highest == lowest {
+                    // When only 1 input was given, than min and max are the same,
+                    // therefore it makes sense to return 50
+                    50.0
+                } else {
+                    (close - lowest) / (highest - lowest) * 100.0
+                }
+            }
+            StochMode::SlowK => {
+                self.ranges[self.index] = (close - lowest, highest - lowest);
+                self.index = (self.index + 1) % SLOW_K_BARS;
+
+                // the buffer starts with zeros, which add nothing to the sums
+                let (numerator, denominator) = self
+                    .ranges
+                    .iter()
+                    .fold((0.0, 0.0), |(n, d), &(num, den)| (n + num, d + den));
+                if denominator == 0.0 {
+                    50.0
+                } else {
+                    numerator / denominator * 100.0
+                }
+            }
+            StochMode::Midpoint => {
+                if highest == lowest {
+                    0.0
+                } else {
+                    (close - (highest + lowest) / 2.0) / ((highest - lowest) / 2.0) * 100.0
+                }
+            }
+        }
+    }
 }
 
 impl Period for FastStochastic {
@@ -62,37 +155,25 @@ impl Period for FastStochastic {
     }
 }
 
-impl Next<f64> for FastStochastic {
-    type Output = f64;
-
-    fn next(&mut self, input: f64) -> Self::Output {
+impl FastStochastic {
+    fn next_value(&mut self, input: f64) -> f64 {
         let min = self.minimum.next(input);
         let max = self.maximum.next(input);
-
-        if min == max {
-            // When only 1 input was given, than min and max are the same,
-            // therefore it makes sense to return 50
-            50.0
-        } else {
-            (input - min) / (max - min) * 100.0
-        }
+        self.stochastic(input, max, min)
     }
 }
 
+impl_next_option!(FastStochastic);
+
 impl<T: High + Low + Close> Next<&T> for FastStochastic {
     type Output = f64;
 
     fn next(&mut self, input: &T) -> Self::Output {
         let highest = self.maximum.next(input.high());
         let lowest = self.minimum.next(input.low());
-        let close = input.close();
-
-        if highest == lowest {
-            // To avoid division by zero, return 50.0
-            50.0
-        } else {
-            (close - lowest) / (highest - lowest) * 100.0
-        }
+        let output = self.stochastic(input.close(), highest, lowest);
+        self.last_output = Some(output);
+        output
     }
 }
 
@@ -100,6 +181,9 @@ impl Reset for FastStochastic {
     fn reset(&mut self) {
         self.minimum.reset();
         self.maximum.reset();
+        self.last_output = None;
+        self.ranges = [(0.0, 0.0); SLOW_K_BARS];
+        self.index = 0;
     }
 }
 
@@ -158,6 +242,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_next_option() {
+        let mut stoch = FastStochastic::new(3).unwrap();
+        assert!(stoch.next(None).is_nan());
+        assert_eq!(stoch.next(20.0), 50.0);
+        assert_eq!(stoch.next(None), 50.0);
+        assert_eq!(stoch.next(Some(30.0)), 100.0);
+        assert_eq!(stoch.next(None), 100.0);
+
+        let bar = Bar::new().high(40.0).low(20.0).close(25.0);
+        assert_eq!(stoch.next(&bar), 25.0);
+        assert_eq!(stoch.next(None), 25.0);
+    }
+
+    #[test]
+    fn test_modes() {
+        // high, low, close
+        let bars = [
+            (30.0, 10.0, 25.0),
+            (40.0, 20.0, 16.0),
+            (35.0, 15.0, 19.0),
+            (30.0, 20.0, 25.0),
+        ];
+        let outputs = |mode| {
+            let mut stoch = FastStochastic::new(3).unwrap().with_mode(mode);
+            bars.iter()
+                .map(|&(high, low, close)| {
+                    round(stoch.next(&Bar::new().high(high).low(low).close(close)))
+                })
+                .collect::<Vec<f64>>()
+        };
+
+        // ranges 10-30, 10-40, 10-40, 15-40
+        assert_eq!(outputs(StochMode::FastK), vec![75.0, 20.0, 30.0, 40.0]);
+        // (15 + 6 + 9) / (20 + 30 + 30) then (6 + 9 + 10) / (30 + 30 + 25)
+        assert_eq!(outputs(StochMode::SlowK), vec![75.0, 42.0, 37.5, 29.412]);
+        assert_eq!(
+            outputs(StochMode::Midpoint),
+            vec![50.0, -60.0, -40.0, -20.0]
+        );
+    }
+
+    #[test]
+    fn test_flat_modes() {
+        for (mode, expected) in [
+            (StochMode::FastK, 50.0),
+            (StochMode::SlowK, 50.0),
+            (StochMode::Midpoint, 0.0),
+        ] {
+            let mut stoch = FastStochastic::new(3).unwrap().with_mode(mode);
+            assert_eq!(stoch.mode(), mode);
+            assert_eq!(stoch.next(10.0), expected);
+            assert_eq!(stoch.next(10.0), expected);
+        }
+    }
+
     #[test]
     fn test_reset() {
         let mut indicator = FastStochastic::new(10).unwrap();
@@ -170,6 +310,13 @@ mod tests {
         assert_eq!(indicator.next(10.0), 50.0);
         assert_eq!(indicator.next(20.0), 100.0);
         assert_eq!(indicator.next(12.5), 25.0);
+
+        let mut slow_k = FastStochastic::new(2).unwrap().with_mode(StochMode::SlowK);
+        slow_k.next(10.0);
+        slow_k.next(20.0);
+        slow_k.reset();
+        assert_eq!(slow_k.next(10.0), 50.0);
+        assert_eq!(slow_k.next(14.0), 100.0);
     }
 
     #[test]
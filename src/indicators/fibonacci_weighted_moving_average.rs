@@ -0,0 +1,241 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Reset};
+use serde::{Deserialize, Serialize};
+
+/// Fibonacci weights above this are rescaled, long before `f64` overflows (~1e308).
+const RESCALE_LIMIT: f64 = 1e290;
+
+/// Largest period whose oldest weight, after the rescalings, is still above 0.
+const MAX_PERIOD: usize = 2776;
+
+/// Fibonacci weighted moving average (FWMA).
+///
+/// A moving average that weights the values of the window by Fibonacci numbers: in an _n_-day
+/// FWMA the latest day has weight _F<sub>n</sub>_, the second latest _F<sub>n-1</sub>_, etc.,
+/// down to _F<sub>1</sub>_ = 1. It reacts faster than the [WMA](struct.WeightedMovingAverage.html)
+/// as the weights grow exponentially.
+///
+/// # Formula
+///
+/// FWMA = (F<sub>1</sub> * p<sub>t-n+1</sub> + ... + F<sub>n</sub> * p<sub>t</sub>) / (F<sub>1</sub> + ... + F<sub>n</sub>)
+///
+/// Where _F<sub>k</sub>_ is the _k_-th Fibonacci number (1, 1, 2, 3, 5, ...) and _n_ the
+/// period. Until the window is filled, _n_ is the number of inputs so far.
+///
+/// The weights are not uniform, so the window is recomputed in O(_period_) on every input
+/// instead of the O(1) update of the WMA. They are kept in `f64` and rescaled for large
+/// periods, where the oldest weights become negligible.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0 and at most 2776, beyond which the
+///   oldest weights underflow to 0 and the oldest values would be silently ignored). Default
+///   is 9.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::FibonacciWeightedMovingAverage;
+/// use tam::Next;
+///
+/// let mut fwma = FibonacciWeightedMovingAverage::new(4).unwrap();
+/// assert_eq!(fwma.next(10.0), 10.0);
+/// assert_eq!(fwma.next(20.0), 15.0);
+/// assert_eq!(fwma.next(30.0), 22.5);
+/// assert_eq!(fwma.next(40.0), 30.0);
+/// assert_eq!(fwma.next(50.0), 40.0);
+/// ```
+///
+#[doc(alias = "FWMA")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FibonacciWeightedMovingAverage {
+    period: usize,
+    index: usize,
+    count: usize,
+    weights: Box<[f64]>,
+    deque: Box<[f64]>,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl FibonacciWeightedMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            p if p > MAX_PERIOD => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                weights: fibonacci_weights(period).into_boxed_slice(),
+                deque: vec![0.0; period].into_boxed_slice(),
+                last_output: None,
+            }),
+        }
+    }
+}
+
+/// Returns F<sub>1</sub>..F<sub>n</sub>, all divided by the same factor when they grow too large.
+fn fibonacci_weights(n: usize) -> Vec<f64> {
+    let mut weights: Vec<f64> = Vec::with_capacity(n);
+    for k in 0..n {
+        let weight = if k < 2 {
+            1.0
+        } else {
+            weights[k - 1] + weights[k - 2]
+        };
+        weights.push(weight);
+
+        if weight > RESCALE_LIMIT {
+            for w in weights.iter_mut() {
+                *w /= RESCALE_LIMIT;
+            }
+        }
+    }
+    weights
+}
+
+impl Period for FibonacciWeightedMovingAverage {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl FibonacciWeightedMovingAverage {
+    fn next_value(&mut self, input: f64) -> f64 {
+        self.deque[self.index] = input;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+        if self.count < self.period {
+            self.count += 1;
+        }
+
+        // oldest value first, until the window is full it starts at 0
+        let start = if self.count < self.period {
+            0
+        } else {
+            self.index
+        };
+        let (mut sum, mut weight_sum) = (0.0, 0.0);
+        for (i, &weight) in self.weights[..self.count].iter().enumerate() {
+            sum += weight * self.deque[(start + i) % self.period];
+            weight_sum += weight;
+        }
+        sum / weight_sum
+    }
+}
+
+impl_next_option!(FibonacciWeightedMovingAverage);
+
+impl<T: Close> Next<&T> for FibonacciWeightedMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for FibonacciWeightedMovingAverage {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+        self.last_output = None;
+    }
+}
+
+impl Default for FibonacciWeightedMovingAverage {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for FibonacciWeightedMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FWMA({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(FibonacciWeightedMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(FibonacciWeightedMovingAverage::new(0).is_err());
+        assert!(FibonacciWeightedMovingAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_fibonacci_weights() {
+        assert_eq!(fibonacci_weights(1), vec![1.0]);
+        assert_eq!(fibonacci_weights(6), vec![1.0, 1.0, 2.0, 3.0, 5.0, 8.0]);
+
+        // F(1500) overflows f64, the rescaled weights keep the Fibonacci ratios
+        let weights = fibonacci_weights(1500);
+        assert!(weights.iter().all(|w| w.is_finite()));
+        assert_eq!(round(weights[1499] / weights[1498]), 1.618);
+    }
+
+    #[test]
+    fn test_next() {
+        let mut fwma = FibonacciWeightedMovingAverage::new(5).unwrap();
+
+        assert_eq!(fwma.next(4.0), 4.0);
+        assert_eq!(fwma.next(8.0), 6.0); // (1*4 + 1*8) / 2
+        assert_eq!(fwma.next(2.0), 4.0); // (1*4 + 1*8 + 2*2) / 4
+        assert_eq!(round(fwma.next(&Bar::new().close(6))), 4.857); // (4 + 8 + 2*2 + 3*6) / 7
+        assert_eq!(fwma.next(10.0), 7.0); // (4 + 8 + 2*2 + 3*6 + 5*10) / 12
+        assert_eq!(round(fwma.next(0.0)), 4.333); // (8 + 2 + 2*6 + 3*10 + 5*0) / 12
+    }
+
+    #[test]
+    fn test_large_period() {
+        let mut fwma = FibonacciWeightedMovingAverage::new(2000).unwrap();
+        for _ in 0..2100 {
+            assert_eq!(round(fwma.next(3.0)), 3.0);
+        }
+    }
+
+    #[test]
+    fn test_max_period() {
+        assert!(fibonacci_weights(MAX_PERIOD)[0] > 0.0);
+        assert_eq!(fibonacci_weights(MAX_PERIOD + 1)[0], 0.0);
+
+        assert!(FibonacciWeightedMovingAverage::new(MAX_PERIOD + 1).is_err());
+        assert!(FibonacciWeightedMovingAverage::new(MAX_PERIOD).is_ok());
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut fwma = FibonacciWeightedMovingAverage::new(3).unwrap();
+        fwma.next(4.0);
+        fwma.next(8.0);
+
+        fwma.reset();
+        assert_eq!(fwma.next(5.0), 5.0);
+        assert_eq!(fwma.next(7.0), 6.0);
+    }
+
+    #[test]
+    fn test_default() {
+        FibonacciWeightedMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let fwma = FibonacciWeightedMovingAverage::new(8).unwrap();
+        assert_eq!(format!("{}", fwma), "FWMA(8)");
+    }
+}
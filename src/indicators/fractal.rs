@@ -0,0 +1,276 @@
+use alloc::collections::VecDeque;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::helpers::warmup_tail;
+use crate::{High, Low, Next, NextWindow, Period, Reset};
+use serde::{Deserialize, Serialize};
+
+/// Bill Williams' Fractal.
+///
+/// An up fractal is a bar whose high is strictly higher than the highs of `bars` bars on each
+/// side of it, a down fractal is a bar whose low is strictly lower than the lows of `bars` bars
+/// on each side of it. A single bar can be both.
+///
+/// Since the bars after the center one must be known, the streaming [Next](../trait.Next.html)
+/// implementation reports the fractal `bars` bars late: the output describes the bar that was
+/// received `bars` bars ago. [NextWindow](../trait.NextWindow.html) classifies the center of an
+/// explicit window of `2 * bars + 1` bars instead.
+///
+/// # Parameters
+///
+/// * _bars_ - number of bars on each side of the center one (integer greater than 0). Default is 2.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::Fractal;
+/// use tam::{DataItem, NextWindow};
+///
+/// let bar = |high: f64, low: f64| {
+///     DataItem::builder()
+///         .open(low).high(high).low(low).close(high).volume(1.0).build().unwrap()
+/// };
+/// let window = [bar(2.0, 1.0), bar(3.0, 2.0), bar(5.0, 3.0), bar(4.0, 2.5), bar(3.5, 2.0)];
+///
+/// let mut fractal = Fractal::new(2).unwrap();
+/// let out = fractal.next_window(&window).unwrap();
+/// assert!(out.up);
+/// assert!(!out.down);
+/// ```
+///
+/// # Links
+///
+/// * [Fractal, Investopedia](https://www.investopedia.com/terms/f/fractal.asp)
+///
+#[doc(alias = "FRACTAL")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Fractal {
+    bars: usize,
+    highs: VecDeque<f64>,
+    lows: VecDeque<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FractalOutput {
+    pub up: bool,
+    pub down: bool,
+}
+
+impl From<FractalOutput> for (bool, bool) {
+    fn from(out: FractalOutput) -> Self {
+        (out.up, out.down)
+    }
+}
+
+impl_output!(FractalOutput, up, down);
+
+impl Fractal {
+    pub fn new(bars: usize) -> Result<Self> {
+        match bars {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                bars,
+                highs: VecDeque::with_capacity(2 * bars + 1),
+                lows: VecDeque::with_capacity(2 * bars + 1),
+            }),
+        }
+    }
+
+    fn size(&self) -> usize {
+        2 * self.bars + 1
+    }
+}
+
+fn detect(bars: usize, high: impl Fn(usize) -> f64, low: impl Fn(usize) -> f64) -> FractalOutput {
+    let others = || (0..2 * bars + 1).filter(move |&i| i != bars);
+    FractalOutput {
+        up: others().all(|i| high(bars) > high(i)),
+        down: others().all(|i| low(bars) < low(i)),
+    }
+}
+
+impl Period for Fractal {
+    fn period(&self) -> usize {
+        self.bars
+    }
+}
+
+impl<T: High + Low> Next<&T> for Fractal {
+    type Output = FractalOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        if self.highs.len() == self.size() {
+            self.highs.pop_front();
+            self.lows.pop_front();
+        }
+        self.highs.push_back(input.high());
+        self.lows.push_back(input.low());
+
+        if self.highs.len() < self.size() {
+            return FractalOutput {
+                up: false,
+                down: false,
+            };
+        }
+
+        detect(self.bars, |i| self.highs[i], |i| self.lows[i])
+    }
+}
+
+impl<T: High + Low> NextWindow<T> for Fractal {
+    type Output = FractalOutput;
+
+    fn window_len(&self) -> usize {
+        self.size()
+    }
+
+    fn next_window(&mut self, window: &[T]) -> Result<Self::Output> {
+        let window = warmup_tail(window, self.size())?;
+        Ok(detect(self.bars, |i| window[i].high(), |i| window[i].low()))
+    }
+}
+
+impl Reset for Fractal {
+    fn reset(&mut self) {
+        self.highs.clear();
+        self.lows.clear();
+    }
+}
+
+impl Default for Fractal {
+    fn default() -> Self {
+        Self::new(2).unwrap()
+    }
+}
+
+impl fmt::Display for Fractal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FRACTAL({})", self.bars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Fractal::new(0).is_err());
+        assert!(Fractal::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut fractal = Fractal::new(2).unwrap();
+        let bars = [
+            Bar::new().high(2.0).low(1.0),
+            Bar::new().high(3.0).low(2.0),
+            Bar::new().high(5.0).low(3.0),
+            Bar::new().high(4.0).low(2.5),
+            Bar::new().high(3.5).low(2.0),
+            Bar::new().high(3.0).low(0.5),
+            Bar::new().high(4.0).low(1.5),
+            Bar::new().high(4.5).low(2.0),
+        ];
+        let outputs: Vec<(bool, bool)> = bars.iter().map(|b| fractal.next(b).into()).collect();
+
+        assert_eq!(outputs[3], (false, false));
+        assert_eq!(outputs[4], (true, false)); // bar 2
+        assert_eq!(outputs[5], (false, false));
+        assert_eq!(outputs[6], (false, false));
+        assert_eq!(outputs[7], (false, true)); // bar 5
+    }
+
+    #[test]
+    fn test_next_window() {
+        let mut fractal = Fractal::new(1).unwrap();
+        assert_eq!(NextWindow::<Bar>::window_len(&fractal), 3);
+
+        let window = [
+            Bar::new().high(9.0).low(1.0),
+            Bar::new().high(3.0).low(2.0),
+            Bar::new().high(2.0).low(0.5),
+            Bar::new().high(4.0).low(1.0),
+        ];
+        assert_eq!(
+            fractal.next_window(&window[..2]),
+            Err(TaError::InsufficientData { needed: 3, got: 2 })
+        );
+
+        // only the last 3 bars are used
+        let out = fractal.next_window(&window).unwrap();
+        assert_eq!(
+            out,
+            FractalOutput {
+                up: false,
+                down: true
+            }
+        );
+
+        // equal highs are not a fractal
+        let flat = [
+            Bar::new().high(3.0).low(2.0),
+            Bar::new().high(3.0).low(1.0),
+            Bar::new().high(2.0).low(1.5),
+        ];
+        let out = fractal.next_window(&flat).unwrap();
+        assert_eq!(
+            out,
+            FractalOutput {
+                up: false,
+                down: true
+            }
+        );
+    }
+
+    #[test]
+    fn test_next_window_matches_next() {
+        let bars = [
+            Bar::new().high(2.0).low(1.0),
+            Bar::new().high(6.0).low(0.0),
+            Bar::new().high(3.0).low(1.0),
+            Bar::new().high(5.0).low(2.0),
+            Bar::new().high(1.0).low(0.5),
+        ];
+        let mut streaming = Fractal::new(1).unwrap();
+        let mut windowed = Fractal::new(1).unwrap();
+
+        for i in 0..bars.len() {
+            let out = streaming.next(&bars[i]);
+            if i >= 2 {
+                assert_eq!(windowed.next_window(&bars[..=i]).unwrap(), out);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut fractal = Fractal::new(1).unwrap();
+        fractal.next(&Bar::new().high(1.0).low(1.0));
+        fractal.next(&Bar::new().high(5.0).low(1.0));
+
+        fractal.reset();
+        fractal.next(&Bar::new().high(2.0).low(1.0));
+        let out = fractal.next(&Bar::new().high(1.0).low(1.0));
+        assert_eq!(
+            out,
+            FractalOutput {
+                up: false,
+                down: false
+            }
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        Fractal::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let fractal = Fractal::new(3).unwrap();
+        assert_eq!(format!("{}", fractal), "FRACTAL(3)");
+    }
+}
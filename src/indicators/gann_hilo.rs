@@ -0,0 +1,306 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{Close, High, Low, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Gann HiLo Activator.
+///
+/// A trend following line made of two moving averages, of the highs and of the lows. In an
+/// uptrend the line follows the SMA of the lows, under the prices; in a downtrend it follows
+/// the SMA of the highs, above them.
+///
+/// # Formula
+///
+/// HI = SMA(high, _period_), LO = SMA(low, _period_)
+///
+/// The direction flips to +1 when the close rises above the HI of the previous bar and to -1
+/// when it falls below the LO of the previous bar; otherwise it stays the same. Comparing
+/// with the averages of the _period_ bars before the current one keeps the bar from being
+/// compared with itself.
+///
+/// Value = LO if the direction is +1, HI if it is -1
+///
+/// The first bar after the warmup starts an uptrend unless its close is below the LO. While
+/// the averages warm up (_period_ bars) the value is NaN and the direction 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars of the averages (integer greater than 0). Default is 3.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::GannHiLo;
+/// use tam::{DataItem, Next};
+///
+/// let bar = |high, low, close| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut hilo = GannHiLo::new(2).unwrap();
+/// hilo.next(&bar(11.0, 9.0, 10.0));
+/// hilo.next(&bar(11.0, 9.0, 10.0));
+/// // below the previous LO of 9
+/// let out = hilo.next(&bar(9.0, 7.0, 8.0));
+/// assert_eq!(out.direction, -1);
+/// assert_eq!(out.value, 10.0);
+/// ```
+///
+/// # Links
+///
+/// * [Gann HiLo Activator, StockCharts](https://chartschool.stockcharts.com/table-of-contents/technical-indicators-and-overlays/technical-overlays/gann-hilo-activator)
+///
+#[doc(alias = "HILO")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GannHiLo {
+    high_sma: Sma,
+    low_sma: Sma,
+    count: usize,
+    /// HI and LO of the previous bar, once the averages are warmed up.
+    prev: Option<(f64, f64)>,
+    direction: i8,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GannHiLoOutput {
+    pub value: f64,
+    /// +1 in an uptrend, -1 in a downtrend, 0 during warmup.
+    pub direction: i8,
+}
+
+impl From<GannHiLoOutput> for (f64, i8) {
+    fn from(out: GannHiLoOutput) -> Self {
+        (out.value, out.direction)
+    }
+}
+
+impl_output!(GannHiLoOutput, value, direction);
+
+impl GannHiLo {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            high_sma: Sma::new(period)?,
+            low_sma: Sma::new(period)?,
+            count: 0,
+            prev: None,
+            direction: 1,
+        })
+    }
+}
+
+impl Period for GannHiLo {
+    fn period(&self) -> usize {
+        self.high_sma.period()
+    }
+}
+
+impl Warmup for GannHiLo {
+    fn lookback(&self) -> usize {
+        self.high_sma.period()
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for GannHiLo {
+    type Output = GannHiLoOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let close = input.close();
+        if let Some((prev_hi, prev_lo)) = self.prev {
+            if close > prev_hi {
+                self.direction = 1;
+            } else if close < prev_lo {
+                self.direction = -1;
+            }
+        }
+
+        let hi = self.high_sma.next(input.high());
+        let lo = self.low_sma.next(input.low());
+        let warm = self.prev.is_some();
+        if self.count < self.period() {
+            self.count += 1;
+        }
+        if self.count == self.period() {
+            self.prev = Some((hi, lo));
+        }
+
+        if !warm {
+            return GannHiLoOutput {
+                value: f64::NAN,
+                direction: 0,
+            };
+        }
+        GannHiLoOutput {
+            value: if self.direction > 0 { lo } else { hi },
+            direction: self.direction,
+        }
+    }
+}
+
+impl Reset for GannHiLo {
+    fn reset(&mut self) {
+        self.high_sma.reset();
+        self.low_sma.reset();
+        self.count = 0;
+        self.prev = None;
+        self.direction = 1;
+    }
+}
+
+impl Default for GannHiLo {
+    fn default() -> Self {
+        Self::new(3).unwrap()
+    }
+}
+
+impl fmt::Display for GannHiLo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HILO({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(GannHiLo::new(0).is_err());
+        assert!(GannHiLo::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_warmup() {
+        let mut hilo = GannHiLo::new(3).unwrap();
+        assert_eq!(hilo.lookback(), 3);
+        for i in 0..3 {
+            let out = hilo.next(
+                &Bar::new()
+                    .high(11.0 + i as f64)
+                    .low(9.0 + i as f64)
+                    .close(10.0 + i as f64),
+            );
+            assert!(out.value.is_nan());
+            assert_eq!(out.direction, 0);
+        }
+        assert_eq!(
+            hilo.next(&Bar::new().high(13.0).low(11.0).close(12.0))
+                .direction,
+            1
+        );
+    }
+
+    #[test]
+    fn test_flip() {
+        let mut hilo = GannHiLo::new(2).unwrap();
+        let out = |value, direction| GannHiLoOutput { value, direction };
+        hilo.next(&Bar::new().high(11.0).low(9.0).close(10.0));
+        hilo.next(&Bar::new().high(11.0).low(9.0).close(10.0));
+
+        // uptrend, on the SMA of the lows
+        assert_eq!(
+            hilo.next(&Bar::new().high(11.0).low(9.0).close(10.0)),
+            out(9.0, 1)
+        );
+        assert_eq!(
+            hilo.next(&Bar::new().high(12.0).low(10.0).close(11.0)),
+            out(9.5, 1)
+        );
+        assert_eq!(
+            hilo.next(&Bar::new().high(13.0).low(11.0).close(12.0)),
+            out(10.5, 1)
+        );
+
+        // the close falls below the previous LO of 10.5: downtrend, on the SMA of the highs
+        assert_eq!(
+            hilo.next(&Bar::new().high(11.0).low(9.0).close(10.0)),
+            out(12.0, -1)
+        );
+        // between the previous LO and HI: no flip
+        assert_eq!(
+            hilo.next(&Bar::new().high(11.5).low(9.5).close(10.5)),
+            out(11.25, -1)
+        );
+        assert_eq!(
+            hilo.next(&Bar::new().high(10.0).low(8.0).close(9.0)),
+            out(10.75, -1)
+        );
+
+        // the close rises above the previous HI of 10.75: uptrend again
+        assert_eq!(
+            hilo.next(&Bar::new().high(12.0).low(10.0).close(11.0)),
+            out(9.0, 1)
+        );
+    }
+
+    #[test]
+    fn test_first_direction() {
+        let mut hilo = GannHiLo::new(1).unwrap();
+        hilo.next(&Bar::new().high(11.0).low(9.0).close(10.0));
+        assert_eq!(
+            hilo.next(&Bar::new().high(9.5).low(7.5).close(8.5))
+                .direction,
+            -1
+        );
+
+        let mut hilo = GannHiLo::new(1).unwrap();
+        hilo.next(&Bar::new().high(11.0).low(9.0).close(10.0));
+        assert_eq!(
+            hilo.next(&Bar::new().high(10.5).low(8.5).close(9.5))
+                .direction,
+            1
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut hilo = GannHiLo::new(1).unwrap();
+        hilo.next(&Bar::new().high(11.0).low(9.0).close(10.0));
+        hilo.next(&Bar::new().high(8.0).low(6.0).close(7.0));
+
+        hilo.reset();
+        assert!(hilo
+            .next(&Bar::new().high(11.0).low(9.0).close(10.0))
+            .value
+            .is_nan());
+        assert_eq!(
+            hilo.next(&Bar::new().high(11.0).low(9.0).close(10.0)),
+            GannHiLoOutput {
+                value: 9.0,
+                direction: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut hilo = GannHiLo::new(2).unwrap();
+        for &close in [10.0, 11.0, 9.0].iter() {
+            hilo.next(&Bar::new().high(close + 1.0).low(close - 1.0).close(close));
+        }
+        let mut restored = serde_round_trip(&hilo);
+        assert_eq!(
+            restored.next(&Bar::new().high(9.0).low(7.0).close(8.0)),
+            hilo.next(&Bar::new().high(9.0).low(7.0).close(8.0))
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        GannHiLo::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", GannHiLo::new(10).unwrap()), "HILO(10)");
+    }
+}
@@ -0,0 +1,182 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::Alligator;
+use crate::{High, Low, Next, Periods, Reset};
+use serde::{Deserialize, Serialize};
+
+/// Gator Oscillator.
+///
+/// Histogram companion of the [Alligator](struct.Alligator.html) that shows how far its lines
+/// are apart. The upper bar is the distance between the jaw and the teeth, the lower bar is the
+/// (negated) distance between the teeth and the lips, so the histogram grows in both directions
+/// while the alligator "eats" (trends) and shrinks while it "sleeps".
+///
+/// # Formula
+///
+/// Upper = |Jaw - Teeth|
+///
+/// Lower = -|Teeth - Lips|
+///
+/// # Parameters
+///
+/// * _jaw_ - period of the Alligator jaw (integer greater than 0). Default is 13.
+/// * _teeth_ - period of the Alligator teeth (integer greater than 0). Default is 8.
+/// * _lips_ - period of the Alligator lips (integer greater than 0). Default is 5.
+///
+/// A bar is NaN until both Alligator lines it depends on are ready.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::GatorOscillator;
+/// use tam::Next;
+///
+/// let mut gator = GatorOscillator::new(3, 2, 1).unwrap();
+///
+/// for price in 1..=12 {
+///     let out = gator.next(price as f64);
+///     assert!(out.upper.is_nan() || out.upper >= 0.0);
+///     assert!(out.lower.is_nan() || out.lower <= 0.0);
+/// }
+/// ```
+///
+/// # Links
+///
+/// * [Gator Oscillator, Investopedia](https://www.investopedia.com/terms/g/gator-oscillator.asp)
+///
+#[doc(alias = "GATOR")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GatorOscillator {
+    alligator: Alligator,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GatorOutput {
+    pub upper: f64,
+    pub lower: f64,
+}
+
+impl From<GatorOutput> for (f64, f64) {
+    fn from(out: GatorOutput) -> Self {
+        (out.upper, out.lower)
+    }
+}
+
+impl_output!(GatorOutput, upper, lower);
+
+impl GatorOscillator {
+    pub fn new(jaw: usize, teeth: usize, lips: usize) -> Result<Self> {
+        Ok(Self {
+            alligator: Alligator::new(jaw, teeth, lips)?,
+        })
+    }
+}
+
+impl Periods for GatorOscillator {
+    fn periods(&self) -> Vec<usize> {
+        self.alligator.periods()
+    }
+}
+
+impl Next<f64> for GatorOscillator {
+    type Output = GatorOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let lines = self.alligator.next(input);
+        GatorOutput {
+            upper: (lines.jaw - lines.teeth).abs(),
+            lower: -(lines.teeth - lines.lips).abs(),
+        }
+    }
+}
+
+impl<T: High + Low> Next<&T> for GatorOscillator {
+    type Output = GatorOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next((input.high() + input.low()) / 2.0)
+    }
+}
+
+impl Reset for GatorOscillator {
+    fn reset(&mut self) {
+        self.alligator.reset();
+    }
+}
+
+impl Default for GatorOscillator {
+    fn default() -> Self {
+        Self::new(13, 8, 5).unwrap()
+    }
+}
+
+impl fmt::Display for GatorOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "GATOR")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(GatorOscillator::new(0, 8, 5).is_err());
+        assert!(GatorOscillator::new(13, 8, 5).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut gator = GatorOscillator::default();
+
+        // jaw is ready after 13 + 8 bars
+        for i in 0..20 {
+            let out = gator.next(&Bar::new().high(i + 1).low(i));
+            assert!(out.upper.is_nan());
+        }
+        let out = gator.next(&Bar::new().high(21).low(20));
+        assert!(!out.upper.is_nan());
+        assert!(!out.lower.is_nan());
+    }
+
+    #[test]
+    fn test_signs() {
+        let mut gator = GatorOscillator::new(5, 3, 2).unwrap();
+        let prices = [
+            10.0, 11.0, 13.0, 12.0, 9.0, 8.0, 10.0, 14.0, 15.0, 13.0, 11.0, 11.5, 12.0, 16.0, 14.0,
+            9.0, 7.0, 8.0, 10.0, 12.0,
+        ];
+
+        for &price in prices.iter() {
+            let out = gator.next(price);
+            assert!(out.upper.is_nan() || out.upper >= 0.0);
+            assert!(out.lower.is_nan() || out.lower <= 0.0);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut gator = GatorOscillator::new(2, 2, 1).unwrap();
+        let prices = [1.0, 3.0, 2.0, 5.0, 4.0, 6.0, 7.0, 9.0, 8.0, 10.0];
+        let first: Vec<(f64, f64)> = prices.iter().map(|&p| gator.next(p).into()).collect();
+
+        gator.reset();
+        let second: Vec<(f64, f64)> = prices.iter().map(|&p| gator.next(p).into()).collect();
+        assert_eq!(first[9], second[9]);
+    }
+
+    #[test]
+    fn test_default() {
+        GatorOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let gator = GatorOscillator::default();
+        assert_eq!(format!("{}", gator), "GATOR");
+    }
+}
@@ -0,0 +1,291 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::indicators::{EmaSeed, ExponentialMovingAverage as Ema};
+use crate::{Close, Next, Periods, Reset};
+use serde::{Deserialize, Serialize};
+
+const SHORT_PERIODS: [usize; 6] = [3, 5, 8, 10, 12, 15];
+const LONG_PERIODS: [usize; 6] = [30, 35, 40, 45, 50, 60];
+
+fn emas(periods: [usize; 6]) -> [Ema; 6] {
+    periods.map(|period| Ema::new(period).unwrap().with_seed(EmaSeed::Sma))
+}
+
+/// Guppy Multiple Moving Average (GMMA).
+///
+/// Two groups of exponential moving averages: the short-term group (3, 5, 8, 10, 12 and 15
+/// periods) follows traders, the long-term group (30, 35, 40, 45, 50 and 60 periods) follows
+/// investors. A trend is strong when the groups are separated and each of them fans out.
+///
+/// The EMAs are [seeded with an SMA](struct.ExponentialMovingAverage.html#seeding), so each
+/// value is NaN until its EMA has seen `period` inputs.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::Gmma;
+/// use tam::Next;
+///
+/// let mut gmma = Gmma::new();
+/// let mut out = gmma.next(10.0);
+/// for _ in 1..60 {
+///     out = gmma.next(10.0);
+/// }
+/// assert_eq!(out.short, [10.0; 6]);
+/// assert_eq!(out.long, [10.0; 6]);
+/// ```
+///
+/// # Links
+///
+/// * [Guppy Multiple Moving Average, Investopedia](https://www.investopedia.com/terms/g/guppy-multiple-moving-average.asp)
+///
+#[doc(alias = "GMMA")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Gmma {
+    short: [Ema; 6],
+    long: [Ema; 6],
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GmmaOutput {
+    pub short: [f64; 6],
+    pub long: [f64; 6],
+}
+
+impl_approx_eq!(GmmaOutput, short, long);
+
+impl Gmma {
+    pub fn new() -> Self {
+        Self {
+            short: emas(SHORT_PERIODS),
+            long: emas(LONG_PERIODS),
+        }
+    }
+}
+
+impl Periods for Gmma {
+    fn periods(&self) -> Vec<usize> {
+        SHORT_PERIODS
+            .iter()
+            .chain(LONG_PERIODS.iter())
+            .cloned()
+            .collect()
+    }
+}
+
+impl Next<f64> for Gmma {
+    type Output = GmmaOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        GmmaOutput {
+            short: self.short.each_mut().map(|ema| ema.next(input)),
+            long: self.long.each_mut().map(|ema| ema.next(input)),
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for Gmma {
+    type Output = GmmaOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for Gmma {
+    fn reset(&mut self) {
+        for ema in self.short.iter_mut().chain(self.long.iter_mut()) {
+            ema.reset();
+        }
+    }
+}
+
+impl Default for Gmma {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Gmma {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "GMMA")
+    }
+}
+
+/// Guppy trend strength score.
+///
+/// Summarizes the [GMMA](struct.Gmma.html) as a single value in [-1, 1]: +1 when every
+/// short-term EMA is above the top of the long-term group, -1 when every short-term EMA is
+/// below its bottom, and a partial score in between.
+///
+/// # Formula
+///
+/// Score = (N<sub>above</sub> - N<sub>below</sub>) / 6
+///
+/// Where:
+///
+/// * _N<sub>above</sub>_ - number of short-term EMAs above the highest long-term EMA
+/// * _N<sub>below</sub>_ - number of short-term EMAs below the lowest long-term EMA
+///
+/// The score is NaN until all EMAs are ready (60 bars).
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::GmmaScore;
+/// use tam::Next;
+///
+/// let mut score = GmmaScore::new();
+/// let mut value = f64::NAN;
+/// for i in 0..100 {
+///     value = score.next(100.0 + i as f64);
+/// }
+/// assert_eq!(value, 1.0);
+/// ```
+#[doc(alias = "GMMASCORE")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GmmaScore {
+    gmma: Gmma,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl GmmaScore {
+    pub fn new() -> Self {
+        Self {
+            gmma: Gmma::new(),
+            last_output: None,
+        }
+    }
+}
+
+impl GmmaScore {
+    fn next_value(&mut self, input: f64) -> f64 {
+        let out = self.gmma.next(input);
+        if out.long.iter().chain(out.short.iter()).any(|v| v.is_nan()) {
+            return f64::NAN;
+        }
+
+        let top = out.long.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let bottom = out.long.iter().cloned().fold(f64::INFINITY, f64::min);
+        let above = out.short.iter().filter(|&&v| v > top).count() as f64;
+        let below = out.short.iter().filter(|&&v| v < bottom).count() as f64;
+
+        (above - below) / out.short.len() as f64
+    }
+}
+
+impl_next_option!(GmmaScore);
+
+impl<T: Close> Next<&T> for GmmaScore {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for GmmaScore {
+    fn reset(&mut self) {
+        self.gmma.reset();
+        self.last_output = None;
+    }
+}
+
+impl Default for GmmaScore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for GmmaScore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "GMMASCORE")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_gmma_next() {
+        let mut gmma = Gmma::new();
+
+        let out = gmma.next(2.0);
+        assert!(out.short[0].is_nan());
+        assert!(out.long[5].is_nan());
+
+        let out = gmma.next(&Bar::new().close(4));
+        assert!(out.short[0].is_nan());
+
+        // the 3 period EMA is seeded with the average of the first 3 inputs
+        let out = gmma.next(6.0);
+        assert_eq!(out.short[0], 4.0);
+        assert!(out.short[1].is_nan());
+    }
+
+    #[test]
+    fn test_score_bullish() {
+        let mut score = GmmaScore::new();
+
+        for i in 0..59 {
+            assert!(score.next(100.0 + i as f64).is_nan());
+        }
+        for i in 59..100 {
+            assert_eq!(score.next(100.0 + i as f64), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_score_bearish() {
+        let mut score = GmmaScore::new();
+
+        let mut value = f64::NAN;
+        for i in 0..100 {
+            value = score.next(&Bar::new().close(200.0 - i as f64));
+        }
+        assert_eq!(value, -1.0);
+    }
+
+    #[test]
+    fn test_score_partial() {
+        let mut score = GmmaScore::new();
+
+        for i in 0..100 {
+            score.next(100.0 + i as f64);
+        }
+        // a sharp drop pulls the fastest EMAs under the long group first
+        let mut values = Vec::new();
+        for _ in 0..10 {
+            values.push(score.next(140.0));
+        }
+        assert!(values.iter().any(|&v| v > -1.0 && v < 1.0));
+        assert!(values.iter().all(|&v| (-1.0..=1.0).contains(&v)));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut score = GmmaScore::new();
+        for i in 0..70 {
+            score.next(100.0 + i as f64);
+        }
+
+        score.reset();
+        assert!(score.next(100.0).is_nan());
+    }
+
+    #[test]
+    fn test_default() {
+        Gmma::default();
+        GmmaScore::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Gmma::new()), "GMMA");
+        assert_eq!(format!("{}", GmmaScore::new()), "GMMASCORE");
+    }
+}
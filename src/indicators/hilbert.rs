@@ -0,0 +1,178 @@
+use alloc::collections::VecDeque;
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float;
+use serde::{Deserialize, Serialize};
+
+/// Longest cycle period that is measured.
+pub(crate) const MAX_PERIOD: usize = 50;
+const MIN_PERIOD: f64 = 6.0;
+
+/// Hilbert transform of a price series and its dominant cycle period, as described by John
+/// Ehlers in _Rocket Science for Traders_ and computed by TA-Lib's `HT_*` functions.
+///
+/// Shared by the Hilbert transform based indicators. All histories are kept newest first.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct HilbertCycle {
+    count: usize,
+    prices: [f64; 4],
+    smooth: VecDeque<f64>,
+    detrender: [f64; 7],
+    i1: [f64; 7],
+    q1: [f64; 7],
+    i2: f64,
+    q2: f64,
+    re: f64,
+    im: f64,
+    period: f64,
+    smooth_period: f64,
+}
+
+/// Pushes `value` at the front of `history`, dropping the oldest value.
+fn shift<const N: usize>(history: &mut [f64; N], value: f64) {
+    history.rotate_right(1);
+    history[0] = value;
+}
+
+/// Hilbert transform FIR filter of the newest 7 values.
+fn transform(history: &[f64; 7], adjustment: f64) -> f64 {
+    (0.0962 * history[0] + 0.5769 * history[2] - 0.5769 * history[4] - 0.0962 * history[6])
+        * adjustment
+}
+
+impl HilbertCycle {
+    pub(crate) fn new() -> Self {
+        Self {
+            count: 0,
+            prices: [0.0; 4],
+            smooth: VecDeque::with_capacity(MAX_PERIOD),
+            detrender: [0.0; 7],
+            i1: [0.0; 7],
+            q1: [0.0; 7],
+            i2: 0.0,
+            q2: 0.0,
+            re: 0.0,
+            im: 0.0,
+            period: 0.0,
+            smooth_period: 0.0,
+        }
+    }
+
+    /// Number of inputs consumed so far.
+    pub(crate) fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Smoothed dominant cycle period, 0 until the first 4 inputs are known.
+    pub(crate) fn smooth_period(&self) -> f64 {
+        self.smooth_period
+    }
+
+    /// Smoothed prices, the newest first, at most [MAX_PERIOD] of them.
+    pub(crate) fn smooth(&self) -> &VecDeque<f64> {
+        &self.smooth
+    }
+
+    pub(crate) fn next(&mut self, input: f64) {
+        self.count += 1;
+        shift(&mut self.prices, input);
+        if self.count < 4 {
+            return;
+        }
+
+        let p = &self.prices;
+        let smooth = (4.0 * p[0] + 3.0 * p[1] + 2.0 * p[2] + p[3]) / 10.0;
+        if self.smooth.len() == MAX_PERIOD {
+            self.smooth.pop_back();
+        }
+        self.smooth.push_front(smooth);
+
+        let adjustment = 0.075 * self.period + 0.54;
+        let mut recent = [0.0; 7];
+        for (i, value) in self.smooth.iter().take(7).enumerate() {
+            recent[i] = *value;
+        }
+        shift(&mut self.detrender, transform(&recent, adjustment));
+
+        // in-phase and quadrature components
+        shift(&mut self.q1, transform(&self.detrender, adjustment));
+        shift(&mut self.i1, self.detrender[3]);
+
+        // advance the phase of I1 and Q1 by 90 degrees
+        let ji = transform(&self.i1, adjustment);
+        let jq = transform(&self.q1, adjustment);
+
+        let i2 = 0.2 * (self.i1[0] - jq) + 0.8 * self.i2;
+        let q2 = 0.2 * (self.q1[0] + ji) + 0.8 * self.q2;
+
+        // homodyne discriminator
+        self.re = 0.2 * (i2 * self.i2 + q2 * self.q2) + 0.8 * self.re;
+        self.im = 0.2 * (i2 * self.q2 - q2 * self.i2) + 0.8 * self.im;
+        self.i2 = i2;
+        self.q2 = q2;
+
+        let prev_period = self.period;
+        let mut period = prev_period;
+        if self.im != 0.0 && self.re != 0.0 {
+            period = 360.0 / (self.im / self.re).atan().to_degrees();
+        }
+        period = period
+            .min(1.5 * prev_period)
+            .max(0.67 * prev_period)
+            .clamp(MIN_PERIOD, MAX_PERIOD as f64);
+        self.period = 0.2 * period + 0.8 * prev_period;
+        self.smooth_period = 0.33 * self.period + 0.67 * self.smooth_period;
+    }
+
+    pub(crate) fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::PI;
+
+    #[test]
+    fn test_shift() {
+        let mut history = [1.0, 2.0, 3.0];
+        shift(&mut history, 0.0);
+        assert_eq!(history, [0.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_dominant_cycle() {
+        for &cycle in [15.0, 20.0, 30.0].iter() {
+            let mut hilbert = HilbertCycle::new();
+            for i in 0..300 {
+                hilbert.next(100.0 + 10.0 * (2.0 * PI * i as f64 / cycle).sin());
+            }
+            let period = hilbert.smooth_period();
+            assert!((period - cycle).abs() < 1.0, "{} for {}", period, cycle);
+        }
+    }
+
+    #[test]
+    fn test_smooth() {
+        let mut hilbert = HilbertCycle::new();
+        for i in 0..100 {
+            hilbert.next(i as f64);
+        }
+        assert_eq!(hilbert.count(), 100);
+        assert_eq!(hilbert.smooth().len(), MAX_PERIOD);
+        // weights 4, 3, 2, 1 of 99, 98, 97, 96
+        assert_eq!(hilbert.smooth()[0], 98.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut hilbert = HilbertCycle::new();
+        for i in 0..10 {
+            hilbert.next(i as f64);
+        }
+        hilbert.reset();
+        assert_eq!(hilbert, HilbertCycle::new());
+    }
+}
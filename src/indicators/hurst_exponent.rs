@@ -0,0 +1,278 @@
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float;
+use crate::{Close, Next, Period, Reset};
+use serde::{Deserialize, Serialize};
+
+const MIN_CHUNK: usize = 8;
+
+/// Hurst Exponent (H).
+///
+/// Measures the long-term memory of a series using rescaled range (R/S) analysis over the
+/// trailing window:
+///
+/// * H ≈ 0.5 - random walk
+/// * H > 0.5 - trending (persistent) series
+/// * H < 0.5 - mean-reverting (anti-persistent) series
+///
+/// # Formula
+///
+/// The changes between consecutive inputs of the window are split into chunks of size _n_,
+/// for _n_ = _m_, _m_/2, _m_/4, ... down to 8, where _m_ is the number of changes. For every
+/// chunk:
+///
+/// * _Y_ - cumulative sum of the deviations from the chunk mean
+/// * _R_ = max(_Y_) - min(_Y_)
+/// * _S_ - standard deviation of the chunk
+///
+/// H is the slope of the least squares line through the points (log(_n_), log(mean(_R_/_S_))).
+/// Chunks with zero standard deviation are ignored, if fewer than two sizes remain the output is
+/// NaN.
+///
+/// R/S analysis is biased on short windows, expect noisy estimates below a few hundred inputs.
+///
+/// # Parameters
+///
+/// * _period_ - number of inputs in the window (integer greater than 16). Default is 100.
+///
+/// The output is NaN until the window is filled.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::HurstExponent;
+/// use tam::Next;
+///
+/// let mut hurst = HurstExponent::new(17).unwrap();
+/// let mut value = f64::NAN;
+/// for i in 0..17 {
+///     // a zig-zag is strongly mean-reverting
+///     value = hurst.next(if i % 2 == 0 { 1.0 } else { 2.0 });
+/// }
+/// assert!(value < 0.5);
+/// ```
+///
+/// # Links
+///
+/// * [Hurst exponent, Wikipedia](https://en.wikipedia.org/wiki/Hurst_exponent)
+///
+#[doc(alias = "HURST")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HurstExponent {
+    period: usize,
+    window: VecDeque<f64>,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl HurstExponent {
+    pub fn new(period: usize) -> Result<Self> {
+        if period <= 2 * MIN_CHUNK {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            window: VecDeque::with_capacity(period),
+            last_output: None,
+        })
+    }
+}
+
+/// Mean R/S of the consecutive chunks of size `n`, taken from the end of `changes`.
+fn rescaled_range(changes: &[f64], n: usize) -> Option<f64> {
+    let mut sum = 0.0;
+    let mut count = 0;
+
+    for chunk in changes.rchunks_exact(n) {
+        let mean = chunk.iter().sum::<f64>() / n as f64;
+        let (mut cum, mut max, mut min, mut sq) = (0.0, 0.0_f64, 0.0_f64, 0.0);
+        for &v in chunk {
+            cum += v - mean;
+            max = max.max(cum);
+            min = min.min(cum);
+            sq += (v - mean) * (v - mean);
+        }
+        let sd = (sq / n as f64).sqrt();
+        if sd > 0.0 {
+            sum += (max - min) / sd;
+            count += 1;
+        }
+    }
+
+    if count > 0 {
+        Some(sum / count as f64)
+    } else {
+        None
+    }
+}
+
+impl Period for HurstExponent {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl HurstExponent {
+    fn next_value(&mut self, input: f64) -> f64 {
+        if self.window.len() == self.period {
+            self.window.pop_front();
+        }
+        self.window.push_back(input);
+
+        if self.window.len() < self.period {
+            return f64::NAN;
+        }
+
+        let changes: Vec<f64> = self
+            .window
+            .iter()
+            .zip(self.window.iter().skip(1))
+            .map(|(prev, cur)| cur - prev)
+            .collect();
+
+        let mut points = Vec::new();
+        let mut n = changes.len();
+        while n >= MIN_CHUNK {
+            if let Some(rs) = rescaled_range(&changes, n) {
+                points.push(((n as f64).ln(), rs.ln()));
+            }
+            n /= 2;
+        }
+
+        if points.len() < 2 {
+            return f64::NAN;
+        }
+
+        let k = points.len() as f64;
+        let sum_x: f64 = points.iter().map(|p| p.0).sum();
+        let sum_y: f64 = points.iter().map(|p| p.1).sum();
+        let sum_xy: f64 = points.iter().map(|p| p.0 * p.1).sum();
+        let sum_x2: f64 = points.iter().map(|p| p.0 * p.0).sum();
+
+        (k * sum_xy - sum_x * sum_y) / (k * sum_x2 - sum_x * sum_x)
+    }
+}
+
+impl_next_option!(HurstExponent);
+
+impl<T: Close> Next<&T> for HurstExponent {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for HurstExponent {
+    fn reset(&mut self) {
+        self.window.clear();
+        self.last_output = None;
+    }
+}
+
+impl Default for HurstExponent {
+    fn default() -> Self {
+        Self::new(100).unwrap()
+    }
+}
+
+impl fmt::Display for HurstExponent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HURST({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    /// Deterministic uniform noise in [-0.5, 0.5).
+    struct Noise(u64);
+
+    impl Noise {
+        fn next(&mut self) -> f64 {
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (self.0 >> 11) as f64 / (1u64 << 53) as f64 - 0.5
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(HurstExponent::new(0).is_err());
+        assert!(HurstExponent::new(16).is_err());
+        assert!(HurstExponent::new(17).is_ok());
+    }
+
+    #[test]
+    fn test_random_walk() {
+        let mut hurst = HurstExponent::new(256).unwrap();
+        let mut noise = Noise(42);
+
+        let mut price = 100.0;
+        let mut value = f64::NAN;
+        for i in 0..256 {
+            price += noise.next();
+            value = hurst.next(price);
+            if i < 255 {
+                assert!(value.is_nan());
+            }
+        }
+        assert!(value > 0.4 && value < 0.6, "H = {}", value);
+    }
+
+    #[test]
+    fn test_trending() {
+        let mut hurst = HurstExponent::new(256).unwrap();
+        let mut noise = Noise(7);
+
+        // persistent changes: each one mostly repeats the previous one
+        let (mut price, mut change) = (100.0, 0.0);
+        let mut value = f64::NAN;
+        for _ in 0..256 {
+            change = 0.9 * change + noise.next();
+            price += change;
+            value = hurst.next(&Bar::new().close(price));
+        }
+        assert!(value > 0.7, "H = {}", value);
+    }
+
+    #[test]
+    fn test_flat() {
+        let mut hurst = HurstExponent::new(17).unwrap();
+        for _ in 0..17 {
+            assert!(hurst.next(5.0).is_nan());
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut hurst = HurstExponent::new(17).unwrap();
+        for i in 0..17 {
+            hurst.next(i as f64 % 3.0);
+        }
+        assert!(!hurst.next(1.0).is_nan());
+
+        hurst.reset();
+        assert!(hurst.next(1.0).is_nan());
+    }
+
+    #[test]
+    fn test_default() {
+        HurstExponent::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let hurst = HurstExponent::new(64).unwrap();
+        assert_eq!(format!("{}", hurst), "HURST(64)");
+    }
+}
@@ -1,4 +1,4 @@
-use std::fmt;
+use core::fmt;
 
 use crate::errors::Result;
 use crate::indicators::{AverageTrueRange, ExponentialMovingAverage};
@@ -54,13 +54,15 @@ pub struct KeltnerChannel {
     ema: ExponentialMovingAverage,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KeltnerChannelOutput {
     pub average: f64,
     pub upper: f64,
     pub lower: f64,
 }
 
+impl_output!(KeltnerChannelOutput, average, upper, lower);
+
 impl KeltnerChannel {
     pub fn new(period: usize, multiplier: f64) -> Result<Self> {
         Ok(Self {
@@ -0,0 +1,314 @@
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, High, Low, Next, Period, Periods, Reset, Volume, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Klinger Volume Oscillator (KVO).
+///
+/// Developed by Stephen Klinger, compares the volume flowing into and out of a security over a
+/// short and a long horizon. Each bar gets a volume force: its volume, signed by the direction
+/// of the trend and scaled by how much of the cumulative range of the current trend the bar
+/// accounts for. The oscillator is the difference of a fast and a slow EMA of the volume force,
+/// with an EMA of it as the signal line.
+///
+/// # Formula
+///
+/// Trend<sub>t</sub> = +1 if (High + Low + Close)<sub>t</sub> > (High + Low + Close)<sub>t-1</sub>, -1 otherwise
+///
+/// DM<sub>t</sub> = High<sub>t</sub> - Low<sub>t</sub>
+///
+/// CM<sub>t</sub> = CM<sub>t-1</sub> + DM<sub>t</sub> if Trend<sub>t</sub> = Trend<sub>t-1</sub>,
+/// DM<sub>t-1</sub> + DM<sub>t</sub> otherwise
+///
+/// VF<sub>t</sub> = Volume<sub>t</sub> * |2 * (DM<sub>t</sub> / CM<sub>t</sub> - 1)| * Trend<sub>t</sub> * 100
+///
+/// KVO = EMA(VF, _fast_period_) - EMA(VF, _slow_period_)
+///
+/// Signal = EMA(KVO, _signal_period_)
+///
+/// The first bar has no prior bar to compare with, it only records the sums and the range and
+/// both outputs are NaN. On the second bar there is no prior trend, CM starts as
+/// DM<sub>t-1</sub> + DM<sub>t</sub>. The volume force is 0 when CM is 0 (bars without range).
+///
+/// # Parameters
+///
+/// * _fast_period_ - period for the fast EMA (integer greater than 0). Default is 34.
+/// * _slow_period_ - period for the slow EMA (integer greater than _fast_period_). Default
+///   is 55.
+/// * _signal_period_ - period for the signal EMA (integer greater than 0). Default is 13.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::KlingerOscillator;
+/// use tam::{DataItem, Next};
+///
+/// let mut kvo = KlingerOscillator::new(2, 3, 2).unwrap();
+/// let bar = |high: f64, low: f64, close: f64, volume: f64| {
+///     DataItem::builder()
+///         .open(close).high(high).low(low).close(close).volume(volume)
+///         .build().unwrap()
+/// };
+///
+/// assert!(kvo.next(&bar(10.0, 8.0, 9.0, 100.0)).kvo.is_nan());
+/// // the EMAs start at the first volume force
+/// assert_eq!(kvo.next(&bar(11.0, 9.0, 10.0, 200.0)).kvo, 0.0);
+/// // the trend turns down with a large volume
+/// assert!(kvo.next(&bar(9.0, 7.0, 8.0, 300.0)).kvo < 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Klinger Oscillator, StockCharts](https://chartschool.stockcharts.com/table-of-contents/technical-indicators-and-overlays/technical-indicators/klinger-oscillator)
+///
+#[doc(alias = "KVO")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KlingerOscillator {
+    fast_ema: Ema,
+    slow_ema: Ema,
+    signal_ema: Ema,
+    /// High + low + close of the previous bar.
+    prev_sum: Option<f64>,
+    prev_trend: f64,
+    prev_dm: f64,
+    cm: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KlingerOutput {
+    pub kvo: f64,
+    pub signal: f64,
+}
+
+impl From<KlingerOutput> for (f64, f64) {
+    fn from(out: KlingerOutput) -> Self {
+        (out.kvo, out.signal)
+    }
+}
+
+impl_output!(KlingerOutput, kvo, signal);
+
+impl KlingerOscillator {
+    pub fn new(fast_period: usize, slow_period: usize, signal_period: usize) -> Result<Self> {
+        if fast_period >= slow_period {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            fast_ema: Ema::new(fast_period)?,
+            slow_ema: Ema::new(slow_period)?,
+            signal_ema: Ema::new(signal_period)?,
+            prev_sum: None,
+            prev_trend: 0.0,
+            prev_dm: 0.0,
+            cm: 0.0,
+        })
+    }
+
+    /// Returns the volume force of the bar and updates the trend state.
+    fn volume_force(&mut self, sum: f64, dm: f64, volume: f64, prev_sum: f64) -> f64 {
+        let trend = if sum > prev_sum { 1.0 } else { -1.0 };
+        self.cm = if trend == self.prev_trend {
+            self.cm + dm
+        } else {
+            self.prev_dm + dm
+        };
+        self.prev_trend = trend;
+
+        if self.cm == 0.0 {
+            0.0
+        } else {
+            volume * (2.0 * (dm / self.cm - 1.0)).abs() * trend * 100.0
+        }
+    }
+}
+
+impl Periods for KlingerOscillator {
+    fn periods(&self) -> Vec<usize> {
+        vec![
+            self.fast_ema.period(),
+            self.slow_ema.period(),
+            self.signal_ema.period(),
+        ]
+    }
+}
+
+impl Warmup for KlingerOscillator {
+    fn lookback(&self) -> usize {
+        1
+    }
+}
+
+impl<T: High + Low + Close + Volume> Next<&T> for KlingerOscillator {
+    type Output = KlingerOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let sum = input.high() + input.low() + input.close();
+        let dm = input.high() - input.low();
+        let prev_sum = self.prev_sum.replace(sum);
+
+        let output = match prev_sum {
+            None => KlingerOutput {
+                kvo: f64::NAN,
+                signal: f64::NAN,
+            },
+            Some(prev_sum) => {
+                let vf = self.volume_force(sum, dm, input.volume(), prev_sum);
+                let kvo = self.fast_ema.next(vf) - self.slow_ema.next(vf);
+                KlingerOutput {
+                    kvo,
+                    signal: self.signal_ema.next(kvo),
+                }
+            }
+        };
+        self.prev_dm = dm;
+        output
+    }
+}
+
+impl Reset for KlingerOscillator {
+    fn reset(&mut self) {
+        self.fast_ema.reset();
+        self.slow_ema.reset();
+        self.signal_ema.reset();
+        self.prev_sum = None;
+        self.prev_trend = 0.0;
+        self.prev_dm = 0.0;
+        self.cm = 0.0;
+    }
+}
+
+impl Default for KlingerOscillator {
+    fn default() -> Self {
+        Self::new(34, 55, 13).unwrap()
+    }
+}
+
+impl fmt::Display for KlingerOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "KVO({}, {}, {})",
+            self.fast_ema.period(),
+            self.slow_ema.period(),
+            self.signal_ema.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn round2(nums: (f64, f64)) -> (f64, f64) {
+        (round(nums.0), round(nums.1))
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(KlingerOscillator::new(0, 55, 13).is_err());
+        assert!(KlingerOscillator::new(34, 55, 0).is_err());
+        assert!(KlingerOscillator::new(55, 55, 13).is_err());
+        assert!(KlingerOscillator::new(56, 55, 13).is_err());
+        assert!(KlingerOscillator::new(1, 2, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut kvo = KlingerOscillator::new(2, 3, 2).unwrap();
+        assert_eq!(kvo.lookback(), 1);
+
+        let out = kvo.next(&Bar::new().high(10.0).low(8.0).close(9.0).volume(100.0));
+        assert!(out.kvo.is_nan() && out.signal.is_nan());
+
+        // up, no prior trend: CM = 2 + 2, VF = 200 * 1 * 100
+        assert_eq!(
+            round2(
+                kvo.next(&Bar::new().high(11.0).low(9.0).close(10.0).volume(200.0))
+                    .into()
+            ),
+            (0.0, 0.0)
+        );
+        // up again: CM = 4 + 3, VF = 100 * 8/7 * 100 = 11428.571
+        assert_eq!(
+            round2(
+                kvo.next(&Bar::new().high(12.0).low(9.0).close(11.0).volume(100.0))
+                    .into()
+            ),
+            (-1428.571, -952.381)
+        );
+        // down: CM = 3 + 3, VF = -300 * 1 * 100
+        assert_eq!(
+            round2(
+                kvo.next(&Bar::new().high(11.0).low(8.0).close(9.0).volume(300.0))
+                    .into()
+            ),
+            (-8095.238, -5714.286)
+        );
+    }
+
+    #[test]
+    fn test_flat_bars() {
+        let mut kvo = KlingerOscillator::new(2, 3, 2).unwrap();
+        kvo.next(&Bar::new().high(10.0).low(10.0).close(10.0).volume(100.0));
+        assert_eq!(
+            round2(
+                kvo.next(&Bar::new().high(10.0).low(10.0).close(10.0).volume(100.0))
+                    .into()
+            ),
+            (0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut kvo = KlingerOscillator::new(2, 3, 2).unwrap();
+        kvo.next(&Bar::new().high(10.0).low(8.0).close(9.0).volume(100.0));
+        kvo.next(&Bar::new().high(11.0).low(9.0).close(10.0).volume(200.0));
+        kvo.next(&Bar::new().high(12.0).low(9.0).close(11.0).volume(100.0));
+
+        kvo.reset();
+        assert!(kvo
+            .next(&Bar::new().high(10.0).low(8.0).close(9.0).volume(100.0))
+            .kvo
+            .is_nan());
+        kvo.next(&Bar::new().high(11.0).low(9.0).close(10.0).volume(200.0));
+        assert_eq!(
+            round2(
+                kvo.next(&Bar::new().high(12.0).low(9.0).close(11.0).volume(100.0))
+                    .into()
+            ),
+            (-1428.571, -952.381)
+        );
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut kvo = KlingerOscillator::default();
+        kvo.next(&Bar::new().high(10.0).low(8.0).close(9.0).volume(100.0));
+        kvo.next(&Bar::new().high(11.0).low(9.0).close(10.0).volume(200.0));
+        let mut restored = serde_round_trip(&kvo);
+
+        let next = Bar::new().high(9.0).low(7.0).close(8.0).volume(300.0);
+        assert_eq!(
+            round2(restored.next(&next).into()),
+            round2(kvo.next(&next).into())
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        KlingerOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            format!("{}", KlingerOscillator::default()),
+            "KVO(34, 55, 13)"
+        );
+    }
+}
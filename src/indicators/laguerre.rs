@@ -0,0 +1,344 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Reset};
+use serde::{Deserialize, Serialize};
+
+/// Laguerre Filter.
+///
+/// John Ehlers' low-lag smoother built from a cascade of four Laguerre stages. The first
+/// stage is an EMA of the price, each next stage is an all-pass filter of the previous one.
+///
+/// # Formula
+///
+/// L0<sub>t</sub> = (1 - γ) * Price<sub>t</sub> + γ * L0<sub>t-1</sub>
+///
+/// L1<sub>t</sub> = -γ * L0<sub>t</sub> + L0<sub>t-1</sub> + γ * L1<sub>t-1</sub>
+///
+/// L2<sub>t</sub> = -γ * L1<sub>t</sub> + L1<sub>t-1</sub> + γ * L2<sub>t-1</sub>
+///
+/// L3<sub>t</sub> = -γ * L2<sub>t</sub> + L2<sub>t-1</sub> + γ * L3<sub>t-1</sub>
+///
+/// Filter = (L0 + 2 * L1 + 2 * L2 + L3) / 6
+///
+/// All stages are seeded with the first price. With γ = 0 the stages become a plain delay
+/// line and the filter is a 4 bar weighted average.
+///
+/// # Parameters
+///
+/// * _gamma_ - damping factor (0 ≤ γ < 1), higher values smooth more and lag more.
+///   Default is 0.5.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::LaguerreFilter;
+/// use tam::Next;
+///
+/// let mut filter = LaguerreFilter::new(0.5).unwrap();
+/// assert_eq!(filter.next(10.0), 10.0);
+/// assert_eq!(filter.next(20.0), 10.3125);
+/// ```
+///
+/// # Links
+///
+/// * [Time Warp - Without Space Travel, John Ehlers](https://www.mesasoftware.com/papers/TimeWarp.pdf)
+///
+#[doc(alias = "LAGUERRE")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LaguerreFilter {
+    gamma: f64,
+    stages: [f64; 4],
+    is_new: bool,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl LaguerreFilter {
+    pub fn new(gamma: f64) -> Result<Self> {
+        if !(0.0..1.0).contains(&gamma) {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            gamma,
+            stages: [0.0; 4],
+            is_new: true,
+            last_output: None,
+        })
+    }
+
+    pub fn gamma(&self) -> f64 {
+        self.gamma
+    }
+
+    /// Feeds `input` through the cascade and returns the updated stages `[L0, L1, L2, L3]`.
+    fn update(&mut self, input: f64) -> [f64; 4] {
+        if self.is_new {
+            self.is_new = false;
+            self.stages = [input; 4];
+            return self.stages;
+        }
+
+        let g = self.gamma;
+        let prev = self.stages;
+        let l0 = (1.0 - g) * input + g * prev[0];
+        let l1 = -g * l0 + prev[0] + g * prev[1];
+        let l2 = -g * l1 + prev[1] + g * prev[2];
+        let l3 = -g * l2 + prev[2] + g * prev[3];
+        self.stages = [l0, l1, l2, l3];
+        self.stages
+    }
+}
+
+impl LaguerreFilter {
+    fn next_value(&mut self, input: f64) -> f64 {
+        let [l0, l1, l2, l3] = self.update(input);
+        (l0 + 2.0 * l1 + 2.0 * l2 + l3) / 6.0
+    }
+}
+
+impl_next_option!(LaguerreFilter);
+
+impl<T: Close> Next<&T> for LaguerreFilter {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for LaguerreFilter {
+    fn reset(&mut self) {
+        self.stages = [0.0; 4];
+        self.is_new = true;
+        self.last_output = None;
+    }
+}
+
+impl Default for LaguerreFilter {
+    fn default() -> Self {
+        Self::new(0.5).unwrap()
+    }
+}
+
+impl fmt::Display for LaguerreFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LAGUERRE({})", self.gamma)
+    }
+}
+
+/// Laguerre RSI (LRSI).
+///
+/// A fast, low-lag oscillator bounded in [0, 1]. Instead of accumulating price changes over a
+/// period like the [RSI](struct.RelativeStrengthIndex.html), it accumulates the differences
+/// between the consecutive stages of a [Laguerre filter](struct.LaguerreFilter.html).
+///
+/// # Formula
+///
+/// CU = sum of the positive differences among L0 - L1, L1 - L2 and L2 - L3
+///
+/// CD = sum of the absolute negative differences among L0 - L1, L1 - L2 and L2 - L3
+///
+/// LRSI = CU / (CU + CD)
+///
+/// When CU + CD is 0 (e.g. on the first input or a flat series) the previous value is kept,
+/// starting from 0.5.
+///
+/// # Parameters
+///
+/// * _gamma_ - damping factor of the Laguerre filter (0 ≤ γ < 1). Default is 0.5.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::LaguerreRsi;
+/// use tam::Next;
+///
+/// let mut lrsi = LaguerreRsi::new(0.5).unwrap();
+/// assert_eq!(lrsi.next(10.0), 0.5);
+/// assert!(lrsi.next(20.0) > 0.5);
+/// ```
+///
+/// # Links
+///
+/// * [Time Warp - Without Space Travel, John Ehlers](https://www.mesasoftware.com/papers/TimeWarp.pdf)
+///
+#[doc(alias = "LRSI")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LaguerreRsi {
+    filter: LaguerreFilter,
+    current: f64,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl LaguerreRsi {
+    pub fn new(gamma: f64) -> Result<Self> {
+        Ok(Self {
+            filter: LaguerreFilter::new(gamma)?,
+            current: 0.5,
+            last_output: None,
+        })
+    }
+
+    pub fn gamma(&self) -> f64 {
+        self.filter.gamma()
+    }
+}
+
+impl LaguerreRsi {
+    fn next_value(&mut self, input: f64) -> f64 {
+        let stages = self.filter.update(input);
+
+        let (mut up, mut down) = (0.0, 0.0);
+        for pair in stages.windows(2) {
+            let diff = pair[0] - pair[1];
+            if diff > 0.0 {
+                up += diff;
+            } else {
+                down -= diff;
+            }
+        }
+
+        if up + down > 0.0 {
+            self.current = up / (up + down);
+        }
+        self.current
+    }
+}
+
+impl_next_option!(LaguerreRsi);
+
+impl<T: Close> Next<&T> for LaguerreRsi {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for LaguerreRsi {
+    fn reset(&mut self) {
+        self.filter.reset();
+        self.current = 0.5;
+        self.last_output = None;
+    }
+}
+
+impl Default for LaguerreRsi {
+    fn default() -> Self {
+        Self::new(0.5).unwrap()
+    }
+}
+
+impl fmt::Display for LaguerreRsi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LRSI({})", self.filter.gamma)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(LaguerreFilter::new(-0.1).is_err());
+        assert!(LaguerreFilter::new(1.0).is_err());
+        assert!(LaguerreFilter::new(f64::NAN).is_err());
+        assert!(LaguerreFilter::new(0.0).is_ok());
+        assert!(LaguerreFilter::new(0.8).is_ok());
+
+        assert!(LaguerreRsi::new(1.0).is_err());
+        assert!(LaguerreRsi::new(0.5).is_ok());
+    }
+
+    #[test]
+    fn test_filter_next() {
+        let mut filter = LaguerreFilter::new(0.5).unwrap();
+
+        assert_eq!(filter.next(10.0), 10.0);
+        // L0 = 15, L1 = 7.5, L2 = 11.25, L3 = 9.375
+        assert_eq!(filter.next(&Bar::new().close(20)), 10.3125);
+        assert_eq!(filter.next(20.0), 10.9375);
+    }
+
+    #[test]
+    fn test_filter_zero_gamma() {
+        // a delay line: (1, 2, 2, 1) / 6 weighted average of the last 4 inputs
+        let mut filter = LaguerreFilter::new(0.0).unwrap();
+        filter.next(6.0);
+        filter.next(12.0);
+        filter.next(18.0);
+        assert_eq!(filter.next(24.0), 15.0);
+    }
+
+    #[test]
+    fn test_rsi_next() {
+        let mut lrsi = LaguerreRsi::new(0.5).unwrap();
+
+        assert_eq!(lrsi.next(10.0), 0.5);
+        // CU = 7.5 + 1.875, CD = 3.75
+        assert_eq!(round(lrsi.next(&Bar::new().close(20))), 0.714);
+    }
+
+    #[test]
+    fn test_rsi_bounds() {
+        let mut lrsi = LaguerreRsi::new(0.6).unwrap();
+
+        let mut values = Vec::new();
+        for i in 0..200 {
+            let x = i as f64;
+            values.push(lrsi.next(100.0 + 10.0 * (x / 7.0).sin() + 3.0 * (x / 2.0).cos()));
+        }
+        assert!(values.iter().all(|v| (0.0..=1.0).contains(v)));
+        assert!(values.iter().any(|&v| v < 0.2));
+        assert!(values.iter().any(|&v| v > 0.8));
+
+        // a steady trend pins the oscillator to its bounds
+        for i in 0..50 {
+            lrsi.next(200.0 + i as f64);
+        }
+        assert_eq!(lrsi.next(250.0), 1.0);
+        for i in 0..50 {
+            lrsi.next(250.0 - i as f64);
+        }
+        assert_eq!(lrsi.next(200.0), 0.0);
+    }
+
+    #[test]
+    fn test_rsi_flat() {
+        let mut lrsi = LaguerreRsi::new(0.5).unwrap();
+        for _ in 0..10 {
+            assert_eq!(lrsi.next(5.0), 0.5);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut filter = LaguerreFilter::new(0.5).unwrap();
+        filter.next(10.0);
+        filter.next(20.0);
+        filter.reset();
+        assert_eq!(filter.next(30.0), 30.0);
+
+        let mut lrsi = LaguerreRsi::new(0.5).unwrap();
+        lrsi.next(10.0);
+        lrsi.next(20.0);
+        lrsi.reset();
+        assert_eq!(lrsi.next(30.0), 0.5);
+    }
+
+    #[test]
+    fn test_default() {
+        LaguerreFilter::default();
+        LaguerreRsi::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", LaguerreFilter::default()), "LAGUERRE(0.5)");
+        assert_eq!(format!("{}", LaguerreRsi::new(0.7).unwrap()), "LRSI(0.7)");
+    }
+}
@@ -0,0 +1,611 @@
+use alloc::{boxed::Box, vec};
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+#[cfg(feature = "deterministic")]
+use crate::helpers::ordered_sum;
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float;
+use crate::{Close, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Least squares line through the last _period_ values, shared by the linear regression
+/// indicators.
+///
+/// The x-values are 0 for the oldest value up to n - 1 for the newest, so sum(x) and sum(x²)
+/// only depend on the number of values: sliding the window only updates sum(y) and sum(x*y),
+/// in O(1). Before the window is full the line goes through the values seen so far.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct RollingRegression {
+    period: usize,
+    index: usize,
+    count: usize,
+    sum_y: f64,
+    sum_xy: f64,
+    values: Box<[f64]>,
+}
+
+impl RollingRegression {
+    /// `period` must be greater than 0.
+    pub(crate) fn new(period: usize) -> Self {
+        Self {
+            period,
+            index: 0,
+            count: 0,
+            sum_y: 0.0,
+            sum_xy: 0.0,
+            values: vec![0.0; period].into_boxed_slice(),
+        }
+    }
+
+    pub(crate) fn period(&self) -> usize {
+        self.period
+    }
+
+    pub(crate) fn is_full(&self) -> bool {
+        self.count == self.period
+    }
+
+    pub(crate) fn push(&mut self, value: f64) {
+        #[cfg(not(feature = "deterministic"))]
+        if self.count < self.period {
+            self.sum_xy += self.count as f64 * value;
+            self.sum_y += value;
+        } else {
+            // every value moves one step to the left, the oldest one drops out at x = 0
+            let oldest = self.values[self.index];
+            self.sum_xy += (self.period - 1) as f64 * value - (self.sum_y - oldest);
+            self.sum_y += value - oldest;
+        }
+
+        self.values[self.index] = value;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+        if self.count < self.period {
+            self.count += 1;
+        }
+
+        // Recompute the sums over the window, oldest value first
+        #[cfg(feature = "deterministic")]
+        {
+            self.sum_y = ordered_sum(self.window());
+            self.sum_xy = ordered_sum(self.window().enumerate().map(|(x, y)| x as f64 * y));
+        }
+    }
+
+    /// Values of the window from the oldest to the newest.
+    #[cfg(feature = "deterministic")]
+    fn window(&self) -> impl Iterator<Item = f64> + '_ {
+        let start = if self.count < self.period {
+            0
+        } else {
+            self.index
+        };
+        (0..self.count).map(move |i| self.values[(start + i) % self.period])
+    }
+
+    /// Slope of the line, 0 with less than 2 values.
+    pub(crate) fn slope(&self) -> f64 {
+        if self.count < 2 {
+            return 0.0;
+        }
+        let n = self.count as f64;
+        let sum_x = n * (n - 1.0) / 2.0;
+        let sum_x2 = (n - 1.0) * n * (2.0 * n - 1.0) / 6.0;
+        (n * self.sum_xy - sum_x * self.sum_y) / (n * sum_x2 - sum_x * sum_x)
+    }
+
+    /// Value of the line at the oldest value (x = 0).
+    pub(crate) fn intercept(&self) -> f64 {
+        if self.count == 0 {
+            return f64::NAN;
+        }
+        let n = self.count as f64;
+        (self.sum_y - self.slope() * n * (n - 1.0) / 2.0) / n
+    }
+
+    /// Value of the line at the newest value.
+    pub(crate) fn value(&self) -> f64 {
+        self.intercept() + self.slope() * (self.count as f64 - 1.0)
+    }
+
+    /// Value of the line one step after the newest value.
+    pub(crate) fn forecast(&self) -> f64 {
+        self.intercept() + self.slope() * self.count as f64
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sum_y = 0.0;
+        self.sum_xy = 0.0;
+        for i in 0..self.period {
+            self.values[i] = 0.0;
+        }
+    }
+}
+
+/// Linear Regression.
+///
+/// Fits a least squares line through the last _period_ prices and returns all its outputs
+/// at once: the end point of the line, its slope, intercept and angle, and the time series
+/// forecast. They all come from the same running sums, so asking for several of them costs
+/// no more than asking for one.
+///
+/// # Formula
+///
+/// With x = 0 for the oldest price up to _period_ - 1 for the newest:
+///
+/// Slope = (n * sum(x*y) - sum(x) * sum(y)) / (n * sum(x²) - sum(x)²)
+///
+/// Intercept = (sum(y) - Slope * sum(x)) / n
+///
+/// Value = Intercept + Slope * (_period_ - 1)
+///
+/// Angle = atan(Slope), in degrees
+///
+/// Forecast = Intercept + Slope * _period_
+///
+/// All the outputs are NaN until _period_ prices are known.
+///
+/// # Parameters
+///
+/// * _period_ - number of prices (integer greater than 1). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::LinearRegression;
+/// use tam::Next;
+///
+/// let mut linreg = LinearRegression::new(3).unwrap();
+/// assert!(linreg.next(1.0).value.is_nan());
+/// assert!(linreg.next(2.0).value.is_nan());
+///
+/// let out = linreg.next(3.0);
+/// assert_eq!(out.value, 3.0);
+/// assert_eq!(out.slope, 1.0);
+/// assert_eq!(out.intercept, 1.0);
+/// assert_eq!(out.angle, 45.0);
+/// assert_eq!(out.forecast, 4.0);
+/// ```
+///
+/// # Links
+///
+/// * [Linear regression, Wikipedia](https://en.wikipedia.org/wiki/Simple_linear_regression)
+///
+#[doc(alias = "LINEARREG")]
+#[doc(alias = "LINEARREG_SLOPE")]
+#[doc(alias = "LINEARREG_INTERCEPT")]
+#[doc(alias = "LINEARREG_ANGLE")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LinearRegression {
+    regression: RollingRegression,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinearRegressionOutput {
+    /// Value of the line at the newest price.
+    pub value: f64,
+    pub slope: f64,
+    /// Value of the line at the oldest price.
+    pub intercept: f64,
+    /// Angle of the line in degrees.
+    pub angle: f64,
+    /// Value of the line one step ahead.
+    pub forecast: f64,
+}
+
+impl From<LinearRegressionOutput> for (f64, f64, f64, f64, f64) {
+    fn from(out: LinearRegressionOutput) -> Self {
+        (out.value, out.slope, out.intercept, out.angle, out.forecast)
+    }
+}
+
+impl_output!(
+    LinearRegressionOutput,
+    value,
+    slope,
+    intercept,
+    angle,
+    forecast
+);
+
+impl LinearRegression {
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 2 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            regression: RollingRegression::new(period),
+        })
+    }
+}
+
+impl Period for LinearRegression {
+    fn period(&self) -> usize {
+        self.regression.period()
+    }
+}
+
+impl Warmup for LinearRegression {
+    fn lookback(&self) -> usize {
+        self.regression.period() - 1
+    }
+}
+
+impl Next<f64> for LinearRegression {
+    type Output = LinearRegressionOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.regression.push(input);
+        if !self.regression.is_full() {
+            return LinearRegressionOutput {
+                value: f64::NAN,
+                slope: f64::NAN,
+                intercept: f64::NAN,
+                angle: f64::NAN,
+                forecast: f64::NAN,
+            };
+        }
+
+        let slope = self.regression.slope();
+        LinearRegressionOutput {
+            value: self.regression.value(),
+            slope,
+            intercept: self.regression.intercept(),
+            angle: slope.atan().to_degrees(),
+            forecast: self.regression.forecast(),
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for LinearRegression {
+    type Output = LinearRegressionOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for LinearRegression {
+    fn reset(&mut self) {
+        self.regression.reset();
+    }
+}
+
+impl Default for LinearRegression {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for LinearRegression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LINEARREG({})", self.regression.period())
+    }
+}
+
+/// Time Series Forecast (TSF).
+///
+/// Projects the least squares line through the last _period_ prices one bar into the future,
+/// like TA-Lib's TSF. It is the `forecast` of [LinearRegression](struct.LinearRegression.html)
+/// on its own, and is one slope step ahead of the end point of the line (LINEARREG).
+///
+/// # Formula
+///
+/// With x = 0 for the oldest price up to _period_ - 1 for the newest:
+///
+/// TSF = Intercept + Slope * _period_
+///
+/// The output is NaN until _period_ prices are known.
+///
+/// # Parameters
+///
+/// * _period_ - number of prices (integer greater than 1). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::TimeSeriesForecast;
+/// use tam::Next;
+///
+/// let mut tsf = TimeSeriesForecast::new(3).unwrap();
+/// assert!(tsf.next(1.0).is_nan());
+/// assert!(tsf.next(2.0).is_nan());
+/// assert_eq!(tsf.next(3.0), 4.0);
+/// // the line through 2, 3, 3
+/// assert_eq!(tsf.next(3.0), 3.6666666666666665);
+/// ```
+///
+/// # Links
+///
+/// * [Time Series Forecast, TradingView](https://www.tradingview.com/support/solutions/43000502276-time-series-forecast/)
+///
+#[doc(alias = "TSF")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TimeSeriesForecast {
+    regression: RollingRegression,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl TimeSeriesForecast {
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 2 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            regression: RollingRegression::new(period),
+            last_output: None,
+        })
+    }
+}
+
+impl Period for TimeSeriesForecast {
+    fn period(&self) -> usize {
+        self.regression.period()
+    }
+}
+
+impl Warmup for TimeSeriesForecast {
+    fn lookback(&self) -> usize {
+        self.regression.period() - 1
+    }
+}
+
+impl TimeSeriesForecast {
+    fn next_value(&mut self, input: f64) -> f64 {
+        self.regression.push(input);
+        if !self.regression.is_full() {
+            return f64::NAN;
+        }
+        self.regression.forecast()
+    }
+}
+
+impl_next_option!(TimeSeriesForecast);
+
+impl<T: Close> Next<&T> for TimeSeriesForecast {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for TimeSeriesForecast {
+    fn reset(&mut self) {
+        self.regression.reset();
+        self.last_output = None;
+    }
+}
+
+impl Default for TimeSeriesForecast {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for TimeSeriesForecast {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TSF({})", self.regression.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    /// Slope and intercept of the least squares line through `values` at x = 0, 1, ...,
+    /// from the centered formula.
+    fn least_squares(values: &[f64]) -> (f64, f64) {
+        let n = values.len() as f64;
+        let mean_x = (n - 1.0) / 2.0;
+        let mean_y = values.iter().sum::<f64>() / n;
+        let (mut cov, mut var) = (0.0, 0.0);
+        for (x, y) in values.iter().enumerate() {
+            let dx = x as f64 - mean_x;
+            cov += dx * (y - mean_y);
+            var += dx * dx;
+        }
+        let slope = if var > 0.0 { cov / var } else { 0.0 };
+        (slope, mean_y - slope * mean_x)
+    }
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(LinearRegression::new(0).is_err());
+        assert!(LinearRegression::new(1).is_err());
+        assert!(LinearRegression::new(2).is_ok());
+    }
+
+    #[test]
+    fn test_rolling_regression() {
+        let values = [
+            4.0, 7.5, 6.0, 9.0, 3.5, 8.0, 12.0, 10.5, 11.0, 6.5, 13.0, 15.5, 14.0,
+        ];
+        let period = 5;
+        let mut regression = RollingRegression::new(period);
+
+        for i in 0..values.len() {
+            regression.push(values[i]);
+            let window = &values[(i + 1).saturating_sub(period)..=i];
+            let (slope, intercept) = least_squares(window);
+            let n = window.len() as f64;
+
+            assert_eq!(regression.is_full(), window.len() == period);
+            assert_close(regression.slope(), slope);
+            assert_close(regression.intercept(), intercept);
+            assert_close(regression.value(), intercept + slope * (n - 1.0));
+            assert_close(regression.forecast(), intercept + slope * n);
+        }
+    }
+
+    #[test]
+    fn test_next() {
+        let values = [4.0, 7.5, 6.0, 9.0, 3.5, 8.0, 12.0, 10.5];
+        let mut linreg = LinearRegression::new(4).unwrap();
+
+        for i in 0..values.len() {
+            let out = linreg.next(values[i]);
+            if i < 3 {
+                assert!(out.value.is_nan());
+                assert!(out.slope.is_nan());
+                assert!(out.forecast.is_nan());
+                continue;
+            }
+            let (slope, intercept) = least_squares(&values[i - 3..=i]);
+            assert_close(out.slope, slope);
+            assert_close(out.intercept, intercept);
+            assert_close(out.value, intercept + slope * 3.0);
+            assert_close(out.angle, slope.atan().to_degrees());
+            assert_close(out.forecast, out.value + out.slope);
+        }
+
+        // 8, 12, 10.5, 9
+        let out = linreg.next(&Bar::new().close(9));
+        assert_eq!(round(out.slope), 0.15);
+        assert_eq!(round(out.value), 10.1);
+    }
+
+    #[test]
+    fn test_flat() {
+        let mut linreg = LinearRegression::new(3).unwrap();
+        for _ in 0..5 {
+            linreg.next(7.0);
+        }
+        let out = linreg.next(7.0);
+        assert_eq!(out.slope, 0.0);
+        assert_eq!(out.angle, 0.0);
+        assert_eq!(out.value, 7.0);
+        assert_eq!(out.intercept, 7.0);
+        assert_eq!(out.forecast, 7.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut linreg = LinearRegression::new(2).unwrap();
+        linreg.next(5.0);
+        linreg.next(9.0);
+
+        linreg.reset();
+        assert!(linreg.next(1.0).value.is_nan());
+        assert_eq!(linreg.next(3.0).slope, 2.0);
+    }
+
+    #[test]
+    fn test_default() {
+        LinearRegression::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let linreg = LinearRegression::new(20).unwrap();
+        assert_eq!(format!("{}", linreg), "LINEARREG(20)");
+    }
+
+    /// TSF of the last `period` values of `inputs`, following TA-Lib's TA_TSF: x = 0 is the
+    /// newest value, and the divisor is negated to give the slope in time order.
+    fn ta_lib_tsf(inputs: &[f64], period: usize) -> f64 {
+        let n = period as f64;
+        let sum_x = n * (n - 1.0) * 0.5;
+        let sum_x_sqr = n * (n - 1.0) * (2.0 * n - 1.0) / 6.0;
+        let divisor = sum_x * sum_x - n * sum_x_sqr;
+        let (mut sum_xy, mut sum_y) = (0.0, 0.0);
+        for i in 0..period {
+            let value = inputs[inputs.len() - 1 - i];
+            sum_y += value;
+            sum_xy += i as f64 * value;
+        }
+        let m = (n * sum_xy - sum_x * sum_y) / divisor;
+        let b = (sum_y - m * sum_x) / n;
+        b + m * n
+    }
+
+    #[test]
+    fn test_tsf_new() {
+        assert!(TimeSeriesForecast::new(0).is_err());
+        assert!(TimeSeriesForecast::new(1).is_err());
+        assert!(TimeSeriesForecast::new(2).is_ok());
+    }
+
+    #[test]
+    fn test_tsf_ground_truth() {
+        let closes = [
+            44.34, 44.09, 44.15, 43.61, 44.33, 44.83, 45.10, 45.42, 45.84, 46.08, 45.89, 46.03,
+            45.61, 46.28, 46.28, 46.00, 46.03, 46.41, 46.22, 45.64, 46.21, 46.25, 45.71, 46.45,
+        ];
+        let period = 14;
+        let mut tsf = TimeSeriesForecast::new(period).unwrap();
+        assert_eq!(tsf.lookback(), 13);
+
+        let outputs: Vec<f64> = closes
+            .iter()
+            .map(|&c| tsf.next(&Bar::new().close(c)))
+            .collect();
+        assert_eq!(warmup_nan_count(&outputs), 13);
+        for i in 13..closes.len() {
+            assert_close(outputs[i], ta_lib_tsf(&closes[..=i], period));
+        }
+        assert_eq!(round(outputs[13]), 46.547);
+    }
+
+    #[test]
+    fn test_tsf_vs_linreg() {
+        let values = [4.0, 7.5, 6.0, 9.0, 3.5, 8.0, 12.0, 10.5, 11.0, 6.5];
+        let mut tsf = TimeSeriesForecast::new(4).unwrap();
+        let mut linreg = LinearRegression::new(4).unwrap();
+
+        for &value in values.iter() {
+            let forecast = tsf.next(value);
+            let out = linreg.next(value);
+            if out.value.is_nan() {
+                assert!(forecast.is_nan());
+                continue;
+            }
+            assert_eq!(forecast, out.forecast);
+            // one slope step after the end of the line
+            assert_close(forecast - out.value, out.slope);
+        }
+    }
+
+    #[test]
+    fn test_tsf_reset() {
+        let mut tsf = TimeSeriesForecast::new(2).unwrap();
+        tsf.next(5.0);
+        tsf.next(9.0);
+
+        tsf.reset();
+        assert!(tsf.next(1.0).is_nan());
+        assert_eq!(tsf.next(3.0), 5.0);
+    }
+
+    #[test]
+    fn test_tsf_serde() {
+        let mut tsf = TimeSeriesForecast::new(3).unwrap();
+        tsf.next(1.0);
+        tsf.next(2.0);
+        let mut restored = serde_round_trip(&tsf);
+        assert_eq!(restored.next(4.0), tsf.next(4.0));
+    }
+
+    #[test]
+    fn test_tsf_default() {
+        TimeSeriesForecast::default();
+    }
+
+    #[test]
+    fn test_tsf_display() {
+        assert_eq!(format!("{}", TimeSeriesForecast::default()), "TSF(14)");
+    }
+}
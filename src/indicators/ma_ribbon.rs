@@ -0,0 +1,250 @@
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::SimpleMovingAverage;
+use crate::{Close, Next, Periods, Reset};
+use serde::{Deserialize, Serialize};
+
+/// Moving Average Ribbon.
+///
+/// A set of simple moving averages with different periods plotted together. The width of the
+/// ribbon (the spread) measures trend strength: the averages fan out while the market is
+/// trending and converge while it moves sideways.
+///
+/// # Formula
+///
+/// values = [SMA(_period<sub>1</sub>_), ..., SMA(_period<sub>n</sub>_)]
+///
+/// spread = (max(values) - min(values)) / price
+///
+/// A zero price yields a spread of 0.
+///
+/// # Parameters
+///
+/// * _periods_ - periods of the moving averages (non-empty, each greater than 0).
+///   Default is `[10, 20, 30, 40, 50, 60]`.
+///
+/// A value is NaN until its moving average has a full window, the spread is NaN until all
+/// of them do.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::MaRibbon;
+/// use tam::Next;
+///
+/// let mut ribbon = MaRibbon::new(vec![1, 2]).unwrap();
+///
+/// let out = ribbon.next(10.0);
+/// assert_eq!(out.values[0], 10.0);
+/// assert!(out.values[1].is_nan());
+/// assert!(out.spread.is_nan());
+///
+/// let out = ribbon.next(12.0);
+/// assert_eq!(out.values, vec![12.0, 11.0]);
+/// assert_eq!(out.spread, 1.0 / 12.0);
+/// ```
+#[doc(alias = "RIBBON")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MaRibbon {
+    periods: Vec<usize>,
+    mas: Vec<SimpleMovingAverage>,
+    max_period: usize,
+    count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RibbonOutput {
+    pub values: Vec<f64>,
+    pub spread: f64,
+}
+
+impl_approx_eq!(RibbonOutput, values, spread);
+
+impl MaRibbon {
+    pub fn new(periods: Vec<usize>) -> Result<Self> {
+        if periods.is_empty() {
+            return Err(TaError::InvalidParameter);
+        }
+        let mas = periods
+            .iter()
+            .map(|&period| SimpleMovingAverage::new(period))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            max_period: periods.iter().cloned().max().unwrap_or(0),
+            periods,
+            mas,
+            count: 0,
+        })
+    }
+}
+
+impl Periods for MaRibbon {
+    fn periods(&self) -> Vec<usize> {
+        self.periods.clone()
+    }
+}
+
+impl Next<f64> for MaRibbon {
+    type Output = RibbonOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        if self.count < self.max_period {
+            self.count += 1;
+        }
+
+        let count = self.count;
+        let values: Vec<f64> = self
+            .mas
+            .iter_mut()
+            .zip(self.periods.iter())
+            .map(|(ma, &period)| {
+                let value = ma.next(input);
+                if count < period {
+                    f64::NAN
+                } else {
+                    value
+                }
+            })
+            .collect();
+
+        let spread = if values.iter().any(|v| v.is_nan()) {
+            f64::NAN
+        } else if input == 0.0 {
+            0.0
+        } else {
+            let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+            (max - min) / input
+        };
+
+        RibbonOutput { values, spread }
+    }
+}
+
+impl<T: Close> Next<&T> for MaRibbon {
+    type Output = RibbonOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for MaRibbon {
+    fn reset(&mut self) {
+        for ma in self.mas.iter_mut() {
+            ma.reset();
+        }
+        self.count = 0;
+    }
+}
+
+impl Default for MaRibbon {
+    fn default() -> Self {
+        Self::new(vec![10, 20, 30, 40, 50, 60]).unwrap()
+    }
+}
+
+impl fmt::Display for MaRibbon {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let periods: Vec<String> = self.periods.iter().map(|p| p.to_string()).collect();
+        write!(f, "RIBBON({})", periods.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(MaRibbon::new(vec![]).is_err());
+        assert!(MaRibbon::new(vec![5, 0]).is_err());
+        assert!(MaRibbon::new(vec![5]).is_ok());
+        assert!(MaRibbon::new(vec![5, 10, 20]).is_ok());
+    }
+
+    #[test]
+    fn test_periods() {
+        let ribbon = MaRibbon::new(vec![5, 10, 20]).unwrap();
+        assert_eq!(ribbon.periods(), vec![5, 10, 20]);
+    }
+
+    #[test]
+    fn test_next() {
+        let mut ribbon = MaRibbon::new(vec![2, 3]).unwrap();
+
+        let out = ribbon.next(2.0);
+        assert!(out.values.iter().all(|v| v.is_nan()));
+        assert!(out.spread.is_nan());
+
+        let out = ribbon.next(4.0);
+        assert_eq!(out.values[0], 3.0);
+        assert!(out.values[1].is_nan());
+        assert!(out.spread.is_nan());
+
+        let out = ribbon.next(6.0);
+        assert_eq!(out.values, vec![5.0, 4.0]);
+        assert_eq!(round(out.spread), 0.167);
+
+        let out = ribbon.next(&Bar::new().close(8));
+        assert_eq!(out.values, vec![7.0, 6.0]);
+        assert_eq!(out.spread, 0.125);
+    }
+
+    #[test]
+    fn test_spread() {
+        let mut ribbon = MaRibbon::new(vec![2, 4, 8]).unwrap();
+
+        // a flat market collapses all averages onto the price
+        let mut spread = 0.0;
+        for _ in 0..10 {
+            spread = ribbon.next(100.0).spread;
+        }
+        assert_eq!(spread, 0.0);
+
+        // a strong trend fans the averages out
+        let mut prev = spread;
+        for i in 1..=7 {
+            spread = ribbon.next(100.0 + 5.0 * i as f64).spread;
+            assert!(spread > prev);
+            prev = spread;
+        }
+        assert!(spread > 0.1);
+
+        // and they converge again once the market goes sideways
+        for _ in 0..8 {
+            spread = ribbon.next(140.0).spread;
+        }
+        assert_eq!(spread, 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ribbon = MaRibbon::new(vec![1, 2]).unwrap();
+        ribbon.next(3.0);
+        ribbon.next(5.0);
+
+        ribbon.reset();
+        let out = ribbon.next(4.0);
+        assert_eq!(out.values[0], 4.0);
+        assert!(out.values[1].is_nan());
+    }
+
+    #[test]
+    fn test_default() {
+        MaRibbon::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let ribbon = MaRibbon::new(vec![5, 10, 20]).unwrap();
+        assert_eq!(format!("{}", ribbon), "RIBBON(5, 10, 20)");
+    }
+}
@@ -1,4 +1,5 @@
-use std::fmt;
+use alloc::{boxed::Box, vec};
+use core::fmt;
 
 use crate::errors::{Result, TaError};
 use crate::{High, Next, Period, Reset};
@@ -6,6 +7,10 @@ use serde::{Deserialize, Serialize};
 
 /// Returns the highest value in a given time frame.
 ///
+/// [next_extreme](#method.next_extreme) also tells how many bars ago the highest value
+/// occurred, e.g. 0 for a new high of the window. Among equal values, the most recent one
+/// counts.
+///
 /// # Parameters
 ///
 /// * _period_ - size of the time frame (integer greater than 0). Default value is 14.
@@ -29,6 +34,18 @@ pub struct Maximum {
     max_index: usize,
     cur_index: usize,
     deque: Box<[f64]>,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+/// Output of [Maximum::next_extreme](struct.Maximum.html#method.next_extreme) and
+/// [Minimum::next_extreme](struct.Minimum.html#method.next_extreme).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ExtremeOutput {
+    /// Highest (or lowest) value of the window.
+    pub value: f64,
+    /// Number of bars since `value`, 0 for the last input.
+    pub bars_ago: usize,
 }
 
 impl Maximum {
@@ -40,18 +57,36 @@ impl Maximum {
                 max_index: 0,
                 cur_index: 0,
                 deque: vec![f64::NEG_INFINITY; period].into_boxed_slice(),
+                last_output: None,
             }),
         }
     }
 
+    /// Number of bars since the highest value of the window, 0 for the last input.
+    pub fn bars_ago(&self) -> usize {
+        let newest = (self.cur_index + self.period - 1) % self.period;
+        (newest + self.period - self.max_index) % self.period
+    }
+
+    /// Like `next`, also returning how many bars ago the highest value occurred.
+    pub fn next_extreme(&mut self, input: f64) -> ExtremeOutput {
+        let value = self.next(input);
+        ExtremeOutput {
+            value,
+            bars_ago: self.bars_ago(),
+        }
+    }
+
     fn find_max_index(&self) -> usize {
         let mut max = f64::NEG_INFINITY;
-        let mut index: usize = 0;
+        let mut index = self.cur_index;
 
-        for (i, &val) in self.deque.iter().enumerate() {
-            if val > max {
-                max = val;
-                index = i;
+        // from the oldest value to the newest, so the most recent of equal values wins
+        for i in 1..=self.period {
+            let j = (self.cur_index + i) % self.period;
+            if self.deque[j] >= max {
+                max = self.deque[j];
+                index = j;
             }
         }
 
@@ -65,16 +100,15 @@ impl Period for Maximum {
     }
 }
 
-impl Next<f64> for Maximum {
-    type Output = f64;
-
-    fn next(&mut self, input: f64) -> Self::Output {
+impl Maximum {
+    fn next_value(&mut self, input: f64) -> f64 {
         self.deque[self.cur_index] = input;
 
-        if input > self.deque[self.max_index] {
-            self.max_index = self.cur_index;
-        } else if self.max_index == self.cur_index {
+        if self.max_index == self.cur_index {
+            // the extreme left the window
             self.max_index = self.find_max_index();
+        } else if input >= self.deque[self.max_index] {
+            self.max_index = self.cur_index;
         }
 
         self.cur_index = if self.cur_index + 1 < self.period {
@@ -87,6 +121,8 @@ impl Next<f64> for Maximum {
     }
 }
 
+impl_next_option!(Maximum);
+
 impl<T: High> Next<&T> for Maximum {
     type Output = f64;
 
@@ -100,6 +136,9 @@ impl Reset for Maximum {
         for i in 0..self.period {
             self.deque[i] = f64::NEG_INFINITY;
         }
+        self.max_index = 0;
+        self.cur_index = 0;
+        self.last_output = None;
     }
 }
 
@@ -157,6 +196,57 @@ mod tests {
         assert_eq!(max.next(&bar(2.0)), 3.5);
     }
 
+    #[test]
+    fn test_bars_ago() {
+        let mut max = Maximum::new(5).unwrap();
+        for &high in [3.0, 9.0, 4.0, 5.0].iter() {
+            max.next(high);
+        }
+        let out = max.next_extreme(6.0);
+        assert_eq!(out.value, 9.0);
+        assert_eq!(out.bars_ago, 3);
+        assert_eq!(max.bars_ago(), 3);
+
+        assert_eq!(
+            max.next_extreme(2.0),
+            ExtremeOutput {
+                value: 9.0,
+                bars_ago: 4
+            }
+        );
+        // the 9 leaves the window
+        assert_eq!(
+            max.next_extreme(1.0),
+            ExtremeOutput {
+                value: 6.0,
+                bars_ago: 2
+            }
+        );
+        // a new high of the window
+        assert_eq!(max.next(&Bar::new().high(10.0)), 10.0);
+        assert_eq!(max.bars_ago(), 0);
+    }
+
+    #[test]
+    fn test_bars_ago_ties() {
+        // the most recent of equal highs counts
+        let mut max = Maximum::new(4).unwrap();
+        for &high in [5.0, 7.0, 7.0].iter() {
+            max.next(high);
+        }
+        assert_eq!(max.next_extreme(3.0).bars_ago, 1);
+        assert_eq!(max.next_extreme(7.0).bars_ago, 0);
+
+        // also when the highest value leaves the window
+        let mut max = Maximum::new(3).unwrap();
+        for &high in [9.0, 5.0, 5.0].iter() {
+            max.next(high);
+        }
+        let out = max.next_extreme(4.0);
+        assert_eq!(out.value, 5.0);
+        assert_eq!(out.bars_ago, 1);
+    }
+
     #[test]
     fn test_reset() {
         let mut max = Maximum::new(100).unwrap();
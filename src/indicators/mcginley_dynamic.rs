@@ -0,0 +1,230 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float;
+use crate::{Close, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Upper bound of the speed factor (_price_ / _MD_)<sup>4</sup>.
+const MAX_FACTOR: f64 = 1e12;
+
+/// McGinley Dynamic.
+///
+/// A moving average that adjusts its own speed to the market: it follows falling prices
+/// quickly and rising prices slowly, and hugs the prices more closely than an EMA of the same
+/// period without whipsawing as much.
+///
+/// # Formula
+///
+/// MD<sub>0</sub> = p<sub>0</sub>
+///
+/// MD<sub>t</sub> = MD<sub>t-1</sub> + (p<sub>t</sub> - MD<sub>t-1</sub>) / (_period_ *
+/// (p<sub>t</sub> / MD<sub>t-1</sub>)<sup>4</sup>)
+///
+/// # Gap guard
+///
+/// On a large gap the speed factor (p / MD)<sup>4</sup> over- or underflows: a price far
+/// above MD makes it infinite (and MD freezes), a price far below MD makes it vanish, so the
+/// step becomes huge and MD overshoots the price, possibly below zero. The factor is clamped
+/// to [1 / _period_, 10<sup>12</sup>]: the lower bound limits a step to the whole distance to
+/// the price, so MD never goes past it, and the upper bound keeps MD moving.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::McginleyDynamic;
+/// use tam::Next;
+///
+/// let mut md = McginleyDynamic::new(4).unwrap();
+/// assert_eq!(md.next(10.0), 10.0);
+/// // 10 + 0 / 4
+/// assert_eq!(md.next(10.0), 10.0);
+/// // 10 + 10 / (4 * 16), slow on the way up
+/// assert_eq!(md.next(20.0), 10.15625);
+/// ```
+///
+/// # Links
+///
+/// * [McGinley Dynamic, Investopedia](https://www.investopedia.com/articles/forex/09/mcginley-dynamic-indicator.asp)
+///
+#[doc(alias = "MD")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct McginleyDynamic {
+    period: usize,
+    md: Option<f64>,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl McginleyDynamic {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                md: None,
+                last_output: None,
+            }),
+        }
+    }
+}
+
+impl Period for McginleyDynamic {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Warmup for McginleyDynamic {
+    fn lookback(&self) -> usize {
+        0
+    }
+}
+
+impl McginleyDynamic {
+    fn next_value(&mut self, input: f64) -> f64 {
+        let md = match self.md {
+            Some(md) if md != input => {
+                let n = self.period as f64;
+                let factor = (input / md).powi(4).clamp(1.0 / n, MAX_FACTOR);
+                md + (input - md) / (n * factor)
+            }
+            Some(md) => md,
+            None => input,
+        };
+        self.md = Some(md);
+        md
+    }
+}
+
+impl_next_option!(McginleyDynamic);
+
+impl<T: Close> Next<&T> for McginleyDynamic {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for McginleyDynamic {
+    fn reset(&mut self) {
+        self.md = None;
+        self.last_output = None;
+    }
+}
+
+impl Default for McginleyDynamic {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for McginleyDynamic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MCGINLEY({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(McginleyDynamic::new(0).is_err());
+        assert!(McginleyDynamic::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut md = McginleyDynamic::new(10).unwrap();
+        assert_eq!(md.lookback(), 0);
+        assert_eq!(md.next(&Bar::new().close(20)), 20.0);
+        // 20 + 2 / (10 * 1.1^4)
+        assert_eq!(round(md.next(22.0)), 20.137);
+        // 20.137 - 2.137 / (10 * 0.8939^4)
+        assert_eq!(round(md.next(18.0)), 19.802);
+    }
+
+    #[test]
+    fn test_asymmetry() {
+        // the same distance is covered faster downwards than upwards
+        let mut up = McginleyDynamic::new(5).unwrap();
+        up.next(100.0);
+        let rise = up.next(110.0) - 100.0;
+
+        let mut down = McginleyDynamic::new(5).unwrap();
+        down.next(100.0);
+        let fall = 100.0 - down.next(90.0);
+        assert!(fall > rise);
+    }
+
+    #[test]
+    fn test_gap_guard() {
+        // a crash: the unclamped factor 1e-8 would move MD by 99 / 14e-8, far below the price
+        let mut md = McginleyDynamic::new(14).unwrap();
+        md.next(100.0);
+        assert_eq!(md.next(1.0), 1.0);
+
+        // a huge jump: the unclamped factor overflows to infinity and MD would never move
+        let mut md = McginleyDynamic::new(14).unwrap();
+        md.next(10.0);
+        let value = md.next(1e80);
+        assert!(value.is_finite());
+        assert!(value > 10.0 && value < 1e80);
+
+        // from zero
+        let mut md = McginleyDynamic::new(14).unwrap();
+        md.next(0.0);
+        let value = md.next(5.0);
+        assert!(value > 0.0 && value < 5.0);
+        assert_eq!(md.next(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_constant() {
+        let mut md = McginleyDynamic::new(3).unwrap();
+        for _ in 0..10 {
+            assert_eq!(md.next(7.5), 7.5);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut md = McginleyDynamic::new(4).unwrap();
+        md.next(10.0);
+        md.next(20.0);
+
+        md.reset();
+        assert_eq!(md.next(30.0), 30.0);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut md = McginleyDynamic::new(4).unwrap();
+        // 10.15625 survives the JSON round trip exactly
+        for &price in [10.0, 10.0, 20.0].iter() {
+            md.next(price);
+        }
+        let mut restored = serde_round_trip(&md);
+        assert_eq!(restored.next(13.0), md.next(13.0));
+    }
+
+    #[test]
+    fn test_default() {
+        McginleyDynamic::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", McginleyDynamic::default()), "MCGINLEY(14)");
+    }
+}
@@ -1,4 +1,5 @@
-use std::fmt;
+use alloc::{boxed::Box, vec};
+use core::fmt;
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{Result, TaError};
@@ -32,6 +33,8 @@ pub struct MeanAbsoluteDeviation {
     count: usize,
     sum: f64,
     deque: Box<[f64]>,
+    #[serde(default)]
+    last_output: Option<f64>,
 }
 
 impl MeanAbsoluteDeviation {
@@ -44,6 +47,7 @@ impl MeanAbsoluteDeviation {
                 count: 0,
                 sum: 0.0,
                 deque: vec![0.0; period].into_boxed_slice(),
+                last_output: None,
             }),
         }
     }
@@ -55,12 +59,10 @@ impl Period for MeanAbsoluteDeviation {
     }
 }
 
-impl Next<f64> for MeanAbsoluteDeviation {
-    type Output = f64;
-
-    fn next(&mut self, input: f64) -> Self::Output {
+impl MeanAbsoluteDeviation {
+    fn next_value(&mut self, input: f64) -> f64 {
         self.sum = if self.count < self.period {
-            self.count = self.count + 1;
+            self.count += 1;
             self.sum + input
         } else {
             self.sum + input - self.deque[self.index]
@@ -83,6 +85,8 @@ impl Next<f64> for MeanAbsoluteDeviation {
     }
 }
 
+impl_next_option!(MeanAbsoluteDeviation);
+
 impl<T: Close> Next<&T> for MeanAbsoluteDeviation {
     type Output = f64;
 
@@ -99,6 +103,7 @@ impl Reset for MeanAbsoluteDeviation {
         for i in 0..self.period {
             self.deque[i] = 0.0;
         }
+        self.last_output = None;
     }
 }
 
@@ -0,0 +1,251 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::cmp::Ordering;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Rolling median.
+///
+/// The middle value of the last _period_ values. Unlike the
+/// [SMA](struct.SimpleMovingAverage.html), a single outlier, e.g. a bad tick, barely moves it.
+///
+/// # Formula
+///
+/// For the window sorted as v<sub>1</sub> ≤ ... ≤ v<sub>n</sub>:
+///
+/// Median = v<sub>(n+1)/2</sub> if _n_ is odd, (v<sub>n/2</sub> + v<sub>n/2+1</sub>) / 2 if _n_
+/// is even
+///
+/// The window is kept sorted next to the ring buffer, so an update is a binary search and a
+/// shift of the sorted values, O(_period_), instead of sorting the window on every input. The
+/// output is NaN until the window is full.
+///
+/// # Parameters
+///
+/// * _period_ - number of values in the window (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::Median;
+/// use tam::Next;
+///
+/// let mut median = Median::new(3).unwrap();
+/// assert!(median.next(10.0).is_nan());
+/// assert!(median.next(11.0).is_nan());
+/// assert_eq!(median.next(12.0), 11.0);
+/// // the outlier does not move the median much
+/// assert_eq!(median.next(1000.0), 12.0);
+/// ```
+///
+/// # Links
+///
+/// * [Median, Wikipedia](https://en.wikipedia.org/wiki/Median)
+///
+#[doc(alias = "MEDIAN")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Median {
+    period: usize,
+    index: usize,
+    deque: Box<[f64]>,
+    /// Values of the window in ascending order.
+    sorted: Vec<f64>,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl Median {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                deque: vec![0.0; period].into_boxed_slice(),
+                sorted: Vec::with_capacity(period),
+                last_output: None,
+            }),
+        }
+    }
+
+    /// Position of `value` in the sorted window, or where it would be inserted.
+    fn position(&self, value: f64) -> usize {
+        self.sorted
+            .partition_point(|v| v.total_cmp(&value) == Ordering::Less)
+    }
+}
+
+impl Period for Median {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Warmup for Median {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl Median {
+    fn next_value(&mut self, input: f64) -> f64 {
+        if self.sorted.len() == self.period {
+            let position = self.position(self.deque[self.index]);
+            self.sorted.remove(position);
+        }
+        let position = self.position(input);
+        self.sorted.insert(position, input);
+
+        self.deque[self.index] = input;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        let n = self.sorted.len();
+        if n < self.period {
+            f64::NAN
+        } else if n % 2 == 1 {
+            self.sorted[n / 2]
+        } else {
+            (self.sorted[n / 2 - 1] + self.sorted[n / 2]) / 2.0
+        }
+    }
+}
+
+impl_next_option!(Median);
+
+impl<T: Close> Next<&T> for Median {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for Median {
+    fn reset(&mut self) {
+        self.index = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+        self.sorted.clear();
+        self.last_output = None;
+    }
+}
+
+impl Default for Median {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for Median {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MEDIAN({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn reference(window: &[f64]) -> f64 {
+        let mut sorted = window.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let n = sorted.len();
+        if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Median::new(0).is_err());
+        assert!(Median::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut median = Median::new(4).unwrap();
+        assert_eq!(median.lookback(), 3);
+
+        assert!(median.next(5.0).is_nan());
+        assert!(median.next(1.0).is_nan());
+        assert!(median.next(&Bar::new().close(9)).is_nan());
+        assert_eq!(median.next(3.0), 4.0); // 1 3 5 9
+        assert_eq!(median.next(3.0), 3.0); // 1 3 3 9
+        assert_eq!(median.next(7.0), 5.0); // 3 3 7 9
+        assert_eq!(median.next(-2.0), 3.0); // -2 3 3 7
+    }
+
+    #[test]
+    fn test_period_1() {
+        let mut median = Median::new(1).unwrap();
+        for &value in [4.0, -1.0, 7.5].iter() {
+            assert_eq!(median.next(value), value);
+        }
+    }
+
+    #[test]
+    fn test_random() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for &period in [1, 2, 5, 20, 51].iter() {
+            // few distinct values, to exercise the duplicates
+            let values: Vec<f64> = (0..500)
+                .map(|_| rng.random_range(0..40) as f64 / 4.0)
+                .collect();
+
+            let mut median = Median::new(period).unwrap();
+            for (i, &value) in values.iter().enumerate() {
+                let output = median.next(value);
+                if i + 1 < period {
+                    assert!(output.is_nan());
+                } else {
+                    assert_eq!(output, reference(&values[i + 1 - period..=i]));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut median = Median::new(3).unwrap();
+        median.next(4.0);
+        median.next(8.0);
+        median.next(6.0);
+
+        median.reset();
+        assert!(median.next(1.0).is_nan());
+        assert!(median.next(2.0).is_nan());
+        assert_eq!(median.next(3.0), 2.0);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut median = Median::new(3).unwrap();
+        median.next(4.0);
+        median.next(8.0);
+        let mut restored = serde_round_trip(&median);
+        assert_eq!(restored.next(6.0), median.next(6.0));
+        assert_eq!(restored.next(1.0), 6.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Median::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Median::new(20).unwrap()), "MEDIAN(20)");
+    }
+}
@@ -1,11 +1,17 @@
-use std::fmt;
+use alloc::{boxed::Box, vec};
+use core::fmt;
 
 use crate::errors::{Result, TaError};
+use crate::indicators::ExtremeOutput;
 use crate::{Low, Next, Period, Reset};
 use serde::{Deserialize, Serialize};
 
 /// Returns the lowest value in a given time frame.
 ///
+/// [next_extreme](#method.next_extreme) also tells how many bars ago the lowest value
+/// occurred, e.g. 0 for a new low of the window. Among equal values, the most recent one
+/// counts.
+///
 /// # Parameters
 ///
 /// * _period_ - size of the time frame (integer greater than 0). Default value is 14.
@@ -28,6 +34,8 @@ pub struct Minimum {
     min_index: usize,
     cur_index: usize,
     deque: Box<[f64]>,
+    #[serde(default)]
+    last_output: Option<f64>,
 }
 
 impl Minimum {
@@ -39,18 +47,36 @@ impl Minimum {
                 min_index: 0,
                 cur_index: 0,
                 deque: vec![f64::INFINITY; period].into_boxed_slice(),
+                last_output: None,
             }),
         }
     }
 
+    /// Number of bars since the lowest value of the window, 0 for the last input.
+    pub fn bars_ago(&self) -> usize {
+        let newest = (self.cur_index + self.period - 1) % self.period;
+        (newest + self.period - self.min_index) % self.period
+    }
+
+    /// Like `next`, also returning how many bars ago the lowest value occurred.
+    pub fn next_extreme(&mut self, input: f64) -> ExtremeOutput {
+        let value = self.next(input);
+        ExtremeOutput {
+            value,
+            bars_ago: self.bars_ago(),
+        }
+    }
+
     fn find_min_index(&self) -> usize {
         let mut min = f64::INFINITY;
-        let mut index: usize = 0;
+        let mut index = self.cur_index;
 
-        for (i, &val) in self.deque.iter().enumerate() {
-            if val < min {
-                min = val;
-                index = i;
+        // from the oldest value to the newest, so the most recent of equal values wins
+        for i in 1..=self.period {
+            let j = (self.cur_index + i) % self.period;
+            if self.deque[j] <= min {
+                min = self.deque[j];
+                index = j;
             }
         }
 
@@ -64,16 +90,15 @@ impl Period for Minimum {
     }
 }
 
-impl Next<f64> for Minimum {
-    type Output = f64;
-
-    fn next(&mut self, input: f64) -> Self::Output {
+impl Minimum {
+    fn next_value(&mut self, input: f64) -> f64 {
         self.deque[self.cur_index] = input;
 
-        if input < self.deque[self.min_index] {
-            self.min_index = self.cur_index;
-        } else if self.min_index == self.cur_index {
+        if self.min_index == self.cur_index {
+            // the extreme left the window
             self.min_index = self.find_min_index();
+        } else if input <= self.deque[self.min_index] {
+            self.min_index = self.cur_index;
         }
 
         self.cur_index = if self.cur_index + 1 < self.period {
@@ -86,6 +111,8 @@ impl Next<f64> for Minimum {
     }
 }
 
+impl_next_option!(Minimum);
+
 impl<T: Low> Next<&T> for Minimum {
     type Output = f64;
 
@@ -99,6 +126,9 @@ impl Reset for Minimum {
         for i in 0..self.period {
             self.deque[i] = f64::INFINITY;
         }
+        self.min_index = 0;
+        self.cur_index = 0;
+        self.last_output = None;
     }
 }
 
@@ -157,6 +187,51 @@ mod tests {
         assert_eq!(min.next(&bar(5.0)), 1.2);
     }
 
+    #[test]
+    fn test_bars_ago() {
+        let mut min = Minimum::new(5).unwrap();
+        for &low in [7.0, 1.0, 6.0, 5.0].iter() {
+            min.next(low);
+        }
+        let out = min.next_extreme(4.0);
+        assert_eq!(out.value, 1.0);
+        assert_eq!(out.bars_ago, 3);
+        assert_eq!(min.bars_ago(), 3);
+
+        assert_eq!(min.next_extreme(8.0).bars_ago, 4);
+        // the 1 leaves the window
+        assert_eq!(
+            min.next_extreme(9.0),
+            ExtremeOutput {
+                value: 4.0,
+                bars_ago: 2
+            }
+        );
+        // a new low of the window
+        assert_eq!(min.next(&Bar::new().low(0.5)), 0.5);
+        assert_eq!(min.bars_ago(), 0);
+    }
+
+    #[test]
+    fn test_bars_ago_ties() {
+        // the most recent of equal lows counts
+        let mut min = Minimum::new(4).unwrap();
+        for &low in [5.0, 3.0, 3.0].iter() {
+            min.next(low);
+        }
+        assert_eq!(min.next_extreme(7.0).bars_ago, 1);
+        assert_eq!(min.next_extreme(3.0).bars_ago, 0);
+
+        // also when the lowest value leaves the window
+        let mut min = Minimum::new(3).unwrap();
+        for &low in [1.0, 5.0, 5.0].iter() {
+            min.next(low);
+        }
+        let out = min.next_extreme(6.0);
+        assert_eq!(out.value, 5.0);
+        assert_eq!(out.bars_ago, 1);
+    }
+
     #[test]
     fn test_reset() {
         let mut min = Minimum::new(10).unwrap();
@@ -1,5 +1,5 @@
 mod exponential_moving_average;
-pub use self::exponential_moving_average::ExponentialMovingAverage;
+pub use self::exponential_moving_average::{EmaSeed, ExponentialMovingAverage};
 
 mod weighted_moving_average;
 pub use self::weighted_moving_average::WeightedMovingAverage;
@@ -14,16 +14,16 @@ mod mean_absolute_deviation;
 pub use self::mean_absolute_deviation::MeanAbsoluteDeviation;
 
 mod relative_strength_index;
-pub use self::relative_strength_index::RelativeStrengthIndex;
+pub use self::relative_strength_index::{FlatRsi, RelativeStrengthIndex, RsiConfig};
 
 mod minimum;
 pub use self::minimum::Minimum;
 
 mod maximum;
-pub use self::maximum::Maximum;
+pub use self::maximum::{ExtremeOutput, Maximum};
 
 mod fast_stochastic;
-pub use self::fast_stochastic::FastStochastic;
+pub use self::fast_stochastic::{FastStochastic, StochMode};
 
 mod slow_stochastic;
 pub use self::slow_stochastic::SlowStochastic;
@@ -69,7 +69,176 @@ mod on_balance_volume;
 pub use self::on_balance_volume::OnBalanceVolume;
 
 mod correlation;
-pub use self::correlation::Correlation;
+pub use self::correlation::{Correlation, CorrelationConfig};
 
 mod average_directional_index;
-pub use self::average_directional_index::AverageDirectionalIndex;
+pub use self::average_directional_index::{
+    AverageDirectionalIndex, AverageDirectionalIndexBuilder,
+};
+
+mod damiani_volatmeter;
+pub use self::damiani_volatmeter::{DamianiOutput, DamianiVolatmeter};
+
+mod alligator;
+pub use self::alligator::{Alligator, AlligatorOutput};
+
+mod gator_oscillator;
+pub use self::gator_oscillator::{GatorOscillator, GatorOutput};
+
+mod fractal;
+pub use self::fractal::{Fractal, FractalOutput};
+
+mod ma_ribbon;
+pub use self::ma_ribbon::{MaRibbon, RibbonOutput};
+
+mod volume_profile;
+pub use self::volume_profile::{VolumeProfile, VolumeProfileOutput};
+
+mod price_volume_trend;
+pub use self::price_volume_trend::PriceVolumeTrend;
+
+mod gmma;
+pub use self::gmma::{Gmma, GmmaOutput, GmmaScore};
+
+mod squeeze_momentum;
+pub use self::squeeze_momentum::{SqueezeMomentum, SqueezeOutput};
+
+mod hurst_exponent;
+pub use self::hurst_exponent::HurstExponent;
+
+mod laguerre;
+pub use self::laguerre::{LaguerreFilter, LaguerreRsi};
+
+mod center_of_gravity;
+pub use self::center_of_gravity::CenterOfGravity;
+
+mod decycler;
+pub use self::decycler::{Decycler, DecyclerOscillator};
+
+mod fibonacci_weighted_moving_average;
+pub use self::fibonacci_weighted_moving_average::FibonacciWeightedMovingAverage;
+
+mod hilbert;
+
+mod sine_wave;
+pub use self::sine_wave::{SineWave, SineWaveOutput};
+
+mod percent_trailing_stop;
+pub use self::percent_trailing_stop::PercentTrailingStop;
+
+mod exponential_correlation;
+pub use self::exponential_correlation::ExponentialCorrelation;
+
+mod chande_momentum_oscillator;
+pub use self::chande_momentum_oscillator::ChandeMomentumOscillator;
+
+mod vidya;
+pub use self::vidya::Vidya;
+
+mod super_trend;
+pub use self::super_trend::{SuperTrend, SuperTrendOutput};
+
+mod linear_regression;
+pub use self::linear_regression::{LinearRegression, LinearRegressionOutput, TimeSeriesForecast};
+
+mod trend_streak;
+pub use self::trend_streak::TrendStreak;
+
+mod relative_momentum_index;
+pub use self::relative_momentum_index::RelativeMomentumIndex;
+
+mod bollinger_percent_b;
+pub use self::bollinger_percent_b::BollingerPercentB;
+
+mod bollinger_bandwidth;
+pub use self::bollinger_bandwidth::BollingerBandwidth;
+
+mod chaikin_volatility;
+pub use self::chaikin_volatility::ChaikinVolatility;
+
+mod beta;
+pub use self::beta::Beta;
+
+mod z_score;
+pub use self::z_score::ZScore;
+
+mod moving_average;
+pub use self::moving_average::{MaType, MovingAverage};
+
+mod ulcer_index;
+pub use self::ulcer_index::UlcerIndex;
+
+mod safe_zone_stop;
+pub use self::safe_zone_stop::{SafeZoneOutput, SafeZoneStop};
+
+mod schaff_trend_cycle;
+pub use self::schaff_trend_cycle::SchaffTrendCycle;
+
+mod zero_lag_ema;
+pub use self::zero_lag_ema::ZeroLagEma;
+
+mod arnaud_legoux_moving_average;
+pub use self::arnaud_legoux_moving_average::Alma;
+
+mod percentage_volume_oscillator;
+pub use self::percentage_volume_oscillator::{PercentageVolumeOscillator, PvoOutput};
+
+mod volume_index;
+pub use self::volume_index::{NegativeVolumeIndex, PositiveVolumeIndex};
+
+mod klinger_oscillator;
+pub use self::klinger_oscillator::{KlingerOscillator, KlingerOutput};
+
+mod median;
+pub use self::median::Median;
+
+mod returns;
+pub use self::returns::{LogReturn, Return};
+
+mod rolling_sharpe;
+pub use self::rolling_sharpe::RollingSharpe;
+
+mod drawdown;
+pub use self::drawdown::MaxDrawdown;
+
+mod chaikin_money_flow;
+pub use self::chaikin_money_flow::ChaikinMoneyFlow;
+
+mod vwap;
+pub use self::vwap::AnchoredVwap;
+
+mod breakout;
+pub use self::breakout::{Breakout, BreakoutSignal};
+
+mod gann_hilo;
+pub use self::gann_hilo::{GannHiLo, GannHiLoOutput};
+
+mod smoothed_moving_average;
+pub use self::smoothed_moving_average::SmoothedMovingAverage;
+
+mod mcginley_dynamic;
+pub use self::mcginley_dynamic::McginleyDynamic;
+
+mod qstick;
+pub use self::qstick::QStick;
+
+mod elder_impulse;
+pub use self::elder_impulse::{ElderImpulse, Impulse};
+
+mod disparity_index;
+pub use self::disparity_index::DisparityIndex;
+
+mod moments;
+pub use self::moments::{Kurtosis, Skewness};
+
+mod double_exponential_moving_average;
+pub use self::double_exponential_moving_average::DoubleExponentialMovingAverage;
+
+mod triple_exponential_moving_average;
+pub use self::triple_exponential_moving_average::TripleExponentialMovingAverage;
+
+mod rainbow;
+pub use self::rainbow::{Rainbow, RainbowOutput};
+
+mod relative_vigor_index;
+pub use self::relative_vigor_index::{RelativeVigorIndex, RviOutput};
@@ -0,0 +1,510 @@
+use alloc::{boxed::Box, vec};
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float;
+use crate::{Close, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Running power sums of the last _period_ values, up to the 4th power.
+///
+/// The values are shifted by the first input before being summed, which keeps the sums small
+/// for prices far from 0 and limits the cancellation in the central moments.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct Moments {
+    period: usize,
+    index: usize,
+    count: usize,
+    shift: f64,
+    sums: [f64; 4],
+    deque: Box<[f64]>,
+}
+
+impl Moments {
+    fn new(period: usize) -> Self {
+        Self {
+            period,
+            index: 0,
+            count: 0,
+            shift: 0.0,
+            sums: [0.0; 4],
+            deque: vec![0.0; period].into_boxed_slice(),
+        }
+    }
+
+    fn push(&mut self, input: f64) {
+        if self.count == 0 {
+            self.shift = input;
+        }
+        let value = input - self.shift;
+
+        if self.count < self.period {
+            self.count += 1;
+        } else {
+            let old = self.deque[self.index];
+            self.sums[0] -= old;
+            self.sums[1] -= old * old;
+            self.sums[2] -= old * old * old;
+            self.sums[3] -= old * old * old * old;
+        }
+        self.sums[0] += value;
+        self.sums[1] += value * value;
+        self.sums[2] += value * value * value;
+        self.sums[3] += value * value * value * value;
+
+        self.deque[self.index] = value;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+    }
+
+    fn is_full(&self) -> bool {
+        self.count == self.period
+    }
+
+    /// Population central moments m2, m3 and m4 of the window, `None` if the values are all
+    /// equal.
+    fn central(&self) -> Option<(f64, f64, f64)> {
+        let n = self.count as f64;
+        let mean = self.sums[0] / n;
+        let s2 = self.sums[1] / n;
+        let s3 = self.sums[2] / n;
+        let s4 = self.sums[3] / n;
+
+        let m2 = s2 - mean * mean;
+        // relative to the sums, anything smaller is rounding noise
+        if m2 <= s2 * 1e-12 {
+            return None;
+        }
+        let m3 = s3 - 3.0 * mean * s2 + 2.0 * mean.powi(3);
+        let m4 = s4 - 4.0 * mean * s3 + 6.0 * mean * mean * s2 - 3.0 * mean.powi(4);
+        Some((m2, m3, m4))
+    }
+
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.shift = 0.0;
+        self.sums = [0.0; 4];
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+/// Rolling skewness.
+///
+/// The asymmetry of the distribution of the last _period_ values: positive when the values
+/// above the mean stretch further than the ones below it (a long right tail), negative for a
+/// long left tail and 0 for a symmetric window.
+///
+/// # Formula
+///
+/// The sample skewness G<sub>1</sub>, i.e. the adjusted Fisher-Pearson coefficient also used
+/// by Excel's SKEW and pandas:
+///
+/// G<sub>1</sub> = g<sub>1</sub> * sqrt(n * (n - 1)) / (n - 2)
+///
+/// g<sub>1</sub> = m<sub>3</sub> / m<sub>2</sub><sup>3/2</sup>
+///
+/// Where:
+///
+/// * _n_ - the period
+/// * _m<sub>k</sub>_ - the population central moment of order _k_ of the window
+///
+/// Running power sums of the window give each output in constant time. The output is NaN
+/// until _period_ values are known, and when they are all equal.
+///
+/// # Parameters
+///
+/// * _period_ - number of values (integer greater than 2). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::Skewness;
+/// use tam::Next;
+///
+/// let mut skew = Skewness::new(3).unwrap();
+/// assert!(skew.next(1.0).is_nan());
+/// assert!(skew.next(1.0).is_nan());
+/// // a single high outlier, G1 = sqrt(3)
+/// assert_eq!((skew.next(10.0) * 1000.0).round(), 1732.0);
+/// ```
+///
+/// # Links
+///
+/// * [Skewness, Wikipedia](https://en.wikipedia.org/wiki/Skewness#Sample_skewness)
+///
+#[doc(alias = "SKEW")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Skewness {
+    moments: Moments,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl Skewness {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0..=2 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                moments: Moments::new(period),
+                last_output: None,
+            }),
+        }
+    }
+}
+
+impl Period for Skewness {
+    fn period(&self) -> usize {
+        self.moments.period
+    }
+}
+
+impl Warmup for Skewness {
+    fn lookback(&self) -> usize {
+        self.moments.period - 1
+    }
+}
+
+impl Skewness {
+    fn next_value(&mut self, input: f64) -> f64 {
+        self.moments.push(input);
+        if !self.moments.is_full() {
+            return f64::NAN;
+        }
+        match self.moments.central() {
+            Some((m2, m3, _)) => {
+                let n = self.moments.period as f64;
+                let g1 = m3 / (m2 * m2.sqrt());
+                g1 * (n * (n - 1.0)).sqrt() / (n - 2.0)
+            }
+            None => f64::NAN,
+        }
+    }
+}
+
+impl_next_option!(Skewness);
+
+impl<T: Close> Next<&T> for Skewness {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for Skewness {
+    fn reset(&mut self) {
+        self.moments.reset();
+        self.last_output = None;
+    }
+}
+
+impl Default for Skewness {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for Skewness {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SKEW({})", self.moments.period)
+    }
+}
+
+/// Rolling excess kurtosis.
+///
+/// How heavy the tails of the distribution of the last _period_ values are compared to a
+/// normal distribution: positive when the window holds more extreme values than a normal one
+/// would, negative when the values are spread evenly.
+///
+/// # Formula
+///
+/// The sample excess kurtosis G<sub>2</sub>, as computed by Excel's KURT and pandas:
+///
+/// G<sub>2</sub> = ((n + 1) * g<sub>2</sub> + 6) * (n - 1) / ((n - 2) * (n - 3))
+///
+/// g<sub>2</sub> = m<sub>4</sub> / m<sub>2</sub><sup>2</sup> - 3
+///
+/// Where:
+///
+/// * _n_ - the period
+/// * _m<sub>k</sub>_ - the population central moment of order _k_ of the window
+///
+/// Running power sums of the window give each output in constant time. The output is NaN
+/// until _period_ values are known, and when they are all equal.
+///
+/// # Parameters
+///
+/// * _period_ - number of values (integer greater than 3). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::Kurtosis;
+/// use tam::Next;
+///
+/// let mut kurt = Kurtosis::new(4).unwrap();
+/// for x in &[1.0, 2.0, 3.0] {
+///     assert!(kurt.next(*x).is_nan());
+/// }
+/// // evenly spread values have thin tails
+/// assert_eq!((kurt.next(4.0) * 1000.0).round(), -1200.0);
+/// ```
+///
+/// # Links
+///
+/// * [Kurtosis, Wikipedia](https://en.wikipedia.org/wiki/Kurtosis#Standard_unbiased_estimator)
+///
+#[doc(alias = "KURT")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Kurtosis {
+    moments: Moments,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl Kurtosis {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0..=3 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                moments: Moments::new(period),
+                last_output: None,
+            }),
+        }
+    }
+}
+
+impl Period for Kurtosis {
+    fn period(&self) -> usize {
+        self.moments.period
+    }
+}
+
+impl Warmup for Kurtosis {
+    fn lookback(&self) -> usize {
+        self.moments.period - 1
+    }
+}
+
+impl Kurtosis {
+    fn next_value(&mut self, input: f64) -> f64 {
+        self.moments.push(input);
+        if !self.moments.is_full() {
+            return f64::NAN;
+        }
+        match self.moments.central() {
+            Some((m2, _, m4)) => {
+                let n = self.moments.period as f64;
+                let g2 = m4 / (m2 * m2) - 3.0;
+                ((n + 1.0) * g2 + 6.0) * (n - 1.0) / ((n - 2.0) * (n - 3.0))
+            }
+            None => f64::NAN,
+        }
+    }
+}
+
+impl_next_option!(Kurtosis);
+
+impl<T: Close> Next<&T> for Kurtosis {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for Kurtosis {
+    fn reset(&mut self) {
+        self.moments.reset();
+        self.last_output = None;
+    }
+}
+
+impl Default for Kurtosis {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for Kurtosis {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "KURT({})", self.moments.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Skewness::new(2).is_err());
+        assert!(Skewness::new(3).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut skew = Skewness::new(5).unwrap();
+        assert_eq!(skew.lookback(), 4);
+
+        let outputs: Vec<f64> = [2.0, 8.0, 0.0, 4.0, 1.0, 9.0, 9.0, 0.0]
+            .iter()
+            .map(|&x| skew.next(&Bar::new().close(x)))
+            .collect();
+        assert_eq!(warmup_nan_count(&outputs), 4);
+        let rounded: Vec<f64> = outputs[4..].iter().map(|&x| round(x)).collect();
+        assert_eq!(rounded, vec![1.186, 0.123, 0.139, 0.139]);
+    }
+
+    #[test]
+    fn test_symmetric() {
+        let mut skew = Skewness::new(5).unwrap();
+        for &x in &[101.0, 102.0, 103.0, 104.0] {
+            skew.next(x);
+        }
+        assert!(skew.next(105.0).abs() < 1e-9);
+
+        // mirrored windows have opposite skews
+        let mut skew = Skewness::new(4).unwrap();
+        let mut mirrored = Skewness::new(4).unwrap();
+        let mut last = (0.0, 0.0);
+        for &x in &[1.0, 2.0, 3.0, 10.0] {
+            last = (skew.next(x), mirrored.next(-x));
+        }
+        assert_eq!(round(last.0), 1.764);
+        assert!((last.0 + last.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_flat() {
+        let mut skew = Skewness::new(3).unwrap();
+        for &x in &[5.0, 1.0, 9.0] {
+            skew.next(x);
+        }
+        for _ in 0..3 {
+            skew.next(42.0);
+        }
+        assert!(skew.next(42.0).is_nan());
+        assert!(!skew.next(43.0).is_nan());
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut skew = Skewness::new(3).unwrap();
+        for &x in &[1.0, 1.0, 10.0] {
+            skew.next(x);
+        }
+
+        skew.reset();
+        assert!(skew.next(1.0).is_nan());
+        assert!(skew.next(1.0).is_nan());
+        assert_eq!(round(skew.next(10.0)), 1.732);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut skew = Skewness::new(4).unwrap();
+        for &x in &[3.0, 1.0, 4.0, 1.0] {
+            skew.next(x);
+        }
+        let mut restored = serde_round_trip(&skew);
+        assert_eq!(restored.next(5.0), skew.next(5.0));
+    }
+
+    #[test]
+    fn test_default() {
+        Skewness::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Skewness::default()), "SKEW(20)");
+    }
+
+    #[test]
+    fn test_kurt_new() {
+        assert!(Kurtosis::new(3).is_err());
+        assert!(Kurtosis::new(4).is_ok());
+    }
+
+    #[test]
+    fn test_kurt_next() {
+        let mut kurt = Kurtosis::new(5).unwrap();
+        assert_eq!(kurt.lookback(), 4);
+
+        let outputs: Vec<f64> = [2.0, 8.0, 0.0, 4.0, 1.0, 9.0, 9.0, 0.0]
+            .iter()
+            .map(|&x| kurt.next(&Bar::new().close(x)))
+            .collect();
+        assert_eq!(warmup_nan_count(&outputs), 4);
+        let rounded: Vec<f64> = outputs[4..].iter().map(|&x| round(x)).collect();
+        assert_eq!(rounded, vec![1.05, -2.711, -2.903, -2.903]);
+    }
+
+    #[test]
+    fn test_kurt_tails() {
+        let mut kurt = Kurtosis::new(4).unwrap();
+        for &x in &[1.0, 2.0, 3.0] {
+            kurt.next(x);
+        }
+        // an outlier makes the tails heavier than evenly spread values
+        assert_eq!(round(kurt.next(10.0)), 3.228);
+
+        let mut kurt = Kurtosis::new(5).unwrap();
+        for &x in &[101.0, 102.0, 103.0, 104.0] {
+            kurt.next(x);
+        }
+        assert_eq!(round(kurt.next(105.0)), -1.2);
+    }
+
+    #[test]
+    fn test_kurt_flat() {
+        let mut kurt = Kurtosis::new(4).unwrap();
+        for _ in 0..5 {
+            assert!(kurt.next(42.0).is_nan());
+        }
+    }
+
+    #[test]
+    fn test_kurt_reset() {
+        let mut kurt = Kurtosis::new(4).unwrap();
+        for &x in &[1.0, 2.0, 3.0, 10.0] {
+            kurt.next(x);
+        }
+
+        kurt.reset();
+        for &x in &[1.0, 2.0, 3.0] {
+            assert!(kurt.next(x).is_nan());
+        }
+        assert_eq!(round(kurt.next(4.0)), -1.2);
+    }
+
+    #[test]
+    fn test_kurt_serde() {
+        let mut kurt = Kurtosis::new(4).unwrap();
+        for &x in &[3.0, 1.0, 4.0, 1.0] {
+            kurt.next(x);
+        }
+        let mut restored = serde_round_trip(&kurt);
+        assert_eq!(restored.next(5.0), kurt.next(5.0));
+    }
+
+    #[test]
+    fn test_kurt_default() {
+        Kurtosis::default();
+    }
+
+    #[test]
+    fn test_kurt_display() {
+        assert_eq!(format!("{}", Kurtosis::default()), "KURT(20)");
+    }
+}
@@ -1,7 +1,8 @@
-use std::fmt;
+use alloc::{boxed::Box, vec};
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Close, High, Low, Next, Period, Reset, Volume};
+use crate::{ClassifiedBar, Close, High, Low, Next, Period, Reset, Volume};
 
 use serde::{Deserialize, Serialize};
 
@@ -26,6 +27,9 @@ use serde::{Deserialize, Serialize};
 ///
 /// Money Flow Index(MFI) = PMF / (PMF + NMF) * 100
 ///
+/// [next_classified](#method.next_classified) uses the tick rule instead when the input knows
+/// its buy and sell volume (see [ClassifiedBar](../trait.ClassifiedBar.html)): TP x Buy Volume
+/// is added to the positive money flow and TP x Sell Volume to the negative money flow.
 ///
 /// # Parameters
 ///
@@ -51,7 +55,6 @@ use serde::{Deserialize, Serialize};
 /// # Links
 /// * [Money Flow Index, Wikipedia](https://en.wikipedia.org/wiki/Money_flow_index)
 /// * [Money Flow Index, stockcharts](https://stockcharts.com/school/doku.php?id=chart_school:technical_indicators:money_flow_index_mfi)
-
 #[doc(alias = "MFI")]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct MoneyFlowIndex {
@@ -61,7 +64,8 @@ pub struct MoneyFlowIndex {
     previous_typical_price: f64,
     total_positive_money_flow: f64,
     total_negative_money_flow: f64,
-    deque: Box<[f64]>,
+    /// Positive and negative money flow of the bars in the window.
+    deque: Box<[(f64, f64)]>,
 }
 
 impl MoneyFlowIndex {
@@ -75,22 +79,25 @@ impl MoneyFlowIndex {
                 previous_typical_price: 0.0,
                 total_positive_money_flow: 0.0,
                 total_negative_money_flow: 0.0,
-                deque: vec![0.0; period].into_boxed_slice(),
+                deque: vec![(0.0, 0.0); period].into_boxed_slice(),
             }),
         }
     }
-}
 
-impl Period for MoneyFlowIndex {
-    fn period(&self) -> usize {
-        self.period
+    /// Like `next`, but splits the money flow of `input` by its buy and sell volume when both
+    /// are known (tick rule). Otherwise the typical prices are compared as usual.
+    pub fn next_classified<T>(&mut self, input: &T) -> f64
+    where
+        T: High + Low + Close + Volume + ClassifiedBar,
+    {
+        self.update(input, input.buy_volume().zip(input.sell_volume()))
     }
-}
-
-impl<T: High + Low + Close + Volume> Next<&T> for MoneyFlowIndex {
-    type Output = f64;
 
-    fn next(&mut self, input: &T) -> f64 {
+    fn update<T: High + Low + Close + Volume>(
+        &mut self,
+        input: &T,
+        classified: Option<(f64, f64)>,
+    ) -> f64 {
         let tp = (input.close() + input.high() + input.low()) / 3.0;
 
         self.index = if self.index + 1 < self.period {
@@ -100,31 +107,29 @@ impl<T: High + Low + Close + Volume> Next<&T> for MoneyFlowIndex {
         };
 
         if self.count < self.period {
-            self.count = self.count + 1;
+            self.count += 1;
             if self.count == 1 {
                 self.previous_typical_price = tp;
                 return 50.0;
             }
         } else {
-            let popped = self.deque[self.index];
-            if popped.is_sign_positive() {
-                self.total_positive_money_flow -= popped;
-            } else {
-                self.total_negative_money_flow += popped;
-            }
+            let (positive, negative) = self.deque[self.index];
+            self.total_positive_money_flow -= positive;
+            self.total_negative_money_flow -= negative;
         }
 
-        if tp > self.previous_typical_price {
-            let raw_money_flow = tp * input.volume();
-            self.total_positive_money_flow += raw_money_flow;
-            self.deque[self.index] = raw_money_flow;
+        let money_flow = if let Some((buy, sell)) = classified {
+            (tp * buy, tp * sell)
+        } else if tp > self.previous_typical_price {
+            (tp * input.volume(), 0.0)
         } else if tp < self.previous_typical_price {
-            let raw_money_flow = tp * input.volume();
-            self.total_negative_money_flow += raw_money_flow;
-            self.deque[self.index] = -raw_money_flow;
+            (0.0, tp * input.volume())
         } else {
-            self.deque[self.index] = 0.0;
-        }
+            (0.0, 0.0)
+        };
+        self.total_positive_money_flow += money_flow.0;
+        self.total_negative_money_flow += money_flow.1;
+        self.deque[self.index] = money_flow;
         self.previous_typical_price = tp;
 
         self.total_positive_money_flow
@@ -133,6 +138,20 @@ impl<T: High + Low + Close + Volume> Next<&T> for MoneyFlowIndex {
     }
 }
 
+impl Period for MoneyFlowIndex {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: High + Low + Close + Volume> Next<&T> for MoneyFlowIndex {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> f64 {
+        self.update(input, None)
+    }
+}
+
 impl Default for MoneyFlowIndex {
     fn default() -> Self {
         Self::new(14).unwrap()
@@ -153,7 +172,7 @@ impl Reset for MoneyFlowIndex {
         self.total_positive_money_flow = 0.0;
         self.total_negative_money_flow = 0.0;
         for i in 0..self.period {
-            self.deque[i] = 0.0;
+            self.deque[i] = (0.0, 0.0);
         }
     }
 }
@@ -198,6 +217,31 @@ mod tests {
         assert_eq!(round(mfi.next(&bar8)), 60.87);
     }
 
+    #[test]
+    fn test_next_classified() {
+        let mut mfi = MoneyFlowIndex::new(3).unwrap();
+
+        let mut close_rule = MoneyFlowIndex::new(3).unwrap();
+
+        let bar1 = Bar::new().high(3).low(1).close(2).volume(500.0);
+        assert_eq!(round(mfi.next_classified(&bar1)), 50.0);
+        assert_eq!(round(close_rule.next(&bar1)), 50.0);
+
+        // TP goes up, but only a quarter of the volume was bought
+        let bar2 = Bar::new()
+            .high(2.3)
+            .low(2.0)
+            .close(2.3)
+            .volume(1000.0)
+            .classified(250.0, 750.0);
+        assert_eq!(round(mfi.next_classified(&bar2)), 25.0);
+        assert_eq!(round(close_rule.next(&bar2)), 100.0);
+
+        // TP goes up again, without classification
+        let bar3 = Bar::new().high(3).low(3).close(3).volume(100.0);
+        assert_eq!(round(mfi.next_classified(&bar3)), 34.0);
+    }
+
     #[test]
     fn test_reset() {
         let mut mfi = MoneyFlowIndex::new(3).unwrap();
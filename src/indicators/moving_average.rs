@@ -0,0 +1,307 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{
+    DoubleExponentialMovingAverage as Dema, ExponentialMovingAverage as Ema,
+    SimpleMovingAverage as Sma, SmoothedMovingAverage as Smma,
+    TripleExponentialMovingAverage as Tema, WeightedMovingAverage as Wma,
+};
+use crate::{Close, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Kind of a [MovingAverage](struct.MovingAverage.html).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum MaType {
+    /// [Simple Moving Average](struct.SimpleMovingAverage.html).
+    #[default]
+    Sma,
+    /// [Exponential Moving Average](struct.ExponentialMovingAverage.html), seeded with the
+    /// first value.
+    Ema,
+    /// [Weighted Moving Average](struct.WeightedMovingAverage.html).
+    Wma,
+    /// [Wilder's smoothing](struct.SmoothedMovingAverage.html) (RMA), the one of the RSI and
+    /// the ADX: an EMA with α = 1 / _period_ seeded with the SMA of the first _period_ inputs.
+    /// Its first _period_ - 1 outputs are NaN.
+    Wilder,
+    /// [Double Exponential Moving Average](struct.DoubleExponentialMovingAverage.html).
+    Dema,
+    /// [Triple Exponential Moving Average](struct.TripleExponentialMovingAverage.html).
+    Tema,
+}
+
+/// Moving average of a type chosen at runtime.
+///
+/// Wraps one of the moving averages of the crate, so indicators and signals can let the user
+/// pick the kind of smoothing with a [MaType](enum.MaType.html). Outputs are the ones of the
+/// wrapped moving average.
+///
+/// # Parameters
+///
+/// * _ma_type_ - kind of moving average. Default is SMA.
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::{MaType, MovingAverage};
+/// use tam::Next;
+///
+/// let mut ma = MovingAverage::new(MaType::Ema, 3).unwrap();
+/// assert_eq!(ma.next(2.0), 2.0);
+/// assert_eq!(ma.next(5.0), 3.5);
+/// assert_eq!(ma.to_string(), "EMA(3)");
+/// ```
+#[doc(alias = "MA")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[non_exhaustive]
+pub enum MovingAverage {
+    Sma(Sma),
+    Ema(Ema),
+    Wma(Wma),
+    Wilder(Smma),
+    Dema(Dema),
+    Tema(Tema),
+}
+
+impl MovingAverage {
+    pub fn new(ma_type: MaType, period: usize) -> Result<Self> {
+        Ok(match ma_type {
+            MaType::Sma => Self::Sma(Sma::new(period)?),
+            MaType::Ema => Self::Ema(Ema::new(period)?),
+            MaType::Wma => Self::Wma(Wma::new(period)?),
+            MaType::Wilder => Self::Wilder(Smma::new(period)?),
+            MaType::Dema => Self::Dema(Dema::new(period)?),
+            MaType::Tema => Self::Tema(Tema::new(period)?),
+        })
+    }
+
+    pub fn ma_type(&self) -> MaType {
+        match self {
+            Self::Sma(_) => MaType::Sma,
+            Self::Ema(_) => MaType::Ema,
+            Self::Wma(_) => MaType::Wma,
+            Self::Wilder(_) => MaType::Wilder,
+            Self::Dema(_) => MaType::Dema,
+            Self::Tema(_) => MaType::Tema,
+        }
+    }
+}
+
+impl Period for MovingAverage {
+    fn period(&self) -> usize {
+        match self {
+            Self::Sma(ma) => ma.period(),
+            Self::Ema(ma) => ma.period(),
+            Self::Wma(ma) => ma.period(),
+            Self::Wilder(ma) => ma.period(),
+            Self::Dema(ma) => ma.period(),
+            Self::Tema(ma) => ma.period(),
+        }
+    }
+}
+
+impl Warmup for MovingAverage {
+    fn lookback(&self) -> usize {
+        match self {
+            Self::Sma(ma) => ma.lookback(),
+            Self::Ema(ma) => ma.lookback(),
+            Self::Wma(ma) => ma.lookback(),
+            Self::Wilder(ma) => ma.lookback(),
+            Self::Dema(ma) => ma.lookback(),
+            Self::Tema(ma) => ma.lookback(),
+        }
+    }
+}
+
+impl Next<f64> for MovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        match self {
+            Self::Sma(ma) => ma.next(input),
+            Self::Ema(ma) => ma.next(input),
+            Self::Wma(ma) => ma.next(input),
+            Self::Wilder(ma) => ma.next(input),
+            Self::Dema(ma) => ma.next(input),
+            Self::Tema(ma) => ma.next(input),
+        }
+    }
+}
+
+impl Next<Option<f64>> for MovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: Option<f64>) -> Self::Output {
+        match self {
+            Self::Sma(ma) => ma.next(input),
+            Self::Ema(ma) => ma.next(input),
+            Self::Wma(ma) => ma.next(input),
+            Self::Wilder(ma) => ma.next(input),
+            Self::Dema(ma) => ma.next(input),
+            Self::Tema(ma) => ma.next(input),
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for MovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for MovingAverage {
+    fn reset(&mut self) {
+        match self {
+            Self::Sma(ma) => ma.reset(),
+            Self::Ema(ma) => ma.reset(),
+            Self::Wma(ma) => ma.reset(),
+            Self::Wilder(ma) => ma.reset(),
+            Self::Dema(ma) => ma.reset(),
+            Self::Tema(ma) => ma.reset(),
+        }
+    }
+}
+
+impl Default for MovingAverage {
+    fn default() -> Self {
+        Self::new(MaType::default(), 9).unwrap()
+    }
+}
+
+impl fmt::Display for MovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Sma(ma) => fmt::Display::fmt(ma, f),
+            Self::Ema(ma) => fmt::Display::fmt(ma, f),
+            Self::Wma(ma) => fmt::Display::fmt(ma, f),
+            Self::Wilder(ma) => write!(f, "RMA({})", ma.period()),
+            Self::Dema(ma) => fmt::Display::fmt(ma, f),
+            Self::Tema(ma) => fmt::Display::fmt(ma, f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(MovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(MovingAverage::new(MaType::Sma, 0).is_err());
+        assert!(MovingAverage::new(MaType::Ema, 0).is_err());
+        assert!(MovingAverage::new(MaType::Wma, 0).is_err());
+        assert!(MovingAverage::new(MaType::Wilder, 0).is_err());
+        assert!(MovingAverage::new(MaType::Dema, 0).is_err());
+        assert!(MovingAverage::new(MaType::Tema, 0).is_err());
+        assert!(MovingAverage::new(MaType::Wma, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let inputs = [10.0, 13.0, 16.0, 14.0, 9.5];
+        for ma_type in [
+            MaType::Sma,
+            MaType::Ema,
+            MaType::Wma,
+            MaType::Wilder,
+            MaType::Dema,
+            MaType::Tema,
+        ] {
+            let mut ma = MovingAverage::new(ma_type, 3).unwrap();
+            assert_eq!(ma.ma_type(), ma_type);
+
+            let mut sma = Sma::new(3).unwrap();
+            let mut ema = Ema::new(3).unwrap();
+            let mut wma = Wma::new(3).unwrap();
+            let mut rma = Smma::new(3).unwrap();
+            let mut dema = Dema::new(3).unwrap();
+            let mut tema = Tema::new(3).unwrap();
+            for &input in inputs.iter() {
+                let expected = match ma_type {
+                    MaType::Sma => sma.next(input),
+                    MaType::Ema => ema.next(input),
+                    MaType::Wma => wma.next(input),
+                    MaType::Wilder => rma.next(input),
+                    MaType::Dema => dema.next(input),
+                    MaType::Tema => tema.next(input),
+                };
+                let value = ma.next(&Bar::new().close(input));
+                assert!(value == expected || (value.is_nan() && expected.is_nan()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_wilder() {
+        let mut rma = MovingAverage::new(MaType::Wilder, 3).unwrap();
+        assert_eq!(rma.ma_type(), MaType::Wilder);
+
+        assert!(rma.next(1.0).is_nan());
+        assert!(rma.next(2.0).is_nan());
+        // seeded with the average of the first 3 inputs
+        assert_eq!(rma.next(6.0), 3.0);
+        // (3 * 2 + 9) / 3
+        assert_eq!(rma.next(9.0), 5.0);
+        assert_eq!(round(rma.next(&Bar::new().close(2))), 4.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        use crate::indicators::EmaSeed;
+
+        for ema in [
+            Ema::new(5).unwrap(),
+            Ema::new(5).unwrap().with_seed(EmaSeed::Sma),
+        ] {
+            assert_eq!(MovingAverage::Ema(ema.clone()).lookback(), ema.lookback());
+        }
+        assert_eq!(MovingAverage::new(MaType::Ema, 5).unwrap().lookback(), 0);
+        assert_eq!(MovingAverage::new(MaType::Sma, 5).unwrap().lookback(), 4);
+        assert_eq!(MovingAverage::new(MaType::Wma, 5).unwrap().lookback(), 4);
+        assert_eq!(MovingAverage::new(MaType::Wilder, 5).unwrap().lookback(), 4);
+    }
+
+    #[test]
+    fn test_next_option() {
+        let mut ma = MovingAverage::new(MaType::Wma, 3).unwrap();
+        assert!(ma.next(None).is_nan());
+        assert_eq!(ma.next(Some(10.0)), 10.0);
+        assert_eq!(ma.next(None), 10.0);
+        assert_eq!(ma.next(Some(13.0)), 12.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ma = MovingAverage::new(MaType::Ema, 3).unwrap();
+        ma.next(2.0);
+        ma.next(5.0);
+
+        ma.reset();
+        assert_eq!(ma.next(4.0), 4.0);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(MovingAverage::default().ma_type(), MaType::Sma);
+    }
+
+    #[test]
+    fn test_display() {
+        let sma = MovingAverage::new(MaType::Sma, 20).unwrap();
+        let wma = MovingAverage::new(MaType::Wma, 5).unwrap();
+        assert_eq!(format!("{}", sma), "SMA(20)");
+        assert_eq!(format!("{}", wma), "WMA(5)");
+        let rma = MovingAverage::new(MaType::Wilder, 14).unwrap();
+        assert_eq!(format!("{}", rma), "RMA(14)");
+        let dema = MovingAverage::new(MaType::Dema, 9).unwrap();
+        assert_eq!(format!("{}", dema), "DEMA(9)");
+        assert_eq!(dema.lookback(), 0);
+    }
+}
@@ -1,8 +1,9 @@
-use std::fmt;
+use alloc::{vec, vec::Vec};
+use core::fmt;
 
 use crate::errors::Result;
-use crate::indicators::ExponentialMovingAverage as Ema;
-use crate::{Close, Next, Period, Reset};
+use crate::indicators::{MaType, MovingAverage};
+use crate::{Close, Next, Period, Periods, Reset};
 use serde::{Deserialize, Serialize};
 
 /// Moving average converge divergence (MACD).
@@ -26,6 +27,12 @@ use serde::{Deserialize, Serialize};
 /// * _fast_period_ - period for the fast EMA. Default is 12.
 /// * _slow_period_ - period for the slow EMA. Default is 26.
 /// * _signal_period_ - period for the signal EMA. Default is 9.
+/// * _ma_type_ - kind of the three moving averages, for
+///   [new_with_ma](#method.new_with_ma) (TA-Lib's MACDEXT) or
+///   [with_ma_type](#method.with_ma_type). Default is EMA.
+///
+/// With a moving average having NaN outputs during its warmup (Wilder's), the signal line
+/// starts with the first defined MACD value.
 ///
 /// # Example
 ///
@@ -52,22 +59,52 @@ use serde::{Deserialize, Serialize};
 #[doc(alias = "MACD")]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct MovingAverageConvergenceDivergence {
-    fast_ema: Ema,
-    slow_ema: Ema,
-    signal_ema: Ema,
+    fast_ma: MovingAverage,
+    slow_ma: MovingAverage,
+    signal_ma: MovingAverage,
 }
 
 impl MovingAverageConvergenceDivergence {
     pub fn new(fast_period: usize, slow_period: usize, signal_period: usize) -> Result<Self> {
+        Self::new_with_ma(fast_period, slow_period, signal_period, MaType::Ema)
+    }
+
+    /// Uses moving averages of the given type for the three smoothings instead of EMAs, like
+    /// the MAEXT variants of TA-Lib.
+    pub fn new_with_ma(
+        fast_period: usize,
+        slow_period: usize,
+        signal_period: usize,
+        ma_type: MaType,
+    ) -> Result<Self> {
         Ok(Self {
-            fast_ema: Ema::new(fast_period)?,
-            slow_ema: Ema::new(slow_period)?,
-            signal_ema: Ema::new(signal_period)?,
+            fast_ma: MovingAverage::new(ma_type, fast_period)?,
+            slow_ma: MovingAverage::new(ma_type, slow_period)?,
+            signal_ma: MovingAverage::new(ma_type, signal_period)?,
         })
     }
+
+    /// Routes the three smoothings through moving averages of the given type, keeping the
+    /// periods, e.g. [DEMA](struct.DoubleExponentialMovingAverage.html) or
+    /// [TEMA](struct.TripleExponentialMovingAverage.html) for a MACD with less lag.
+    ///
+    /// Returns `TaError::InvalidParameter` if the moving average rejects a period (the WMA has
+    /// a maximum period).
+    pub fn with_ma_type(self, ma_type: MaType) -> Result<Self> {
+        Self::new_with_ma(
+            self.fast_ma.period(),
+            self.slow_ma.period(),
+            self.signal_ma.period(),
+            ma_type,
+        )
+    }
+
+    pub fn ma_type(&self) -> MaType {
+        self.fast_ma.ma_type()
+    }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MovingAverageConvergenceDivergenceOutput {
     pub macd: f64,
     pub signal: f64,
@@ -80,15 +117,37 @@ impl From<MovingAverageConvergenceDivergenceOutput> for (f64, f64, f64) {
     }
 }
 
+impl_output!(
+    MovingAverageConvergenceDivergenceOutput,
+    macd,
+    signal,
+    histogram
+);
+
+impl Periods for MovingAverageConvergenceDivergence {
+    fn periods(&self) -> Vec<usize> {
+        vec![
+            self.fast_ma.period(),
+            self.slow_ma.period(),
+            self.signal_ma.period(),
+        ]
+    }
+}
+
 impl Next<f64> for MovingAverageConvergenceDivergence {
     type Output = MovingAverageConvergenceDivergenceOutput;
 
     fn next(&mut self, input: f64) -> Self::Output {
-        let fast_val = self.fast_ema.next(input);
-        let slow_val = self.slow_ema.next(input);
+        let fast_val = self.fast_ma.next(input);
+        let slow_val = self.slow_ma.next(input);
 
         let macd = fast_val - slow_val;
-        let signal = self.signal_ema.next(macd);
+        // a NaN from the warmup of the moving averages would poison the signal line
+        let signal = if macd.is_nan() {
+            self.signal_ma.next(None)
+        } else {
+            self.signal_ma.next(macd)
+        };
         let histogram = macd - signal;
 
         MovingAverageConvergenceDivergenceOutput {
@@ -109,9 +168,9 @@ impl<T: Close> Next<&T> for MovingAverageConvergenceDivergence {
 
 impl Reset for MovingAverageConvergenceDivergence {
     fn reset(&mut self) {
-        self.fast_ema.reset();
-        self.slow_ema.reset();
-        self.signal_ema.reset();
+        self.fast_ma.reset();
+        self.slow_ma.reset();
+        self.signal_ma.reset();
     }
 }
 
@@ -123,19 +182,27 @@ impl Default for MovingAverageConvergenceDivergence {
 
 impl fmt::Display for MovingAverageConvergenceDivergence {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "MACD({}, {}, {})",
-            self.fast_ema.period(),
-            self.slow_ema.period(),
-            self.signal_ema.period()
-        )
+        match self.ma_type() {
+            MaType::Ema => write!(
+                f,
+                "MACD({}, {}, {})",
+                self.fast_ma.period(),
+                self.slow_ma.period(),
+                self.signal_ma.period()
+            ),
+            _ => write!(
+                f,
+                "MACD({}, {}, {})",
+                self.fast_ma, self.slow_ma, self.signal_ma
+            ),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::SimpleMovingAverage;
     use crate::test_helper::*;
     type Macd = MovingAverageConvergenceDivergence;
 
@@ -168,6 +235,167 @@ mod tests {
         assert_eq!(round(macd.next(6.5).into()), (0.94, 0.87, 0.07));
     }
 
+    #[test]
+    fn test_new_with_ma() {
+        assert!(Macd::new_with_ma(3, 6, 0, MaType::Wma).is_err());
+        assert_eq!(Macd::default().ma_type(), MaType::Ema);
+
+        let prices = [2.0, 3.0, 4.2, 7.0, 6.7, 6.5, 5.0, 5.5, 6.1];
+        let mut default = Macd::new(3, 6, 4).unwrap();
+        let mut ema = Macd::new_with_ma(3, 6, 4, MaType::Ema).unwrap();
+        let mut sma = Macd::new_with_ma(3, 6, 4, MaType::Sma).unwrap();
+        assert_eq!(sma.ma_type(), MaType::Sma);
+
+        let mut fast = SimpleMovingAverage::new(3).unwrap();
+        let mut slow = SimpleMovingAverage::new(6).unwrap();
+        let mut signal = SimpleMovingAverage::new(4).unwrap();
+        for &price in prices.iter() {
+            assert_eq!(ema.next(price), default.next(price));
+
+            let macd = fast.next(price) - slow.next(price);
+            let signal = signal.next(macd);
+            let out = sma.next(price);
+            assert_eq!(out.macd, macd);
+            assert_eq!(out.signal, signal);
+            assert_eq!(out.histogram, macd - signal);
+        }
+        // the SMA lags the EMA on the way up
+        let (ema_out, sma_out) = (default.next(8.0), sma.next(8.0));
+        assert!(sma_out.macd < ema_out.macd);
+    }
+
+    #[test]
+    fn test_with_ma_type() {
+        let macd = Macd::new(3, 6, 4)
+            .unwrap()
+            .with_ma_type(MaType::Dema)
+            .unwrap();
+        assert_eq!(macd.ma_type(), MaType::Dema);
+        assert_eq!(macd.periods(), vec![3, 6, 4]);
+        assert_eq!(macd, Macd::new_with_ma(3, 6, 4, MaType::Dema).unwrap());
+        assert_eq!(
+            format!("{}", macd.with_ma_type(MaType::Tema).unwrap()),
+            "MACD(TEMA(3), TEMA(6), TEMA(4))"
+        );
+
+        // the WMA rejects the slow period
+        let macd = Macd::new(3, 1 << 27, 4).unwrap();
+        assert!(macd.with_ma_type(MaType::Wma).is_err());
+    }
+
+    #[test]
+    fn test_dema_leads() {
+        let mut ema = Macd::new(12, 26, 9).unwrap();
+        let mut dema = Macd::new(12, 26, 9)
+            .unwrap()
+            .with_ma_type(MaType::Dema)
+            .unwrap();
+        let mut tema = Macd::new(12, 26, 9)
+            .unwrap()
+            .with_ma_type(MaType::Tema)
+            .unwrap();
+        for _ in 0..50 {
+            ema.next(10.0);
+            dema.next(10.0);
+            tema.next(10.0);
+        }
+
+        // step change: the faster averages react sooner and peak earlier
+        let (mut ema_macd, mut dema_macd, mut tema_macd) = (vec![], vec![], vec![]);
+        for _ in 0..40 {
+            ema_macd.push(ema.next(20.0).macd);
+            dema_macd.push(dema.next(20.0).macd);
+            tema_macd.push(tema.next(20.0).macd);
+        }
+        assert!(dema_macd[0] > ema_macd[0]);
+        assert!(tema_macd[0] > dema_macd[0]);
+
+        let peak = |values: &[f64]| {
+            (0..values.len())
+                .max_by(|&a, &b| values[a].partial_cmp(&values[b]).unwrap())
+                .unwrap()
+        };
+        assert!(peak(&dema_macd) < peak(&ema_macd));
+        assert!(peak(&tema_macd) <= peak(&dema_macd));
+    }
+
+    #[test]
+    fn test_wilder_warmup() {
+        let mut macd = Macd::new_with_ma(3, 6, 4, MaType::Wilder).unwrap();
+        let outputs: Vec<MovingAverageConvergenceDivergenceOutput> =
+            (0..20).map(|i| macd.next(10.0 + i as f64)).collect();
+
+        // NaN until the slow RMA, then until the signal RMA are warmed up
+        assert!(outputs[4].macd.is_nan());
+        assert!(!outputs[5].macd.is_nan());
+        assert!(outputs[7].signal.is_nan());
+        assert!(outputs[8..].iter().all(|out| !out.signal.is_nan()));
+    }
+
+    #[test]
+    fn test_periods() {
+        let macd = Macd::new(12, 26, 9).unwrap();
+        assert_eq!(macd.periods(), vec![12, 26, 9]);
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        use crate::ApproxEq;
+
+        let a = MovingAverageConvergenceDivergenceOutput {
+            macd: 0.5,
+            signal: 0.25,
+            histogram: 0.25,
+        };
+        let close = MovingAverageConvergenceDivergenceOutput {
+            macd: 0.5004,
+            signal: 0.2498,
+            histogram: 0.2506,
+        };
+        let far = MovingAverageConvergenceDivergenceOutput {
+            macd: 0.5,
+            signal: 0.25,
+            histogram: 0.252,
+        };
+
+        assert!(a.approx_eq(&close, 1e-3));
+        assert!(!a.approx_eq(&far, 1e-3));
+        assert!(a.approx_eq(&far, 1e-2));
+
+        let warming_up = MovingAverageConvergenceDivergenceOutput {
+            macd: f64::NAN,
+            signal: f64::NAN,
+            histogram: f64::NAN,
+        };
+        assert!(warming_up.approx_eq(&warming_up.clone(), 0.0));
+        assert!(!warming_up.approx_eq(&a, 1e-3));
+    }
+
+    #[test]
+    fn test_named_output() {
+        use crate::NamedOutput;
+
+        let out = MovingAverageConvergenceDivergenceOutput {
+            macd: 0.5,
+            signal: 0.25,
+            histogram: 0.25,
+        };
+        let map = out.to_named_f64();
+
+        let keys: Vec<&str> = map.keys().cloned().collect();
+        let mut names = MovingAverageConvergenceDivergenceOutput::field_names().to_vec();
+        names.sort();
+        assert_eq!(keys, names);
+        assert_eq!(map["macd"], 0.5);
+        assert_eq!(map["histogram"], 0.25);
+
+        let json = serde_json::to_string(&out).unwrap();
+        assert_eq!(json, r#"{"macd":0.5,"signal":0.25,"histogram":0.25}"#);
+        let restored: MovingAverageConvergenceDivergenceOutput =
+            serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, out);
+    }
+
     #[test]
     fn test_reset() {
         let mut macd = Macd::new(3, 6, 4).unwrap();
@@ -190,5 +418,7 @@ mod tests {
     fn test_display() {
         let indicator = Macd::new(13, 30, 10).unwrap();
         assert_eq!(format!("{}", indicator), "MACD(13, 30, 10)");
+        let indicator = Macd::new_with_ma(13, 30, 10, MaType::Sma).unwrap();
+        assert_eq!(format!("{}", indicator), "MACD(SMA(13), SMA(30), SMA(10))");
     }
 }
@@ -1,6 +1,6 @@
-use std::fmt;
+use core::fmt;
 
-use crate::{Close, Next, Reset, Volume};
+use crate::{ClassifiedBar, Close, Next, Reset, Volume};
 use serde::{Deserialize, Serialize};
 
 /// On Balance Volume (OBV).
@@ -24,6 +24,10 @@ use serde::{Deserialize, Serialize};
 ///
 /// obv - on the balance volume
 ///
+/// [next_classified](#method.next_classified) uses the tick rule instead when the input knows
+/// its buy and sell volume (see [ClassifiedBar](../trait.ClassifiedBar.html)):
+/// Current OBV = Previous OBV + Buy Volume - Sell Volume
+///
 /// # Example
 ///
 /// ```
@@ -56,7 +60,6 @@ use serde::{Deserialize, Serialize};
 ///
 /// * [On Balance Volume, Wikipedia](https://en.wikipedia.org/wiki/On-balance_volume)
 /// * [On Balance Volume, stockcharts](https://stockcharts.com/school/doku.php?id=chart_school:technical_indicators:on_balance_volume_obv)
-
 #[doc(alias = "OBV")]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct OnBalanceVolume {
@@ -71,22 +74,34 @@ impl OnBalanceVolume {
             prev_close: 0.0,
         }
     }
-}
 
-impl<T: Close + Volume> Next<&T> for OnBalanceVolume {
-    type Output = f64;
+    /// Like `next`, but adds the buy volume and subtracts the sell volume of `input` when both
+    /// are known (tick rule). Otherwise the closes are compared as usual.
+    pub fn next_classified<T: Close + Volume + ClassifiedBar>(&mut self, input: &T) -> f64 {
+        self.update(input, input.buy_volume().zip(input.sell_volume()))
+    }
 
-    fn next(&mut self, input: &T) -> f64 {
-        if input.close() > self.prev_close {
-            self.obv = self.obv + input.volume();
+    fn update<T: Close + Volume>(&mut self, input: &T, classified: Option<(f64, f64)>) -> f64 {
+        if let Some((buy, sell)) = classified {
+            self.obv += buy - sell;
+        } else if input.close() > self.prev_close {
+            self.obv += input.volume();
         } else if input.close() < self.prev_close {
-            self.obv = self.obv - input.volume();
+            self.obv -= input.volume();
         }
         self.prev_close = input.close();
         self.obv
     }
 }
 
+impl<T: Close + Volume> Next<&T> for OnBalanceVolume {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> f64 {
+        self.update(input, None)
+    }
+}
+
 impl Default for OnBalanceVolume {
     fn default() -> Self {
         Self::new()
@@ -132,6 +147,37 @@ mod tests {
         assert_eq!(obv.next(&bar4), -3000.0);
     }
 
+    #[test]
+    fn test_next_classified() {
+        let mut obv = OnBalanceVolume::new();
+        let mut close_rule = OnBalanceVolume::new();
+
+        let bar1 = Bar::new().close(2).volume(1000.0);
+        let bar2 = Bar::new()
+            .close(3)
+            .volume(5000.0)
+            .classified(2000.0, 3000.0);
+        let bar3 = Bar::new()
+            .close(3)
+            .volume(4000.0)
+            .classified(3000.0, 1000.0);
+
+        assert_eq!(obv.next_classified(&bar1), 1000.0);
+        assert_eq!(close_rule.next(&bar1), 1000.0);
+
+        // the close went up, but sellers dominated
+        assert_eq!(obv.next_classified(&bar2), 0.0);
+        assert_eq!(close_rule.next(&bar2), 6000.0);
+
+        // flat close, buyers dominated
+        assert_eq!(obv.next_classified(&bar3), 2000.0);
+        assert_eq!(close_rule.next(&bar3), 6000.0);
+
+        // back to the close rule, compared to the close of the classified bar
+        let bar4 = Bar::new().close(2).volume(500.0);
+        assert_eq!(obv.next_classified(&bar4), 1500.0);
+    }
+
     #[test]
     fn test_reset() {
         let mut obv = OnBalanceVolume::new();
@@ -0,0 +1,205 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Reset};
+use serde::{Deserialize, Serialize};
+
+/// Percentage trailing stop.
+///
+/// A stop that trails the price by a fixed percentage. In long mode it stays _percent_ below
+/// the highest price since the mode started: it moves up with new highs and never moves down.
+/// When the price falls below it, the stop flips to short mode and trails _percent_ above the
+/// lowest price instead, until the price rises above it again.
+///
+/// It starts in long mode at the first input.
+///
+/// # Formula
+///
+/// Stop (long) = Highest * (1 - _percent_ / 100)
+///
+/// Stop (short) = Lowest * (1 + _percent_ / 100)
+///
+/// Where _Highest_ / _Lowest_ is the extreme price since the last flip.
+///
+/// # Parameters
+///
+/// * _percent_ - distance of the stop from the extreme price, in percent (number in (0, 100)).
+///   Default is 5.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::PercentTrailingStop;
+/// use tam::Next;
+///
+/// let mut stop = PercentTrailingStop::new(10.0).unwrap();
+/// assert_eq!(stop.next(100.0), 90.0);
+/// assert_eq!(stop.next(110.0), 99.0);
+/// assert_eq!(stop.next(105.0), 99.0);
+/// // the stop is hit, now trailing above the price
+/// assert_eq!(stop.next(95.0), 104.5);
+/// assert!(!stop.is_long());
+/// ```
+///
+#[doc(alias = "PCTSTOP")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PercentTrailingStop {
+    percent: f64,
+    is_long: bool,
+    extreme: f64,
+    is_new: bool,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl PercentTrailingStop {
+    pub fn new(percent: f64) -> Result<Self> {
+        if !(percent > 0.0 && percent < 100.0) {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            percent,
+            is_long: true,
+            extreme: 0.0,
+            is_new: true,
+            last_output: None,
+        })
+    }
+
+    /// Whether the stop is below the price (long mode) or above it (short mode).
+    pub fn is_long(&self) -> bool {
+        self.is_long
+    }
+
+    fn stop(&self) -> f64 {
+        let offset = self.extreme * self.percent / 100.0;
+        if self.is_long {
+            self.extreme - offset
+        } else {
+            self.extreme + offset
+        }
+    }
+}
+
+impl PercentTrailingStop {
+    fn next_value(&mut self, input: f64) -> f64 {
+        if self.is_new {
+            self.is_new = false;
+            self.extreme = input;
+        } else if self.is_long {
+            if input < self.stop() {
+                self.is_long = false;
+                self.extreme = input;
+            } else {
+                self.extreme = self.extreme.max(input);
+            }
+        } else if input > self.stop() {
+            self.is_long = true;
+            self.extreme = input;
+        } else {
+            self.extreme = self.extreme.min(input);
+        }
+        self.stop()
+    }
+}
+
+impl_next_option!(PercentTrailingStop);
+
+impl<T: Close> Next<&T> for PercentTrailingStop {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for PercentTrailingStop {
+    fn reset(&mut self) {
+        self.is_long = true;
+        self.extreme = 0.0;
+        self.is_new = true;
+        self.last_output = None;
+    }
+}
+
+impl Default for PercentTrailingStop {
+    fn default() -> Self {
+        Self::new(5.0).unwrap()
+    }
+}
+
+impl fmt::Display for PercentTrailingStop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PCTSTOP({})", self.percent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(PercentTrailingStop);
+
+    #[test]
+    fn test_new() {
+        assert!(PercentTrailingStop::new(0.0).is_err());
+        assert!(PercentTrailingStop::new(-1.0).is_err());
+        assert!(PercentTrailingStop::new(100.0).is_err());
+        assert!(PercentTrailingStop::new(f64::NAN).is_err());
+        assert!(PercentTrailingStop::new(0.5).is_ok());
+    }
+
+    #[test]
+    fn test_ratchet_up() {
+        let mut stop = PercentTrailingStop::new(10.0).unwrap();
+
+        assert_eq!(stop.next(100.0), 90.0);
+        assert_eq!(stop.next(120.0), 108.0);
+        // pullbacks above the stop never lower it
+        assert_eq!(stop.next(110.0), 108.0);
+        assert_eq!(stop.next(&Bar::new().close(108)), 108.0);
+        assert_eq!(stop.next(130.0), 117.0);
+        assert!(stop.is_long());
+    }
+
+    #[test]
+    fn test_flip() {
+        let mut stop = PercentTrailingStop::new(10.0).unwrap();
+        stop.next(100.0);
+        stop.next(120.0);
+
+        // below the stop of 108: short, trailing above the low
+        assert_eq!(round(stop.next(100.0)), 110.0);
+        assert!(!stop.is_long());
+        assert_eq!(round(stop.next(90.0)), 99.0);
+        assert_eq!(round(stop.next(95.0)), 99.0);
+
+        // above the stop of 99: long again
+        assert_eq!(round(stop.next(100.0)), 90.0);
+        assert!(stop.is_long());
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut stop = PercentTrailingStop::new(10.0).unwrap();
+        stop.next(100.0);
+        stop.next(80.0);
+        assert!(!stop.is_long());
+
+        stop.reset();
+        assert!(stop.is_long());
+        assert_eq!(stop.next(50.0), 45.0);
+    }
+
+    #[test]
+    fn test_default() {
+        PercentTrailingStop::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let stop = PercentTrailingStop::new(2.5).unwrap();
+        assert_eq!(format!("{}", stop), "PCTSTOP(2.5)");
+    }
+}
@@ -1,8 +1,9 @@
-use std::fmt;
+use alloc::{vec, vec::Vec};
+use core::fmt;
 
 use crate::errors::Result;
-use crate::indicators::ExponentialMovingAverage as Ema;
-use crate::{Close, Next, Period, Reset};
+use crate::indicators::{MaType, MovingAverage};
+use crate::{Close, Next, Period, Periods, Reset};
 use serde::{Deserialize, Serialize};
 
 /// Percentage Price Oscillator (PPO).
@@ -26,6 +27,11 @@ use serde::{Deserialize, Serialize};
 /// * _fast_period_ - period for the fast EMA. Default is 12.
 /// * _slow_period_ - period for the slow EMA. Default is 26.
 /// * _signal_period_ - period for the signal EMA. Default is 9.
+/// * _ma_type_ - kind of the three moving averages, for
+///   [new_with_ma](#method.new_with_ma) (the _matype_ of TA-Lib's PPO). Default is EMA.
+///
+/// With a moving average having NaN outputs during its warmup (Wilder's), the signal line
+/// starts with the first defined PPO value.
 ///
 /// # Example
 ///
@@ -52,22 +58,36 @@ use serde::{Deserialize, Serialize};
 #[doc(alias = "PPO")]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct PercentagePriceOscillator {
-    fast_ema: Ema,
-    slow_ema: Ema,
-    signal_ema: Ema,
+    fast_ma: MovingAverage,
+    slow_ma: MovingAverage,
+    signal_ma: MovingAverage,
 }
 
 impl PercentagePriceOscillator {
     pub fn new(fast_period: usize, slow_period: usize, signal_period: usize) -> Result<Self> {
-        Ok(PercentagePriceOscillator {
-            fast_ema: Ema::new(fast_period)?,
-            slow_ema: Ema::new(slow_period)?,
-            signal_ema: Ema::new(signal_period)?,
+        Self::new_with_ma(fast_period, slow_period, signal_period, MaType::Ema)
+    }
+
+    /// Uses moving averages of the given type for the three smoothings instead of EMAs.
+    pub fn new_with_ma(
+        fast_period: usize,
+        slow_period: usize,
+        signal_period: usize,
+        ma_type: MaType,
+    ) -> Result<Self> {
+        Ok(Self {
+            fast_ma: MovingAverage::new(ma_type, fast_period)?,
+            slow_ma: MovingAverage::new(ma_type, slow_period)?,
+            signal_ma: MovingAverage::new(ma_type, signal_period)?,
         })
     }
+
+    pub fn ma_type(&self) -> MaType {
+        self.fast_ma.ma_type()
+    }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PercentagePriceOscillatorOutput {
     pub ppo: f64,
     pub signal: f64,
@@ -80,15 +100,32 @@ impl From<PercentagePriceOscillatorOutput> for (f64, f64, f64) {
     }
 }
 
+impl_output!(PercentagePriceOscillatorOutput, ppo, signal, histogram);
+
+impl Periods for PercentagePriceOscillator {
+    fn periods(&self) -> Vec<usize> {
+        vec![
+            self.fast_ma.period(),
+            self.slow_ma.period(),
+            self.signal_ma.period(),
+        ]
+    }
+}
+
 impl Next<f64> for PercentagePriceOscillator {
     type Output = PercentagePriceOscillatorOutput;
 
     fn next(&mut self, input: f64) -> Self::Output {
-        let fast_val = self.fast_ema.next(input);
-        let slow_val = self.slow_ema.next(input);
+        let fast_val = self.fast_ma.next(input);
+        let slow_val = self.slow_ma.next(input);
 
         let ppo = (fast_val - slow_val) / slow_val * 100.0;
-        let signal = self.signal_ema.next(ppo);
+        // a NaN from the warmup of the moving averages would poison the signal line
+        let signal = if ppo.is_nan() {
+            self.signal_ma.next(None)
+        } else {
+            self.signal_ma.next(ppo)
+        };
         let histogram = ppo - signal;
 
         PercentagePriceOscillatorOutput {
@@ -109,9 +146,9 @@ impl<T: Close> Next<&T> for PercentagePriceOscillator {
 
 impl Reset for PercentagePriceOscillator {
     fn reset(&mut self) {
-        self.fast_ema.reset();
-        self.slow_ema.reset();
-        self.signal_ema.reset();
+        self.fast_ma.reset();
+        self.slow_ma.reset();
+        self.signal_ma.reset();
     }
 }
 
@@ -123,19 +160,27 @@ impl Default for PercentagePriceOscillator {
 
 impl fmt::Display for PercentagePriceOscillator {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "PPO({}, {}, {})",
-            self.fast_ema.period(),
-            self.slow_ema.period(),
-            self.signal_ema.period()
-        )
+        match self.ma_type() {
+            MaType::Ema => write!(
+                f,
+                "PPO({}, {}, {})",
+                self.fast_ma.period(),
+                self.slow_ma.period(),
+                self.signal_ma.period()
+            ),
+            _ => write!(
+                f,
+                "PPO({}, {}, {})",
+                self.fast_ma, self.slow_ma, self.signal_ma
+            ),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::SimpleMovingAverage;
     use crate::test_helper::*;
     type Ppo = PercentagePriceOscillator;
 
@@ -168,6 +213,34 @@ mod tests {
         assert_eq!(round(ppo.next(6.5).into()), (17.84, 19.08, -1.24));
     }
 
+    #[test]
+    fn test_new_with_ma() {
+        assert!(Ppo::new_with_ma(3, 0, 4, MaType::Sma).is_err());
+        assert_eq!(Ppo::default().ma_type(), MaType::Ema);
+
+        let prices = [2.0, 3.0, 4.2, 7.0, 6.7, 6.5, 5.0, 5.5, 6.1];
+        let mut default = Ppo::new(3, 6, 4).unwrap();
+        let mut ema = Ppo::new_with_ma(3, 6, 4, MaType::Ema).unwrap();
+        let mut sma = Ppo::new_with_ma(3, 6, 4, MaType::Sma).unwrap();
+        assert_eq!(sma.ma_type(), MaType::Sma);
+
+        let mut fast = SimpleMovingAverage::new(3).unwrap();
+        let mut slow = SimpleMovingAverage::new(6).unwrap();
+        let mut signal = SimpleMovingAverage::new(4).unwrap();
+        for &price in prices.iter() {
+            assert_eq!(ema.next(price), default.next(price));
+
+            let (fast, slow) = (fast.next(price), slow.next(price));
+            let ppo = (fast - slow) / slow * 100.0;
+            let signal = signal.next(ppo);
+            let out = sma.next(price);
+            assert_eq!(out.ppo, ppo);
+            assert_eq!(out.signal, signal);
+            assert_eq!(out.histogram, ppo - signal);
+        }
+        assert_ne!(sma.next(7.0), default.next(7.0));
+    }
+
     #[test]
     fn test_reset() {
         let mut ppo = Ppo::new(3, 6, 4).unwrap();
@@ -190,5 +263,7 @@ mod tests {
     fn test_display() {
         let indicator = Ppo::new(13, 30, 10).unwrap();
         assert_eq!(format!("{}", indicator), "PPO(13, 30, 10)");
+        let indicator = Ppo::new_with_ma(13, 30, 10, MaType::Wma).unwrap();
+        assert_eq!(format!("{}", indicator), "PPO(WMA(13), WMA(30), WMA(10))");
     }
 }
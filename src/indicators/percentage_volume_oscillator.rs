@@ -0,0 +1,235 @@
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Next, Period, Periods, Reset, Volume};
+use serde::{Deserialize, Serialize};
+
+/// Percentage Volume Oscillator (PVO).
+///
+/// The [PPO](struct.PercentagePriceOscillator.html) of the volume: the difference between a
+/// fast and a slow EMA of the volume, as a percentage of the slow one. It is positive when the
+/// volume is above its longer average, i.e. when the activity is increasing, and the signal
+/// line and histogram are read like those of the MACD.
+///
+/// # Formula
+///
+/// PVO = 100 * (EMA(volume, _fast_period_) - EMA(volume, _slow_period_)) / EMA(volume, _slow_period_)
+///
+/// Signal = EMA(PVO, _signal_period_)
+///
+/// Histogram = PVO - Signal
+///
+/// The PVO is 0 while the slow EMA is 0 (no volume yet).
+///
+/// # Parameters
+///
+/// * _fast_period_ - period for the fast EMA (integer greater than 0). Default is 12.
+/// * _slow_period_ - period for the slow EMA (integer greater than _fast_period_). Default
+///   is 26.
+/// * _signal_period_ - period for the signal EMA (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::PercentageVolumeOscillator as Pvo;
+/// use tam::{DataItem, Next};
+///
+/// let mut pvo = Pvo::new(2, 3, 2).unwrap();
+/// let bar = |volume: f64| {
+///     DataItem::builder()
+///         .open(10.0).high(10.0).low(10.0).close(10.0).volume(volume)
+///         .build().unwrap()
+/// };
+///
+/// assert_eq!(pvo.next(&bar(1000.0)).pvo, 0.0);
+/// // fast EMA 1500, slow EMA 1375
+/// assert_eq!(pvo.next(&bar(1750.0)).pvo.round(), 9.0);
+/// ```
+///
+/// # Links
+///
+/// * [Percentage Volume Oscillator, StockCharts](https://chartschool.stockcharts.com/table-of-contents/technical-indicators-and-overlays/technical-indicators/percentage-volume-oscillator-pvo)
+///
+#[doc(alias = "PVO")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PercentageVolumeOscillator {
+    fast_ema: Ema,
+    slow_ema: Ema,
+    signal_ema: Ema,
+}
+
+impl PercentageVolumeOscillator {
+    pub fn new(fast_period: usize, slow_period: usize, signal_period: usize) -> Result<Self> {
+        if fast_period >= slow_period {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            fast_ema: Ema::new(fast_period)?,
+            slow_ema: Ema::new(slow_period)?,
+            signal_ema: Ema::new(signal_period)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PvoOutput {
+    pub pvo: f64,
+    pub signal: f64,
+    pub histogram: f64,
+}
+
+impl From<PvoOutput> for (f64, f64, f64) {
+    fn from(out: PvoOutput) -> Self {
+        (out.pvo, out.signal, out.histogram)
+    }
+}
+
+impl_output!(PvoOutput, pvo, signal, histogram);
+
+impl Periods for PercentageVolumeOscillator {
+    fn periods(&self) -> Vec<usize> {
+        vec![
+            self.fast_ema.period(),
+            self.slow_ema.period(),
+            self.signal_ema.period(),
+        ]
+    }
+}
+
+impl<T: Volume> Next<&T> for PercentageVolumeOscillator {
+    type Output = PvoOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let volume = input.volume();
+        let fast = self.fast_ema.next(volume);
+        let slow = self.slow_ema.next(volume);
+
+        let pvo = if slow == 0.0 {
+            0.0
+        } else {
+            100.0 * (fast - slow) / slow
+        };
+        let signal = self.signal_ema.next(pvo);
+
+        PvoOutput {
+            pvo,
+            signal,
+            histogram: pvo - signal,
+        }
+    }
+}
+
+impl Reset for PercentageVolumeOscillator {
+    fn reset(&mut self) {
+        self.fast_ema.reset();
+        self.slow_ema.reset();
+        self.signal_ema.reset();
+    }
+}
+
+impl Default for PercentageVolumeOscillator {
+    fn default() -> Self {
+        Self::new(12, 26, 9).unwrap()
+    }
+}
+
+impl fmt::Display for PercentageVolumeOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "PVO({}, {}, {})",
+            self.fast_ema.period(),
+            self.slow_ema.period(),
+            self.signal_ema.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    type Pvo = PercentageVolumeOscillator;
+
+    fn round3(nums: (f64, f64, f64)) -> (f64, f64, f64) {
+        (round(nums.0), round(nums.1), round(nums.2))
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Pvo::new(0, 2, 1).is_err());
+        assert!(Pvo::new(1, 2, 0).is_err());
+        assert!(Pvo::new(3, 3, 1).is_err());
+        assert!(Pvo::new(4, 3, 1).is_err());
+        assert!(Pvo::new(1, 2, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut pvo = Pvo::new(2, 3, 2).unwrap();
+
+        // fast EMA 100, 166.667, 88.889 and slow EMA 100, 150, 100
+        assert_eq!(
+            round3(pvo.next(&Bar::new().volume(100.0)).into()),
+            (0.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            round3(pvo.next(&Bar::new().volume(200.0)).into()),
+            (11.111, 7.407, 3.704)
+        );
+        assert_eq!(
+            round3(pvo.next(&Bar::new().volume(50.0)).into()),
+            (-11.111, -4.938, -6.173)
+        );
+    }
+
+    #[test]
+    fn test_no_volume() {
+        let mut pvo = Pvo::new(2, 3, 2).unwrap();
+        assert_eq!(
+            round3(pvo.next(&Bar::new().volume(0.0)).into()),
+            (0.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            round3(pvo.next(&Bar::new().volume(0.0)).into()),
+            (0.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut pvo = Pvo::new(2, 3, 2).unwrap();
+        pvo.next(&Bar::new().volume(100.0));
+        pvo.next(&Bar::new().volume(300.0));
+
+        pvo.reset();
+        pvo.next(&Bar::new().volume(100.0));
+        assert_eq!(
+            round3(pvo.next(&Bar::new().volume(200.0)).into()),
+            (11.111, 7.407, 3.704)
+        );
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut pvo = Pvo::default();
+        pvo.next(&Bar::new().volume(100.0));
+        let mut restored = serde_round_trip(&pvo);
+        assert_eq!(
+            round3(restored.next(&Bar::new().volume(250.0)).into()),
+            round3(pvo.next(&Bar::new().volume(250.0)).into())
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        Pvo::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Pvo::default()), "PVO(12, 26, 9)");
+    }
+}
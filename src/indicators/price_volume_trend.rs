@@ -0,0 +1,168 @@
+use core::fmt;
+
+use crate::{Close, Next, Reset, Volume};
+use serde::{Deserialize, Serialize};
+
+/// Price Volume Trend (PVT).
+///
+/// A cumulative volume indicator similar to [OBV](struct.OnBalanceVolume.html), but instead of
+/// adding or subtracting the whole volume it adds the volume weighted by the percentage change
+/// of the close price.
+///
+/// # Formula
+///
+/// PVT<sub>t</sub> = PVT<sub>t-1</sub> + Volume<sub>t</sub> * (Close<sub>t</sub> - Close<sub>t-1</sub>) / Close<sub>t-1</sub>
+///
+/// The first bar has no prior close and yields 0. A bar whose prior close is 0 leaves the PVT
+/// unchanged.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::PriceVolumeTrend;
+/// use tam::{Next, DataItem};
+///
+/// let mut pvt = PriceVolumeTrend::new();
+///
+/// let di1 = DataItem::builder()
+///             .high(3.0)
+///             .low(1.0)
+///             .close(2.0)
+///             .open(1.5)
+///             .volume(1000.0)
+///             .build().unwrap();
+///
+/// let di2 = DataItem::builder()
+///             .high(3.0)
+///             .low(1.0)
+///             .close(2.5)
+///             .open(1.5)
+///             .volume(300.0)
+///             .build().unwrap();
+///
+/// assert_eq!(pvt.next(&di1), 0.0);
+/// assert_eq!(pvt.next(&di2), 75.0);
+/// ```
+///
+/// # Links
+///
+/// * [Price Volume Trend, Investopedia](https://www.investopedia.com/terms/v/vptindicator.asp)
+#[doc(alias = "PVT")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PriceVolumeTrend {
+    pvt: f64,
+    prev_close: Option<f64>,
+}
+
+impl PriceVolumeTrend {
+    pub fn new() -> Self {
+        Self {
+            pvt: 0.0,
+            prev_close: None,
+        }
+    }
+}
+
+impl<T: Close + Volume> Next<&T> for PriceVolumeTrend {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> f64 {
+        if let Some(prev_close) = self.prev_close {
+            if prev_close != 0.0 {
+                self.pvt += input.volume() * (input.close() - prev_close) / prev_close;
+            }
+        }
+        self.prev_close = Some(input.close());
+        self.pvt
+    }
+}
+
+impl Default for PriceVolumeTrend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for PriceVolumeTrend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PVT")
+    }
+}
+
+impl Reset for PriceVolumeTrend {
+    fn reset(&mut self) {
+        self.pvt = 0.0;
+        self.prev_close = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next_bar() {
+        let mut pvt = PriceVolumeTrend::new();
+
+        let bar1 = Bar::new().close(10).volume(1000.0);
+        let bar2 = Bar::new().close(11).volume(2000.0);
+        let bar3 = Bar::new().close(9.9).volume(5000.0);
+
+        // no prior close
+        assert_eq!(pvt.next(&bar1), 0.0);
+
+        // +10%
+        assert_eq!(round(pvt.next(&bar2)), 200.0);
+
+        // -10%
+        assert_eq!(round(pvt.next(&bar3)), -300.0);
+    }
+
+    #[test]
+    fn test_flat_price() {
+        let mut pvt = PriceVolumeTrend::new();
+
+        pvt.next(&Bar::new().close(10).volume(1000.0));
+        let value = pvt.next(&Bar::new().close(12).volume(1000.0));
+
+        assert_eq!(pvt.next(&Bar::new().close(12).volume(1.0)), value);
+        assert_eq!(pvt.next(&Bar::new().close(12).volume(1e9)), value);
+    }
+
+    #[test]
+    fn test_zero_prev_close() {
+        let mut pvt = PriceVolumeTrend::new();
+
+        pvt.next(&Bar::new().close(0).volume(1000.0));
+        assert_eq!(pvt.next(&Bar::new().close(5).volume(1000.0)), 0.0);
+        assert_eq!(pvt.next(&Bar::new().close(10).volume(1000.0)), 1000.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut pvt = PriceVolumeTrend::new();
+
+        let bar1 = Bar::new().close(2).volume(1000.0);
+        let bar2 = Bar::new().close(4).volume(2000.0);
+
+        assert_eq!(pvt.next(&bar1), 0.0);
+        assert_eq!(pvt.next(&bar2), 2000.0);
+
+        pvt.reset();
+
+        assert_eq!(pvt.next(&bar1), 0.0);
+        assert_eq!(pvt.next(&bar2), 2000.0);
+    }
+
+    #[test]
+    fn test_default() {
+        PriceVolumeTrend::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let pvt = PriceVolumeTrend::new();
+        assert_eq!(format!("{}", pvt), "PVT");
+    }
+}
@@ -0,0 +1,172 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{Close, Next, Open, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// QStick indicator.
+///
+/// Tushar Chande's measure of the candlestick bodies: the average of the close minus the open
+/// over the last _period_ bars. Positive values show mostly bullish (white) candles, negative
+/// values mostly bearish (black) ones.
+///
+/// # Formula
+///
+/// QStick = SMA(Close - Open, _period_)
+///
+/// Like the [SMA](struct.SimpleMovingAverage.html), the first _period_ - 1 outputs average the
+/// bars seen so far.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars (integer greater than 0). Default is 8.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::QStick;
+/// use tam::{DataItem, Next};
+///
+/// let candle = |open, close| {
+///     DataItem::builder()
+///         .open(open)
+///         .high(open.max(close))
+///         .low(open.min(close))
+///         .close(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut qstick = QStick::new(2).unwrap();
+/// assert_eq!(qstick.next(&candle(10.0, 12.0)), 2.0);
+/// assert_eq!(qstick.next(&candle(12.0, 11.0)), 0.5);
+/// assert_eq!(qstick.next(&candle(11.0, 8.0)), -2.0);
+/// ```
+///
+/// # Links
+///
+/// * [QStick, Investopedia](https://www.investopedia.com/terms/q/qstick.asp)
+///
+#[doc(alias = "QSTICK")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct QStick {
+    sma: Sma,
+}
+
+impl QStick {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            sma: Sma::new(period)?,
+        })
+    }
+}
+
+impl Period for QStick {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl Warmup for QStick {
+    fn lookback(&self) -> usize {
+        self.sma.lookback()
+    }
+}
+
+impl<T: Open + Close> Next<&T> for QStick {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.sma.next(input.close() - input.open())
+    }
+}
+
+impl Reset for QStick {
+    fn reset(&mut self) {
+        self.sma.reset();
+    }
+}
+
+impl Default for QStick {
+    fn default() -> Self {
+        Self::new(8).unwrap()
+    }
+}
+
+impl fmt::Display for QStick {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "QSTICK({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(QStick::new(0).is_err());
+        assert!(QStick::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut qstick = QStick::new(3).unwrap();
+        assert_eq!(qstick.lookback(), 2);
+
+        // bodies +2, -1, +2, -4, -3, +0.5
+        let outputs: Vec<f64> = [
+            Bar::new().open(10.0).close(12.0),
+            Bar::new().open(12.0).close(11.0),
+            Bar::new().open(11.0).close(13.0),
+            Bar::new().open(13.0).close(9.0),
+            Bar::new().open(9.0).close(6.0),
+            Bar::new().open(6.0).close(6.5),
+        ]
+        .iter()
+        .map(|c| round(qstick.next(c)))
+        .collect();
+        assert_eq!(outputs, vec![2.0, 0.5, 1.0, -1.0, -1.667, -2.167]);
+    }
+
+    #[test]
+    fn test_doji() {
+        let mut qstick = QStick::new(2).unwrap();
+        qstick.next(&Bar::new().open(10.0).close(12.0));
+        qstick.next(&Bar::new().open(12.0).close(12.0));
+        assert_eq!(qstick.next(&Bar::new().open(12.0).close(12.0)), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut qstick = QStick::new(2).unwrap();
+        qstick.next(&Bar::new().open(10.0).close(12.0));
+        qstick.next(&Bar::new().open(12.0).close(11.0));
+
+        qstick.reset();
+        assert_eq!(qstick.next(&Bar::new().open(11.0).close(8.0)), -3.0);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut qstick = QStick::new(3).unwrap();
+        qstick.next(&Bar::new().open(10.0).close(12.0));
+        qstick.next(&Bar::new().open(12.0).close(11.0));
+        let mut restored = serde_round_trip(&qstick);
+        let c = Bar::new().open(11.0).close(8.5);
+        assert_eq!(restored.next(&c), qstick.next(&c));
+    }
+
+    #[test]
+    fn test_default() {
+        QStick::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", QStick::default()), "QSTICK(8)");
+    }
+}
@@ -0,0 +1,300 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{Close, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Rainbow Moving Average.
+///
+/// Mel Widner's fan of recursively smoothed averages: the first band is an SMA of the price,
+/// every following band an SMA of the previous band. Each band is smoother and lags more than
+/// the one before, so like a [ribbon](struct.MaRibbon.html) the bands fan out in a trend and
+/// collapse onto the price when it goes sideways.
+///
+/// # Formula
+///
+/// band<sub>1</sub> = SMA(price, _period_)
+///
+/// band<sub>i</sub> = SMA(band<sub>i-1</sub>, _period_)
+///
+/// spread = (max(bands) - min(bands)) / price
+///
+/// A band is NaN until its window holds _period_ defined values of the previous band, so band
+/// _i_ starts after _i_ * (_period_ - 1) inputs. The spread is NaN until all bands are
+/// defined, and 0 for a zero price.
+///
+/// # Parameters
+///
+/// * _period_ - period of every SMA (integer greater than 0). Default is 2.
+/// * _count_ - number of bands (integer greater than 0). Default is 10.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::Rainbow;
+/// use tam::Next;
+///
+/// let mut rainbow = Rainbow::new(2, 3).unwrap();
+/// rainbow.next(10.0);
+/// rainbow.next(12.0);
+/// rainbow.next(14.0);
+///
+/// let out = rainbow.next(16.0);
+/// // SMA(2) of 14 and 16, of 13 and 15, of 12 and 14
+/// assert_eq!(out.values, vec![15.0, 14.0, 13.0]);
+/// assert_eq!(out.spread, 0.125);
+/// ```
+///
+/// # Links
+///
+/// * [Rainbow Moving Average, Metastock](https://www.metastock.com/customer/resources/taaz/?p=97)
+///
+#[doc(alias = "RAINBOW")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Rainbow {
+    period: usize,
+    smas: Vec<Sma>,
+    count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RainbowOutput {
+    /// The bands, from the least to the most smoothed.
+    pub values: Vec<f64>,
+    pub spread: f64,
+}
+
+impl_approx_eq!(RainbowOutput, values, spread);
+
+impl Rainbow {
+    pub fn new(period: usize, count: usize) -> Result<Self> {
+        if count == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+        let smas = (0..count)
+            .map(|_| Sma::new(period))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            period,
+            smas,
+            count: 0,
+        })
+    }
+
+    /// Number of bands.
+    pub fn bands(&self) -> usize {
+        self.smas.len()
+    }
+}
+
+impl Period for Rainbow {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Warmup for Rainbow {
+    fn lookback(&self) -> usize {
+        self.bands() * (self.period - 1)
+    }
+}
+
+impl Next<f64> for Rainbow {
+    type Output = RainbowOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        if self.count <= self.lookback() {
+            self.count += 1;
+        }
+
+        let (period, count) = (self.period, self.count);
+        let mut value = input;
+        let values: Vec<f64> = self
+            .smas
+            .iter_mut()
+            .enumerate()
+            .map(|(i, sma)| {
+                // only the full windows of the previous band are fed to the next one
+                if !value.is_nan() {
+                    let band = sma.next(value);
+                    value = if count > (i + 1) * (period - 1) {
+                        band
+                    } else {
+                        f64::NAN
+                    };
+                }
+                value
+            })
+            .collect();
+
+        let spread = if value.is_nan() {
+            f64::NAN
+        } else if input == 0.0 {
+            0.0
+        } else {
+            let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+            (max - min) / input
+        };
+
+        RainbowOutput { values, spread }
+    }
+}
+
+impl<T: Close> Next<&T> for Rainbow {
+    type Output = RainbowOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for Rainbow {
+    fn reset(&mut self) {
+        for sma in self.smas.iter_mut() {
+            sma.reset();
+        }
+        self.count = 0;
+    }
+}
+
+impl Default for Rainbow {
+    fn default() -> Self {
+        Self::new(2, 10).unwrap()
+    }
+}
+
+impl fmt::Display for Rainbow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RAINBOW({}, {})", self.period, self.bands())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn prices(n: usize) -> Vec<f64> {
+        (0..n)
+            .map(|i| 100.0 + 5.0 * (i as f64 * 1.3).sin() + 2.0 * (i as f64 * 0.7).cos())
+            .collect()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Rainbow::new(0, 3).is_err());
+        assert!(Rainbow::new(2, 0).is_err());
+        assert!(Rainbow::new(1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_warmup() {
+        let mut rainbow = Rainbow::new(3, 4).unwrap();
+        assert_eq!(rainbow.lookback(), 8);
+
+        let outputs: Vec<RainbowOutput> = prices(12)
+            .iter()
+            .map(|&p| rainbow.next(&Bar::new().close(p)))
+            .collect();
+        for band in 0..4 {
+            let values: Vec<f64> = outputs.iter().map(|out| out.values[band]).collect();
+            assert_eq!(warmup_nan_count(&values), (band + 1) * 2);
+            assert!(values[(band + 1) * 2..].iter().all(|v| v.is_finite()));
+        }
+        let spreads: Vec<f64> = outputs.iter().map(|out| out.spread).collect();
+        assert_eq!(warmup_nan_count(&spreads), 8);
+    }
+
+    #[test]
+    fn test_cascade() {
+        // every band is the SMA of the full windows of the previous one
+        let mut rainbow = Rainbow::new(3, 3).unwrap();
+        let mut smas = [
+            Sma::new(3).unwrap(),
+            Sma::new(3).unwrap(),
+            Sma::new(3).unwrap(),
+        ];
+        for (t, p) in prices(20).into_iter().enumerate() {
+            let out = rainbow.next(p);
+            let mut value = p;
+            for (i, sma) in smas.iter_mut().enumerate() {
+                if t < i * 2 {
+                    break;
+                }
+                value = sma.next(value);
+                if t >= (i + 1) * 2 {
+                    assert_eq!(out.values[i], value);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_smoother() {
+        let mut rainbow = Rainbow::new(2, 10).unwrap();
+        let outputs: Vec<RainbowOutput> = prices(100).iter().map(|&p| rainbow.next(p)).collect();
+
+        // total variation of every band once all of them are defined
+        let variation = |band: usize| {
+            outputs[rainbow.lookback()..]
+                .windows(2)
+                .map(|w| (w[1].values[band] - w[0].values[band]).abs())
+                .sum::<f64>()
+        };
+        for band in 1..rainbow.bands() {
+            assert!(variation(band) < variation(band - 1));
+        }
+    }
+
+    #[test]
+    fn test_spread() {
+        let mut rainbow = Rainbow::new(2, 4).unwrap();
+
+        let mut spread = 0.0;
+        for _ in 0..10 {
+            spread = rainbow.next(100.0).spread;
+        }
+        assert_eq!(spread, 0.0);
+
+        // a trend fans the bands out
+        for i in 1..=10 {
+            spread = rainbow.next(100.0 + 2.0 * i as f64).spread;
+        }
+        assert_eq!(round(spread), 0.025);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut rainbow = Rainbow::new(2, 2).unwrap();
+        let first: Vec<RainbowOutput> = prices(5).iter().map(|&p| rainbow.next(p)).collect();
+
+        rainbow.reset();
+        let second: Vec<RainbowOutput> = prices(5).iter().map(|&p| rainbow.next(p)).collect();
+        assert_eq!(first[2..], second[2..]);
+        assert!(second[0].spread.is_nan());
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut rainbow = Rainbow::new(2, 3).unwrap();
+        for p in [10.0, 12.0, 14.0, 16.0] {
+            rainbow.next(p);
+        }
+        let mut restored = serde_round_trip(&rainbow);
+        assert_eq!(restored.next(15.0), rainbow.next(15.0));
+    }
+
+    #[test]
+    fn test_default() {
+        let rainbow = Rainbow::default();
+        assert_eq!(rainbow.bands(), 10);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Rainbow::default()), "RAINBOW(2, 10)");
+    }
+}
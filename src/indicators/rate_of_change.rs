@@ -1,4 +1,5 @@
-use std::fmt;
+use alloc::{boxed::Box, vec};
+use core::fmt;
 
 use crate::errors::{Result, TaError};
 use crate::traits::{Close, Next, Period, Reset};
@@ -44,6 +45,8 @@ pub struct RateOfChange {
     index: usize,
     count: usize,
     deque: Box<[f64]>,
+    #[serde(default)]
+    last_output: Option<f64>,
 }
 
 impl RateOfChange {
@@ -55,6 +58,7 @@ impl RateOfChange {
                 index: 0,
                 count: 0,
                 deque: vec![0.0; period].into_boxed_slice(),
+                last_output: None,
             }),
         }
     }
@@ -66,10 +70,8 @@ impl Period for RateOfChange {
     }
 }
 
-impl Next<f64> for RateOfChange {
-    type Output = f64;
-
-    fn next(&mut self, input: f64) -> f64 {
+impl RateOfChange {
+    fn next_value(&mut self, input: f64) -> f64 {
         let previous = if self.count > self.period {
             self.deque[self.index]
         } else {
@@ -92,6 +94,8 @@ impl Next<f64> for RateOfChange {
     }
 }
 
+impl_next_option!(RateOfChange);
+
 impl<T: Close> Next<&T> for RateOfChange {
     type Output = f64;
 
@@ -119,6 +123,7 @@ impl Reset for RateOfChange {
         for i in 0..self.period {
             self.deque[i] = 0.0;
         }
+        self.last_output = None;
     }
 }
 
@@ -0,0 +1,271 @@
+use alloc::{boxed::Box, vec};
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Relative Momentum Index (RMI).
+///
+/// Developed by Roger Altman, a generalization of the
+/// [RSI](struct.RelativeStrengthIndex.html): the gains and losses are measured against the
+/// price _momentum_ bars ago instead of the previous price, which smooths out the short
+/// swings. With a _momentum_ of 1 it is exactly the RSI.
+///
+/// # Formula
+///
+/// Change = p<sub>t</sub> - p<sub>t-momentum</sub>
+///
+/// Gain = max(Change, 0), Loss = max(-Change, 0)
+///
+/// RMI = 100 - 100 / (1 + AvgGain / AvgLoss)
+///
+/// The averages start as the simple average of the first _period_ gains and losses, then use
+/// Wilder's smoothing:
+/// * AvgGain = (PreviousAvgGain * (_period_ - 1) + Gain) / _period_
+/// * AvgLoss = (PreviousAvgLoss * (_period_ - 1) + Loss) / _period_
+///
+/// The output is NaN until _period_ changes are known (_period_ + _momentum_ inputs). It is
+/// 100 when there are only gains and 50 when the price did not change at all.
+///
+/// # Parameters
+///
+/// * _period_ - number of changes averaged (integer greater than 0). Default is 14.
+/// * _momentum_ - distance in bars of the compared prices (integer greater than 0). Default
+///   is 5.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::RelativeMomentumIndex;
+/// use tam::Next;
+///
+/// let mut rmi = RelativeMomentumIndex::new(2, 2).unwrap();
+/// assert!(rmi.next(10.0).is_nan());
+/// assert!(rmi.next(11.0).is_nan());
+/// assert!(rmi.next(13.0).is_nan()); // +3
+/// assert_eq!(rmi.next(10.0), 75.0); // -1
+/// assert_eq!(rmi.next(11.0), 37.5); // -2
+/// ```
+///
+/// # Links
+///
+/// * [Relative Momentum Index, Investopedia](https://www.investopedia.com/terms/r/relative-momentum-index-rmi.asp)
+///
+#[doc(alias = "RMI")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RelativeMomentumIndex {
+    period: usize,
+    momentum: usize,
+    index: usize,
+    count: usize,
+    changes: usize,
+    avg_gain: f64,
+    avg_loss: f64,
+    prices: Box<[f64]>,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl RelativeMomentumIndex {
+    pub fn new(period: usize, momentum: usize) -> Result<Self> {
+        if period == 0 || momentum == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            momentum,
+            index: 0,
+            count: 0,
+            changes: 0,
+            avg_gain: 0.0,
+            avg_loss: 0.0,
+            prices: vec![0.0; momentum].into_boxed_slice(),
+            last_output: None,
+        })
+    }
+}
+
+impl Period for RelativeMomentumIndex {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Warmup for RelativeMomentumIndex {
+    fn lookback(&self) -> usize {
+        self.period + self.momentum - 1
+    }
+}
+
+impl RelativeMomentumIndex {
+    fn next_value(&mut self, input: f64) -> f64 {
+        // the oldest stored price is the one _momentum_ bars ago
+        let past = self.prices[self.index];
+        self.prices[self.index] = input;
+        self.index = if self.index + 1 < self.momentum {
+            self.index + 1
+        } else {
+            0
+        };
+        if self.count < self.momentum {
+            self.count += 1;
+            return f64::NAN;
+        }
+
+        let change = input - past;
+        let (gain, loss) = if change >= 0.0 {
+            (change, 0.0)
+        } else {
+            (0.0, -change)
+        };
+
+        let period = self.period as f64;
+        if self.changes < self.period {
+            self.changes += 1;
+            self.avg_gain += gain;
+            self.avg_loss += loss;
+            if self.changes < self.period {
+                return f64::NAN;
+            }
+            self.avg_gain /= period;
+            self.avg_loss /= period;
+        } else {
+            self.avg_gain = (self.avg_gain * (period - 1.0) + gain) / period;
+            self.avg_loss = (self.avg_loss * (period - 1.0) + loss) / period;
+        }
+
+        if self.avg_loss == 0.0 {
+            return if self.avg_gain == 0.0 { 50.0 } else { 100.0 };
+        }
+        let rs = self.avg_gain / self.avg_loss;
+        100.0 - (100.0 / (1.0 + rs))
+    }
+}
+
+impl_next_option!(RelativeMomentumIndex);
+
+impl<T: Close> Next<&T> for RelativeMomentumIndex {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for RelativeMomentumIndex {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.changes = 0;
+        self.avg_gain = 0.0;
+        self.avg_loss = 0.0;
+        for i in 0..self.momentum {
+            self.prices[i] = 0.0;
+        }
+        self.last_output = None;
+    }
+}
+
+impl Default for RelativeMomentumIndex {
+    fn default() -> Self {
+        Self::new(14, 5).unwrap()
+    }
+}
+
+impl fmt::Display for RelativeMomentumIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RMI({}, {})", self.period, self.momentum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::RelativeStrengthIndex;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(RelativeMomentumIndex::new(0, 5).is_err());
+        assert!(RelativeMomentumIndex::new(14, 0).is_err());
+        assert!(RelativeMomentumIndex::new(1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_momentum_one_is_rsi() {
+        // the TA-Lib fixture of the RSI tests
+        let mut rmi = RelativeMomentumIndex::new(3, 1).unwrap();
+        for &price in [10.0, 10.5, 10.0].iter() {
+            assert!(rmi.next(price).is_nan());
+        }
+        assert_eq!(round(rmi.next(9.5)), 33.333);
+        assert_eq!(round(rmi.next(9.0)), 22.222);
+        assert_eq!(round(rmi.next(10.0)), 61.111);
+        assert_eq!(round(rmi.next(10.5)), 71.717);
+        assert_eq!(round(rmi.next(17.2)), 95.637);
+
+        let mut rmi = RelativeMomentumIndex::new(5, 1).unwrap();
+        let mut rsi = RelativeStrengthIndex::new(5).unwrap();
+        for i in 0..50 {
+            let price = 100.0 + (i as f64 * 0.7).sin() * 10.0 + i as f64 * 0.1;
+            let (a, b) = (rmi.next(price), rsi.next(price));
+            assert!(a == b || (a.is_nan() && b.is_nan()), "{} != {}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_next() {
+        let mut rmi = RelativeMomentumIndex::new(2, 2).unwrap();
+
+        assert!(rmi.next(10.0).is_nan());
+        assert!(rmi.next(11.0).is_nan());
+        assert!(rmi.next(13.0).is_nan()); // +3
+        assert_eq!(rmi.next(10.0), 75.0); // -1: RS = 1.5 / 0.5
+        assert_eq!(rmi.next(&Bar::new().close(11)), 37.5); // -2: RS = 0.75 / 1.25
+        assert_eq!(round(rmi.next(14.0)), 79.167); // +4: RS = 2.375 / 0.625
+    }
+
+    #[test]
+    fn test_warmup() {
+        let mut rmi = RelativeMomentumIndex::new(4, 3).unwrap();
+        assert_eq!(rmi.lookback(), 6);
+        for i in 0..6 {
+            assert!(rmi.next(i as f64).is_nan());
+        }
+        assert_eq!(rmi.next(6.0), 100.0);
+    }
+
+    #[test]
+    fn test_flat() {
+        let mut rmi = RelativeMomentumIndex::new(2, 3).unwrap();
+        for _ in 0..4 {
+            rmi.next(7.0);
+        }
+        assert_eq!(rmi.next(7.0), 50.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut rmi = RelativeMomentumIndex::new(1, 2).unwrap();
+        rmi.next(10.0);
+        rmi.next(11.0);
+        rmi.next(12.0);
+
+        rmi.reset();
+        assert!(rmi.next(5.0).is_nan());
+        assert!(rmi.next(6.0).is_nan());
+        assert_eq!(rmi.next(4.0), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        RelativeMomentumIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let rmi = RelativeMomentumIndex::new(20, 5).unwrap();
+        assert_eq!(format!("{}", rmi), "RMI(20, 5)");
+    }
+}
@@ -1,8 +1,10 @@
-use std::fmt;
-use std::collections::VecDeque;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::fmt;
 
 use crate::errors::Result;
-use crate::{Close, Next, Period, Reset};
+use crate::helpers::{batch, fmt_current, round_decimals, warmup_tail};
+use crate::{Close, Current, Next, Period, Reset, Warmup, WarmupPolicy};
 use serde::{Deserialize, Serialize};
 
 /// The relative strength index (RSI).
@@ -49,6 +51,19 @@ use serde::{Deserialize, Serialize};
 ///
 /// * _period_ - number of periods (integer greater than 0). Default value is 14.
 ///
+/// # Flat window
+///
+/// When the price did not move at all, both averages are 0 and RS is undefined. This RSI
+/// outputs 50 by default, but libraries disagree: TA-Lib outputs 0, implementations that
+/// divide the averages get NaN and others treat the missing losses as an RSI of 100. Use
+/// [flat_value](#method.flat_value) to choose, see [FlatRsi](enum.FlatRsi.html).
+///
+/// # Precision
+///
+/// [with_decimals](#method.with_decimals) rounds the outputs to a number of decimal places,
+/// e.g. to compare them with TA-Lib values printed with two decimals. Only the outputs are
+/// rounded, the averages keep their full precision.
+///
 /// # Example
 ///
 /// ```
@@ -76,6 +91,39 @@ pub struct RelativeStrengthIndex {
     price_changes: VecDeque<(f64, f64)>,
     avg_gain: f64,
     avg_loss: f64,
+    #[serde(default)]
+    flat: FlatRsi,
+    #[serde(default)]
+    last_output: Option<f64>,
+    #[serde(default)]
+    warmup_policy: WarmupPolicy,
+    #[serde(default)]
+    decimals: Option<u32>,
+}
+
+/// Output of an [RSI](struct.RelativeStrengthIndex.html) over a window without any price
+/// change.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+pub enum FlatRsi {
+    /// 50, halfway between gains and losses.
+    #[default]
+    Fifty,
+    /// 100, as if there were only gains.
+    Hundred,
+    /// NaN, RS = 0 / 0.
+    Nan,
+}
+
+/// Configuration of an [RSI](struct.RelativeStrengthIndex.html), without its state.
+///
+/// Unlike the indicator it is `Eq` and `Hash`, e.g. to key a cache of instances.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct RsiConfig {
+    pub period: usize,
+    pub flat: FlatRsi,
+    pub warmup_policy: WarmupPolicy,
+    #[serde(default)]
+    pub decimals: Option<u32>,
 }
 
 impl RelativeStrengthIndex {
@@ -83,7 +131,7 @@ impl RelativeStrengthIndex {
         if period == 0 {
             return Err(crate::errors::TaError::InvalidParameter);
         }
-        
+
         Ok(Self {
             period,
             prev_val: 0.0,
@@ -91,8 +139,85 @@ impl RelativeStrengthIndex {
             price_changes: VecDeque::with_capacity(period),
             avg_gain: 0.0,
             avg_loss: 0.0,
+            flat: FlatRsi::default(),
+            last_output: None,
+            warmup_policy: WarmupPolicy::default(),
+            decimals: None,
         })
     }
+
+    /// Changes the output over a flat window, see [Flat window](#flat-window).
+    pub fn flat_value(mut self, flat: FlatRsi) -> Self {
+        self.flat = flat;
+        self
+    }
+
+    /// Changes the output during the warmup, NaN by default.
+    pub fn with_warmup_policy(mut self, policy: WarmupPolicy) -> Self {
+        self.warmup_policy = policy;
+        self
+    }
+
+    /// Rounds the outputs to `decimals` decimal places, see [Precision](#precision).
+    pub fn with_decimals(mut self, decimals: u32) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+
+    /// Decimals of the outputs, `None` for full precision.
+    pub fn decimals(&self) -> Option<u32> {
+        self.decimals
+    }
+
+    /// The parameters the RSI was created with, whatever it has been fed since.
+    pub fn config(&self) -> RsiConfig {
+        RsiConfig {
+            period: self.period,
+            flat: self.flat,
+            warmup_policy: self.warmup_policy,
+            decimals: self.decimals,
+        }
+    }
+
+    /// Creates an RSI warmed up with the last `period` values of `warmup`, so the next call
+    /// of `next` returns a valid (non-NaN) value.
+    ///
+    /// This is the checked way to seed an RSI: unlike [seed](#method.seed), it returns
+    /// `TaError::InsufficientData` if `warmup` has fewer than `period` values.
+    pub fn ready(period: usize, warmup: &[f64]) -> Result<Self> {
+        let mut rsi = Self::new(period)?;
+        for &value in warmup_tail(warmup, rsi.lookback())? {
+            rsi.next(value);
+        }
+        Ok(rsi)
+    }
+
+    /// Feeds `history` to the RSI and discards the outputs, e.g. to resume a live feed from
+    /// the last bars.
+    ///
+    /// Wilder's smoothing remembers every change, so the RSI only matches one fed the whole
+    /// series if `history` starts at the same bar; `period` values are enough to leave the
+    /// warmup, more make it converge.
+    ///
+    /// A shorter `history` is not an error: the RSI stays in its warmup and the next outputs
+    /// are NaN until it has seen `period` values. Use [ready](#method.ready) to get
+    /// `TaError::InsufficientData` instead.
+    pub fn seed<I: IntoIterator<Item = f64>>(&mut self, history: I) {
+        for value in history {
+            self.next(value);
+        }
+    }
+
+    /// Computes the RSI of every value of `inputs`.
+    ///
+    /// The first `period` outputs are NaN (see [Warmup](../trait.Warmup.html)), so all of
+    /// them are NaN if `period` is not smaller than the number of inputs.
+    ///
+    /// Returns `TaError::InvalidParameter` if `period` is 0.
+    pub fn calculate(period: usize, inputs: &[f64]) -> Result<Vec<f64>> {
+        let mut rsi = Self::new(period)?;
+        Ok(batch(rsi.lookback(), inputs, |&value| rsi.next(value)))
+    }
 }
 
 impl Period for RelativeStrengthIndex {
@@ -101,74 +226,108 @@ impl Period for RelativeStrengthIndex {
     }
 }
 
-impl Next<f64> for RelativeStrengthIndex {
-    type Output = f64;
+impl Warmup for RelativeStrengthIndex {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+}
 
-    fn next(&mut self, input: f64) -> Self::Output {
+impl RelativeStrengthIndex {
+    /// Computes the RSI of the next input, NaN while warming up.
+    fn update(&mut self, input: f64) -> f64 {
         // Handle the first input
         if self.is_new {
             self.is_new = false;
             self.prev_val = input;
-            return std::f64::NAN; // TA-Lib returns NaN for first values
+            return f64::NAN; // TA-Lib returns NaN for first values
         }
-        
+
         // Calculate price change
         let change = input - self.prev_val;
         self.prev_val = input;
-        
+
         // Split the change into gain and loss components
         let (gain, loss) = if change >= 0.0 {
             (change, 0.0)
         } else {
             (0.0, -change) // Make loss positive
         };
-        
+
         // Store price change data
         self.price_changes.push_back((gain, loss));
-        
+
         // If we don't have a full period of price changes yet, return NaN
         if self.price_changes.len() < self.period {
-            return std::f64::NAN;
+            return f64::NAN;
         }
-        
+
         // Keep only the changes needed for the calculation
         while self.price_changes.len() > self.period {
             self.price_changes.pop_front();
         }
-        
+
         // First time we have enough data - use simple average
         if self.price_changes.len() == self.period && self.avg_gain == 0.0 && self.avg_loss == 0.0 {
             // Calculate initial averages using simple average method
             let mut sum_gains = 0.0;
             let mut sum_losses = 0.0;
-            
+
             for &(gain, loss) in self.price_changes.iter() {
                 sum_gains += gain;
                 sum_losses += loss;
             }
-            
+
             self.avg_gain = sum_gains / self.period as f64;
             self.avg_loss = sum_losses / self.period as f64;
         } else {
             // For subsequent calculations, use Wilder's smoothing
-            self.avg_gain = ((self.avg_gain * (self.period as f64 - 1.0)) + gain) / self.period as f64;
-            self.avg_loss = ((self.avg_loss * (self.period as f64 - 1.0)) + loss) / self.period as f64;
+            self.avg_gain =
+                ((self.avg_gain * (self.period as f64 - 1.0)) + gain) / self.period as f64;
+            self.avg_loss =
+                ((self.avg_loss * (self.period as f64 - 1.0)) + loss) / self.period as f64;
         }
-        
+
         // Calculate RSI
         if self.avg_loss == 0.0 {
             if self.avg_gain == 0.0 {
-                return 50.0; // No movement
+                // No movement
+                return match self.flat {
+                    FlatRsi::Fifty => 50.0,
+                    FlatRsi::Hundred => 100.0,
+                    FlatRsi::Nan => f64::NAN,
+                };
             }
             return 100.0; // Only gains
         }
-        
+
         // RSI = 100 - (100 / (1 + RS))
         let rs = self.avg_gain / self.avg_loss;
         100.0 - (100.0 / (1.0 + rs))
     }
 }
 
+impl RelativeStrengthIndex {
+    fn next_value(&mut self, input: f64) -> f64 {
+        let rsi = round_decimals(self.update(input), self.decimals);
+        if self.price_changes.len() < self.period {
+            return self.warmup_policy.warmup_value();
+        }
+        rsi
+    }
+}
+
+impl Current for RelativeStrengthIndex {
+    type Output = f64;
+
+    fn current(&self) -> Option<f64> {
+        // the warmup outputs are not RSIs
+        self.last_output
+            .filter(|_| self.price_changes.len() >= self.period)
+    }
+}
+
+impl_next_option!(RelativeStrengthIndex);
+
 impl<T: Close> Next<&T> for RelativeStrengthIndex {
     type Output = f64;
 
@@ -184,6 +343,7 @@ impl Reset for RelativeStrengthIndex {
         self.price_changes.clear();
         self.avg_gain = 0.0;
         self.avg_loss = 0.0;
+        self.last_output = None;
     }
 }
 
@@ -195,7 +355,8 @@ impl Default for RelativeStrengthIndex {
 
 impl fmt::Display for RelativeStrengthIndex {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "RSI({})", self.period)
+        write!(f, "RSI({})", self.period)?;
+        fmt_current(f, self.current())
     }
 }
 
@@ -226,7 +387,7 @@ mod tests {
         assert!(first_output.is_nan());
 
         // ensure Display is implemented
-        format!("{}", indicator);
+        let _ = format!("{}", indicator);
     }
 
     #[test]
@@ -249,33 +410,33 @@ mod tests {
     #[test]
     fn test_next() {
         let mut rsi = RelativeStrengthIndex::new(3).unwrap();
-        
+
         // First value: TA-Lib returns NaN for the first data point
         let first = rsi.next(10.0);
         assert!(first.is_nan());
-        
+
         // Second value: TA-Lib returns NaN for the second data point
         let second = rsi.next(10.5);
         assert!(second.is_nan());
-        
+
         // Third value: TA-Lib returns NaN for the third data point
         let third = rsi.next(10.0);
         assert!(third.is_nan());
-        
+
         // Fourth value: Now we have enough data for a real RSI calculation
         let fourth = rsi.next(9.5);
-        
+
         // Matches TA-Lib value of 33.333 (rounds to 33)
         assert_eq!(fourth.round(), 33.0);
-        
+
         // Fifth value: Continues with valid RSI values
         let fifth = rsi.next(9.0);
         assert_eq!(fifth.round(), 22.0); // TA-Lib: 22.222 -> 22
-        
+
         // Sixth value
         let sixth = rsi.next(10.0);
         assert_eq!(sixth.round(), 61.0); // TA-Lib: 61.111 -> 61
-        
+
         // Seventh value
         let seventh = rsi.next(10.5);
         assert_eq!(seventh.round(), 72.0); // TA-Lib: 71.717 -> 72
@@ -284,30 +445,249 @@ mod tests {
         assert!((eighth - 95.6365903070).abs() < 0.001);
     }
 
+    #[test]
+    fn test_flat_value() {
+        let flat = |mut rsi: RelativeStrengthIndex| -> Vec<f64> {
+            (0..10).map(|_| rsi.next(7.0)).skip(3).collect()
+        };
+
+        let rsi = RelativeStrengthIndex::new(3).unwrap();
+        assert!(flat(rsi.clone()).iter().all(|&v| v == 50.0));
+        assert!(flat(rsi.clone().flat_value(FlatRsi::Fifty))
+            .iter()
+            .all(|&v| v == 50.0));
+        assert!(flat(rsi.clone().flat_value(FlatRsi::Hundred))
+            .iter()
+            .all(|&v| v == 100.0));
+        assert!(flat(rsi.flat_value(FlatRsi::Nan))
+            .iter()
+            .all(|v| v.is_nan()));
+
+        // only a flat window is affected
+        let mut rsi = RelativeStrengthIndex::new(3)
+            .unwrap()
+            .flat_value(FlatRsi::Nan);
+        for &price in [7.0, 8.0, 9.0].iter() {
+            rsi.next(price);
+        }
+        assert_eq!(rsi.next(10.0), 100.0);
+    }
+
+    #[test]
+    fn test_calculate() {
+        use crate::errors::TaError;
+
+        assert_eq!(
+            RelativeStrengthIndex::calculate(0, &[1.0]),
+            Err(TaError::InvalidParameter)
+        );
+
+        let out = RelativeStrengthIndex::calculate(3, &[10.0, 10.5, 10.0]).unwrap();
+        assert!(out.iter().all(|v| v.is_nan()));
+
+        let out = RelativeStrengthIndex::calculate(3, &[10.0, 10.5, 10.0, 9.5]).unwrap();
+        assert!(out[..3].iter().all(|v| v.is_nan()));
+        assert_eq!(out[3].round(), 33.0);
+    }
+
+    #[test]
+    fn test_ready() {
+        use crate::errors::TaError;
+
+        assert_eq!(
+            RelativeStrengthIndex::ready(0, &[]),
+            Err(TaError::InvalidParameter)
+        );
+        assert_eq!(
+            RelativeStrengthIndex::ready(3, &[10.0, 10.5]),
+            Err(TaError::InsufficientData { needed: 3, got: 2 })
+        );
+
+        let mut rsi = RelativeStrengthIndex::ready(3, &[10.0, 10.5, 10.0]).unwrap();
+        assert_eq!(rsi.next(9.5).round(), 33.0);
+    }
+
+    #[test]
+    fn test_ready_insufficient_data() {
+        use crate::errors::TaError;
+
+        let prices: Vec<f64> = (0..14).map(|i| 40.0 + i as f64).collect();
+        for got in [0, 1, 13] {
+            let err = RelativeStrengthIndex::ready(14, &prices[..got]).unwrap_err();
+            assert_eq!(err, TaError::InsufficientData { needed: 14, got });
+        }
+        let err = RelativeStrengthIndex::ready(14, &prices[..5]).unwrap_err();
+        assert_eq!(format!("{}", err), "insufficient data: needed 14, got 5");
+        assert!(RelativeStrengthIndex::ready(14, &prices).is_ok());
+    }
+
+    #[test]
+    fn test_seed() {
+        let history = [44.0, 44.5, 44.2, 44.8, 45.0, 44.6, 44.1, 44.9];
+
+        let mut full = RelativeStrengthIndex::new(3).unwrap();
+        for &price in history.iter() {
+            full.next(price);
+        }
+        let mut seeded = RelativeStrengthIndex::new(3).unwrap();
+        seeded.seed(history.iter().copied());
+        assert_eq!(seeded.current(), full.current());
+        assert_eq!(seeded.next(45.3), full.next(45.3));
+
+        // unchecked, unlike ready
+        let mut rsi = RelativeStrengthIndex::new(3).unwrap();
+        rsi.seed(vec![44.0, 44.5]);
+        assert!(rsi.next(44.2).is_nan());
+        assert!(RelativeStrengthIndex::ready(3, &[44.0, 44.5]).is_err());
+    }
+
     #[test]
     fn test_reset() {
         let mut rsi = RelativeStrengthIndex::new(3).unwrap();
-        
+
         // First value after initialization is NaN
         let first = rsi.next(10.0);
         assert!(first.is_nan());
-        
+
         // Second value is NaN
         let second = rsi.next(10.5);
         assert!(second.is_nan());
 
         // After reset, behavior should repeat
         rsi.reset();
-        
+
         // First value after reset is NaN
         let first_after_reset = rsi.next(10.0);
         assert!(first_after_reset.is_nan());
-        
+
         // Second value after reset is NaN
         let second_after_reset = rsi.next(10.5);
         assert!(second_after_reset.is_nan());
     }
 
+    #[test]
+    fn test_decimals() {
+        let prices = [10.0, 10.5, 10.0, 9.5, 9.0, 10.0, 10.5, 17.2];
+        let mut rsi = RelativeStrengthIndex::new(3).unwrap().with_decimals(2);
+        let outputs: Vec<f64> = prices.iter().map(|&p| rsi.next(p)).collect();
+        assert_eq!(warmup_nan_count(&outputs), 3);
+        // 33.333, 22.222, 61.111, 71.717, 95.637
+        assert_eq!(&outputs[3..], &[33.33, 22.22, 61.11, 71.72, 95.64]);
+        assert_eq!(rsi.current(), Some(95.64));
+
+        // the averages are not rounded
+        let mut exact = RelativeStrengthIndex::new(3).unwrap();
+        let mut rounded = RelativeStrengthIndex::new(3).unwrap().with_decimals(0);
+        for &p in prices.iter() {
+            let (value, rounded_value) = (exact.next(p), rounded.next(p));
+            if !value.is_nan() {
+                assert_eq!(rounded_value, value.round());
+            }
+        }
+    }
+
+    #[test]
+    fn test_decimals_reset() {
+        let mut rsi = RelativeStrengthIndex::new(3).unwrap().with_decimals(1);
+        for &p in [10.0, 10.5, 10.0, 9.5].iter() {
+            rsi.next(p);
+        }
+
+        rsi.reset();
+        assert_eq!(rsi.decimals(), Some(1));
+        let outputs: Vec<f64> = [10.0, 10.5, 10.0, 9.5]
+            .iter()
+            .map(|&p| rsi.next(p))
+            .collect();
+        assert_eq!(outputs[3], 33.3);
+    }
+
+    #[test]
+    fn test_current() {
+        let mut rsi = RelativeStrengthIndex::new(3).unwrap();
+        for &price in [10.0, 10.5, 10.0].iter() {
+            rsi.next(price);
+            assert_eq!(rsi.current(), None);
+        }
+        for &price in [9.5, 9.8, 10.4].iter() {
+            let value = rsi.next(price);
+            assert_eq!(rsi.current(), Some(value));
+        }
+        // a gap leaves the current value as is
+        let value = rsi.next(None);
+        assert_eq!(rsi.current(), Some(value));
+
+        rsi.reset();
+        assert_eq!(rsi.current(), None);
+    }
+
+    #[test]
+    fn test_warmup_policy() {
+        let prices = [44.0, 44.5, 44.2, 44.8, 45.0, 44.6];
+        let run = |policy: WarmupPolicy| -> Vec<f64> {
+            let mut rsi = RelativeStrengthIndex::new(3)
+                .unwrap()
+                .with_warmup_policy(policy);
+            prices.iter().map(|&p| rsi.next(p)).collect()
+        };
+
+        let nan = run(WarmupPolicy::Nan);
+        assert_eq!(warmup_nan_count(&nan), 3);
+
+        let zero = run(WarmupPolicy::Zero);
+        assert_eq!(&zero[..3], &[0.0, 0.0, 0.0]);
+        assert_eq!(&zero[3..], &nan[3..]);
+
+        // streaming cannot backfill
+        let mut repeat = run(WarmupPolicy::Repeat);
+        assert_eq!(warmup_nan_count(&repeat), 3);
+        WarmupPolicy::Repeat.apply(3, &mut repeat);
+        assert_eq!(&repeat[..3], &[nan[3]; 3]);
+        assert_eq!(&repeat[3..], &nan[3..]);
+
+        // the warmup outputs are not remembered
+        let mut rsi = RelativeStrengthIndex::new(3)
+            .unwrap()
+            .with_warmup_policy(WarmupPolicy::Zero);
+        rsi.next(44.0);
+        assert_eq!(rsi.current(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_config() {
+        use std::collections::HashSet;
+
+        let mut fed = RelativeStrengthIndex::new(14).unwrap();
+        for &price in [10.0, 10.5, 10.0, 9.5, 9.8].iter() {
+            fed.next(price);
+        }
+        let fresh = RelativeStrengthIndex::new(14).unwrap();
+        assert_ne!(fed, fresh);
+        assert_eq!(fed.config(), fresh.config());
+        assert_eq!(
+            fresh.config(),
+            RsiConfig {
+                period: 14,
+                flat: FlatRsi::Fifty,
+                warmup_policy: WarmupPolicy::Nan,
+                decimals: None
+            }
+        );
+
+        let hundred = RelativeStrengthIndex::new(14)
+            .unwrap()
+            .flat_value(FlatRsi::Hundred);
+        assert_ne!(hundred.config(), fresh.config());
+        let rounded = RelativeStrengthIndex::new(14).unwrap().with_decimals(2);
+        assert_ne!(rounded.config(), fresh.config());
+
+        let configs: HashSet<RsiConfig> = [fed.config(), fresh.config(), hundred.config()]
+            .into_iter()
+            .collect();
+        assert_eq!(configs.len(), 2);
+    }
+
     #[test]
     fn test_default() {
         RelativeStrengthIndex::default();
@@ -318,5 +698,18 @@ mod tests {
         let rsi = RelativeStrengthIndex::new(16).unwrap();
         assert_eq!(format!("{}", rsi), "RSI(16)");
     }
-}
 
+    #[test]
+    fn test_display_alternate() {
+        let mut rsi = RelativeStrengthIndex::new(3).unwrap();
+        rsi.next(10.0);
+        assert_eq!(format!("{:#}", rsi), "RSI(3)=NaN");
+        rsi.next(10.5);
+        rsi.next(10.0);
+
+        let value = rsi.next(9.5);
+        assert_eq!(format!("{}", rsi), "RSI(3)");
+        assert_eq!(format!("{:#}", rsi), format!("RSI(3)={}", value));
+        assert_eq!(format!("{:#.2}", rsi), "RSI(3)=33.33");
+    }
+}
@@ -0,0 +1,267 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{Close, High, Low, Next, Open, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Relative Vigor Index (RVI).
+///
+/// John Ehlers' measure of the conviction of a move: in an uptrend prices tend to close above
+/// their open, in a downtrend below it. The RVI compares the body of the candles to their
+/// range, both smoothed with symmetric weights over four bars, and a signal line smooths the
+/// RVI the same way. Crossings of the two lines are used as signals.
+///
+/// # Formula
+///
+/// SWMA(x) = (x<sub>t</sub> + 2 * x<sub>t-1</sub> + 2 * x<sub>t-2</sub> + x<sub>t-3</sub>) / 6
+///
+/// RVI = SMA(SWMA(Close - Open), _period_) / SMA(SWMA(High - Low), _period_)
+///
+/// Signal = SWMA(RVI)
+///
+/// The RVI is NaN until _period_ + 3 bars are known, the signal 3 bars more. A window of bars
+/// without range has no vigor: its RVI is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars of the SMAs (integer greater than 0). Default is 10.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::RelativeVigorIndex;
+/// use tam::{DataItem, Next};
+///
+/// let candle = |open: f64, close: f64| {
+///     DataItem::builder()
+///         .open(open)
+///         .high(open.max(close) + 1.0)
+///         .low(open.min(close) - 1.0)
+///         .close(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut rvi = RelativeVigorIndex::new(2).unwrap();
+/// for _ in 0..4 {
+///     assert!(rvi.next(&candle(10.0, 12.0)).rvi.is_nan());
+/// }
+/// // bodies of 2 in ranges of 4
+/// assert_eq!(rvi.next(&candle(10.0, 12.0)).rvi, 0.5);
+/// ```
+///
+/// # Links
+///
+/// * [Relative Vigor Index, Investopedia](https://www.investopedia.com/terms/r/relative_vigor_index.asp)
+///
+#[doc(alias = "RVI")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RelativeVigorIndex {
+    period: usize,
+    /// Close - open and high - low of the last 4 bars, newest first.
+    bars: [(f64, f64); 4],
+    /// Last 4 RVI values, newest first.
+    rvis: [f64; 4],
+    count: usize,
+    numerator: Sma,
+    denominator: Sma,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RviOutput {
+    pub rvi: f64,
+    pub signal: f64,
+}
+
+impl From<RviOutput> for (f64, f64) {
+    fn from(out: RviOutput) -> Self {
+        (out.rvi, out.signal)
+    }
+}
+
+impl_output!(RviOutput, rvi, signal);
+
+/// Symmetric weighted average of 4 values, newest first.
+fn swma(x: [f64; 4]) -> f64 {
+    (x[0] + 2.0 * x[1] + 2.0 * x[2] + x[3]) / 6.0
+}
+
+impl RelativeVigorIndex {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            period,
+            bars: [(0.0, 0.0); 4],
+            rvis: [0.0; 4],
+            count: 0,
+            numerator: Sma::new(period)?,
+            denominator: Sma::new(period)?,
+        })
+    }
+}
+
+impl Period for RelativeVigorIndex {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Warmup for RelativeVigorIndex {
+    fn lookback(&self) -> usize {
+        self.period + 2
+    }
+}
+
+impl<T: Open + High + Low + Close> Next<&T> for RelativeVigorIndex {
+    type Output = RviOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let nan = RviOutput {
+            rvi: f64::NAN,
+            signal: f64::NAN,
+        };
+
+        self.bars.rotate_right(1);
+        self.bars[0] = (input.close() - input.open(), input.high() - input.low());
+        if self.count < self.period + 6 {
+            self.count += 1;
+        }
+        if self.count < 4 {
+            return nan;
+        }
+
+        let numerator = self.numerator.next(swma(self.bars.map(|bar| bar.0)));
+        let denominator = self.denominator.next(swma(self.bars.map(|bar| bar.1)));
+        if self.count <= self.lookback() {
+            return nan;
+        }
+
+        let rvi = if denominator == 0.0 {
+            0.0
+        } else {
+            numerator / denominator
+        };
+        self.rvis.rotate_right(1);
+        self.rvis[0] = rvi;
+
+        let signal = if self.count < self.period + 6 {
+            f64::NAN
+        } else {
+            swma(self.rvis)
+        };
+        RviOutput { rvi, signal }
+    }
+}
+
+impl Reset for RelativeVigorIndex {
+    fn reset(&mut self) {
+        self.bars = [(0.0, 0.0); 4];
+        self.rvis = [0.0; 4];
+        self.count = 0;
+        self.numerator.reset();
+        self.denominator.reset();
+    }
+}
+
+impl Default for RelativeVigorIndex {
+    fn default() -> Self {
+        Self::new(10).unwrap()
+    }
+}
+
+impl fmt::Display for RelativeVigorIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RVI({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use crate::ApproxEq;
+
+    fn bars() -> Vec<Bar> {
+        vec![
+            Bar::new().open(10.0).high(11.0).low(9.5).close(10.5),
+            Bar::new().open(10.5).high(11.5).low(10.0).close(11.25),
+            Bar::new().open(11.25).high(11.5).low(10.5).close(10.75),
+            Bar::new().open(10.75).high(12.0).low(10.5).close(11.75),
+            Bar::new().open(11.75).high(12.5).low(11.5).close(12.25),
+            Bar::new().open(12.25).high(12.5).low(11.0).close(11.5),
+            Bar::new().open(11.5).high(12.0).low(11.0).close(11.75),
+            Bar::new().open(11.75).high(13.0).low(11.5).close(12.75),
+            Bar::new().open(12.75).high(13.5).low(12.5).close(13.25),
+            Bar::new().open(13.25).high(13.5).low(12.0).close(12.25),
+        ]
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(RelativeVigorIndex::new(0).is_err());
+        assert!(RelativeVigorIndex::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut rvi = RelativeVigorIndex::new(3).unwrap();
+        assert_eq!(rvi.lookback(), 5);
+
+        let outputs: Vec<RviOutput> = bars().iter().map(|b| rvi.next(b)).collect();
+        let rvis: Vec<f64> = outputs.iter().map(|out| out.rvi).collect();
+        let signals: Vec<f64> = outputs.iter().map(|out| out.signal).collect();
+        assert_eq!(warmup_nan_count(&rvis), 5);
+        assert_eq!(warmup_nan_count(&signals), 8);
+
+        let rvis: Vec<f64> = rvis[5..].iter().map(|&v| round(v)).collect();
+        assert_eq!(rvis, vec![0.261, 0.211, 0.133, 0.156, 0.222]);
+        assert_eq!(round(signals[8]), 0.184);
+        assert_eq!(round(signals[9]), 0.169);
+    }
+
+    #[test]
+    fn test_flat() {
+        let mut rvi = RelativeVigorIndex::new(2).unwrap();
+        let mut out = rvi.next(&Bar::new().open(10.0).high(10.0).low(10.0).close(10.0));
+        for _ in 0..8 {
+            out = rvi.next(&Bar::new().open(10.0).high(10.0).low(10.0).close(10.0));
+        }
+        assert_eq!(out.rvi, 0.0);
+        assert_eq!(out.signal, 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut rvi = RelativeVigorIndex::new(3).unwrap();
+        let first: Vec<RviOutput> = bars().iter().map(|b| rvi.next(b)).collect();
+
+        rvi.reset();
+        let second: Vec<RviOutput> = bars().iter().map(|b| rvi.next(b)).collect();
+        assert_eq!(first[8..], second[8..]);
+        assert!(second[4].rvi.is_nan());
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut rvi = RelativeVigorIndex::new(3).unwrap();
+        let bars = bars();
+        for b in &bars[..6] {
+            rvi.next(b);
+        }
+        let mut restored = serde_round_trip(&rvi);
+        for b in &bars[6..] {
+            assert!(restored.next(b).approx_eq(&rvi.next(b), 1e-9));
+        }
+    }
+
+    #[test]
+    fn test_default() {
+        RelativeVigorIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", RelativeVigorIndex::default()), "RVI(10)");
+    }
+}
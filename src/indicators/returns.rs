@@ -0,0 +1,273 @@
+use core::fmt;
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float;
+use crate::{Close, Next, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Simple return.
+///
+/// The relative change of the price from one bar to the next, the usual input of volatility
+/// and risk-adjusted performance measures. See [LogReturn](struct.LogReturn.html) for the
+/// continuously compounded version.
+///
+/// # Formula
+///
+/// RET = (p<sub>t</sub> - p<sub>t-1</sub>) / p<sub>t-1</sub>
+///
+/// The first value has no prior price and yields NaN. A prior price that is not greater than
+/// 0 yields NaN too.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::Return;
+/// use tam::Next;
+///
+/// let mut ret = Return::new();
+/// assert!(ret.next(10.0).is_nan());
+/// assert_eq!(ret.next(12.0), 0.2);
+/// assert_eq!(ret.next(9.0), -0.25);
+/// ```
+///
+/// # Links
+///
+/// * [Rate of return, Wikipedia](https://en.wikipedia.org/wiki/Rate_of_return)
+///
+#[doc(alias = "RET")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Return {
+    prev: Option<f64>,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+/// Logarithmic return.
+///
+/// The continuously compounded [return](struct.Return.html) from one bar to the next. Log
+/// returns add up over time and are symmetric: a rise followed by the same fall in log terms
+/// brings the price back.
+///
+/// # Formula
+///
+/// LOGRET = ln(p<sub>t</sub> / p<sub>t-1</sub>)
+///
+/// The first value has no prior price and yields NaN. A prior price that is not greater than
+/// 0 yields NaN too.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::LogReturn;
+/// use tam::Next;
+///
+/// let mut ret = LogReturn::new();
+/// assert!(ret.next(10.0).is_nan());
+/// assert_eq!(ret.next(10.0), 0.0);
+/// assert_eq!(ret.next(20.0), 2.0_f64.ln());
+/// ```
+///
+/// # Links
+///
+/// * [Rate of return, Wikipedia](https://en.wikipedia.org/wiki/Rate_of_return#Logarithmic_or_continuously_compounded_return)
+///
+#[doc(alias = "LOGRET")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LogReturn {
+    prev: Option<f64>,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl Return {
+    pub fn new() -> Self {
+        Self {
+            prev: None,
+            last_output: None,
+        }
+    }
+}
+
+impl LogReturn {
+    pub fn new() -> Self {
+        Self {
+            prev: None,
+            last_output: None,
+        }
+    }
+}
+
+/// Applies `f` to the current and previous prices, NaN without a positive previous price.
+fn relative(prev: Option<f64>, input: f64, f: fn(f64, f64) -> f64) -> f64 {
+    match prev {
+        Some(prev) if prev > 0.0 => f(input, prev),
+        _ => f64::NAN,
+    }
+}
+
+impl Warmup for Return {
+    fn lookback(&self) -> usize {
+        1
+    }
+}
+
+impl Warmup for LogReturn {
+    fn lookback(&self) -> usize {
+        1
+    }
+}
+
+impl Return {
+    fn next_value(&mut self, input: f64) -> f64 {
+        let prev = self.prev.replace(input);
+        relative(prev, input, |p, prev| (p - prev) / prev)
+    }
+}
+
+impl LogReturn {
+    fn next_value(&mut self, input: f64) -> f64 {
+        let prev = self.prev.replace(input);
+        relative(prev, input, |p, prev| (p / prev).ln())
+    }
+}
+
+impl_next_option!(Return);
+impl_next_option!(LogReturn);
+
+impl<T: Close> Next<&T> for Return {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl<T: Close> Next<&T> for LogReturn {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for Return {
+    fn reset(&mut self) {
+        self.prev = None;
+        self.last_output = None;
+    }
+}
+
+impl Reset for LogReturn {
+    fn reset(&mut self) {
+        self.prev = None;
+        self.last_output = None;
+    }
+}
+
+impl Default for Return {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for LogReturn {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Return {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RET")
+    }
+}
+
+impl fmt::Display for LogReturn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LOGRET")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_return() {
+        let mut ret = Return::new();
+        assert_eq!(ret.lookback(), 1);
+
+        assert!(ret.next(100.0).is_nan());
+        assert_eq!(ret.next(&Bar::new().close(110)), 0.1);
+        assert_eq!(round(ret.next(99.0)), -0.1);
+        assert_eq!(ret.next(99.0), 0.0);
+    }
+
+    #[test]
+    fn test_log_return() {
+        let mut ret = LogReturn::new();
+        assert_eq!(ret.lookback(), 1);
+
+        assert!(ret.next(100.0).is_nan());
+        assert_eq!(round(ret.next(&Bar::new().close(110))), 0.095);
+        // the log returns of a round trip cancel out
+        let up = ret.next(220.0);
+        let down = ret.next(110.0);
+        assert_eq!(round(up + down), 0.0);
+    }
+
+    #[test]
+    fn test_non_positive_price() {
+        let mut ret = Return::new();
+        let mut log_ret = LogReturn::new();
+        ret.next(0.0);
+        log_ret.next(0.0);
+        assert!(ret.next(5.0).is_nan());
+        assert!(log_ret.next(5.0).is_nan());
+
+        assert_eq!(ret.next(-5.0), -2.0);
+        assert!(log_ret.next(-5.0).is_nan());
+        assert!(ret.next(10.0).is_nan());
+        assert!(log_ret.next(10.0).is_nan());
+
+        assert_eq!(ret.next(15.0), 0.5);
+        assert_eq!(round(log_ret.next(15.0)), 0.405);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ret = Return::new();
+        let mut log_ret = LogReturn::new();
+        for &price in [10.0, 20.0].iter() {
+            ret.next(price);
+            log_ret.next(price);
+        }
+
+        ret.reset();
+        log_ret.reset();
+        assert!(ret.next(30.0).is_nan());
+        assert!(log_ret.next(30.0).is_nan());
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut ret = Return::new();
+        ret.next(10.0);
+        let mut restored = serde_round_trip(&ret);
+        assert_eq!(restored.next(12.0), ret.next(12.0));
+    }
+
+    #[test]
+    fn test_default() {
+        Return::default();
+        LogReturn::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Return::new()), "RET");
+        assert_eq!(format!("{}", LogReturn::new()), "LOGRET");
+    }
+}
@@ -0,0 +1,190 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::StandardDeviation as Sd;
+use crate::{Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Rolling Sharpe ratio.
+///
+/// The excess return per unit of risk over the last _period_ bars: the mean of the returns
+/// minus the risk-free rate, divided by their standard deviation. Fed with the returns of a
+/// strategy, e.g. from [Return](struct.Return.html), it tracks how its risk-adjusted
+/// performance evolves. The ratio is per bar, it is not annualized.
+///
+/// # Formula
+///
+/// SHARPE = (mean(r, _period_) - _risk_free_per_period_) / SD(r, _period_)
+///
+/// Where the mean and the population standard deviation are updated incrementally, as in
+/// [StandardDeviation](struct.StandardDeviation.html). The output is NaN until the window is
+/// full and when the standard deviation is 0 (constant returns).
+///
+/// # Parameters
+///
+/// * _period_ - number of returns in the window (integer greater than 1). Default is 20.
+/// * _risk_free_per_period_ - risk-free return over one bar (finite number). Default is 0.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::RollingSharpe;
+/// use tam::Next;
+///
+/// let mut sharpe = RollingSharpe::new(2, 0.0).unwrap();
+/// assert!(sharpe.next(0.01).is_nan());
+/// // mean 0.02, standard deviation 0.01
+/// assert_eq!((sharpe.next(0.03) * 1000.0).round(), 2000.0);
+/// ```
+///
+/// # Links
+///
+/// * [Sharpe ratio, Wikipedia](https://en.wikipedia.org/wiki/Sharpe_ratio)
+///
+#[doc(alias = "SHARPE")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RollingSharpe {
+    sd: Sd,
+    risk_free: f64,
+    count: usize,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl RollingSharpe {
+    pub fn new(period: usize, risk_free_per_period: f64) -> Result<Self> {
+        if period < 2 || !risk_free_per_period.is_finite() {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            sd: Sd::new(period)?,
+            risk_free: risk_free_per_period,
+            count: 0,
+            last_output: None,
+        })
+    }
+
+    pub fn risk_free_per_period(&self) -> f64 {
+        self.risk_free
+    }
+}
+
+impl Period for RollingSharpe {
+    fn period(&self) -> usize {
+        self.sd.period()
+    }
+}
+
+impl Warmup for RollingSharpe {
+    fn lookback(&self) -> usize {
+        self.sd.period() - 1
+    }
+}
+
+impl RollingSharpe {
+    fn next_value(&mut self, input: f64) -> f64 {
+        let sd = self.sd.next(input);
+        if self.count < self.lookback() {
+            self.count += 1;
+            return f64::NAN;
+        }
+        if sd == 0.0 {
+            return f64::NAN;
+        }
+        (self.sd.mean() - self.risk_free) / sd
+    }
+}
+
+impl_next_option!(RollingSharpe);
+
+impl Reset for RollingSharpe {
+    fn reset(&mut self) {
+        self.sd.reset();
+        self.count = 0;
+        self.last_output = None;
+    }
+}
+
+impl Default for RollingSharpe {
+    fn default() -> Self {
+        Self::new(20, 0.0).unwrap()
+    }
+}
+
+impl fmt::Display for RollingSharpe {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SHARPE({})", self.sd.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(RollingSharpe::new(0, 0.0).is_err());
+        assert!(RollingSharpe::new(1, 0.0).is_err());
+        assert!(RollingSharpe::new(20, f64::NAN).is_err());
+        assert!(RollingSharpe::new(20, f64::INFINITY).is_err());
+        assert!(RollingSharpe::new(2, -0.001).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut sharpe = RollingSharpe::new(4, 0.005).unwrap();
+        assert_eq!(sharpe.lookback(), 3);
+
+        // mean 0.02, variance (1 + 1 + 9 + 9) / 4 * 1e-4
+        for &r in [0.01, 0.03, -0.01].iter() {
+            assert!(sharpe.next(r).is_nan());
+        }
+        assert_eq!(round(sharpe.next(0.05)), 0.671);
+        // the window moves: 0.03, -0.01, 0.05, 0.01
+        assert_eq!(round(sharpe.next(0.01)), 0.671);
+        // mean 0, below the risk-free rate
+        assert_eq!(round(sharpe.next(-0.05)), -0.139);
+    }
+
+    #[test]
+    fn test_constant_returns() {
+        let mut sharpe = RollingSharpe::new(5, 0.0).unwrap();
+        for _ in 0..20 {
+            assert!(sharpe.next(0.01).is_nan());
+        }
+        // the returns vary again
+        sharpe.next(0.03);
+        assert!(!sharpe.next(0.01).is_nan());
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sharpe = RollingSharpe::new(2, 0.0).unwrap();
+        sharpe.next(0.01);
+        sharpe.next(0.05);
+
+        sharpe.reset();
+        assert!(sharpe.next(0.01).is_nan());
+        assert_eq!(round(sharpe.next(0.03)), 2.0);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut sharpe = RollingSharpe::new(3, 0.001).unwrap();
+        sharpe.next(0.01);
+        sharpe.next(0.02);
+        let mut restored = serde_round_trip(&sharpe);
+        assert_eq!(round(restored.next(-0.01)), round(sharpe.next(-0.01)));
+    }
+
+    #[test]
+    fn test_default() {
+        RollingSharpe::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", RollingSharpe::default()), "SHARPE(20)");
+    }
+}
@@ -0,0 +1,313 @@
+use alloc::{boxed::Box, vec};
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{High, Low, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Elder's SafeZone stop.
+///
+/// Developed by Alexander Elder, places the stops just beyond the normal noise of the market.
+/// The noise of an uptrend is the downside penetrations, the bars whose low is below the
+/// previous low; the long stop is placed below the low by a multiple of their average depth.
+/// Symmetrically, the short stop is placed above the high by a multiple of the average upside
+/// penetration.
+///
+/// # Formula
+///
+/// Long stop = Low<sub>t</sub> - _coefficient_ * average(Low<sub>t-1</sub> - Low<sub>t</sub>)
+///
+/// Short stop = High<sub>t</sub> + _coefficient_ * average(High<sub>t</sub> - High<sub>t-1</sub>)
+///
+/// Where the averages are taken over the penetrations of the last _period_ bars, i.e. only
+/// over the bars where the difference is positive. Without any penetration in the window the
+/// stop is the low (or the high). The output is NaN until _period_ bars after the first one
+/// are known.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars of the averages (integer greater than 0). Default is 10.
+/// * _coefficient_ - multiple of the average penetration (number greater than 0). Default
+///   is 2.5.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::SafeZoneStop;
+/// use tam::{DataItem, Next};
+///
+/// let mut stop = SafeZoneStop::new(1, 2.0).unwrap();
+/// let bar = |high: f64, low: f64| {
+///     DataItem::builder()
+///         .open(low).high(high).low(low).close(high).volume(1.0)
+///         .build().unwrap()
+/// };
+///
+/// assert!(stop.next(&bar(10.0, 8.0)).long_stop.is_nan());
+/// // 1 below the previous low, 1 above the previous high
+/// let out = stop.next(&bar(11.0, 7.0));
+/// assert_eq!(out.long_stop, 5.0);
+/// assert_eq!(out.short_stop, 13.0);
+/// ```
+///
+/// # Links
+///
+/// * [SafeZone Stops, Incredible Charts](https://www.incrediblecharts.com/indicators/safezone.php)
+///
+#[doc(alias = "SAFEZONE")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SafeZoneStop {
+    period: usize,
+    coefficient: f64,
+    index: usize,
+    count: usize,
+    prev_high: f64,
+    prev_low: f64,
+    down: Penetrations,
+    up: Penetrations,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SafeZoneOutput {
+    pub long_stop: f64,
+    pub short_stop: f64,
+}
+
+impl From<SafeZoneOutput> for (f64, f64) {
+    fn from(out: SafeZoneOutput) -> Self {
+        (out.long_stop, out.short_stop)
+    }
+}
+
+impl_output!(SafeZoneOutput, long_stop, short_stop);
+
+/// Penetrations of the last bars, 0 for the bars without one.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct Penetrations {
+    values: Box<[f64]>,
+    sum: f64,
+    /// Number of positive values.
+    count: usize,
+}
+
+impl Penetrations {
+    fn new(period: usize) -> Self {
+        Self {
+            values: vec![0.0; period].into_boxed_slice(),
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    /// Replaces the value at `index` with `penetration` (clamped to 0).
+    fn push(&mut self, index: usize, penetration: f64) {
+        let old = self.values[index];
+        if old > 0.0 {
+            self.sum -= old;
+            self.count -= 1;
+        }
+        let new = penetration.max(0.0);
+        if new > 0.0 {
+            self.sum += new;
+            self.count += 1;
+        }
+        self.values[index] = new;
+    }
+
+    fn average(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.sum / self.count as f64
+        }
+    }
+
+    fn reset(&mut self) {
+        for value in self.values.iter_mut() {
+            *value = 0.0;
+        }
+        self.sum = 0.0;
+        self.count = 0;
+    }
+}
+
+impl SafeZoneStop {
+    pub fn new(period: usize, coefficient: f64) -> Result<Self> {
+        if period == 0 || coefficient.is_nan() || coefficient <= 0.0 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            coefficient,
+            index: 0,
+            count: 0,
+            prev_high: f64::NAN,
+            prev_low: f64::NAN,
+            down: Penetrations::new(period),
+            up: Penetrations::new(period),
+        })
+    }
+
+    pub fn coefficient(&self) -> f64 {
+        self.coefficient
+    }
+}
+
+impl Period for SafeZoneStop {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Warmup for SafeZoneStop {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: High + Low> Next<&T> for SafeZoneStop {
+    type Output = SafeZoneOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let (high, low) = (input.high(), input.low());
+        let (prev_high, prev_low) = (self.prev_high, self.prev_low);
+        self.prev_high = high;
+        self.prev_low = low;
+
+        if self.count == 0 {
+            // no previous bar to penetrate
+            self.count += 1;
+            return SafeZoneOutput {
+                long_stop: f64::NAN,
+                short_stop: f64::NAN,
+            };
+        }
+
+        self.down.push(self.index, prev_low - low);
+        self.up.push(self.index, high - prev_high);
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count < self.lookback() {
+            self.count += 1;
+            return SafeZoneOutput {
+                long_stop: f64::NAN,
+                short_stop: f64::NAN,
+            };
+        }
+        SafeZoneOutput {
+            long_stop: low - self.coefficient * self.down.average(),
+            short_stop: high + self.coefficient * self.up.average(),
+        }
+    }
+}
+
+impl Reset for SafeZoneStop {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.prev_high = f64::NAN;
+        self.prev_low = f64::NAN;
+        self.down.reset();
+        self.up.reset();
+    }
+}
+
+impl Default for SafeZoneStop {
+    fn default() -> Self {
+        Self::new(10, 2.5).unwrap()
+    }
+}
+
+impl fmt::Display for SafeZoneStop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SAFEZONE({}, {})", self.period, self.coefficient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(SafeZoneStop::new(0, 2.0).is_err());
+        assert!(SafeZoneStop::new(10, 0.0).is_err());
+        assert!(SafeZoneStop::new(10, f64::NAN).is_err());
+        assert!(SafeZoneStop::new(1, 0.5).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut stop = SafeZoneStop::new(2, 2.0).unwrap();
+
+        let out = stop.next(&Bar::new().high(10.0).low(8.0));
+        assert!(out.long_stop.is_nan() && out.short_stop.is_nan());
+        // penetrations: 1 down, 1 up
+        let out = stop.next(&Bar::new().high(11.0).low(7.0));
+        assert!(out.long_stop.is_nan() && out.short_stop.is_nan());
+
+        // no new down penetration, 1 up: averages 1 and 1
+        let out = stop.next(&Bar::new().high(12.0).low(9.0));
+        assert_eq!((out.long_stop, out.short_stop), (7.0, 14.0));
+        // 3 down, no new up: averages 3 and 1
+        let out = stop.next(&Bar::new().high(11.0).low(6.0));
+        assert_eq!((out.long_stop, out.short_stop), (0.0, 13.0));
+        // 2 up: averages 3 and 2
+        let out = stop.next(&Bar::new().high(13.0).low(10.0));
+        assert_eq!((out.long_stop, out.short_stop), (4.0, 17.0));
+        // the down penetration of 3 left the window, 1 up: averages 0 and 1.5
+        let out = stop.next(&Bar::new().high(14.0).low(11.0));
+        assert_eq!((out.long_stop, out.short_stop), (11.0, 17.0));
+    }
+
+    #[test]
+    fn test_trend() {
+        let mut stop = SafeZoneStop::new(10, 2.0).unwrap();
+
+        // an uptrend of 1 per bar with a pullback every 4th bar
+        let mut long_stops = Vec::new();
+        for i in 0..60 {
+            let base = 100.0 + i as f64 - if i % 4 == 3 { 2.5 } else { 0.0 };
+            let out = stop.next(&Bar::new().high(base + 1.0).low(base - 1.0));
+            if i < 10 {
+                assert!(out.long_stop.is_nan());
+                continue;
+            }
+            // every pullback is 1.5 below the previous low
+            assert_eq!(out.long_stop, base - 1.0 - 2.0 * 1.5);
+            long_stops.push(out.long_stop);
+        }
+        assert!(long_stops.last().unwrap() - long_stops[0] > 40.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut stop = SafeZoneStop::new(1, 2.0).unwrap();
+        stop.next(&Bar::new().high(10.0).low(8.0));
+        stop.next(&Bar::new().high(11.0).low(7.0));
+
+        stop.reset();
+        assert!(stop
+            .next(&Bar::new().high(11.0).low(7.0))
+            .long_stop
+            .is_nan());
+        let out = stop.next(&Bar::new().high(12.0).low(8.0));
+        assert_eq!((out.long_stop, out.short_stop), (8.0, 14.0));
+    }
+
+    #[test]
+    fn test_default() {
+        SafeZoneStop::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let stop = SafeZoneStop::new(10, 2.5).unwrap();
+        assert_eq!(format!("{}", stop), "SAFEZONE(10, 2.5)");
+    }
+}
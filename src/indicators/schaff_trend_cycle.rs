@@ -0,0 +1,319 @@
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{EmaSeed, ExponentialMovingAverage as Ema, Maximum, Minimum};
+use crate::{Close, Next, Period, Periods, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Schaff Trend Cycle (STC).
+///
+/// Developed by Doug Schaff, applies a stochastic twice to the MACD line. The result is an
+/// oscillator between 0 and 100 that reacts faster than the MACD: it stays at 100 in an uptrend
+/// and at 0 in a downtrend, and turns quickly when the trend does.
+///
+/// # Formula
+///
+/// MACD = EMA(_macd_fast_) - EMA(_macd_slow_)
+///
+/// %K<sub>1</sub> = 100 * (MACD - min(MACD, _cycle_)) / (max(MACD, _cycle_) - min(MACD, _cycle_))
+///
+/// %D<sub>1</sub> = EMA(%K<sub>1</sub>, _d1_)
+///
+/// %K<sub>2</sub> = 100 * (%D<sub>1</sub> - min(%D<sub>1</sub>, _cycle_)) / (max(%D<sub>1</sub>, _cycle_) - min(%D<sub>1</sub>, _cycle_))
+///
+/// STC = EMA(%K<sub>2</sub>, _d2_)
+///
+/// Where min and max are taken over the last _cycle_ values. When they are equal, the previous
+/// %K is kept (50 at first). With the default smoothing periods of 3 the EMAs have α = 0.5,
+/// the factor of the original formula.
+///
+/// # Warmup
+///
+/// Every EMA is seeded with an SMA and every stage only starts once the previous one is
+/// valid, so the warmups add up. The first
+/// (max(_macd_fast_, _macd_slow_) - 1) + 2 * (_cycle_ - 1) + (_d1_ - 1) + (_d2_ - 1)
+/// outputs are NaN, 71 with the defaults (see [Warmup](../trait.Warmup.html)).
+///
+/// # Parameters
+///
+/// * _macd_fast_ - period of the fast EMA of the MACD (integer greater than 0). Default is 23.
+/// * _macd_slow_ - period of the slow EMA of the MACD (integer greater than 0). Default is 50.
+/// * _cycle_ - number of values of the stochastics (integer greater than 0). Default is 10.
+/// * _d1_ - period of the EMA of the first stochastic (integer greater than 0). Default is 3.
+/// * _d2_ - period of the EMA of the second stochastic (integer greater than 0). Default is 3.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::SchaffTrendCycle;
+/// use tam::{Next, Warmup};
+///
+/// let mut stc = SchaffTrendCycle::new(3, 6, 4, 3, 3).unwrap();
+/// assert_eq!(stc.lookback(), 15);
+///
+/// let prices: Vec<f64> = (0..30).map(|i| 10.0 + 5.0 * (i as f64 * 0.35).sin()).collect();
+/// let outputs: Vec<f64> = prices.iter().map(|&p| stc.next(p)).collect();
+/// assert!(outputs[..15].iter().all(|v| v.is_nan()));
+/// // 100 while the price rises, then falls to 0 quickly once it turns at bar 22
+/// assert!(outputs[15..22].iter().all(|&v| v > 99.0));
+/// assert!(outputs[29] < 1.0);
+/// ```
+///
+/// # Links
+///
+/// * [Schaff Trend Cycle, Investopedia](https://www.investopedia.com/articles/forex/10/schaff-trend-cycle-indicator.asp)
+///
+#[doc(alias = "STC")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SchaffTrendCycle {
+    fast_ema: Ema,
+    slow_ema: Ema,
+    cycle: usize,
+    macd_max: Maximum,
+    macd_min: Minimum,
+    d1_ema: Ema,
+    d1_max: Maximum,
+    d1_min: Minimum,
+    d2_ema: Ema,
+    k1: f64,
+    k2: f64,
+    count: usize,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl SchaffTrendCycle {
+    pub fn new(
+        macd_fast: usize,
+        macd_slow: usize,
+        cycle: usize,
+        d1: usize,
+        d2: usize,
+    ) -> Result<Self> {
+        let ema = |period| Ok(Ema::new(period)?.with_seed(EmaSeed::Sma));
+        Ok(Self {
+            fast_ema: ema(macd_fast)?,
+            slow_ema: ema(macd_slow)?,
+            cycle,
+            macd_max: Maximum::new(cycle)?,
+            macd_min: Minimum::new(cycle)?,
+            d1_ema: ema(d1)?,
+            d1_max: Maximum::new(cycle)?,
+            d1_min: Minimum::new(cycle)?,
+            d2_ema: ema(d2)?,
+            k1: 50.0,
+            k2: 50.0,
+            count: 0,
+            last_output: None,
+        })
+    }
+
+    /// Number of inputs before the MACD, %K<sub>1</sub>, %D<sub>1</sub> and %K<sub>2</sub>
+    /// are valid.
+    fn stage_lookbacks(&self) -> [usize; 4] {
+        let macd = self.fast_ema.lookback().max(self.slow_ema.lookback());
+        let k1 = macd + self.cycle - 1;
+        let d1 = k1 + self.d1_ema.lookback();
+        [macd, k1, d1, d1 + self.cycle - 1]
+    }
+}
+
+/// Stochastic of `value` in [min, max], `prev` if the range is empty.
+fn stochastic(value: f64, min: f64, max: f64, prev: f64) -> f64 {
+    if max > min {
+        100.0 * (value - min) / (max - min)
+    } else {
+        prev
+    }
+}
+
+impl Periods for SchaffTrendCycle {
+    fn periods(&self) -> Vec<usize> {
+        vec![
+            self.fast_ema.period(),
+            self.slow_ema.period(),
+            self.cycle,
+            self.d1_ema.period(),
+            self.d2_ema.period(),
+        ]
+    }
+}
+
+impl Warmup for SchaffTrendCycle {
+    fn lookback(&self) -> usize {
+        self.stage_lookbacks()[3] + self.d2_ema.lookback()
+    }
+}
+
+impl SchaffTrendCycle {
+    fn next_value(&mut self, input: f64) -> f64 {
+        let [macd_ready, k1_ready, d1_ready, k2_ready] = self.stage_lookbacks();
+        let index = self.count;
+        if self.count < self.lookback() {
+            self.count += 1;
+        }
+
+        let macd = self.fast_ema.next(input) - self.slow_ema.next(input);
+        if index < macd_ready {
+            return f64::NAN;
+        }
+
+        let (max, min) = (self.macd_max.next(macd), self.macd_min.next(macd));
+        if index < k1_ready {
+            return f64::NAN;
+        }
+        self.k1 = stochastic(macd, min, max, self.k1);
+
+        let d1 = self.d1_ema.next(self.k1);
+        if index < d1_ready {
+            return f64::NAN;
+        }
+
+        let (max, min) = (self.d1_max.next(d1), self.d1_min.next(d1));
+        if index < k2_ready {
+            return f64::NAN;
+        }
+        self.k2 = stochastic(d1, min, max, self.k2);
+
+        self.d2_ema.next(self.k2)
+    }
+}
+
+impl_next_option!(SchaffTrendCycle);
+
+impl<T: Close> Next<&T> for SchaffTrendCycle {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for SchaffTrendCycle {
+    fn reset(&mut self) {
+        self.fast_ema.reset();
+        self.slow_ema.reset();
+        self.macd_max.reset();
+        self.macd_min.reset();
+        self.d1_ema.reset();
+        self.d1_max.reset();
+        self.d1_min.reset();
+        self.d2_ema.reset();
+        self.k1 = 50.0;
+        self.k2 = 50.0;
+        self.count = 0;
+        self.last_output = None;
+    }
+}
+
+impl Default for SchaffTrendCycle {
+    fn default() -> Self {
+        Self::new(23, 50, 10, 3, 3).unwrap()
+    }
+}
+
+impl fmt::Display for SchaffTrendCycle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "STC({}, {}, {})",
+            self.fast_ema.period(),
+            self.slow_ema.period(),
+            self.cycle
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn cycles(n: usize) -> Vec<f64> {
+        (0..n)
+            .map(|i| 100.0 + 10.0 * (i as f64 * 2.0 * core::f64::consts::PI / 40.0).sin())
+            .collect()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(SchaffTrendCycle::new(0, 50, 10, 3, 3).is_err());
+        assert!(SchaffTrendCycle::new(23, 0, 10, 3, 3).is_err());
+        assert!(SchaffTrendCycle::new(23, 50, 0, 3, 3).is_err());
+        assert!(SchaffTrendCycle::new(23, 50, 10, 0, 3).is_err());
+        assert!(SchaffTrendCycle::new(23, 50, 10, 3, 0).is_err());
+        assert!(SchaffTrendCycle::new(1, 1, 1, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        // without smoothing and with a cycle of 2, each stochastic is 100 on a rise and 0 on
+        // a fall of its input: the STC follows the direction of the MACD
+        let mut stc = SchaffTrendCycle::new(1, 2, 2, 1, 1).unwrap();
+        assert_eq!(stc.lookback(), 3);
+
+        // MACD (p - EMA(2)): NaN, 1, 1.333, 0.111, 0.037, 1.346
+        let prices = [1.0, 3.0, 6.0, 5.0, 5.0, 9.0];
+        let outputs: Vec<f64> = prices
+            .iter()
+            .map(|&p| stc.next(&Bar::new().close(p)))
+            .collect();
+        assert!(outputs[..3].iter().all(|v| v.is_nan()));
+        assert_eq!(outputs[3..], [0.0, 0.0, 100.0]);
+    }
+
+    #[test]
+    fn test_warmup() {
+        let mut stc = SchaffTrendCycle::default();
+        // 49 for the MACD, 9 + 9 for the stochastics, 2 + 2 for their EMAs
+        assert_eq!(stc.lookback(), 71);
+
+        let outputs: Vec<f64> = cycles(200).into_iter().map(|p| stc.next(p)).collect();
+        assert_eq!(warmup_nan_count(&outputs), 71);
+        assert!(outputs[71..].iter().all(|&v| (0.0..=100.0).contains(&v)));
+    }
+
+    #[test]
+    fn test_cycles() {
+        // the STC swings between its extremes with the price cycles
+        let mut stc = SchaffTrendCycle::new(5, 10, 5, 3, 3).unwrap();
+        let outputs: Vec<f64> = cycles(200).into_iter().map(|p| stc.next(p)).collect();
+        let tail = &outputs[80..];
+        assert!(tail.iter().any(|&v| v > 99.0));
+        assert!(tail.iter().any(|&v| v < 1.0));
+    }
+
+    #[test]
+    fn test_flat() {
+        let mut stc = SchaffTrendCycle::new(2, 3, 2, 2, 2).unwrap();
+        for _ in 0..stc.lookback() {
+            assert!(stc.next(10.0).is_nan());
+        }
+        assert_eq!(stc.next(10.0), 50.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut stc = SchaffTrendCycle::new(3, 6, 4, 3, 3).unwrap();
+        let first: Vec<f64> = cycles(40).into_iter().map(|p| stc.next(p)).collect();
+
+        stc.reset();
+        let second: Vec<f64> = cycles(40).into_iter().map(|p| stc.next(p)).collect();
+        assert_eq!(first[15..], second[15..]);
+        assert_eq!(warmup_nan_count(&second), 15);
+    }
+
+    #[test]
+    fn test_default() {
+        SchaffTrendCycle::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            format!("{}", SchaffTrendCycle::default()),
+            "STC(23, 50, 10)"
+        );
+    }
+}
@@ -1,7 +1,11 @@
-use std::fmt;
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "deterministic")]
+use crate::helpers::ordered_sum;
+use crate::helpers::{batch, warmup_tail};
+use crate::{Close, Next, Period, Reset, Warmup};
 use serde::{Deserialize, Serialize};
 
 /// Simple moving average (SMA).
@@ -20,6 +24,10 @@ use serde::{Deserialize, Serialize};
 ///
 /// * _period_ - number of periods (integer greater than 0)
 ///
+/// With the `deterministic` feature the sum is recomputed over the window, oldest value first,
+/// on every input instead of being updated incrementally. This costs O(_period_) per input but
+/// makes every output bit-identical to a plain left-to-right average of the window.
+///
 /// # Example
 ///
 /// ```
@@ -44,7 +52,11 @@ pub struct SimpleMovingAverage {
     index: usize,
     count: usize,
     sum: f64,
-    deque: Box<[f64]>,
+    /// Ring buffer written twice, at `i` and `i + period`, so the window is always a
+    /// contiguous slice.
+    buffer: Box<[f64]>,
+    #[serde(default)]
+    last_output: Option<f64>,
 }
 
 impl SimpleMovingAverage {
@@ -56,10 +68,55 @@ impl SimpleMovingAverage {
                 index: 0,
                 count: 0,
                 sum: 0.0,
-                deque: vec![0.0; period].into_boxed_slice(),
+                buffer: vec![0.0; 2 * period].into_boxed_slice(),
+                last_output: None,
             }),
         }
     }
+
+    /// Creates an SMA warmed up with the last `period - 1` values of `warmup`, so the next
+    /// call of `next` already averages a full window.
+    ///
+    /// Returns `TaError::InsufficientData` if `warmup` has fewer than `period - 1` values.
+    pub fn ready(period: usize, warmup: &[f64]) -> Result<Self> {
+        let mut sma = Self::new(period)?;
+        for &value in warmup_tail(warmup, sma.lookback())? {
+            sma.next(value);
+        }
+        Ok(sma)
+    }
+
+    /// Feeds `history` to the SMA and discards the outputs, e.g. to resume a live feed from
+    /// the last bars.
+    ///
+    /// Only the last `period` values matter. With fewer than `period - 1` values, the next
+    /// outputs still average a partial window.
+    pub fn seed<I: IntoIterator<Item = f64>>(&mut self, history: I) {
+        for value in history {
+            self.next(value);
+        }
+    }
+
+    /// Computes the SMA of every value of `inputs`.
+    ///
+    /// The first `period - 1` outputs are NaN (see [Warmup](../trait.Warmup.html)), so all of
+    /// them are NaN if `period` is larger than the number of inputs.
+    ///
+    /// Returns `TaError::InvalidParameter` if `period` is 0.
+    pub fn calculate(period: usize, inputs: &[f64]) -> Result<Vec<f64>> {
+        let mut sma = Self::new(period)?;
+        Ok(batch(sma.lookback(), inputs, |&value| sma.next(value)))
+    }
+
+    /// Values of the window from the oldest to the newest, fewer than `period` of them during
+    /// the warmup.
+    pub fn window(&self) -> &[f64] {
+        if self.count < self.period {
+            &self.buffer[..self.count]
+        } else {
+            &self.buffer[self.index..self.index + self.period]
+        }
+    }
 }
 
 impl Period for SimpleMovingAverage {
@@ -68,12 +125,18 @@ impl Period for SimpleMovingAverage {
     }
 }
 
-impl Next<f64> for SimpleMovingAverage {
-    type Output = f64;
+impl Warmup for SimpleMovingAverage {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
 
-    fn next(&mut self, input: f64) -> Self::Output {
-        let old_val = self.deque[self.index];
-        self.deque[self.index] = input;
+impl SimpleMovingAverage {
+    fn next_value(&mut self, input: f64) -> f64 {
+        #[cfg(not(feature = "deterministic"))]
+        let old_val = self.buffer[self.index];
+        self.buffer[self.index] = input;
+        self.buffer[self.index + self.period] = input;
 
         self.index = if self.index + 1 < self.period {
             self.index + 1
@@ -85,11 +148,20 @@ impl Next<f64> for SimpleMovingAverage {
             self.count += 1;
         }
 
-        self.sum = self.sum - old_val + input;
+        #[cfg(not(feature = "deterministic"))]
+        {
+            self.sum = self.sum - old_val + input;
+        }
+        #[cfg(feature = "deterministic")]
+        {
+            self.sum = ordered_sum(self.window().iter().copied());
+        }
         self.sum / (self.count as f64)
     }
 }
 
+impl_next_option!(SimpleMovingAverage);
+
 impl<T: Close> Next<&T> for SimpleMovingAverage {
     type Output = f64;
 
@@ -103,9 +175,8 @@ impl Reset for SimpleMovingAverage {
         self.index = 0;
         self.count = 0;
         self.sum = 0.0;
-        for i in 0..self.period {
-            self.deque[i] = 0.0;
-        }
+        self.buffer.fill(0.0);
+        self.last_output = None;
     }
 }
 
@@ -134,6 +205,92 @@ mod tests {
         assert!(SimpleMovingAverage::new(1).is_ok());
     }
 
+    #[test]
+    fn test_calculate() {
+        assert_eq!(
+            SimpleMovingAverage::calculate(0, &[1.0, 2.0]),
+            Err(TaError::InvalidParameter)
+        );
+
+        // period > len: no valid output ever appears
+        let inputs = [1.0, 2.0, 3.0];
+        assert!(SimpleMovingAverage::new(4).unwrap().lookback() >= inputs.len());
+        let out = SimpleMovingAverage::calculate(4, &inputs).unwrap();
+        assert_eq!(out.len(), 3);
+        assert!(out.iter().all(|v| v.is_nan()));
+
+        // period == len: only the last output is valid
+        let out = SimpleMovingAverage::calculate(3, &[1.0, 2.0, 6.0]).unwrap();
+        assert!(out[0].is_nan() && out[1].is_nan());
+        assert_eq!(out[2], 3.0);
+
+        let out = SimpleMovingAverage::calculate(2, &[1.0, 3.0, 7.0]).unwrap();
+        assert_eq!(&out[1..], &[2.0, 5.0]);
+        assert!(SimpleMovingAverage::calculate(2, &[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_ready() {
+        assert_eq!(
+            SimpleMovingAverage::ready(0, &[]),
+            Err(TaError::InvalidParameter)
+        );
+        assert_eq!(
+            SimpleMovingAverage::ready(3, &[1.0]),
+            Err(TaError::InsufficientData { needed: 2, got: 1 })
+        );
+
+        let mut sma = SimpleMovingAverage::ready(3, &[1.0, 2.0]).unwrap();
+        assert_eq!(sma.next(6.0), 3.0);
+
+        // only the last `period - 1` values are used
+        let mut sma = SimpleMovingAverage::ready(3, &[100.0, 1.0, 2.0]).unwrap();
+        assert_eq!(sma.next(6.0), 3.0);
+
+        let mut sma = SimpleMovingAverage::ready(1, &[]).unwrap();
+        assert_eq!(sma.next(6.0), 6.0);
+    }
+
+    #[test]
+    fn test_window() {
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+        assert!(sma.window().is_empty());
+
+        sma.next(1.0);
+        sma.next(2.0);
+        assert_eq!(sma.window(), &[1.0, 2.0]);
+
+        // every rotation of the ring buffer
+        let mut expected = vec![1.0, 2.0];
+        for value in 3..10 {
+            sma.next(value as f64);
+            expected.push(value as f64);
+            assert_eq!(sma.window(), &expected[expected.len() - 3..]);
+        }
+
+        sma.reset();
+        assert!(sma.window().is_empty());
+    }
+
+    #[test]
+    fn test_seed() {
+        let history = [4.0, 7.0, 1.0, 3.0, 9.0, 2.0];
+
+        let mut full = SimpleMovingAverage::new(3).unwrap();
+        for &value in history.iter() {
+            full.next(value);
+        }
+        let mut seeded = SimpleMovingAverage::new(3).unwrap();
+        seeded.seed(history.iter().copied());
+        assert_eq!(seeded.next(5.0), full.next(5.0));
+
+        // too short a history leaves a partial window
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+        sma.seed(vec![1.0]);
+        assert_eq!(sma.next(2.0), 1.5);
+        assert_eq!(sma.next(6.0), 3.0);
+    }
+
     #[test]
     fn test_next() {
         let mut sma = SimpleMovingAverage::new(4).unwrap();
@@ -159,6 +316,30 @@ mod tests {
         assert_eq!(sma.next(&bar(1.0)), 4.0);
     }
 
+    #[test]
+    fn test_next_option() {
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+
+        assert!(sma.next(None).is_nan());
+        assert_eq!(sma.next(Some(3.0)), 3.0);
+        assert_eq!(sma.next(Some(6.0)), 4.5);
+        // gaps repeat the last output and do not enter the window
+        assert_eq!(sma.next(None), 4.5);
+        assert_eq!(sma.next(None), 4.5);
+        assert_eq!(sma.next(Some(9.0)), 6.0);
+        assert_eq!(sma.next(None), 6.0);
+        // 3.0 leaves the window only now
+        assert_eq!(sma.next(Some(12.0)), 9.0);
+
+        sma.reset();
+        assert!(sma.next(None).is_nan());
+        // plain inputs are remembered too
+        assert_eq!(sma.next(6.0), 6.0);
+        assert_eq!(sma.next(None), 6.0);
+        assert_eq!(sma.next(&Bar::new().close(9.0)), 7.5);
+        assert_eq!(sma.next(None), 7.5);
+    }
+
     #[test]
     fn test_reset() {
         let mut sma = SimpleMovingAverage::new(4).unwrap();
@@ -180,4 +361,23 @@ mod tests {
         let sma = SimpleMovingAverage::new(5).unwrap();
         assert_eq!(format!("{}", sma), "SMA(5)");
     }
+
+    #[cfg(feature = "deterministic")]
+    #[test]
+    fn test_deterministic() {
+        let inputs: Vec<f64> = (0..50)
+            .map(|i| if i % 7 == 0 { 1e12 } else { 0.1 * i as f64 })
+            .collect();
+        let mut sma = SimpleMovingAverage::new(5).unwrap();
+
+        for (i, &input) in inputs.iter().enumerate() {
+            let window = &inputs[(i + 1).saturating_sub(5)..=i];
+            let mut sum = 0.0;
+            for &value in window {
+                sum += value;
+            }
+            let expected = sum / window.len() as f64;
+            assert_eq!(sma.next(input).to_bits(), expected.to_bits());
+        }
+    }
 }
@@ -0,0 +1,257 @@
+use core::f64::consts::PI;
+use core::fmt;
+
+use crate::indicators::hilbert::HilbertCycle;
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float;
+use crate::{Close, Next, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Number of inputs before the first output, as in TA-Lib.
+const LOOKBACK: usize = 63;
+
+/// Ehlers Sine Wave.
+///
+/// Measures the phase of the dominant cycle of the price with the Hilbert transform and
+/// outputs its sine, and the sine of the phase advanced by 45°. In a cycling market the two
+/// lines cross shortly before the cycle turns; in a trending market the phase stalls and the
+/// lines run side by side.
+///
+/// # Formula
+///
+/// The dominant cycle period _n_ is measured with a homodyne discriminator on the Hilbert
+/// transform of the smoothed price _s_. Its phase is then
+///
+/// Re = Σ sin(360° * i / _n_) * s<sub>t-i</sub>, Im = Σ cos(360° * i / _n_) * s<sub>t-i</sub>, for _i_ in 0.._n_
+///
+/// Phase = atan(Re / Im) + 90° + 360° / _n_ (+ 180° if Im < 0)
+///
+/// Sine = sin(Phase)
+///
+/// Lead Sine = sin(Phase + 45°)
+///
+/// Both outputs are NaN for the first 63 inputs, like TA-Lib's `HT_SINE`.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::SineWave;
+/// use tam::Next;
+///
+/// let mut sine_wave = SineWave::new();
+/// let mut out = sine_wave.next(10.0);
+/// assert!(out.sine.is_nan());
+///
+/// for i in 0..100 {
+///     out = sine_wave.next(10.0 + (i as f64 / 3.0).sin());
+/// }
+/// assert!(out.sine.abs() <= 1.0);
+/// assert!(out.lead_sine.abs() <= 1.0);
+/// ```
+///
+/// # Links
+///
+/// * [Hilbert Transform - SineWave, TA-Lib](https://ta-lib.org/functions/ht_sine/)
+/// * _Rocket Science for Traders_, John Ehlers
+///
+#[doc(alias = "HT_SINE")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SineWave {
+    hilbert: HilbertCycle,
+    phase: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SineWaveOutput {
+    pub sine: f64,
+    pub lead_sine: f64,
+}
+
+impl From<SineWaveOutput> for (f64, f64) {
+    fn from(out: SineWaveOutput) -> Self {
+        (out.sine, out.lead_sine)
+    }
+}
+
+impl_output!(SineWaveOutput, sine, lead_sine);
+
+impl SineWave {
+    pub fn new() -> Self {
+        Self {
+            hilbert: HilbertCycle::new(),
+            phase: 0.0,
+        }
+    }
+
+    /// Phase of the dominant cycle in degrees, in (-45, 315].
+    fn update_phase(&mut self) {
+        let smooth_period = self.hilbert.smooth_period();
+        let period = (smooth_period + 0.5) as usize;
+
+        let (mut re, mut im) = (0.0, 0.0);
+        for (i, value) in self.hilbert.smooth().iter().take(period).enumerate() {
+            let angle = 2.0 * PI * i as f64 / period as f64;
+            re += angle.sin() * value;
+            im += angle.cos() * value;
+        }
+
+        if im.abs() > 0.0 {
+            self.phase = (re / im).atan().to_degrees();
+        } else if re < 0.0 {
+            self.phase -= 90.0;
+        } else if re > 0.0 {
+            self.phase += 90.0;
+        }
+        self.phase += 90.0;
+        // compensates the lag of the smoothing
+        self.phase += 360.0 / smooth_period;
+        if im < 0.0 {
+            self.phase += 180.0;
+        }
+        if self.phase > 315.0 {
+            self.phase -= 360.0;
+        }
+    }
+}
+
+impl Warmup for SineWave {
+    fn lookback(&self) -> usize {
+        LOOKBACK
+    }
+}
+
+impl Next<f64> for SineWave {
+    type Output = SineWaveOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.hilbert.next(input);
+        if self.hilbert.count() < 4 {
+            return SineWaveOutput {
+                sine: f64::NAN,
+                lead_sine: f64::NAN,
+            };
+        }
+
+        self.update_phase();
+        if self.hilbert.count() <= LOOKBACK {
+            return SineWaveOutput {
+                sine: f64::NAN,
+                lead_sine: f64::NAN,
+            };
+        }
+
+        SineWaveOutput {
+            sine: self.phase.to_radians().sin(),
+            lead_sine: (self.phase + 45.0).to_radians().sin(),
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for SineWave {
+    type Output = SineWaveOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for SineWave {
+    fn reset(&mut self) {
+        self.hilbert.reset();
+        self.phase = 0.0;
+    }
+}
+
+impl Default for SineWave {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for SineWave {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SINE")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn cycle(i: usize) -> f64 {
+        100.0 + 10.0 * (2.0 * PI * i as f64 / 20.0).sin()
+    }
+
+    #[test]
+    fn test_warmup() {
+        let mut sine_wave = SineWave::new();
+        for i in 0..LOOKBACK {
+            let out = sine_wave.next(cycle(i));
+            assert!(out.sine.is_nan() && out.lead_sine.is_nan(), "bar {}", i);
+        }
+        let out = sine_wave.next(&Bar::new().close(cycle(LOOKBACK)));
+        assert!(!out.sine.is_nan() && !out.lead_sine.is_nan());
+    }
+
+    #[test]
+    fn test_lead_45_degrees() {
+        let mut sine_wave = SineWave::new();
+        for i in 0..300 {
+            let out = sine_wave.next(cycle(i));
+            if i < LOOKBACK {
+                continue;
+            }
+            // sin(x + 45°) = (sin x + cos x) / √2, one of the two signs of cos x matches
+            let cos = (1.0 - out.sine * out.sine).sqrt();
+            let lead = [1.0, -1.0]
+                .iter()
+                .map(|sign| (out.sine + sign * cos) / 2f64.sqrt())
+                .map(|lead| (lead - out.lead_sine).abs())
+                .fold(f64::INFINITY, f64::min);
+            assert!(lead < 1e-9, "bar {}", i);
+        }
+    }
+
+    #[test]
+    fn test_clean_cycle() {
+        // once locked on the 20 bar cycle, the phase advances by ~18° per bar and the sine
+        // completes one period every 20 bars
+        let mut sine_wave = SineWave::new();
+        let mut crossings = Vec::new();
+        let mut prev = f64::NAN;
+        for i in 0..300 {
+            let out = sine_wave.next(cycle(i));
+            if prev < 0.0 && out.sine >= 0.0 && i >= 100 {
+                crossings.push(i);
+            }
+            prev = out.sine;
+        }
+        assert!(crossings.len() >= 9);
+        for pair in crossings.windows(2) {
+            let distance = pair[1] - pair[0];
+            assert!((19..=21).contains(&distance), "{:?}", crossings);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sine_wave = SineWave::new();
+        let first: Vec<SineWaveOutput> = (0..80).map(|i| sine_wave.next(cycle(i))).collect();
+
+        sine_wave.reset();
+        let second: Vec<SineWaveOutput> = (0..80).map(|i| sine_wave.next(cycle(i))).collect();
+        assert_eq!(format!("{:?}", first), format!("{:?}", second));
+    }
+
+    #[test]
+    fn test_default() {
+        SineWave::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", SineWave::new()), "SINE");
+    }
+}
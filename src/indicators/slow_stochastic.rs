@@ -1,8 +1,9 @@
-use std::fmt;
+use alloc::{vec, vec::Vec};
+use core::fmt;
 
 use crate::errors::Result;
 use crate::indicators::{ExponentialMovingAverage, FastStochastic};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::{Close, High, Low, Next, Period, Periods, Reset};
 use serde::{Deserialize, Serialize};
 
 /// Slow stochastic oscillator.
@@ -31,6 +32,8 @@ use serde::{Deserialize, Serialize};
 pub struct SlowStochastic {
     fast_stochastic: FastStochastic,
     ema: ExponentialMovingAverage,
+    #[serde(default)]
+    last_output: Option<f64>,
 }
 
 impl SlowStochastic {
@@ -38,23 +41,32 @@ impl SlowStochastic {
         Ok(Self {
             fast_stochastic: FastStochastic::new(stochastic_period)?,
             ema: ExponentialMovingAverage::new(ema_period)?,
+            last_output: None,
         })
     }
 }
 
-impl Next<f64> for SlowStochastic {
-    type Output = f64;
+impl Periods for SlowStochastic {
+    fn periods(&self) -> Vec<usize> {
+        vec![self.fast_stochastic.period(), self.ema.period()]
+    }
+}
 
-    fn next(&mut self, input: f64) -> Self::Output {
+impl SlowStochastic {
+    fn next_value(&mut self, input: f64) -> f64 {
         self.ema.next(self.fast_stochastic.next(input))
     }
 }
 
+impl_next_option!(SlowStochastic);
+
 impl<T: High + Low + Close> Next<&T> for SlowStochastic {
     type Output = f64;
 
     fn next(&mut self, input: &T) -> Self::Output {
-        self.ema.next(self.fast_stochastic.next(input))
+        let output = self.ema.next(self.fast_stochastic.next(input));
+        self.last_output = Some(output);
+        output
     }
 }
 
@@ -62,6 +74,7 @@ impl Reset for SlowStochastic {
     fn reset(&mut self) {
         self.fast_stochastic.reset();
         self.ema.reset();
+        self.last_output = None;
     }
 }
 
@@ -0,0 +1,241 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Smoothed moving average (SMMA), also known as Wilder's smoothing or running moving average
+/// (RMA).
+///
+/// The smoothing of the RSI, the ATR and the ADX: an
+/// [EMA](struct.ExponentialMovingAverage.html) with a slower α of 1 / _period_, seeded with
+/// the simple average of the first _period_ inputs.
+///
+/// # Formula
+///
+/// SMMA<sub>_period_-1</sub> = SMA(p<sub>0</sub>, ..., p<sub>_period_-1</sub>)
+///
+/// SMMA<sub>t</sub> = (SMMA<sub>t-1</sub> * (_period_ - 1) + p<sub>t</sub>) / _period_
+///
+/// The first _period_ - 1 outputs are NaN.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::SmoothedMovingAverage;
+/// use tam::Next;
+///
+/// let mut smma = SmoothedMovingAverage::new(3).unwrap();
+/// assert!(smma.next(1.0).is_nan());
+/// assert!(smma.next(2.0).is_nan());
+/// assert_eq!(smma.next(6.0), 3.0);
+/// // (3 * 2 + 9) / 3
+/// assert_eq!(smma.next(9.0), 5.0);
+/// ```
+///
+/// # Links
+///
+/// * [Modified moving average, Wikipedia](https://en.wikipedia.org/wiki/Moving_average#Modified_moving_average)
+///
+#[doc(alias = "SMMA")]
+#[doc(alias = "RMA")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct SmoothedMovingAverage {
+    ema: Ema,
+}
+
+impl SmoothedMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            ema: Ema::wilder(period)?,
+        })
+    }
+
+    /// Recomputes the smoothed value from `recent` history, discarding the accumulated state,
+    /// see [ExponentialMovingAverage::recalibrate](struct.ExponentialMovingAverage.html#method.recalibrate).
+    ///
+    /// The SMMA is seeded again with the average of the first _period_ values of `recent`, so
+    /// it needs at least _period_ of them to produce a value. Does nothing if `recent` is empty.
+    pub fn recalibrate(&mut self, recent: &[f64]) {
+        self.ema.recalibrate(recent);
+    }
+}
+
+impl Period for SmoothedMovingAverage {
+    fn period(&self) -> usize {
+        self.ema.period()
+    }
+}
+
+impl Warmup for SmoothedMovingAverage {
+    fn lookback(&self) -> usize {
+        self.ema.lookback()
+    }
+}
+
+impl Next<f64> for SmoothedMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.ema.next(input)
+    }
+}
+
+impl Next<Option<f64>> for SmoothedMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: Option<f64>) -> Self::Output {
+        self.ema.next(input)
+    }
+}
+
+impl<T: Close> Next<&T> for SmoothedMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for SmoothedMovingAverage {
+    fn reset(&mut self) {
+        self.ema.reset();
+    }
+}
+
+impl Default for SmoothedMovingAverage {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for SmoothedMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SMMA({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::RelativeStrengthIndex;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(SmoothedMovingAverage::new(0).is_err());
+        assert!(SmoothedMovingAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut smma = SmoothedMovingAverage::new(4).unwrap();
+        assert_eq!(smma.lookback(), 3);
+
+        for &value in [2.0, 4.0, 6.0].iter() {
+            assert!(smma.next(value).is_nan());
+        }
+        assert_eq!(smma.next(&Bar::new().close(8)), 5.0);
+        // (5 * 3 + 1) / 4
+        assert_eq!(smma.next(1.0), 4.0);
+        assert_eq!(smma.next(8.0), 5.0);
+    }
+
+    #[test]
+    fn test_period_1() {
+        let mut smma = SmoothedMovingAverage::new(1).unwrap();
+        for &value in [4.0, -1.0, 7.5].iter() {
+            assert_eq!(smma.next(value), value);
+        }
+    }
+
+    #[test]
+    fn test_matches_rsi() {
+        let prices = [
+            44.34, 44.09, 44.15, 43.61, 44.33, 44.83, 45.10, 45.42, 45.84, 46.08, 45.89, 46.03,
+            45.61, 46.28, 46.28, 46.00, 46.03, 46.41, 46.22, 45.64,
+        ];
+        let mut rsi = RelativeStrengthIndex::new(5).unwrap();
+        let mut avg_gain = SmoothedMovingAverage::new(5).unwrap();
+        let mut avg_loss = SmoothedMovingAverage::new(5).unwrap();
+
+        rsi.next(prices[0]);
+        for pair in prices.windows(2) {
+            let change = pair[1] - pair[0];
+            let gain = avg_gain.next(change.max(0.0));
+            let loss = avg_loss.next((-change).max(0.0));
+            let expected = 100.0 - 100.0 / (1.0 + gain / loss);
+
+            let value = rsi.next(pair[1]);
+            if expected.is_nan() {
+                assert!(value.is_nan());
+            } else {
+                assert!((value - expected).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut smma = SmoothedMovingAverage::new(2).unwrap();
+        smma.next(2.0);
+        smma.next(4.0);
+
+        smma.reset();
+        assert!(smma.next(10.0).is_nan());
+        assert_eq!(smma.next(20.0), 15.0);
+    }
+
+    #[test]
+    fn test_recalibrate() {
+        let mut smma = SmoothedMovingAverage::new(10).unwrap();
+        smma.next(1e9);
+        for _ in 0..1_000_000 {
+            smma.next(0.1);
+        }
+        // the rounding error of the spike is never washed out
+        let mut drifted = smma.clone();
+        assert_ne!(drifted.next(0.1), 0.1);
+
+        smma.recalibrate(&[0.1; 30]);
+        assert_eq!(smma.next(0.1), 0.1);
+        assert_ne!(smma, drifted);
+
+        // empty history keeps the current state
+        smma.recalibrate(&[]);
+        assert_eq!(smma.next(0.1), 0.1);
+
+        // seeded with the average of the first 3 values
+        let mut smma = SmoothedMovingAverage::new(3).unwrap();
+        smma.recalibrate(&[1.0, 2.0]);
+        assert!(smma.next(None).is_nan());
+        smma.recalibrate(&[1.0, 2.0, 6.0]);
+        assert_eq!(smma.next(9.0), 5.0);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut smma = SmoothedMovingAverage::new(3).unwrap();
+        for &value in [1.0, 2.0, 6.0].iter() {
+            smma.next(value);
+        }
+        let mut restored = serde_round_trip(&smma);
+        assert_eq!(restored.next(9.0), smma.next(9.0));
+    }
+
+    #[test]
+    fn test_default() {
+        SmoothedMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", SmoothedMovingAverage::default()), "SMMA(14)");
+    }
+}
@@ -0,0 +1,238 @@
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::linear_regression::RollingRegression;
+use crate::indicators::{BollingerBands, KeltnerChannel, Maximum, Minimum, SimpleMovingAverage};
+use crate::{Close, High, Low, Next, Period, Periods, Reset};
+use serde::{Deserialize, Serialize};
+
+/// Squeeze Momentum (TTM Squeeze).
+///
+/// Detects periods of low volatility that often precede strong moves. The squeeze is on when
+/// the [Bollinger Bands](struct.BollingerBands.html) are inside the
+/// [Keltner Channel](struct.KeltnerChannel.html). The momentum tells the direction of the
+/// breakout: it is the linear regression value of the distance between the close and the
+/// middle of the recent price range.
+///
+/// # Formula
+///
+/// Squeeze on = BB<sub>lower</sub> > KC<sub>lower</sub> and BB<sub>upper</sub> < KC<sub>upper</sub>
+///
+/// Delta = Close - ((Highest High + Lowest Low) / 2 + SMA(Close)) / 2
+///
+/// Momentum = LinReg(Delta)
+///
+/// Where the highest high, lowest low, SMA and the linear regression use _kc_period_ bars, and
+/// LinReg is the value of the least squares line at the latest bar.
+///
+/// # Parameters
+///
+/// * _bb_period_ - period of the Bollinger Bands (integer greater than 0). Default is 20.
+/// * _bb_mult_ - multiplier of the Bollinger Bands. Default is 2.0.
+/// * _kc_period_ - period of the Keltner Channel and the momentum (integer greater than 0). Default is 20.
+/// * _kc_mult_ - multiplier of the Keltner Channel. Default is 1.5.
+///
+/// The momentum is NaN and the squeeze is off until both periods are filled.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::SqueezeMomentum;
+/// use tam::{DataItem, Next};
+///
+/// let mut squeeze = SqueezeMomentum::new(3, 2.0, 3, 1.5).unwrap();
+/// let bar = DataItem::builder()
+///     .open(10.0).high(11.0).low(9.0).close(10.0).volume(1.0).build().unwrap();
+///
+/// squeeze.next(&bar);
+/// squeeze.next(&bar);
+/// let out = squeeze.next(&bar);
+/// assert!(out.squeeze_on);
+/// assert_eq!(out.momentum, 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [TTM Squeeze, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:ttm_squeeze)
+///
+#[doc(alias = "TTM")]
+#[doc(alias = "SQUEEZE")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SqueezeMomentum {
+    bb_mult: f64,
+    kc_mult: f64,
+    bb: BollingerBands,
+    kc: KeltnerChannel,
+    highest: Maximum,
+    lowest: Minimum,
+    sma: SimpleMovingAverage,
+    deltas: RollingRegression,
+    warmup: usize,
+    count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SqueezeOutput {
+    pub momentum: f64,
+    pub squeeze_on: bool,
+}
+
+impl From<SqueezeOutput> for (f64, bool) {
+    fn from(out: SqueezeOutput) -> Self {
+        (out.momentum, out.squeeze_on)
+    }
+}
+
+impl_output!(SqueezeOutput, momentum, squeeze_on);
+
+impl SqueezeMomentum {
+    pub fn new(bb_period: usize, bb_mult: f64, kc_period: usize, kc_mult: f64) -> Result<Self> {
+        Ok(Self {
+            bb_mult,
+            kc_mult,
+            bb: BollingerBands::new(bb_period, bb_mult)?,
+            kc: KeltnerChannel::new(kc_period, kc_mult)?,
+            highest: Maximum::new(kc_period)?,
+            lowest: Minimum::new(kc_period)?,
+            sma: SimpleMovingAverage::new(kc_period)?,
+            deltas: RollingRegression::new(kc_period),
+            warmup: bb_period.max(kc_period),
+            count: 0,
+        })
+    }
+
+    fn kc_period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl Periods for SqueezeMomentum {
+    fn periods(&self) -> Vec<usize> {
+        vec![self.bb.period(), self.kc.period()]
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for SqueezeMomentum {
+    type Output = SqueezeOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let bb = self.bb.next(input);
+        let kc = self.kc.next(input);
+        let highest = self.highest.next(input);
+        let lowest = self.lowest.next(input);
+        let sma = self.sma.next(input);
+
+        let delta = input.close() - ((highest + lowest) / 2.0 + sma) / 2.0;
+        self.deltas.push(delta);
+
+        if self.count < self.warmup {
+            self.count += 1;
+        }
+        if self.count < self.warmup {
+            return SqueezeOutput {
+                momentum: f64::NAN,
+                squeeze_on: false,
+            };
+        }
+
+        SqueezeOutput {
+            momentum: self.deltas.value(),
+            squeeze_on: bb.lower > kc.lower && bb.upper < kc.upper,
+        }
+    }
+}
+
+impl Reset for SqueezeMomentum {
+    fn reset(&mut self) {
+        self.bb.reset();
+        self.kc.reset();
+        self.highest.reset();
+        self.lowest.reset();
+        self.sma.reset();
+        self.deltas.reset();
+        self.count = 0;
+    }
+}
+
+impl Default for SqueezeMomentum {
+    fn default() -> Self {
+        Self::new(20, 2.0, 20, 1.5).unwrap()
+    }
+}
+
+impl fmt::Display for SqueezeMomentum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SQUEEZE({}, {}, {}, {})",
+            self.bb.period(),
+            self.bb_mult,
+            self.kc_period(),
+            self.kc_mult
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(SqueezeMomentum::new(0, 2.0, 20, 1.5).is_err());
+        assert!(SqueezeMomentum::new(20, 2.0, 0, 1.5).is_err());
+        assert!(SqueezeMomentum::new(20, 2.0, 20, 1.5).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut squeeze = SqueezeMomentum::new(5, 2.0, 5, 1.5).unwrap();
+
+        // a strong trend: the closes spread the bands far outside the channel
+        for i in 0..4 {
+            let close = 100.0 + 5.0 * i as f64;
+            let out = squeeze.next(&Bar::new().high(close + 0.5).low(close - 0.5).close(close));
+            assert!(out.momentum.is_nan());
+            assert!(!out.squeeze_on);
+        }
+        for i in 4..10 {
+            let close = 100.0 + 5.0 * i as f64;
+            let out = squeeze.next(&Bar::new().high(close + 0.5).low(close - 0.5).close(close));
+            assert!(!out.squeeze_on);
+            assert!(out.momentum > 0.0);
+        }
+
+        // low volatility: flat closes collapse the bands inside the channel
+        let mut out = squeeze.next(&Bar::new().high(146.0).low(144.0).close(145.0));
+        for _ in 0..10 {
+            out = squeeze.next(&Bar::new().high(146.0).low(144.0).close(145.0));
+        }
+        assert!(out.squeeze_on);
+        assert_eq!(round(out.momentum), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut squeeze = SqueezeMomentum::new(2, 2.0, 2, 1.5).unwrap();
+        squeeze.next(&Bar::new().high(11.0).low(9.0).close(10.0));
+        squeeze.next(&Bar::new().high(12.0).low(10.0).close(11.0));
+
+        squeeze.reset();
+        let out = squeeze.next(&Bar::new().high(11.0).low(9.0).close(10.0));
+        assert!(out.momentum.is_nan());
+        assert!(!out.squeeze_on);
+    }
+
+    #[test]
+    fn test_default() {
+        SqueezeMomentum::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let squeeze = SqueezeMomentum::default();
+        assert_eq!(format!("{}", squeeze), "SQUEEZE(20, 2, 20, 1.5)");
+    }
+}
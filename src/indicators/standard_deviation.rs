@@ -1,6 +1,10 @@
-use std::fmt;
+use alloc::{boxed::Box, vec};
+use core::fmt;
 
 use crate::errors::{Result, TaError};
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float;
 use crate::{Close, Next, Period, Reset};
 use serde::{Deserialize, Serialize};
 
@@ -46,6 +50,8 @@ pub struct StandardDeviation {
     m: f64,
     m2: f64,
     deque: Box<[f64]>,
+    #[serde(default)]
+    last_output: Option<f64>,
 }
 
 impl StandardDeviation {
@@ -59,6 +65,7 @@ impl StandardDeviation {
                 m: 0.0,
                 m2: 0.0,
                 deque: vec![0.0; period].into_boxed_slice(),
+                last_output: None,
             }),
         }
     }
@@ -74,10 +81,8 @@ impl Period for StandardDeviation {
     }
 }
 
-impl Next<f64> for StandardDeviation {
-    type Output = f64;
-
-    fn next(&mut self, input: f64) -> Self::Output {
+impl StandardDeviation {
+    fn next_value(&mut self, input: f64) -> f64 {
         let old_val = self.deque[self.index];
         self.deque[self.index] = input;
 
@@ -108,6 +113,8 @@ impl Next<f64> for StandardDeviation {
     }
 }
 
+impl_next_option!(StandardDeviation);
+
 impl<T: Close> Next<&T> for StandardDeviation {
     type Output = f64;
 
@@ -125,6 +132,7 @@ impl Reset for StandardDeviation {
         for i in 0..self.period {
             self.deque[i] = 0.0;
         }
+        self.last_output = None;
     }
 }
 
@@ -0,0 +1,295 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::AverageTrueRange;
+use crate::{Close, High, Low, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// SuperTrend.
+///
+/// A trend following stop and reverse line built from ATR bands around the median price. In
+/// an uptrend the line is the lower band, which can only move up; in a downtrend it is the
+/// upper band, which can only move down. The trend flips when the close crosses the line.
+///
+/// # Formula
+///
+/// Basic Upper = (high + low) / 2 + _multiplier_ * ATR(_atr_period_)
+///
+/// Basic Lower = (high + low) / 2 - _multiplier_ * ATR(_atr_period_)
+///
+/// The final upper band is the basic upper band if it is lower than the previous final upper
+/// band or the previous close was above it, otherwise the previous final upper band (and
+/// conversely for the lower band).
+///
+/// The direction turns to +1 when the close rises above the final upper band and to -1 when
+/// it falls below the final lower band. The value is the final lower band in an uptrend, the
+/// final upper band in a downtrend.
+///
+/// The first bar starts an uptrend. While the ATR warms up (_atr_period_ bars) the value is
+/// NaN and the direction 0.
+///
+/// # Parameters
+///
+/// * _atr_period_ - period of the ATR (integer greater than 0). Default is 10.
+/// * _multiplier_ - ATR factor (number greater than 0). Default is 3.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::SuperTrend;
+/// use tam::{DataItem, Next};
+///
+/// let mut supertrend = SuperTrend::new(2, 1.0).unwrap();
+///
+/// let mut out = None;
+/// for i in 0..5 {
+///     let close = 10.0 + i as f64;
+///     let bar = DataItem::builder()
+///         .open(close).high(close + 1.0).low(close - 1.0).close(close).volume(1.0)
+///         .build().unwrap();
+///     out = Some(supertrend.next(&bar));
+/// }
+/// let out = out.unwrap();
+/// assert_eq!(out.direction, 1);
+/// assert_eq!(out.value, 12.0);
+/// ```
+///
+/// # Links
+///
+/// * [SuperTrend, TradingView](https://www.tradingview.com/support/solutions/43000634738-supertrend/)
+///
+#[doc(alias = "SUPERTREND")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SuperTrend {
+    atr: AverageTrueRange,
+    multiplier: f64,
+    count: usize,
+    upper: f64,
+    lower: f64,
+    prev_close: f64,
+    direction: i8,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SuperTrendOutput {
+    pub value: f64,
+    /// +1 in an uptrend, -1 in a downtrend, 0 during warmup.
+    pub direction: i8,
+}
+
+impl From<SuperTrendOutput> for (f64, i8) {
+    fn from(out: SuperTrendOutput) -> Self {
+        (out.value, out.direction)
+    }
+}
+
+impl_output!(SuperTrendOutput, value, direction);
+
+impl SuperTrend {
+    pub fn new(atr_period: usize, multiplier: f64) -> Result<Self> {
+        if multiplier.is_nan() || multiplier <= 0.0 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            atr: AverageTrueRange::new(atr_period)?,
+            multiplier,
+            count: 0,
+            upper: 0.0,
+            lower: 0.0,
+            prev_close: 0.0,
+            direction: 1,
+        })
+    }
+}
+
+impl Period for SuperTrend {
+    fn period(&self) -> usize {
+        self.atr.period()
+    }
+}
+
+impl Warmup for SuperTrend {
+    fn lookback(&self) -> usize {
+        self.atr.period()
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for SuperTrend {
+    type Output = SuperTrendOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let atr = self.atr.next(input);
+        let median = (input.high() + input.low()) / 2.0;
+        let basic_upper = median + self.multiplier * atr;
+        let basic_lower = median - self.multiplier * atr;
+        let close = input.close();
+
+        if self.count == 0 {
+            self.upper = basic_upper;
+            self.lower = basic_lower;
+        } else {
+            if basic_upper < self.upper || self.prev_close > self.upper {
+                self.upper = basic_upper;
+            }
+            if basic_lower > self.lower || self.prev_close < self.lower {
+                self.lower = basic_lower;
+            }
+
+            if self.direction < 0 && close > self.upper {
+                self.direction = 1;
+            } else if self.direction > 0 && close < self.lower {
+                self.direction = -1;
+            }
+        }
+        self.count += 1;
+        self.prev_close = close;
+
+        if self.count <= self.lookback() {
+            return SuperTrendOutput {
+                value: f64::NAN,
+                direction: 0,
+            };
+        }
+        SuperTrendOutput {
+            value: if self.direction > 0 {
+                self.lower
+            } else {
+                self.upper
+            },
+            direction: self.direction,
+        }
+    }
+}
+
+impl Reset for SuperTrend {
+    fn reset(&mut self) {
+        self.atr.reset();
+        self.count = 0;
+        self.upper = 0.0;
+        self.lower = 0.0;
+        self.prev_close = 0.0;
+        self.direction = 1;
+    }
+}
+
+impl Default for SuperTrend {
+    fn default() -> Self {
+        Self::new(10, 3.0).unwrap()
+    }
+}
+
+impl fmt::Display for SuperTrend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SUPERTREND({}, {})", self.atr.period(), self.multiplier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(SuperTrend::new(0, 3.0).is_err());
+        assert!(SuperTrend::new(10, 0.0).is_err());
+        assert!(SuperTrend::new(10, f64::NAN).is_err());
+        assert!(SuperTrend::new(1, 0.5).is_ok());
+    }
+
+    #[test]
+    fn test_warmup() {
+        let mut supertrend = SuperTrend::new(3, 1.0).unwrap();
+        for i in 0..3 {
+            let out = supertrend.next(
+                &Bar::new()
+                    .high(11.0 + i as f64)
+                    .low(9.0 + i as f64)
+                    .close(10.0 + i as f64),
+            );
+            assert!(out.value.is_nan());
+            assert_eq!(out.direction, 0);
+        }
+        assert_eq!(
+            supertrend
+                .next(&Bar::new().high(14.0).low(12.0).close(13.0))
+                .direction,
+            1
+        );
+    }
+
+    #[test]
+    fn test_flip() {
+        let mut supertrend = SuperTrend::new(1, 1.0).unwrap();
+        let out = |value, direction| SuperTrendOutput { value, direction };
+        assert!(supertrend
+            .next(&Bar::new().high(11.0).low(9.0).close(10.0))
+            .value
+            .is_nan());
+
+        // uptrend: the lower band ratchets up and never down
+        assert_eq!(
+            supertrend.next(&Bar::new().high(12.0).low(10.0).close(11.0)),
+            out(9.0, 1)
+        );
+        assert_eq!(
+            supertrend.next(&Bar::new().high(13.0).low(11.0).close(12.0)),
+            out(10.0, 1)
+        );
+        assert_eq!(
+            supertrend.next(&Bar::new().high(12.0).low(10.0).close(11.0)),
+            out(10.0, 1)
+        );
+
+        // the close falls below the lower band: downtrend on the upper band
+        assert_eq!(
+            supertrend.next(&Bar::new().high(10.0).low(8.0).close(9.0)),
+            out(12.0, -1)
+        );
+        // the upper band only moves down
+        assert_eq!(
+            supertrend.next(&Bar::new().high(9.0).low(7.0).close(8.0)),
+            out(10.0, -1)
+        );
+        assert_eq!(
+            supertrend.next(&Bar::new().high(10.0).low(8.0).close(9.0)),
+            out(10.0, -1)
+        );
+
+        // the close rises above the upper band: uptrend again
+        assert_eq!(
+            supertrend.next(&Bar::new().high(12.0).low(10.0).close(11.0)),
+            out(8.0, 1)
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut supertrend = SuperTrend::new(1, 1.0).unwrap();
+        supertrend.next(&Bar::new().high(11.0).low(9.0).close(10.0));
+        supertrend.next(&Bar::new().high(13.0).low(11.0).close(12.0));
+
+        supertrend.reset();
+        assert!(supertrend
+            .next(&Bar::new().high(11.0).low(9.0).close(10.0))
+            .value
+            .is_nan());
+        assert_eq!(
+            supertrend
+                .next(&Bar::new().high(12.0).low(10.0).close(11.0))
+                .value,
+            9.0
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        SuperTrend::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let supertrend = SuperTrend::new(7, 2.5).unwrap();
+        assert_eq!(format!("{}", supertrend), "SUPERTREND(7, 2.5)");
+    }
+}
@@ -0,0 +1,164 @@
+use core::fmt;
+
+use crate::{Close, Next, Reset};
+use serde::{Deserialize, Serialize};
+
+/// Trend streak.
+///
+/// Counts the consecutive inputs the value has risen (positive count) or fallen (negative
+/// count). Fed with closes it gives the number of consecutive up or down closes; fed with an
+/// oscillator it tells how long the oscillator has kept moving in one direction.
+///
+/// # Formula
+///
+/// * value > previous value: Streak = Streak<sub>t-1</sub> + 1 if Streak<sub>t-1</sub> > 0, else 1
+/// * value < previous value: Streak = Streak<sub>t-1</sub> - 1 if Streak<sub>t-1</sub> < 0, else -1
+/// * value = previous value: Streak = 0
+///
+/// The first input has no previous value and yields 0.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::TrendStreak;
+/// use tam::Next;
+///
+/// let mut streak = TrendStreak::new();
+/// assert_eq!(streak.next(10.0), 0);
+/// assert_eq!(streak.next(11.0), 1);
+/// assert_eq!(streak.next(12.0), 2);
+/// assert_eq!(streak.next(11.5), -1);
+/// assert_eq!(streak.next(11.5), 0);
+/// ```
+///
+#[doc(alias = "STREAK")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TrendStreak {
+    prev: Option<f64>,
+    streak: i64,
+}
+
+impl TrendStreak {
+    pub fn new() -> Self {
+        Self {
+            prev: None,
+            streak: 0,
+        }
+    }
+}
+
+impl Next<f64> for TrendStreak {
+    type Output = i64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        if let Some(prev) = self.prev {
+            self.streak = if input > prev {
+                self.streak.max(0) + 1
+            } else if input < prev {
+                self.streak.min(0) - 1
+            } else {
+                0
+            };
+        }
+        self.prev = Some(input);
+        self.streak
+    }
+}
+
+impl<T: Close> Next<&T> for TrendStreak {
+    type Output = i64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for TrendStreak {
+    fn reset(&mut self) {
+        self.prev = None;
+        self.streak = 0;
+    }
+}
+
+impl Default for TrendStreak {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for TrendStreak {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "STREAK")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(TrendStreak);
+
+    #[test]
+    fn test_next() {
+        let mut streak = TrendStreak::new();
+
+        assert_eq!(streak.next(10.0), 0);
+        assert_eq!(streak.next(11.0), 1);
+        assert_eq!(streak.next(&Bar::new().close(12)), 2);
+        assert_eq!(streak.next(15.0), 3);
+        assert_eq!(streak.next(14.0), -1);
+        assert_eq!(streak.next(13.0), -2);
+        assert_eq!(streak.next(12.0), -3);
+    }
+
+    #[test]
+    fn test_sign_flip() {
+        let mut streak = TrendStreak::new();
+        streak.next(1.0);
+        streak.next(2.0);
+        streak.next(3.0);
+
+        // a single fall restarts the count at -1, a single rise at +1
+        assert_eq!(streak.next(2.0), -1);
+        assert_eq!(streak.next(3.0), 1);
+        assert_eq!(streak.next(2.5), -1);
+    }
+
+    #[test]
+    fn test_equality() {
+        let mut streak = TrendStreak::new();
+        streak.next(1.0);
+        streak.next(2.0);
+        streak.next(3.0);
+
+        assert_eq!(streak.next(3.0), 0);
+        assert_eq!(streak.next(3.0), 0);
+        // the count starts over after an unchanged value
+        assert_eq!(streak.next(4.0), 1);
+        streak.next(3.0);
+        assert_eq!(streak.next(3.0), 0);
+        assert_eq!(streak.next(2.0), -1);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut streak = TrendStreak::new();
+        streak.next(1.0);
+        streak.next(2.0);
+
+        streak.reset();
+        assert_eq!(streak.next(5.0), 0);
+        assert_eq!(streak.next(4.0), -1);
+    }
+
+    #[test]
+    fn test_default() {
+        TrendStreak::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", TrendStreak::new()), "STREAK");
+    }
+}
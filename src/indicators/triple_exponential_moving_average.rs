@@ -0,0 +1,185 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Triple Exponential Moving Average (TEMA).
+///
+/// Patrick Mulloy's extension of the [DEMA](struct.DoubleExponentialMovingAverage.html) to
+/// three cascaded EMAs, removing even more of the lag of a single EMA.
+///
+/// # Formula
+///
+/// EMA<sub>1</sub> = EMA(p<sub>t</sub>, _period_)
+///
+/// EMA<sub>2</sub> = EMA(EMA<sub>1</sub>, _period_)
+///
+/// EMA<sub>3</sub> = EMA(EMA<sub>2</sub>, _period_)
+///
+/// TEMA = 3 * EMA<sub>1</sub> - 3 * EMA<sub>2</sub> + EMA<sub>3</sub>
+///
+/// The EMAs are seeded with their first input, so the first output is the first price.
+///
+/// # Parameters
+///
+/// * _period_ - period of the EMAs (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::TripleExponentialMovingAverage as Tema;
+/// use tam::Next;
+///
+/// let mut tema = Tema::new(3).unwrap();
+/// assert_eq!(tema.next(2.0), 2.0);
+/// // EMAs 3.5, 2.75 and 2.375
+/// assert_eq!(tema.next(5.0), 4.625);
+/// ```
+///
+/// # Links
+///
+/// * [Triple exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Triple_exponential_moving_average)
+///
+#[doc(alias = "TEMA")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TripleExponentialMovingAverage {
+    ema1: Ema,
+    ema2: Ema,
+    ema3: Ema,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl TripleExponentialMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            ema1: Ema::new(period)?,
+            ema2: Ema::new(period)?,
+            ema3: Ema::new(period)?,
+            last_output: None,
+        })
+    }
+}
+
+impl Period for TripleExponentialMovingAverage {
+    fn period(&self) -> usize {
+        self.ema1.period()
+    }
+}
+
+impl Warmup for TripleExponentialMovingAverage {
+    fn lookback(&self) -> usize {
+        3 * self.ema1.lookback()
+    }
+}
+
+impl TripleExponentialMovingAverage {
+    fn next_value(&mut self, input: f64) -> f64 {
+        let ema1 = self.ema1.next(input);
+        let ema2 = self.ema2.next(ema1);
+        let ema3 = self.ema3.next(ema2);
+        3.0 * (ema1 - ema2) + ema3
+    }
+}
+
+impl_next_option!(TripleExponentialMovingAverage);
+
+impl<T: Close> Next<&T> for TripleExponentialMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for TripleExponentialMovingAverage {
+    fn reset(&mut self) {
+        self.ema1.reset();
+        self.ema2.reset();
+        self.ema3.reset();
+        self.last_output = None;
+    }
+}
+
+impl Default for TripleExponentialMovingAverage {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for TripleExponentialMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TEMA({})", self.ema1.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(TripleExponentialMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(TripleExponentialMovingAverage::new(0).is_err());
+        assert!(TripleExponentialMovingAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut tema = TripleExponentialMovingAverage::new(3).unwrap();
+        assert_eq!(tema.lookback(), 0);
+
+        let outputs: Vec<f64> = [2.0, 5.0, 1.0, 6.25]
+            .iter()
+            .map(|&p| tema.next(&Bar::new().close(p)))
+            .collect();
+        // EMA 2, 3.5, 2.25, 4.25, then 2, 2.75, 2.5, 3.375, then 2, 2.375, 2.4375, 2.90625
+        assert_eq!(outputs, vec![2.0, 4.625, 1.6875, 5.53125]);
+    }
+
+    #[test]
+    fn test_lag() {
+        // on a linear trend the TEMA catches up with the price
+        let mut tema = TripleExponentialMovingAverage::new(5).unwrap();
+        let mut lag = 0.0;
+        for i in 0..80 {
+            let price = i as f64;
+            lag = price - tema.next(price);
+        }
+        assert_eq!(round(lag), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut tema = TripleExponentialMovingAverage::new(3).unwrap();
+        tema.next(2.0);
+        tema.next(5.0);
+
+        tema.reset();
+        assert_eq!(tema.next(5.0), 5.0);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut tema = TripleExponentialMovingAverage::new(3).unwrap();
+        tema.next(2.0);
+        tema.next(5.0);
+        let mut restored = serde_round_trip(&tema);
+        assert_eq!(restored.next(1.0), tema.next(1.0));
+    }
+
+    #[test]
+    fn test_default() {
+        TripleExponentialMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let tema = TripleExponentialMovingAverage::new(9).unwrap();
+        assert_eq!(format!("{}", tema), "TEMA(9)");
+    }
+}
@@ -1,4 +1,4 @@
-use std::fmt;
+use core::fmt;
 
 use crate::helpers::max3;
 use crate::{Close, High, Low, Next, Reset};
@@ -51,11 +51,16 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct TrueRange {
     prev_close: Option<f64>,
+    #[serde(default)]
+    last_output: Option<f64>,
 }
 
 impl TrueRange {
     pub fn new() -> Self {
-        Self { prev_close: None }
+        Self {
+            prev_close: None,
+            last_output: None,
+        }
     }
 }
 
@@ -71,10 +76,8 @@ impl fmt::Display for TrueRange {
     }
 }
 
-impl Next<f64> for TrueRange {
-    type Output = f64;
-
-    fn next(&mut self, input: f64) -> Self::Output {
+impl TrueRange {
+    fn next_value(&mut self, input: f64) -> f64 {
         let distance = match self.prev_close {
             Some(prev) => (input - prev).abs(),
             None => 0.0,
@@ -84,6 +87,8 @@ impl Next<f64> for TrueRange {
     }
 }
 
+impl_next_option!(TrueRange);
+
 impl<T: High + Low + Close> Next<&T> for TrueRange {
     type Output = f64;
 
@@ -98,6 +103,7 @@ impl<T: High + Low + Close> Next<&T> for TrueRange {
             None => bar.high() - bar.low(),
         };
         self.prev_close = Some(bar.close());
+        self.last_output = Some(max_dist);
         max_dist
     }
 }
@@ -105,6 +111,7 @@ impl<T: High + Low + Close> Next<&T> for TrueRange {
 impl Reset for TrueRange {
     fn reset(&mut self) {
         self.prev_close = None;
+        self.last_output = None;
     }
 }
 
@@ -0,0 +1,205 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{Maximum, SimpleMovingAverage as Sma};
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float;
+use crate::{Close, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Ulcer Index (UI).
+///
+/// Developed by Peter Martin, measures downside volatility: how deep and how long the
+/// drawdowns from the recent highs are. Unlike the standard deviation, rises do not count,
+/// so a steady uptrend has an Ulcer Index close to 0.
+///
+/// # Formula
+///
+/// R<sub>t</sub> = 100 * (p<sub>t</sub> - max(_period_)) / max(_period_)
+///
+/// UI = sqrt(SMA(R<sup>2</sup>, _period_))
+///
+/// Where max(_period_) is the highest price of the last _period_ prices. The drawdowns are only
+/// computed on full windows, so the output is NaN for the first 2 * (_period_ - 1) prices.
+///
+/// # Parameters
+///
+/// * _period_ - number of prices of the highs and of the average (integer greater than 0).
+///   Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::UlcerIndex;
+/// use tam::Next;
+///
+/// let mut ulcer = UlcerIndex::new(2).unwrap();
+/// assert!(ulcer.next(10.0).is_nan());
+/// assert!(ulcer.next(12.0).is_nan());
+/// // drawdowns 0% and -20%
+/// assert_eq!(ulcer.next(9.6).round(), 14.0);
+/// ```
+///
+/// # Links
+///
+/// * [Ulcer Index, Wikipedia](https://en.wikipedia.org/wiki/Ulcer_index)
+///
+#[doc(alias = "ULCER")]
+#[doc(alias = "UI")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UlcerIndex {
+    maximum: Maximum,
+    sma: Sma,
+    count: usize,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl UlcerIndex {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            maximum: Maximum::new(period)?,
+            sma: Sma::new(period)?,
+            count: 0,
+            last_output: None,
+        })
+    }
+}
+
+impl Period for UlcerIndex {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl Warmup for UlcerIndex {
+    fn lookback(&self) -> usize {
+        2 * (self.sma.period() - 1)
+    }
+}
+
+impl UlcerIndex {
+    fn next_value(&mut self, input: f64) -> f64 {
+        let max = self.maximum.next(input);
+        if self.count < self.sma.lookback() {
+            self.count += 1;
+            return f64::NAN;
+        }
+
+        let drawdown = if max == 0.0 {
+            0.0
+        } else {
+            100.0 * (input - max) / max
+        };
+        // the running sum can end up slightly below 0 after a drawdown leaves the window
+        let mean_square = self.sma.next(drawdown * drawdown).max(0.0);
+        if self.count < self.lookback() {
+            self.count += 1;
+            return f64::NAN;
+        }
+        mean_square.sqrt()
+    }
+}
+
+impl_next_option!(UlcerIndex);
+
+impl<T: Close> Next<&T> for UlcerIndex {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for UlcerIndex {
+    fn reset(&mut self) {
+        self.maximum.reset();
+        self.sma.reset();
+        self.count = 0;
+        self.last_output = None;
+    }
+}
+
+impl Default for UlcerIndex {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for UlcerIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ULCER({})", self.sma.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(UlcerIndex::new(0).is_err());
+        assert!(UlcerIndex::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut ulcer = UlcerIndex::new(2).unwrap();
+        assert_eq!(ulcer.lookback(), 2);
+
+        // highs 10, 12, 12, 9, 12 and drawdowns 0%, -25%, -11.111%, 0%
+        assert!(ulcer.next(10.0).is_nan());
+        assert!(ulcer.next(12.0).is_nan());
+        assert_eq!(round(ulcer.next(9.0)), 17.678);
+        assert_eq!(round(ulcer.next(&Bar::new().close(8))), 19.345);
+        assert_eq!(round(ulcer.next(12.0)), 7.857);
+        assert_eq!(round(ulcer.next(13.0)), 0.0);
+    }
+
+    #[test]
+    fn test_uptrend_and_dip() {
+        let mut uptrend = UlcerIndex::new(14).unwrap();
+        let mut dip = UlcerIndex::new(14).unwrap();
+
+        let mut calm = 0.0;
+        let mut stressed: f64 = 0.0;
+        for i in 0..60 {
+            let price = 100.0 + i as f64;
+            // a 30% crash from bar 40, recovered 5 bars later
+            let dipped = if (40..45).contains(&i) {
+                price * 0.7
+            } else {
+                price
+            };
+            calm = uptrend.next(price);
+            stressed = stressed.max(dip.next(dipped));
+        }
+        assert_eq!(calm, 0.0);
+        assert!(stressed > 15.0, "{}", stressed);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ulcer = UlcerIndex::new(2).unwrap();
+        for &price in [10.0, 12.0, 9.0].iter() {
+            ulcer.next(price);
+        }
+
+        ulcer.reset();
+        assert!(ulcer.next(10.0).is_nan());
+        assert!(ulcer.next(12.0).is_nan());
+        assert_eq!(round(ulcer.next(9.0)), 17.678);
+    }
+
+    #[test]
+    fn test_default() {
+        UlcerIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", UlcerIndex::new(14).unwrap()), "ULCER(14)");
+    }
+}
@@ -0,0 +1,257 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::ChandeMomentumOscillator as Cmo;
+use crate::{Close, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Chande's Variable Index Dynamic Average (VIDYA).
+///
+/// An EMA whose smoothing factor is scaled by the absolute value of the
+/// [Chande Momentum Oscillator](struct.ChandeMomentumOscillator.html): when the price moves
+/// strongly in one direction the average speeds up, in sideways markets it almost stalls.
+///
+/// # Formula
+///
+/// α = 2 / (_period_ + 1) * |CMO(_cmo_period_)| / 100
+///
+/// VIDYA<sub>t</sub> = VIDYA<sub>t-1</sub> + α * (p<sub>t</sub> - VIDYA<sub>t-1</sub>)
+///
+/// The output is NaN while the CMO warms up (_cmo_period_ inputs) and starts at the
+/// first price with a valid CMO.
+///
+/// # Parameters
+///
+/// * _period_ - period of the EMA (integer greater than 0). Default is 14.
+/// * _cmo_period_ - period of the CMO (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::Vidya;
+/// use tam::Next;
+///
+/// let mut vidya = Vidya::new(3, 2).unwrap();
+/// assert!(vidya.next(10.0).is_nan());
+/// assert!(vidya.next(11.0).is_nan());
+/// assert_eq!(vidya.next(12.0), 12.0);
+/// // CMO of 0: the average does not move
+/// assert_eq!(vidya.next(11.0), 12.0);
+/// ```
+///
+/// # Links
+///
+/// * [Variable Index Dynamic Average, Wikipedia](https://en.wikipedia.org/wiki/Variable_index_dynamic_average)
+///
+#[doc(alias = "VIDYA")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Vidya {
+    period: usize,
+    k: f64,
+    cmo: Cmo,
+    current: f64,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl Vidya {
+    pub fn new(period: usize, cmo_period: usize) -> Result<Self> {
+        if period == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            k: 2.0 / (period + 1) as f64,
+            cmo: Cmo::new(cmo_period)?,
+            current: f64::NAN,
+            last_output: None,
+        })
+    }
+
+    pub fn cmo_period(&self) -> usize {
+        self.cmo.period()
+    }
+}
+
+impl Period for Vidya {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Warmup for Vidya {
+    fn lookback(&self) -> usize {
+        self.cmo.lookback()
+    }
+}
+
+impl Vidya {
+    fn next_value(&mut self, input: f64) -> f64 {
+        let cmo = self.cmo.next(input);
+        if cmo.is_nan() {
+            return f64::NAN;
+        }
+        if self.current.is_nan() {
+            self.current = input;
+        } else {
+            let alpha = (self.k * cmo.abs() / 100.0).clamp(0.0, 1.0);
+            self.current += alpha * (input - self.current);
+        }
+        self.current
+    }
+}
+
+impl_next_option!(Vidya);
+
+impl<T: Close> Next<&T> for Vidya {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for Vidya {
+    fn reset(&mut self) {
+        self.cmo.reset();
+        self.current = f64::NAN;
+        self.last_output = None;
+    }
+}
+
+impl Default for Vidya {
+    fn default() -> Self {
+        Self::new(14, 9).unwrap()
+    }
+}
+
+impl fmt::Display for Vidya {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VIDYA({}, {})", self.period, self.cmo.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::ExponentialMovingAverage;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Vidya::new(0, 9).is_err());
+        assert!(Vidya::new(14, 0).is_err());
+        assert!(Vidya::new(1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        // k = 0.5, CMO over 2 changes
+        let mut vidya = Vidya::new(3, 2).unwrap();
+
+        assert!(vidya.next(10.0).is_nan());
+        assert!(vidya.next(11.0).is_nan());
+        assert_eq!(vidya.next(12.0), 12.0); // seed, CMO 100
+        assert_eq!(vidya.next(11.0), 12.0); // CMO 0
+        assert_eq!(vidya.next(&Bar::new().close(8)), 10.0); // CMO -100, α 0.5
+        assert_eq!(round(vidya.next(12.0)), 10.143); // CMO (4 - 3) / 7, α 0.071
+    }
+
+    #[test]
+    fn test_trend() {
+        // with a CMO of 100 in a steady trend, the VIDYA is the EMA seeded at the same price
+        let mut vidya = Vidya::new(10, 5).unwrap();
+        let mut ema = ExponentialMovingAverage::new(10).unwrap();
+        for i in 0..50 {
+            let price = 100.0 + i as f64;
+            let value = vidya.next(price);
+            if i >= 5 {
+                assert_eq!(round(value), round(ema.next(price)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_range() {
+        // alternating prices give a CMO of ±20: α is 5 times smaller than for the EMA
+        let mut vidya = Vidya::new(3, 5).unwrap();
+        for &price in [10.0, 12.0, 10.0, 12.0, 10.0].iter() {
+            assert!(vidya.next(price).is_nan());
+        }
+        assert_eq!(vidya.next(12.0), 12.0);
+        assert_eq!(round(vidya.next(10.0)), 11.8);
+        assert_eq!(round(vidya.next(12.0)), 11.82);
+    }
+
+    #[test]
+    fn test_ground_truth() {
+        // straightforward evaluation of the formula, the CMO summed over each window
+        let prices: Vec<f64> = (0..60)
+            .map(|i| 100.0 + 8.0 * (i as f64 * 0.3).sin() + 0.2 * i as f64)
+            .collect();
+        let (period, cmo_period) = (6, 4);
+        let k = 2.0 / (period + 1) as f64;
+
+        let mut expected = vec![f64::NAN; cmo_period];
+        let mut current = prices[cmo_period];
+        for t in cmo_period..prices.len() {
+            let (mut up, mut down) = (0.0, 0.0);
+            for j in t + 1 - cmo_period..=t {
+                let change = prices[j] - prices[j - 1];
+                if change > 0.0 {
+                    up += change;
+                } else {
+                    down -= change;
+                }
+            }
+            let cmo = 100.0 * (up - down) / (up + down);
+            current += k * cmo.abs() / 100.0 * (prices[t] - current);
+            expected.push(current);
+        }
+
+        let mut vidya = Vidya::new(period, cmo_period).unwrap();
+        let outputs: Vec<f64> = prices.iter().map(|&p| vidya.next(p)).collect();
+        assert_eq!(warmup_nan_count(&outputs), cmo_period);
+        for (output, expected) in outputs[cmo_period..].iter().zip(&expected[cmo_period..]) {
+            assert!(
+                (output - expected).abs() < 1e-9,
+                "{} != {}",
+                output,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut vidya = Vidya::new(3, 2).unwrap();
+        for &price in [10.0, 11.0, 12.0].iter() {
+            vidya.next(price);
+        }
+        let mut restored = serde_round_trip(&vidya);
+        assert_eq!(round(restored.next(8.0)), round(vidya.next(8.0)));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vidya = Vidya::new(3, 2).unwrap();
+        vidya.next(10.0);
+        vidya.next(11.0);
+        vidya.next(12.0);
+
+        vidya.reset();
+        assert!(vidya.next(5.0).is_nan());
+        assert!(vidya.next(6.0).is_nan());
+        assert_eq!(vidya.next(7.0), 7.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Vidya::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Vidya::new(14, 9).unwrap()), "VIDYA(14, 9)");
+    }
+}
@@ -0,0 +1,285 @@
+use core::fmt;
+
+use crate::{Close, Next, Reset, Volume};
+use serde::{Deserialize, Serialize};
+
+/// Starting value of the volume indexes.
+const BASE: f64 = 1000.0;
+
+/// Negative Volume Index (NVI).
+///
+/// Developed by Paul Dysart and popularized by Norman Fosback, a cumulative index that only
+/// moves on the days when the volume decreases. The idea is that the informed investors trade
+/// on the quiet days, while the crowd follows on the busy ones (see the
+/// [Positive Volume Index](struct.PositiveVolumeIndex.html)).
+///
+/// # Formula
+///
+/// If Volume<sub>t</sub> < Volume<sub>t-1</sub>:
+/// NVI<sub>t</sub> = NVI<sub>t-1</sub> + NVI<sub>t-1</sub> * (Close<sub>t</sub> - Close<sub>t-1</sub>) / Close<sub>t-1</sub>
+///
+/// Otherwise: NVI<sub>t</sub> = NVI<sub>t-1</sub>
+///
+/// The index starts at 1000 on the first bar, which only records the close and the volume.
+/// A bar whose prior close is 0 leaves the index unchanged.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::NegativeVolumeIndex;
+/// use tam::{DataItem, Next};
+///
+/// let mut nvi = NegativeVolumeIndex::new();
+/// let bar = |close: f64, volume: f64| {
+///     DataItem::builder()
+///         .open(close).high(close).low(close).close(close).volume(volume)
+///         .build().unwrap()
+/// };
+///
+/// assert_eq!(nvi.next(&bar(10.0, 1000.0)), 1000.0);
+/// // the volume decreases: +10%
+/// assert_eq!(nvi.next(&bar(11.0, 800.0)), 1100.0);
+/// // the volume increases: unchanged
+/// assert_eq!(nvi.next(&bar(12.0, 900.0)), 1100.0);
+/// ```
+///
+/// # Links
+///
+/// * [Negative Volume Index, Wikipedia](https://en.wikipedia.org/wiki/Negative_volume_index)
+///
+#[doc(alias = "NVI")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NegativeVolumeIndex {
+    index: VolumeIndex,
+}
+
+/// Positive Volume Index (PVI).
+///
+/// The counterpart of the [Negative Volume Index](struct.NegativeVolumeIndex.html): a
+/// cumulative index that only moves on the days when the volume increases, which are
+/// considered driven by the crowd.
+///
+/// # Formula
+///
+/// If Volume<sub>t</sub> > Volume<sub>t-1</sub>:
+/// PVI<sub>t</sub> = PVI<sub>t-1</sub> + PVI<sub>t-1</sub> * (Close<sub>t</sub> - Close<sub>t-1</sub>) / Close<sub>t-1</sub>
+///
+/// Otherwise: PVI<sub>t</sub> = PVI<sub>t-1</sub>
+///
+/// The index starts at 1000 on the first bar, which only records the close and the volume.
+/// A bar whose prior close is 0 leaves the index unchanged.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::PositiveVolumeIndex;
+/// use tam::{DataItem, Next};
+///
+/// let mut pvi = PositiveVolumeIndex::new();
+/// let bar = |close: f64, volume: f64| {
+///     DataItem::builder()
+///         .open(close).high(close).low(close).close(close).volume(volume)
+///         .build().unwrap()
+/// };
+///
+/// assert_eq!(pvi.next(&bar(10.0, 1000.0)), 1000.0);
+/// // the volume decreases: unchanged
+/// assert_eq!(pvi.next(&bar(11.0, 800.0)), 1000.0);
+/// // the volume increases: -50%
+/// assert_eq!(pvi.next(&bar(5.5, 900.0)), 500.0);
+/// ```
+///
+/// # Links
+///
+/// * [Positive Volume Index, Wikipedia](https://en.wikipedia.org/wiki/Positive_volume_index)
+///
+#[doc(alias = "PVI")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PositiveVolumeIndex {
+    index: VolumeIndex,
+}
+
+/// State shared by the NVI and the PVI.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct VolumeIndex {
+    value: f64,
+    /// Close and volume of the previous bar.
+    prev: Option<(f64, f64)>,
+}
+
+impl VolumeIndex {
+    fn new() -> Self {
+        Self {
+            value: BASE,
+            prev: None,
+        }
+    }
+
+    /// Applies the change of the close if `moves` holds for the previous and current volume.
+    fn update(&mut self, close: f64, volume: f64, moves: fn(f64, f64) -> bool) -> f64 {
+        if let Some((prev_close, prev_volume)) = self.prev {
+            if moves(prev_volume, volume) && prev_close != 0.0 {
+                self.value += self.value * (close - prev_close) / prev_close;
+            }
+        }
+        self.prev = Some((close, volume));
+        self.value
+    }
+
+    fn reset(&mut self) {
+        self.value = BASE;
+        self.prev = None;
+    }
+}
+
+impl NegativeVolumeIndex {
+    pub fn new() -> Self {
+        Self {
+            index: VolumeIndex::new(),
+        }
+    }
+}
+
+impl PositiveVolumeIndex {
+    pub fn new() -> Self {
+        Self {
+            index: VolumeIndex::new(),
+        }
+    }
+}
+
+impl<T: Close + Volume> Next<&T> for NegativeVolumeIndex {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> f64 {
+        self.index
+            .update(input.close(), input.volume(), |prev, volume| volume < prev)
+    }
+}
+
+impl<T: Close + Volume> Next<&T> for PositiveVolumeIndex {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> f64 {
+        self.index
+            .update(input.close(), input.volume(), |prev, volume| volume > prev)
+    }
+}
+
+impl Reset for NegativeVolumeIndex {
+    fn reset(&mut self) {
+        self.index.reset();
+    }
+}
+
+impl Reset for PositiveVolumeIndex {
+    fn reset(&mut self) {
+        self.index.reset();
+    }
+}
+
+impl Default for NegativeVolumeIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for PositiveVolumeIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for NegativeVolumeIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NVI")
+    }
+}
+
+impl fmt::Display for PositiveVolumeIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PVI")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bars() -> Vec<Bar> {
+        // volume down, up, unchanged, down, up
+        [
+            (10.0, 1000.0),
+            (11.0, 800.0),
+            (9.9, 1200.0),
+            (12.0, 1200.0),
+            (9.0, 600.0),
+            (10.8, 700.0),
+        ]
+        .iter()
+        .map(|&(close, volume)| Bar::new().close(close).volume(volume))
+        .collect()
+    }
+
+    #[test]
+    fn test_nvi() {
+        let mut nvi = NegativeVolumeIndex::new();
+        let outputs: Vec<f64> = bars().iter().map(|bar| round(nvi.next(bar))).collect();
+        // +10% and -25% on the bars with less volume
+        assert_eq!(outputs, vec![1000.0, 1100.0, 1100.0, 1100.0, 825.0, 825.0]);
+    }
+
+    #[test]
+    fn test_pvi() {
+        let mut pvi = PositiveVolumeIndex::new();
+        let outputs: Vec<f64> = bars().iter().map(|bar| round(pvi.next(bar))).collect();
+        // -10% and +20% on the bars with more volume
+        assert_eq!(outputs, vec![1000.0, 1000.0, 900.0, 900.0, 900.0, 1080.0]);
+    }
+
+    #[test]
+    fn test_zero_prev_close() {
+        let mut nvi = NegativeVolumeIndex::new();
+        nvi.next(&Bar::new().close(0).volume(1000.0));
+        assert_eq!(nvi.next(&Bar::new().close(5).volume(500.0)), 1000.0);
+        assert_eq!(nvi.next(&Bar::new().close(10).volume(100.0)), 2000.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut nvi = NegativeVolumeIndex::new();
+        let mut pvi = PositiveVolumeIndex::new();
+        for bar in bars().iter() {
+            nvi.next(bar);
+            pvi.next(bar);
+        }
+
+        nvi.reset();
+        pvi.reset();
+        let bar = Bar::new().close(20).volume(1.0);
+        assert_eq!(nvi.next(&bar), 1000.0);
+        assert_eq!(pvi.next(&bar), 1000.0);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut nvi = NegativeVolumeIndex::new();
+        nvi.next(&Bar::new().close(10).volume(1000.0));
+        let mut restored = serde_round_trip(&nvi);
+        let bar = Bar::new().close(11).volume(800.0);
+        assert_eq!(restored.next(&bar), nvi.next(&bar));
+    }
+
+    #[test]
+    fn test_default() {
+        NegativeVolumeIndex::default();
+        PositiveVolumeIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", NegativeVolumeIndex::new()), "NVI");
+        assert_eq!(format!("{}", PositiveVolumeIndex::new()), "PVI");
+    }
+}
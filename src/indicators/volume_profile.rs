@@ -0,0 +1,285 @@
+use alloc::collections::VecDeque;
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{High, Low, Next, Reset, Volume};
+use serde::{Deserialize, Serialize};
+
+const VALUE_AREA: f64 = 0.7;
+
+/// Volume Profile.
+///
+/// Distributes the volume of the last `period` bars over `num_bins` equal price bins spanning
+/// the lowest low and the highest high of the window. The volume of a bar is spread evenly
+/// over its high-low range (a bar without range puts all of its volume into a single bin).
+///
+/// * _poc_ - Point of Control, the middle of the bin with the highest volume
+/// * _value_area_high_, _value_area_low_ - bounds of the Value Area, the range around the
+///   POC holding 70% of the volume. It is built by starting from the POC bin and repeatedly
+///   adding the neighbouring bin (above or below) with the higher volume.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars in the window (integer greater than 0). Default is 30.
+/// * _num_bins_ - number of price bins (integer greater than 0). Default is 24.
+///
+/// Outputs are NaN until the window is filled, and when the window has no volume.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::VolumeProfile;
+/// use tam::{DataItem, Next};
+///
+/// let bar = |high: f64, low: f64, volume: f64| {
+///     DataItem::builder()
+///         .open(low).high(high).low(low).close(high).volume(volume).build().unwrap()
+/// };
+///
+/// let mut vp = VolumeProfile::new(2, 4).unwrap();
+/// assert!(vp.next(&bar(14.0, 10.0, 100.0)).poc.is_nan());
+///
+/// let out = vp.next(&bar(13.0, 12.0, 500.0));
+/// assert_eq!(out.poc, 12.5);
+/// assert_eq!(out.value_area_high, 13.0);
+/// assert_eq!(out.value_area_low, 12.0);
+/// ```
+///
+/// # Links
+///
+/// * [Volume Profile, Investopedia](https://www.investopedia.com/terms/v/volume-analysis.asp)
+///
+#[doc(alias = "VP")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VolumeProfile {
+    period: usize,
+    num_bins: usize,
+    bars: VecDeque<(f64, f64, f64)>,
+    bins: Vec<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VolumeProfileOutput {
+    pub poc: f64,
+    pub value_area_high: f64,
+    pub value_area_low: f64,
+}
+
+impl From<VolumeProfileOutput> for (f64, f64, f64) {
+    fn from(out: VolumeProfileOutput) -> Self {
+        (out.poc, out.value_area_high, out.value_area_low)
+    }
+}
+
+impl_output!(VolumeProfileOutput, poc, value_area_high, value_area_low);
+
+impl VolumeProfile {
+    pub fn new(period: usize, num_bins: usize) -> Result<Self> {
+        if period == 0 || num_bins == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            num_bins,
+            bars: VecDeque::with_capacity(period),
+            bins: vec![0.0; num_bins],
+        })
+    }
+
+    pub fn num_bins(&self) -> usize {
+        self.num_bins
+    }
+
+    fn fill_bins(&mut self, min: f64, width: f64) {
+        for bin in self.bins.iter_mut() {
+            *bin = 0.0;
+        }
+
+        for &(high, low, volume) in self.bars.iter() {
+            if high > low {
+                for (i, bin) in self.bins.iter_mut().enumerate() {
+                    let start = min + i as f64 * width;
+                    let end = start + width;
+                    let overlap = high.min(end) - low.max(start);
+                    if overlap > 0.0 {
+                        *bin += volume * overlap / (high - low);
+                    }
+                }
+            } else {
+                let i = ((low - min) / width) as usize;
+                self.bins[i.min(self.num_bins - 1)] += volume;
+            }
+        }
+    }
+}
+
+impl<T: High + Low + Volume> Next<&T> for VolumeProfile {
+    type Output = VolumeProfileOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        if self.bars.len() == self.period {
+            self.bars.pop_front();
+        }
+        self.bars
+            .push_back((input.high(), input.low(), input.volume()));
+
+        let total: f64 = self.bars.iter().map(|&(_, _, volume)| volume).sum();
+        if self.bars.len() < self.period || total <= 0.0 {
+            return VolumeProfileOutput {
+                poc: f64::NAN,
+                value_area_high: f64::NAN,
+                value_area_low: f64::NAN,
+            };
+        }
+
+        let max = self
+            .bars
+            .iter()
+            .map(|b| b.0)
+            .fold(f64::NEG_INFINITY, f64::max);
+        let min = self.bars.iter().map(|b| b.1).fold(f64::INFINITY, f64::min);
+        if max == min {
+            return VolumeProfileOutput {
+                poc: max,
+                value_area_high: max,
+                value_area_low: min,
+            };
+        }
+
+        let width = (max - min) / self.num_bins as f64;
+        self.fill_bins(min, width);
+
+        let mut poc = 0;
+        for i in 1..self.num_bins {
+            if self.bins[i] > self.bins[poc] {
+                poc = i;
+            }
+        }
+
+        let (mut lo, mut hi) = (poc, poc);
+        let mut area = self.bins[poc];
+        while area < VALUE_AREA * total && (lo > 0 || hi + 1 < self.num_bins) {
+            let below = if lo > 0 { self.bins[lo - 1] } else { -1.0 };
+            let above = if hi + 1 < self.num_bins {
+                self.bins[hi + 1]
+            } else {
+                -1.0
+            };
+            if above >= below {
+                hi += 1;
+                area += above;
+            } else {
+                lo -= 1;
+                area += below;
+            }
+        }
+
+        VolumeProfileOutput {
+            poc: min + (poc as f64 + 0.5) * width,
+            value_area_high: min + (hi + 1) as f64 * width,
+            value_area_low: min + lo as f64 * width,
+        }
+    }
+}
+
+impl Reset for VolumeProfile {
+    fn reset(&mut self) {
+        self.bars.clear();
+    }
+}
+
+impl Default for VolumeProfile {
+    fn default() -> Self {
+        Self::new(30, 24).unwrap()
+    }
+}
+
+impl fmt::Display for VolumeProfile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VP({}, {})", self.period, self.num_bins)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(VolumeProfile::new(0, 10).is_err());
+        assert!(VolumeProfile::new(10, 0).is_err());
+        assert!(VolumeProfile::new(10, 10).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vp = VolumeProfile::new(5, 5).unwrap();
+
+        // bins [10, 11] .. [14, 15] get 45, 145, 620, 120, 20
+        let bars = [
+            Bar::new().high(15.0).low(10.0).volume(100.0),
+            Bar::new().high(13.0).low(12.0).volume(300.0),
+            Bar::new().high(14.0).low(11.0).volume(300.0),
+            Bar::new().high(12.5).low(12.5).volume(200.0),
+        ];
+        for b in bars.iter() {
+            let out = vp.next(b);
+            assert!(out.poc.is_nan());
+            assert!(out.value_area_high.is_nan());
+            assert!(out.value_area_low.is_nan());
+        }
+
+        let out = vp.next(&Bar::new().high(12.0).low(10.0).volume(50.0));
+        assert_eq!(out.poc, 12.5);
+        assert_eq!(out.value_area_high, 13.0);
+        assert_eq!(out.value_area_low, 11.0);
+
+        // the wide bar drops out of the window, the range is now [10, 14]
+        let out = vp.next(&Bar::new().high(14.0).low(13.0).volume(1000.0));
+        assert_eq!(round(out.poc), 13.6);
+        assert_eq!(round(out.value_area_high), 14.0);
+        assert_eq!(round(out.value_area_low), 12.4);
+    }
+
+    #[test]
+    fn test_flat_window() {
+        let mut vp = VolumeProfile::new(2, 4).unwrap();
+        vp.next(&Bar::new().high(5.0).low(5.0).volume(10.0));
+        let out = vp.next(&Bar::new().high(5.0).low(5.0).volume(20.0));
+        assert_eq!(out.poc, 5.0);
+        assert_eq!(out.value_area_high, 5.0);
+        assert_eq!(out.value_area_low, 5.0);
+
+        let mut vp = VolumeProfile::new(1, 4).unwrap();
+        assert!(vp
+            .next(&Bar::new().high(6.0).low(5.0).volume(0.0))
+            .poc
+            .is_nan());
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vp = VolumeProfile::new(2, 4).unwrap();
+        vp.next(&Bar::new().high(14.0).low(10.0).volume(100.0));
+        vp.next(&Bar::new().high(13.0).low(12.0).volume(500.0));
+
+        vp.reset();
+        assert!(vp
+            .next(&Bar::new().high(13.0).low(12.0).volume(500.0))
+            .poc
+            .is_nan());
+    }
+
+    #[test]
+    fn test_default() {
+        VolumeProfile::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let vp = VolumeProfile::new(20, 10).unwrap();
+        assert_eq!(format!("{}", vp), "VP(20, 10)");
+    }
+}
@@ -0,0 +1,219 @@
+use core::fmt;
+
+use crate::{Close, High, Low, Next, Reset, Volume};
+use serde::{Deserialize, Serialize};
+
+/// Anchored Volume Weighted Average Price (AVWAP).
+///
+/// The average typical price weighted by volume, accumulated from an anchor bar chosen by the
+/// caller, e.g. a swing high or an earnings release detected elsewhere. Calling
+/// [anchor](#method.anchor) clears the running sums and the next bar becomes the first one of
+/// the average, while the indicator keeps processing. Without an anchor it accumulates from
+/// the first bar.
+///
+/// # Formula
+///
+/// TP = (High + Low + Close) / 3
+///
+/// AVWAP = sum(TP * Volume) / sum(Volume), over the bars since the anchor
+///
+/// The output is NaN until some volume has been traded since the anchor.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::AnchoredVwap;
+/// use tam::{DataItem, Next};
+///
+/// let bar = |price, volume| {
+///     DataItem::builder()
+///         .open(price)
+///         .high(price)
+///         .low(price)
+///         .close(price)
+///         .volume(volume)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut vwap = AnchoredVwap::new();
+/// assert_eq!(vwap.next(&bar(10.0, 100.0)), 10.0);
+/// assert_eq!(vwap.next(&bar(13.0, 200.0)), 12.0);
+///
+/// // anchored at the next bar
+/// vwap.anchor();
+/// assert_eq!(vwap.next(&bar(20.0, 100.0)), 20.0);
+/// ```
+///
+/// # Links
+///
+/// * [Anchored VWAP, StockCharts](https://chartschool.stockcharts.com/table-of-contents/technical-indicators-and-overlays/technical-overlays/anchored-vwap)
+///
+#[doc(alias = "AVWAP")]
+#[doc(alias = "VWAP")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AnchoredVwap {
+    sum_price_volume: f64,
+    sum_volume: f64,
+    /// Bars since the anchor.
+    count: usize,
+}
+
+impl AnchoredVwap {
+    pub fn new() -> Self {
+        Self {
+            sum_price_volume: 0.0,
+            sum_volume: 0.0,
+            count: 0,
+        }
+    }
+
+    /// Anchors the average at the next bar: the bars seen so far no longer count.
+    ///
+    /// Unlike [reset](#method.reset), it is meant to be called while processing a stream,
+    /// each time a new anchor is found.
+    pub fn anchor(&mut self) {
+        self.sum_price_volume = 0.0;
+        self.sum_volume = 0.0;
+        self.count = 0;
+    }
+
+    /// Number of bars accumulated since the anchor.
+    pub fn bars_since_anchor(&self) -> usize {
+        self.count
+    }
+}
+
+impl<T: High + Low + Close + Volume> Next<&T> for AnchoredVwap {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let typical_price = (input.high() + input.low() + input.close()) / 3.0;
+        self.sum_price_volume += typical_price * input.volume();
+        self.sum_volume += input.volume();
+        self.count += 1;
+
+        if self.sum_volume > 0.0 {
+            self.sum_price_volume / self.sum_volume
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+impl Reset for AnchoredVwap {
+    fn reset(&mut self) {
+        self.anchor();
+    }
+}
+
+impl Default for AnchoredVwap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for AnchoredVwap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AVWAP")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next() {
+        let mut vwap = AnchoredVwap::new();
+        // typical prices 10, 12, 11
+        assert_eq!(
+            vwap.next(&Bar::new().high(11.0).low(9.0).close(10.0).volume(100.0)),
+            10.0
+        );
+        assert_eq!(
+            vwap.next(&Bar::new().high(13.0).low(11.0).close(12.0).volume(300.0)),
+            11.5
+        );
+        assert_eq!(
+            round(vwap.next(&Bar::new().high(12.0).low(9.0).close(12.0).volume(200.0))),
+            11.333
+        );
+        assert_eq!(vwap.bars_since_anchor(), 3);
+    }
+
+    #[test]
+    fn test_anchor_mid_stream() {
+        let bars = [
+            Bar::new().high(11.0).low(9.0).close(10.0).volume(100.0),
+            Bar::new().high(13.0).low(11.0).close(12.0).volume(300.0),
+            Bar::new().high(16.0).low(14.0).close(15.0).volume(500.0),
+            Bar::new().high(15.0).low(13.0).close(14.0).volume(100.0),
+            Bar::new().high(14.0).low(12.0).close(13.0).volume(400.0),
+        ];
+
+        let mut anchored = AnchoredVwap::new();
+        for b in bars[..2].iter() {
+            anchored.next(b);
+        }
+        anchored.anchor();
+        assert_eq!(anchored.bars_since_anchor(), 0);
+
+        // same as a VWAP started at the anchor bar
+        let mut fresh = AnchoredVwap::new();
+        for b in bars[2..].iter() {
+            assert_eq!(anchored.next(b), fresh.next(b));
+        }
+        // (15 * 500 + 14 * 100 + 13 * 400) / 1000
+        assert_eq!(
+            round(anchored.next(&Bar::new().high(13.0).low(13.0).close(13.0).volume(0.0))),
+            14.1
+        );
+        assert_eq!(anchored.bars_since_anchor(), 4);
+    }
+
+    #[test]
+    fn test_no_volume() {
+        let mut vwap = AnchoredVwap::new();
+        assert!(vwap
+            .next(&Bar::new().high(11.0).low(9.0).close(10.0).volume(0.0))
+            .is_nan());
+        assert_eq!(
+            vwap.next(&Bar::new().high(13.0).low(11.0).close(12.0).volume(10.0)),
+            12.0
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vwap = AnchoredVwap::new();
+        vwap.next(&Bar::new().high(11.0).low(9.0).close(10.0).volume(100.0));
+
+        vwap.reset();
+        assert_eq!(vwap, AnchoredVwap::new());
+        assert_eq!(
+            vwap.next(&Bar::new().high(13.0).low(11.0).close(12.0).volume(10.0)),
+            12.0
+        );
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut vwap = AnchoredVwap::new();
+        vwap.next(&Bar::new().high(11.0).low(9.0).close(10.0).volume(100.0));
+        let mut restored = serde_round_trip(&vwap);
+        let b = Bar::new().high(13.0).low(11.0).close(12.5).volume(300.0);
+        assert_eq!(restored.next(&b), vwap.next(&b));
+    }
+
+    #[test]
+    fn test_default() {
+        AnchoredVwap::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", AnchoredVwap::new()), "AVWAP");
+    }
+}
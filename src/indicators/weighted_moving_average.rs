@@ -1,9 +1,14 @@
-use std::fmt;
+use alloc::{boxed::Box, vec};
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Next, Period, Reset, Warmup};
 use serde::{Deserialize, Serialize};
 
+/// Largest period whose normalization factor _n(n+1)/2_ is below 2<sup>53</sup>, so that it
+/// and the integer weights are exact in `f64`.
+const MAX_PERIOD: usize = (1 << 27) - 1;
+
 /// Weighted moving average (WMA).
 ///
 /// A moving average that assigns weights that decrease in arithmetical progression.
@@ -19,6 +24,11 @@ use serde::{Deserialize, Serialize};
 /// * _n_ - is the period.
 /// * _p<sub>M</sub>_ - is the input value at a time period t.
 ///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0 and at most 134,217,727, where the
+///   normalization factor would stop being exact in `f64`). Default is 9.
+///
 /// # Example
 ///
 /// ```
@@ -36,7 +46,6 @@ use serde::{Deserialize, Serialize};
 ///
 /// * [Weighted moving average, Wikipedia](https://en.wikipedia.org/wiki/Moving_average#Weighted_moving_average)
 ///
-
 #[doc(alias = "WMA")]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct WeightedMovingAverage {
@@ -47,12 +56,15 @@ pub struct WeightedMovingAverage {
     sum: f64,
     sum_flat: f64,
     deque: Box<[f64]>,
+    #[serde(default)]
+    last_output: Option<f64>,
 }
 
 impl WeightedMovingAverage {
     pub fn new(period: usize) -> Result<Self> {
         match period {
             0 => Err(TaError::InvalidParameter),
+            p if p > MAX_PERIOD => Err(TaError::InvalidParameter),
             _ => Ok(Self {
                 period,
                 index: 0,
@@ -61,6 +73,7 @@ impl WeightedMovingAverage {
                 sum: 0.0,
                 sum_flat: 0.0,
                 deque: vec![0.0; period].into_boxed_slice(),
+                last_output: None,
             }),
         }
     }
@@ -72,10 +85,14 @@ impl Period for WeightedMovingAverage {
     }
 }
 
-impl Next<f64> for WeightedMovingAverage {
-    type Output = f64;
+impl Warmup for WeightedMovingAverage {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
 
-    fn next(&mut self, input: f64) -> Self::Output {
+impl WeightedMovingAverage {
+    fn next_value(&mut self, input: f64) -> f64 {
         let old_val: f64 = self.deque[self.index];
         self.deque[self.index] = input;
 
@@ -97,6 +114,8 @@ impl Next<f64> for WeightedMovingAverage {
     }
 }
 
+impl_next_option!(WeightedMovingAverage);
+
 impl<T: Close> Next<&T> for WeightedMovingAverage {
     type Output = f64;
 
@@ -115,6 +134,7 @@ impl Reset for WeightedMovingAverage {
         for i in 0..self.period {
             self.deque[i] = 0.0;
         }
+        self.last_output = None;
     }
 }
 
@@ -143,6 +163,17 @@ mod tests {
         assert!(WeightedMovingAverage::new(1).is_ok());
     }
 
+    #[test]
+    fn test_max_period() {
+        assert_eq!(MAX_PERIOD * (MAX_PERIOD + 1) / 2, (1 << 53) - (1 << 26));
+        assert!(WeightedMovingAverage::new(MAX_PERIOD + 1).is_err());
+        assert!(WeightedMovingAverage::new(usize::MAX).is_err());
+
+        // the window is zeroed memory that is never touched here
+        let wma = WeightedMovingAverage::new(MAX_PERIOD).unwrap();
+        assert_eq!(wma.period(), MAX_PERIOD);
+    }
+
     #[test]
     fn test_next() {
         let mut wma = WeightedMovingAverage::new(3).unwrap();
@@ -0,0 +1,167 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::StandardDeviation as Sd;
+use crate::{Close, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Z-Score.
+///
+/// Distance of the price from its moving average, in standard deviations: how unusual the
+/// price is compared to the last _period_ prices.
+///
+/// # Formula
+///
+/// Z-Score = (p<sub>t</sub> - SMA(_period_)) / SD(_period_)
+///
+/// Where the [standard deviation](struct.StandardDeviation.html) is the population one. The
+/// output is NaN until _period_ prices are known, and 0 when they are all equal.
+///
+/// # Parameters
+///
+/// * _period_ - number of prices (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::ZScore;
+/// use tam::Next;
+///
+/// let mut z = ZScore::new(2).unwrap();
+/// assert!(z.next(10.0).is_nan());
+/// // mean 11, SD 1
+/// assert_eq!(z.next(12.0), 1.0);
+/// ```
+///
+/// # Links
+///
+/// * [Standard score, Wikipedia](https://en.wikipedia.org/wiki/Standard_score)
+///
+#[doc(alias = "ZSCORE")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ZScore {
+    sd: Sd,
+    count: usize,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl ZScore {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            sd: Sd::new(period)?,
+            count: 0,
+            last_output: None,
+        })
+    }
+}
+
+impl Period for ZScore {
+    fn period(&self) -> usize {
+        self.sd.period()
+    }
+}
+
+impl Warmup for ZScore {
+    fn lookback(&self) -> usize {
+        self.sd.period() - 1
+    }
+}
+
+impl ZScore {
+    fn next_value(&mut self, input: f64) -> f64 {
+        let sd = self.sd.next(input);
+        if self.count < self.lookback() {
+            self.count += 1;
+            return f64::NAN;
+        }
+        if sd == 0.0 {
+            return 0.0;
+        }
+        (input - self.sd.mean()) / sd
+    }
+}
+
+impl_next_option!(ZScore);
+
+impl<T: Close> Next<&T> for ZScore {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for ZScore {
+    fn reset(&mut self) {
+        self.sd.reset();
+        self.count = 0;
+        self.last_output = None;
+    }
+}
+
+impl Default for ZScore {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for ZScore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ZSCORE({})", self.sd.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(ZScore::new(0).is_err());
+        assert!(ZScore::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut z = ZScore::new(3).unwrap();
+
+        assert!(z.next(2.0).is_nan());
+        assert!(z.next(5.0).is_nan());
+        // mean 2.667, SD 1.700
+        assert_eq!(round(z.next(1.0)), -0.981);
+        // mean 4.083, SD 2.239
+        assert_eq!(round(z.next(&Bar::new().close(6.25))), 0.968);
+    }
+
+    #[test]
+    fn test_flat() {
+        let mut z = ZScore::new(3).unwrap();
+        for _ in 0..3 {
+            z.next(7.0);
+        }
+        assert_eq!(z.next(7.0), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut z = ZScore::new(2).unwrap();
+        z.next(10.0);
+        z.next(12.0);
+
+        z.reset();
+        assert!(z.next(12.0).is_nan());
+        assert_eq!(z.next(10.0), -1.0);
+    }
+
+    #[test]
+    fn test_default() {
+        ZScore::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", ZScore::new(20).unwrap()), "ZSCORE(20)");
+    }
+}
@@ -0,0 +1,227 @@
+use alloc::{boxed::Box, vec};
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Zero-Lag Exponential Moving Average (ZLEMA).
+///
+/// Developed by John Ehlers and Ric Way, an EMA of the price corrected by its momentum. Adding
+/// the change over the lag of the EMA before smoothing compensates most of that lag, so the
+/// average follows a trend more closely, at the cost of overshooting after sudden moves.
+///
+/// # Formula
+///
+/// _lag_ = (_period_ - 1) / 2 (integer division)
+///
+/// ZLEMA = EMA(p<sub>t</sub> + (p<sub>t</sub> - p<sub>t-lag</sub>), _period_)
+///
+/// The output is NaN until the price _lag_ bars back is known, the EMA starts at the first
+/// corrected price.
+///
+/// # Parameters
+///
+/// * _period_ - period of the EMA (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::ZeroLagEma;
+/// use tam::Next;
+///
+/// // lag of 1
+/// let mut zlema = ZeroLagEma::new(3).unwrap();
+/// assert!(zlema.next(2.0).is_nan());
+/// assert_eq!(zlema.next(4.0), 6.0);  // 4 + (4 - 2)
+/// assert_eq!(zlema.next(3.0), 4.0);  // (6 + 2) / 2
+/// assert_eq!(zlema.next(7.0), 7.5);  // (4 + 11) / 2
+/// ```
+///
+/// # Links
+///
+/// * [Zero lag exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Zero_lag_exponential_moving_average)
+///
+#[doc(alias = "ZLEMA")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ZeroLagEma {
+    ema: Ema,
+    index: usize,
+    count: usize,
+    prices: Box<[f64]>,
+    #[serde(default)]
+    last_output: Option<f64>,
+}
+
+impl ZeroLagEma {
+    pub fn new(period: usize) -> Result<Self> {
+        let ema = Ema::new(period)?;
+        Ok(Self {
+            ema,
+            index: 0,
+            count: 0,
+            prices: vec![0.0; (period - 1) / 2].into_boxed_slice(),
+            last_output: None,
+        })
+    }
+
+    /// Number of bars between the price and the price of its momentum.
+    pub fn lag(&self) -> usize {
+        self.prices.len()
+    }
+}
+
+impl Period for ZeroLagEma {
+    fn period(&self) -> usize {
+        self.ema.period()
+    }
+}
+
+impl Warmup for ZeroLagEma {
+    fn lookback(&self) -> usize {
+        self.lag()
+    }
+}
+
+impl ZeroLagEma {
+    fn next_value(&mut self, input: f64) -> f64 {
+        if self.prices.is_empty() {
+            return self.ema.next(input);
+        }
+
+        let lagged = self.prices[self.index];
+        self.prices[self.index] = input;
+        self.index = if self.index + 1 < self.prices.len() {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count < self.lookback() {
+            self.count += 1;
+            return f64::NAN;
+        }
+        self.ema.next(input + (input - lagged))
+    }
+}
+
+impl_next_option!(ZeroLagEma);
+
+impl<T: Close> Next<&T> for ZeroLagEma {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for ZeroLagEma {
+    fn reset(&mut self) {
+        self.ema.reset();
+        self.index = 0;
+        self.count = 0;
+        for price in self.prices.iter_mut() {
+            *price = 0.0;
+        }
+        self.last_output = None;
+    }
+}
+
+impl Default for ZeroLagEma {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for ZeroLagEma {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ZLEMA({})", self.ema.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(ZeroLagEma::new(0).is_err());
+        assert!(ZeroLagEma::new(1).is_ok());
+        assert_eq!(ZeroLagEma::new(20).unwrap().lag(), 9);
+    }
+
+    #[test]
+    fn test_next() {
+        let mut zlema = ZeroLagEma::new(5).unwrap();
+        assert_eq!(zlema.lookback(), 2);
+
+        // α = 1/3, corrected prices 8, 2, 13
+        assert!(zlema.next(2.0).is_nan());
+        assert!(zlema.next(&Bar::new().close(4)).is_nan());
+        assert_eq!(zlema.next(5.0), 8.0);
+        assert_eq!(round(zlema.next(3.0)), 6.0);
+        assert_eq!(round(zlema.next(9.0)), 8.333);
+    }
+
+    #[test]
+    fn test_no_lag() {
+        // periods 1 and 2 have no lag: a plain EMA
+        let mut zlema = ZeroLagEma::new(2).unwrap();
+        let mut ema = Ema::new(2).unwrap();
+        for &price in [3.0, 5.0, 4.0, 8.0].iter() {
+            assert_eq!(zlema.next(price), ema.next(price));
+        }
+    }
+
+    #[test]
+    fn test_step() {
+        let mut zlema = ZeroLagEma::new(9).unwrap();
+        let mut ema = Ema::new(9).unwrap();
+        for _ in 0..20 {
+            zlema.next(0.0);
+            ema.next(0.0);
+        }
+
+        // after a step from 0 to 10, the ZLEMA is closer to the new level on the first bars
+        let mut zlema_bars = None;
+        let mut ema_bars = None;
+        for bar in 1..=30 {
+            let (z, e) = (zlema.next(10.0), ema.next(10.0));
+            if bar <= 3 {
+                assert!(10.0 - z < 10.0 - e);
+            }
+            if z >= 9.0 && zlema_bars.is_none() {
+                zlema_bars = Some(bar);
+            }
+            if e >= 9.0 && ema_bars.is_none() {
+                ema_bars = Some(bar);
+            }
+        }
+        // 90% of the step is reached after 3 bars instead of 11
+        assert_eq!(zlema_bars, Some(3));
+        assert_eq!(ema_bars, Some(11));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut zlema = ZeroLagEma::new(3).unwrap();
+        zlema.next(2.0);
+        zlema.next(4.0);
+
+        zlema.reset();
+        assert!(zlema.next(5.0).is_nan());
+        assert_eq!(zlema.next(4.0), 3.0);
+    }
+
+    #[test]
+    fn test_default() {
+        ZeroLagEma::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", ZeroLagEma::new(20).unwrap()), "ZLEMA(20)");
+    }
+}
@@ -29,7 +29,27 @@
 //! * Trend
 //!   * [Exponential Moving Average (EMA)](crate::indicators::ExponentialMovingAverage)
 //!   * [Simple Moving Average (SMA)](crate::indicators::SimpleMovingAverage)
+//!   * [Smoothed Moving Average (SMMA)](crate::indicators::SmoothedMovingAverage)
 //!   * [Weighted Moving Average (WMA)](crate::indicators::WeightedMovingAverage)
+//!   * [Fibonacci Weighted Moving Average (FWMA)](crate::indicators::FibonacciWeightedMovingAverage)
+//!   * [Arnaud Legoux Moving Average (ALMA)](crate::indicators::Alma)
+//!   * [Double Exponential Moving Average (DEMA)](crate::indicators::DoubleExponentialMovingAverage)
+//!   * [Triple Exponential Moving Average (TEMA)](crate::indicators::TripleExponentialMovingAverage)
+//!   * [Moving Average (SMA, EMA, WMA, RMA, DEMA or TEMA)](crate::indicators::MovingAverage)
+//!   * [Alligator](crate::indicators::Alligator)
+//!   * [Moving Average Ribbon](crate::indicators::MaRibbon)
+//!   * [Rainbow Moving Average](crate::indicators::Rainbow)
+//!   * [Guppy Multiple Moving Average (GMMA)](crate::indicators::Gmma)
+//!   * [GMMA Score](crate::indicators::GmmaScore)
+//!   * [Laguerre Filter](crate::indicators::LaguerreFilter)
+//!   * [Decycler](crate::indicators::Decycler)
+//!   * [Chande's Variable Index Dynamic Average (VIDYA)](crate::indicators::Vidya)
+//!   * [McGinley Dynamic](crate::indicators::McginleyDynamic)
+//!   * [Zero-Lag Exponential Moving Average (ZLEMA)](crate::indicators::ZeroLagEma)
+//!   * [SuperTrend](crate::indicators::SuperTrend)
+//!   * [Gann HiLo Activator](crate::indicators::GannHiLo)
+//!   * [Linear Regression](crate::indicators::LinearRegression)
+//!   * [Time Series Forecast (TSF)](crate::indicators::TimeSeriesForecast)
 //! * Oscillators
 //!   * [Relative Strength Index (RSI)](indicators/struct.RelativeStrengthIndex.html)
 //!   * [Fast Stochastic](indicators/struct.FastStochastic.html)
@@ -38,31 +58,138 @@
 //!   * [Percentage Price Oscillator (PPO)](indicators/struct.PercentagePriceOscillator.html)
 //!   * [Commodity Channel Index (CCI)](indicators/struct.CommodityChannelIndex.html)
 //!   * [Money Flow Index (MFI)](indicators/struct.MoneyFlowIndex.html)
+//!   * [Gator Oscillator](crate::indicators::GatorOscillator)
+//!   * [Laguerre RSI (LRSI)](crate::indicators::LaguerreRsi)
+//!   * [Center of Gravity (COG)](crate::indicators::CenterOfGravity)
+//!   * [Decycler Oscillator](crate::indicators::DecyclerOscillator)
+//!   * [Sine Wave (HT_SINE)](crate::indicators::SineWave)
+//!   * [Chande Momentum Oscillator (CMO)](crate::indicators::ChandeMomentumOscillator)
+//!   * [Relative Momentum Index (RMI)](crate::indicators::RelativeMomentumIndex)
+//!   * [Schaff Trend Cycle (STC)](crate::indicators::SchaffTrendCycle)
+//!   * [QStick](crate::indicators::QStick)
+//!   * [Elder Impulse System](crate::indicators::ElderImpulse)
+//!   * [Disparity Index](crate::indicators::DisparityIndex)
+//!   * [Relative Vigor Index (RVI)](crate::indicators::RelativeVigorIndex)
 //! * Other
 //!   * [Standard Deviation (SD)](indicators/struct.StandardDeviation.html)
 //!   * [Mean Absolute Deviation (MAD)](indicators/struct.MeanAbsoluteDeviation.html)
+//!   * [Median](crate::indicators::Median)
+//!   * [Skewness](crate::indicators::Skewness)
+//!   * [Kurtosis](crate::indicators::Kurtosis)
 //!   * [Bollinger Bands (BB)](indicators/struct.BollingerBands.html)
+//!   * [Bollinger %B](crate::indicators::BollingerPercentB)
+//!   * [Bollinger Bandwidth (BBW)](crate::indicators::BollingerBandwidth)
 //!   * [Chandelier Exit (CE)](indicators/struct.ChandelierExit.html)
+//!   * [Percent Trailing Stop](crate::indicators::PercentTrailingStop)
+//!   * [SafeZone Stop](crate::indicators::SafeZoneStop)
 //!   * [Keltner Channel (KC)](indicators/struct.KeltnerChannel.html)
+//!   * [Squeeze Momentum (TTM Squeeze)](crate::indicators::SqueezeMomentum)
+//!   * [Donchian Breakout](crate::indicators::Breakout)
 //!   * [Maximum](indicators/struct.Maximum.html)
 //!   * [Minimum](indicators/struct.Minimum.html)
 //!   * [True Range](indicators/struct.TrueRange.html)
 //!   * [Average True Range (ATR)](indicators/struct.AverageTrueRange.html)
+//!   * [Chaikin Volatility](crate::indicators::ChaikinVolatility)
+//!   * [Chaikin Money Flow (CMF)](crate::indicators::ChaikinMoneyFlow)
+//!   * [Ulcer Index (UI)](crate::indicators::UlcerIndex)
 //!   * [Efficiency Ratio (ER)](indicators/struct.EfficiencyRatio.html)
 //!   * [Rate of Change (ROC)](indicators/struct.RateOfChange.html)
+//!   * [Return](crate::indicators::Return)
+//!   * [Log Return](crate::indicators::LogReturn)
+//!   * [Rolling Sharpe Ratio](crate::indicators::RollingSharpe)
+//!   * [Maximum Drawdown (MAXDD)](crate::indicators::MaxDrawdown)
 //!   * [On Balance Volume (OBV)](indicators/struct.OnBalanceVolume.html)
+//!   * [Price Volume Trend (PVT)](crate::indicators::PriceVolumeTrend)
+//!   * [Percentage Volume Oscillator (PVO)](crate::indicators::PercentageVolumeOscillator)
+//!   * [Negative Volume Index (NVI)](crate::indicators::NegativeVolumeIndex)
+//!   * [Positive Volume Index (PVI)](crate::indicators::PositiveVolumeIndex)
+//!   * [Klinger Volume Oscillator (KVO)](crate::indicators::KlingerOscillator)
+//!   * [Anchored VWAP (AVWAP)](crate::indicators::AnchoredVwap)
+//!   * [Damiani Volatmeter](indicators/struct.DamianiVolatmeter.html)
+//!   * [Fractal](crate::indicators::Fractal)
+//!   * [Volume Profile](crate::indicators::VolumeProfile)
+//!   * [Hurst Exponent](crate::indicators::HurstExponent)
+//!   * [Exponential Correlation](crate::indicators::ExponentialCorrelation)
+//!   * [Beta](crate::indicators::Beta)
+//!   * [Z-Score](crate::indicators::ZScore)
+//!   * [Trend Streak](crate::indicators::TrendStreak)
 //!
+//! # List of signals
+//!
+//! * [Divergence](crate::signals::Divergence)
+//! * [COG Cross](crate::signals::CogCross)
+//! * [Regime Filter](crate::signals::RegimeFilter)
+//! * [Spread Z-Score](crate::signals::SpreadZScore)
+//! * [Moving Average Cross](crate::signals::MaCross)
+//! * [Overbought/Oversold Bands](crate::signals::Bands)
+//! * [DI Cross](crate::signals::DiCross)
+//! * [Cross Tracker](crate::signals::CrossTracker)
+//!
+//! # Features
+//!
+//! * `std` (default) - without it the crate is `no_std` and only needs `alloc`. The float
+//!   functions missing from `core` are then computed with [libm](https://docs.rs/libm), which
+//!   is always a dependency (Cargo cannot enable one when a feature is off) but is only linked
+//!   without `std`.
+//! * `deterministic` - accumulate sums in a fixed, left-to-right order instead of updating them
+//!   incrementally ([SMA](crate::indicators::SimpleMovingAverage),
+//!   [Correlation](crate::indicators::Correlation)), so results are bit-identical across
+//!   machines and only depend on the current window. Slower for long periods.
+//! * `rayon` - `run_parallel`, running one indicator configuration over
+//!   many independent series across threads.
+//!
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 #[cfg(test)]
 #[macro_use]
 mod test_helper;
 
+#[macro_use]
 mod helpers;
 
+#[cfg(not(feature = "std"))]
+mod math;
+
 pub mod errors;
 pub mod indicators;
+pub mod signals;
 
 mod traits;
 pub use crate::traits::*;
 
 mod data_item;
 pub use crate::data_item::DataItem;
+
+mod output_history;
+pub use crate::output_history::OutputHistory;
+
+mod aggregate;
+pub use crate::aggregate::BarAggregator;
+
+mod multi_timeframe;
+pub use crate::multi_timeframe::MultiTimeframe;
+
+mod persist;
+pub use crate::persist::Persist;
+
+mod composite_score;
+pub use crate::composite_score::{CompositeScore, ScoreWeight};
+
+mod pool;
+pub use crate::pool::Pool;
+
+mod pipeline;
+pub use crate::pipeline::Pipeline;
+
+mod compose;
+pub use crate::compose::Chain;
+
+mod buffered;
+pub use crate::buffered::Buffered;
+
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "rayon")]
+pub use crate::parallel::run_parallel;
@@ -0,0 +1,53 @@
+//! Float functions of `std` missing from `core`, for builds without the `std` feature.
+//!
+//! They are computed with [libm](https://docs.rs/libm). The files calling them import
+//! [Float](trait.Float.html) when `std` is disabled, and use the inherent `f64` methods
+//! otherwise. The imports allow `unused_imports`: when another crate of the build links `std`
+//! (e.g. the dev-dependencies), its inherent methods are found first and the trait goes unused.
+
+/// The `std` methods of `f64` used by the indicators.
+#[allow(dead_code)]
+pub(crate) trait Float {
+    fn sqrt(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn round(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn atan(self) -> Self;
+}
+
+impl Float for f64 {
+    fn sqrt(self) -> f64 {
+        libm::sqrt(self)
+    }
+
+    fn powi(self, n: i32) -> f64 {
+        libm::pow(self, n as f64)
+    }
+
+    fn exp(self) -> f64 {
+        libm::exp(self)
+    }
+
+    fn ln(self) -> f64 {
+        libm::log(self)
+    }
+
+    fn round(self) -> f64 {
+        libm::round(self)
+    }
+
+    fn sin(self) -> f64 {
+        libm::sin(self)
+    }
+
+    fn cos(self) -> f64 {
+        libm::cos(self)
+    }
+
+    fn atan(self) -> f64 {
+        libm::atan(self)
+    }
+}
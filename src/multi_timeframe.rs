@@ -0,0 +1,186 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{BarAggregator, Close, DataItem, High, Low, Next, Open, Reset, Volume};
+use serde::{Deserialize, Serialize};
+
+/// Runs copies of an indicator on several timeframes from a single stream of bars.
+///
+/// Every timeframe is a multiple (_factor_) of the input timeframe: with 1 minute bars the
+/// factors `[1, 5, 15]` give the 1, 5 and 15 minutes timeframes. Each timeframe has its own
+/// indicator, fed with a [DataItem](struct.DataItem.html) every `factor` input bars by a
+/// [BarAggregator](struct.BarAggregator.html). The bar opens with the first input, closes
+/// with the last one, spans their highest high and lowest low and sums their volume.
+///
+/// The output has one entry per factor, in the order of the factors. An entry is `Some`
+/// only when a bar of its timeframe closed with the current input.
+///
+/// # Parameters
+///
+/// * _make_ - creates the indicator of each timeframe
+/// * _factors_ - number of input bars in a bar of each timeframe (not empty, integers
+///   greater than 0)
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::Maximum;
+/// use tam::{DataItem, MultiTimeframe, Next};
+///
+/// let mut mtf = MultiTimeframe::new(|| Maximum::new(2).unwrap(), &[1, 2]).unwrap();
+///
+/// let bar = |high: f64| {
+///     DataItem::builder().open(1.0).high(high).low(1.0).close(1.0).volume(1.0).build().unwrap()
+/// };
+///
+/// assert_eq!(mtf.next(&bar(5.0)), vec![Some(5.0), None]);
+/// assert_eq!(mtf.next(&bar(3.0)), vec![Some(5.0), Some(5.0)]);
+/// assert_eq!(mtf.next(&bar(2.0)), vec![Some(3.0), None]);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MultiTimeframe<I> {
+    indicators: Vec<I>,
+    aggregators: Vec<BarAggregator>,
+}
+
+impl<I> MultiTimeframe<I> {
+    pub fn new(make: impl Fn() -> I, factors: &[usize]) -> Result<Self> {
+        if factors.is_empty() || factors.contains(&0) {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            indicators: factors.iter().map(|_| make()).collect(),
+            aggregators: factors
+                .iter()
+                .map(|&factor| BarAggregator::new(factor))
+                .collect::<Result<_>>()?,
+        })
+    }
+
+    pub fn factors(&self) -> Vec<usize> {
+        self.aggregators.iter().map(|agg| agg.factor()).collect()
+    }
+
+    /// Indicators of the timeframes, in the order of the factors.
+    pub fn indicators(&self) -> &[I] {
+        &self.indicators
+    }
+}
+
+impl<I, T, O> Next<&T> for MultiTimeframe<I>
+where
+    I: for<'a> Next<&'a DataItem, Output = O>,
+    T: Open + High + Low + Close + Volume,
+{
+    type Output = Vec<Option<O>>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.aggregators
+            .iter_mut()
+            .zip(self.indicators.iter_mut())
+            .map(|(agg, indicator)| agg.next(input).map(|bar| indicator.next(&bar)))
+            .collect()
+    }
+}
+
+impl<I: Reset> Reset for MultiTimeframe<I> {
+    fn reset(&mut self) {
+        for (agg, indicator) in self.aggregators.iter_mut().zip(self.indicators.iter_mut()) {
+            agg.reset();
+            indicator.reset();
+        }
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for MultiTimeframe<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MTF({}", self.indicators[0])?;
+        for agg in self.aggregators.iter() {
+            write!(f, ", {}", agg.factor())?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{Maximum, RelativeStrengthIndex as Rsi};
+    use crate::test_helper::*;
+
+    fn rsi_mtf(factors: &[usize]) -> Result<MultiTimeframe<Rsi>> {
+        MultiTimeframe::new(|| Rsi::new(3).unwrap(), factors)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(rsi_mtf(&[]).is_err());
+        assert!(rsi_mtf(&[1, 0]).is_err());
+        assert!(rsi_mtf(&[1, 5, 15]).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut mtf = rsi_mtf(&[1, 5, 15]).unwrap();
+        let mut rsi_1 = Rsi::new(3).unwrap();
+        let mut rsi_5 = Rsi::new(3).unwrap();
+        let mut rsi_15 = Rsi::new(3).unwrap();
+
+        // compares bits, so the NaNs of the warmup are equal too
+        let bits = |value: Option<f64>| value.map(f64::to_bits);
+
+        for i in 0..45 {
+            let close = 100.0 + (i as f64 * 0.9).sin() * 10.0;
+            let out = mtf.next(&Bar::new().high(close).low(close).close(close));
+
+            assert_eq!(out.len(), 3);
+            assert_eq!(bits(out[0]), bits(Some(rsi_1.next(close))));
+            if i % 5 == 4 {
+                assert_eq!(bits(out[1]), bits(Some(rsi_5.next(close))));
+                if i >= 24 {
+                    assert!(!out[1].unwrap().is_nan());
+                }
+            } else {
+                assert_eq!(out[1], None);
+            }
+            if i % 15 == 14 {
+                assert_eq!(bits(out[2]), bits(Some(rsi_15.next(close))));
+            } else {
+                assert_eq!(out[2], None);
+            }
+        }
+    }
+
+    #[test]
+    fn test_high_low() {
+        let mut mtf = MultiTimeframe::new(|| Maximum::new(1).unwrap(), &[2]).unwrap();
+        assert_eq!(mtf.next(&Bar::new().high(4)), vec![None]);
+        assert_eq!(mtf.next(&Bar::new().high(3)), vec![Some(4.0)]);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut mtf = MultiTimeframe::new(|| Maximum::new(2).unwrap(), &[2]).unwrap();
+        mtf.next(&Bar::new().high(10));
+        assert_eq!(mtf.next(&Bar::new().high(11)), vec![Some(11.0)]);
+        mtf.next(&Bar::new().high(12));
+
+        mtf.reset();
+        assert_eq!(mtf.next(&Bar::new().high(5)), vec![None]);
+        assert_eq!(mtf.next(&Bar::new().high(4)), vec![Some(5.0)]);
+    }
+
+    #[test]
+    fn test_accessors() {
+        let mtf = rsi_mtf(&[1, 5]).unwrap();
+        assert_eq!(mtf.factors(), vec![1, 5]);
+        assert_eq!(mtf.indicators().len(), 2);
+    }
+
+    #[test]
+    fn test_display() {
+        let mtf = rsi_mtf(&[1, 5, 15]).unwrap();
+        assert_eq!(format!("{}", mtf), "MTF(RSI(3), 1, 5, 15)");
+    }
+}
@@ -0,0 +1,182 @@
+use alloc::collections::VecDeque;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Next, Reset};
+use serde::{Deserialize, Serialize};
+
+/// Keeps a bounded log of the most recent outputs of an indicator.
+///
+/// `OutputHistory` wraps an indicator, forwards every input to it and records the outputs,
+/// so the current value can be compared to the values several bars ago (e.g. to detect
+/// divergences or crossovers). Only the last `capacity` outputs are kept.
+///
+/// # Parameters
+///
+/// * _inner_ - the wrapped indicator, its output must be `f64`
+/// * _capacity_ - number of outputs to keep (integer greater than 0)
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::SimpleMovingAverage;
+/// use tam::{Next, OutputHistory};
+///
+/// let sma = SimpleMovingAverage::new(2).unwrap();
+/// let mut history = OutputHistory::new(sma, 3).unwrap();
+///
+/// history.next(2.0);
+/// history.next(4.0);
+/// history.next(6.0);
+///
+/// assert_eq!(history.get(0), Some(5.0));
+/// assert_eq!(history.get(1), Some(3.0));
+/// assert_eq!(history.get(2), Some(2.0));
+/// assert_eq!(history.get(3), None);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutputHistory<I> {
+    inner: I,
+    capacity: usize,
+    values: VecDeque<f64>,
+}
+
+impl<I> OutputHistory<I> {
+    pub fn new(inner: I, capacity: usize) -> Result<Self> {
+        match capacity {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                inner,
+                capacity,
+                values: VecDeque::with_capacity(capacity),
+            }),
+        }
+    }
+
+    /// Returns the output recorded `bars_ago` bars back, where `0` is the latest one.
+    ///
+    /// Returns `None` if fewer than `bars_ago + 1` outputs have been recorded or
+    /// `bars_ago` is not less than the capacity.
+    pub fn get(&self, bars_ago: usize) -> Option<f64> {
+        let len = self.values.len();
+        if bars_ago < len {
+            Some(self.values[len - 1 - bars_ago])
+        } else {
+            None
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of outputs recorded so far (never greater than the capacity).
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn inner(&self) -> &I {
+        &self.inner
+    }
+}
+
+impl<I, T> Next<T> for OutputHistory<I>
+where
+    I: Next<T, Output = f64>,
+{
+    type Output = f64;
+
+    fn next(&mut self, input: T) -> Self::Output {
+        let value = self.inner.next(input);
+
+        if self.values.len() == self.capacity {
+            self.values.pop_front();
+        }
+        self.values.push_back(value);
+
+        value
+    }
+}
+
+impl<I: Reset> Reset for OutputHistory<I> {
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.values.clear();
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for OutputHistory<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HISTORY({}, {})", self.inner, self.capacity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::SimpleMovingAverage;
+    use crate::test_helper::*;
+
+    fn history(capacity: usize) -> OutputHistory<SimpleMovingAverage> {
+        OutputHistory::new(SimpleMovingAverage::new(1).unwrap(), capacity).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        let sma = SimpleMovingAverage::new(3).unwrap();
+        assert!(OutputHistory::new(sma.clone(), 0).is_err());
+        assert!(OutputHistory::new(sma, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut history = history(3);
+        assert!(history.is_empty());
+        assert_eq!(history.get(0), None);
+
+        assert_eq!(history.next(1.0), 1.0);
+        assert_eq!(history.next(2.0), 2.0);
+        assert_eq!(history.get(0), Some(2.0));
+        assert_eq!(history.get(1), Some(1.0));
+        assert_eq!(history.get(2), None);
+
+        history.next(3.0);
+        history.next(4.0);
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.get(0), Some(4.0));
+        assert_eq!(history.get(1), Some(3.0));
+        assert_eq!(history.get(2), Some(2.0));
+        assert_eq!(history.get(3), None);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut history = history(2);
+        history.next(&Bar::new().close(7.5));
+        assert_eq!(history.get(0), Some(7.5));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut history = history(2);
+        history.next(1.0);
+        history.next(2.0);
+
+        history.reset();
+        assert!(history.is_empty());
+        assert_eq!(history.get(0), None);
+
+        history.next(5.0);
+        assert_eq!(history.get(0), Some(5.0));
+    }
+
+    #[test]
+    fn test_display() {
+        let history = history(4);
+        assert_eq!(format!("{}", history), "HISTORY(SMA(1), 4)");
+    }
+}
@@ -0,0 +1,110 @@
+use rayon::prelude::*;
+
+use crate::Next;
+
+/// Runs a clone of `template` over each series, the series being processed in parallel.
+///
+/// The series are independent: every one gets its own clone and nothing is shared between the
+/// threads. The outputs are in the order of `series`, as with a sequential
+/// `series.iter().map(...)`.
+///
+/// A clone carries the state of `template`, so every series starts where the template is.
+/// Pass a fresh or [reset](trait.Reset.html) instance to get the same outputs as a new
+/// indicator per series.
+///
+/// Requires the `rayon` feature.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::SimpleMovingAverage;
+/// use tam::run_parallel;
+///
+/// let sma = SimpleMovingAverage::new(2).unwrap();
+/// let series = vec![vec![1.0, 3.0, 5.0], vec![10.0, 20.0]];
+/// let outputs = run_parallel(&sma, &series);
+/// assert_eq!(outputs, vec![vec![1.0, 2.0, 4.0], vec![10.0, 15.0]]);
+/// ```
+pub fn run_parallel<I>(template: &I, series: &[Vec<f64>]) -> Vec<Vec<I::Output>>
+where
+    I: Next<f64> + Clone + Send,
+    I::Output: Send,
+{
+    // cloned upfront, so the template itself does not need to be shared between threads
+    let indicators: Vec<I> = series.iter().map(|_| template.clone()).collect();
+    indicators
+        .into_par_iter()
+        .zip(series.par_iter())
+        .map(|(mut indicator, values)| values.iter().map(|&v| indicator.next(v)).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{ExponentialMovingAverage, RelativeStrengthIndex};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn random_series(count: usize) -> Vec<Vec<f64>> {
+        let mut rng = StdRng::seed_from_u64(7);
+        (0..count)
+            .map(|_| {
+                let len = rng.random_range(0..300);
+                (0..len).map(|_| rng.random_range(50.0..150.0)).collect()
+            })
+            .collect()
+    }
+
+    fn run_sequential<I: Next<f64> + Clone>(
+        template: &I,
+        series: &[Vec<f64>],
+    ) -> Vec<Vec<I::Output>> {
+        series
+            .iter()
+            .map(|values| {
+                let mut indicator = template.clone();
+                values.iter().map(|&v| indicator.next(v)).collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_matches_sequential() {
+        let series = random_series(200);
+
+        let ema = ExponentialMovingAverage::new(9).unwrap();
+        assert_eq!(run_parallel(&ema, &series), run_sequential(&ema, &series));
+
+        // NaN during the warmup, compared as bits
+        let rsi = RelativeStrengthIndex::new(14).unwrap();
+        let bits = |outputs: Vec<Vec<f64>>| -> Vec<Vec<u64>> {
+            outputs
+                .into_iter()
+                .map(|values| values.into_iter().map(f64::to_bits).collect())
+                .collect()
+        };
+        assert_eq!(
+            bits(run_parallel(&rsi, &series)),
+            bits(run_sequential(&rsi, &series))
+        );
+    }
+
+    #[test]
+    fn test_template_state() {
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+        ema.next(10.0);
+
+        let outputs = run_parallel(&ema, &[vec![20.0], vec![10.0]]);
+        assert_eq!(outputs, vec![vec![15.0], vec![10.0]]);
+        // the template is left untouched
+        assert_eq!(ema.next(20.0), 15.0);
+    }
+
+    #[test]
+    fn test_empty() {
+        let ema = ExponentialMovingAverage::new(3).unwrap();
+        assert!(run_parallel(&ema, &[]).is_empty());
+        assert_eq!(run_parallel(&ema, &[vec![]]), vec![Vec::<f64>::new()]);
+    }
+}
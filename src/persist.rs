@@ -0,0 +1,170 @@
+use alloc::vec::Vec;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::errors::{Result, TaError};
+use crate::Reset;
+
+const MAGIC: &[u8; 4] = b"TAM\0";
+const FORMAT_VERSION: u16 = 1;
+const HEADER_LEN: usize = 10;
+
+/// Validated binary format to save the state of an indicator and resume it later, e.g. in
+/// another process.
+///
+/// The bytes are an envelope around the [bincode](https://docs.rs/bincode) encoding of the
+/// indicator:
+///
+/// * 4 bytes - magic `TAM\0`
+/// * 2 bytes - format version (little endian)
+/// * 4 bytes - FNV-1a checksum of the payload (little endian)
+/// * the payload
+///
+/// `from_bytes` returns `TaError::DeserializeError` if any part of the envelope does not
+/// match or the payload can not be decoded entirely, so truncated or corrupted files are
+/// detected instead of resuming from a garbage state. The envelope does not record the type
+/// of the indicator, the bytes must be decoded as the type that wrote them.
+///
+/// It is implemented for every indicator that implements serde's `Serialize` and
+/// `Deserialize`.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::SimpleMovingAverage;
+/// use tam::{Next, Persist};
+///
+/// let mut sma = SimpleMovingAverage::new(3).unwrap();
+/// sma.next(1.0);
+/// sma.next(2.0);
+///
+/// let bytes = sma.to_bytes();
+/// let mut resumed = SimpleMovingAverage::from_bytes(&bytes).unwrap();
+/// assert_eq!(resumed.next(3.0), sma.next(3.0));
+///
+/// assert!(SimpleMovingAverage::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+/// ```
+pub trait Persist: Sized {
+    fn to_bytes(&self) -> Vec<u8>;
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self>;
+}
+
+impl<T: Serialize + DeserializeOwned + Reset> Persist for T {
+    fn to_bytes(&self) -> Vec<u8> {
+        let payload = bincode::serde::encode_to_vec(self, bincode::config::standard())
+            .expect("indicators always serialize");
+
+        let mut bytes = Vec::with_capacity(HEADER_LEN + payload.len());
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&checksum(&payload).to_le_bytes());
+        bytes.extend_from_slice(&payload);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < HEADER_LEN || &bytes[0..4] != MAGIC {
+            return Err(TaError::DeserializeError);
+        }
+        let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+        let sum = u32::from_le_bytes([bytes[6], bytes[7], bytes[8], bytes[9]]);
+        let payload = &bytes[HEADER_LEN..];
+        if version != FORMAT_VERSION || sum != checksum(payload) {
+            return Err(TaError::DeserializeError);
+        }
+
+        match bincode::serde::decode_from_slice(payload, bincode::config::standard()) {
+            Ok((value, read)) if read == payload.len() => Ok(value),
+            _ => Err(TaError::DeserializeError),
+        }
+    }
+}
+
+/// 32 bit FNV-1a hash.
+fn checksum(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811c_9dc5, |hash, &byte| {
+        (hash ^ byte as u32).wrapping_mul(0x0100_0193)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{
+        ExponentialMovingAverage as Ema, MovingAverageConvergenceDivergence as Macd,
+        SimpleMovingAverage as Sma,
+    };
+    use crate::test_helper::*;
+    use crate::Next;
+
+    #[test]
+    fn test_round_trip() {
+        let mut sma = Sma::new(4).unwrap();
+        let mut macd = Macd::new(3, 6, 2).unwrap();
+        for i in 0..10 {
+            sma.next(i as f64);
+            macd.next(&Bar::new().close(i * i));
+        }
+
+        let bytes = sma.to_bytes();
+        assert_eq!(&bytes[0..4], b"TAM\0");
+        let mut resumed = Sma::from_bytes(&bytes).unwrap();
+        assert_eq!(resumed, sma);
+        assert_eq!(resumed.next(3.0), sma.next(3.0));
+
+        let mut resumed = Macd::from_bytes(&macd.to_bytes()).unwrap();
+        assert_eq!(resumed.next(7.0), macd.next(7.0));
+    }
+
+    #[test]
+    fn test_corrupted() {
+        let mut ema = Ema::new(5).unwrap();
+        ema.next(2.0);
+        let bytes = ema.to_bytes();
+
+        assert_eq!(Ema::from_bytes(&[]), Err(TaError::DeserializeError));
+        assert_eq!(
+            Ema::from_bytes(&bytes[..HEADER_LEN]),
+            Err(TaError::DeserializeError)
+        );
+        assert_eq!(
+            Ema::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(TaError::DeserializeError)
+        );
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(Ema::from_bytes(&trailing), Err(TaError::DeserializeError));
+
+        // every single flipped byte is detected
+        for i in 0..bytes.len() {
+            let mut corrupted = bytes.clone();
+            corrupted[i] ^= 0x55;
+            assert_eq!(
+                Ema::from_bytes(&corrupted),
+                Err(TaError::DeserializeError),
+                "byte {}",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn test_valid_checksum_bad_payload() {
+        let payload = [0xff; 8];
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&checksum(&payload).to_le_bytes());
+        bytes.extend_from_slice(&payload);
+
+        assert_eq!(Sma::from_bytes(&bytes), Err(TaError::DeserializeError));
+    }
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(checksum(b""), 0x811c_9dc5);
+        assert_eq!(checksum(b"a"), 0xe40c_292c);
+    }
+}
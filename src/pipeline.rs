@@ -0,0 +1,185 @@
+use core::fmt;
+
+use crate::{Next, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Chains two indicators: the outputs of the first one are the inputs of the second one.
+///
+/// The second indicator is only fed once the first one is warmed up, so its state never sees
+/// the NaN of the warmup (a NaN would stay in the running sum of an SMA forever). The output
+/// is NaN until both are warmed up: the [lookback](trait.Warmup.html) of a pipeline is the sum
+/// of the lookbacks of its stages, and pipelines can be nested to chain more indicators.
+///
+/// To skip every NaN of the first indicator rather than its warmup, see
+/// [Chain](struct.Chain.html).
+///
+/// # Parameters
+///
+/// * _first_ - indicator consuming the inputs, its output must be `f64`
+/// * _second_ - indicator consuming the outputs of _first_
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::{RelativeStrengthIndex, SimpleMovingAverage};
+/// use tam::{Next, Pipeline, Warmup};
+///
+/// // RSI(14) smoothed by an SMA(5)
+/// let rsi = RelativeStrengthIndex::new(14).unwrap();
+/// let sma = SimpleMovingAverage::new(5).unwrap();
+/// let mut pipeline = Pipeline::new(rsi, sma);
+/// assert_eq!(pipeline.ready_at(), 18);
+///
+/// for i in 0..18 {
+///     assert!(pipeline.next(i as f64).is_nan());
+/// }
+/// assert_eq!(pipeline.next(18.0), 100.0);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Pipeline<A, B> {
+    first: A,
+    second: B,
+    count: usize,
+}
+
+impl<A, B> Pipeline<A, B> {
+    pub fn new(first: A, second: B) -> Self {
+        Self {
+            first,
+            second,
+            count: 0,
+        }
+    }
+
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+}
+
+impl<A: Warmup, B: Warmup> Warmup for Pipeline<A, B> {
+    fn lookback(&self) -> usize {
+        self.first.lookback() + self.second.lookback()
+    }
+}
+
+impl<A, B, T> Next<T> for Pipeline<A, B>
+where
+    A: Next<T, Output = f64> + Warmup,
+    B: Next<f64, Output = f64> + Warmup,
+{
+    type Output = f64;
+
+    fn next(&mut self, input: T) -> Self::Output {
+        let value = self.first.next(input);
+        if self.count < self.first.lookback() {
+            self.count += 1;
+            return f64::NAN;
+        }
+
+        let value = self.second.next(value);
+        if self.count < self.lookback() {
+            self.count += 1;
+            return f64::NAN;
+        }
+        value
+    }
+}
+
+impl<A: Reset, B: Reset> Reset for Pipeline<A, B> {
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+        self.count = 0;
+    }
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for Pipeline<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PIPELINE({}, {})", self.first, self.second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{
+        ExponentialMovingAverage as Ema, RelativeStrengthIndex as Rsi, SimpleMovingAverage as Sma,
+    };
+    use crate::test_helper::*;
+
+    fn prices(n: usize) -> Vec<f64> {
+        (0..n)
+            .map(|i| 100.0 + 10.0 * (i as f64 * 0.4).sin() + i as f64 * 0.1)
+            .collect()
+    }
+
+    #[test]
+    fn test_ready_at() {
+        let mut pipeline = Pipeline::new(Rsi::new(14).unwrap(), Sma::new(5).unwrap());
+        assert_eq!(pipeline.lookback(), 18);
+        assert_eq!(pipeline.ready_at(), 18);
+
+        let outputs: Vec<f64> = prices(40)
+            .iter()
+            .map(|&p| pipeline.next(&Bar::new().close(p)))
+            .collect();
+        let first_valid = outputs.iter().position(|v| !v.is_nan());
+        assert_eq!(first_valid, Some(pipeline.ready_at()));
+        assert!(outputs[18..].iter().all(|v| v.is_finite()));
+    }
+
+    #[test]
+    fn test_next() {
+        // the SMA only sees the valid RSI values
+        let mut pipeline = Pipeline::new(Rsi::new(3).unwrap(), Sma::new(2).unwrap());
+        let mut rsi = Rsi::new(3).unwrap();
+        let mut sma = Sma::new(2).unwrap();
+
+        for (i, p) in prices(20).into_iter().enumerate() {
+            let value = pipeline.next(p);
+            let rsi = rsi.next(p);
+            if i < 3 {
+                assert!(value.is_nan());
+                continue;
+            }
+            let expected = sma.next(rsi);
+            if i < 4 {
+                assert!(value.is_nan());
+            } else {
+                assert_eq!(value, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_nested() {
+        let inner = Pipeline::new(Rsi::new(14).unwrap(), Sma::new(5).unwrap());
+        let mut pipeline = Pipeline::new(inner, Ema::new(3).unwrap());
+        assert_eq!(pipeline.ready_at(), 18);
+
+        let outputs: Vec<f64> = prices(40).iter().map(|&p| pipeline.next(p)).collect();
+        let first_valid = outputs.iter().position(|v| !v.is_nan());
+        assert_eq!(first_valid, Some(18));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut pipeline = Pipeline::new(Rsi::new(3).unwrap(), Sma::new(2).unwrap());
+        let first: Vec<f64> = prices(10).iter().map(|&p| pipeline.next(p)).collect();
+
+        pipeline.reset();
+        let second: Vec<f64> = prices(10).iter().map(|&p| pipeline.next(p)).collect();
+        assert_eq!(first[4..], second[4..]);
+        assert!(second[..4].iter().all(|v| v.is_nan()));
+    }
+
+    #[test]
+    fn test_display() {
+        let pipeline = Pipeline::new(Rsi::new(14).unwrap(), Sma::new(5).unwrap());
+        assert_eq!(format!("{}", pipeline), "PIPELINE(RSI(14), SMA(5))");
+    }
+}
@@ -0,0 +1,164 @@
+use alloc::vec::Vec;
+
+use crate::Reset;
+
+/// Recycles indicator instances to avoid reallocating their buffers.
+///
+/// A parameter sweep creates and drops many indicators of the same configuration. A `Pool`
+/// hands out clones of a prototype and takes the instances back when they are no longer
+/// needed: released instances are reset and handed out again by the next
+/// [acquire](#method.acquire), keeping their heap buffers (the windows of an SMA, the
+/// changes of an RSI, ...) instead of allocating new ones.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::SimpleMovingAverage;
+/// use tam::{Next, Pool};
+///
+/// let mut pool = Pool::new(SimpleMovingAverage::new(3).unwrap());
+///
+/// let mut sma = pool.acquire();
+/// sma.next(10.0);
+/// pool.release(sma);
+/// assert_eq!(pool.len(), 1);
+///
+/// // the same instance, reset
+/// let mut sma = pool.acquire();
+/// assert_eq!(sma.next(4.0), 4.0);
+/// assert!(pool.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Pool<I> {
+    prototype: I,
+    idle: Vec<I>,
+}
+
+impl<I: Clone + Reset> Pool<I> {
+    /// Creates an empty pool handing out (reset) clones of `prototype`.
+    pub fn new(mut prototype: I) -> Self {
+        prototype.reset();
+        Self {
+            prototype,
+            idle: Vec::new(),
+        }
+    }
+
+    /// Returns a released instance if there is one, a clone of the prototype otherwise.
+    /// Either way the instance is in its initial state.
+    pub fn acquire(&mut self) -> I {
+        match self.idle.pop() {
+            Some(indicator) => indicator,
+            None => self.prototype.clone(),
+        }
+    }
+
+    /// Resets `indicator` and keeps it for a later [acquire](#method.acquire).
+    ///
+    /// The instance should come from this pool, or at least have the same configuration as
+    /// the prototype.
+    pub fn release(&mut self, mut indicator: I) {
+        indicator.reset();
+        self.idle.push(indicator);
+    }
+
+    /// Number of released instances waiting to be acquired.
+    pub fn len(&self) -> usize {
+        self.idle.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.idle.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{AverageDirectionalIndex, RelativeStrengthIndex, SimpleMovingAverage};
+    use crate::test_helper::*;
+    use crate::Next;
+
+    /// Indicator whose buffer capacity is observable.
+    #[derive(Debug, Clone)]
+    struct Buffered {
+        values: Vec<f64>,
+    }
+
+    impl Reset for Buffered {
+        fn reset(&mut self) {
+            self.values.clear();
+        }
+    }
+
+    #[test]
+    fn test_reacquire_matches_fresh() {
+        let prices = [10.0, 11.0, 10.5, 12.0, 13.0, 12.5, 11.0, 12.0];
+
+        let mut pool = Pool::new(SimpleMovingAverage::new(3).unwrap());
+        let mut sma = pool.acquire();
+        prices.iter().for_each(|&p| {
+            sma.next(p);
+        });
+        pool.release(sma);
+        let mut recycled = pool.acquire();
+        let mut fresh = SimpleMovingAverage::new(3).unwrap();
+        for &price in prices.iter() {
+            assert_eq!(recycled.next(price), fresh.next(price));
+        }
+
+        let mut pool = Pool::new(RelativeStrengthIndex::new(3).unwrap());
+        let mut rsi = pool.acquire();
+        prices.iter().for_each(|&p| {
+            rsi.next(p);
+        });
+        pool.release(rsi);
+        let mut recycled = pool.acquire();
+        let mut fresh = RelativeStrengthIndex::new(3).unwrap();
+        for &price in prices.iter() {
+            let (a, b) = (recycled.next(price), fresh.next(price));
+            assert!(a == b || (a.is_nan() && b.is_nan()));
+        }
+
+        let mut pool = Pool::new(AverageDirectionalIndex::new(3).unwrap());
+        let bars: Vec<Bar> = prices
+            .iter()
+            .map(|&p| Bar::new().high(p + 1.0).low(p - 1.0).close(p))
+            .collect();
+        let mut adx = pool.acquire();
+        bars.iter().for_each(|bar| {
+            adx.next(bar);
+        });
+        pool.release(adx);
+        let mut recycled = pool.acquire();
+        let mut fresh = AverageDirectionalIndex::new(3).unwrap();
+        for bar in bars.iter() {
+            let (a, b) = (recycled.next(bar), fresh.next(bar));
+            assert!(a == b || (a.is_nan() && b.is_nan()));
+        }
+    }
+
+    #[test]
+    fn test_capacity_retained() {
+        let mut pool = Pool::new(Buffered { values: Vec::new() });
+
+        let mut indicator = pool.acquire();
+        indicator.values.extend((0..100).map(|i| i as f64));
+        let capacity = indicator.values.capacity();
+        pool.release(indicator);
+
+        let indicator = pool.acquire();
+        assert!(indicator.values.is_empty());
+        assert_eq!(indicator.values.capacity(), capacity);
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    fn test_prototype_is_reset() {
+        let mut sma = SimpleMovingAverage::new(2).unwrap();
+        sma.next(100.0);
+
+        let mut pool = Pool::new(sma);
+        assert_eq!(pool.acquire().next(4.0), 4.0);
+    }
+}
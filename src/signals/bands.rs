@@ -0,0 +1,234 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Next, Period, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Zone of an oscillator reported by [Bands](struct.Bands.html).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum BandState {
+    /// The value is above the upper band.
+    Overbought,
+    /// The value is below the lower band.
+    Oversold,
+    /// The value is between the bands, or equal to one of them.
+    Neutral,
+    /// The wrapped indicator is still warming up (NaN).
+    Warmup,
+}
+
+/// Overbought/oversold bands of an oscillator.
+///
+/// Wraps an indicator such as the [RSI](../indicators/struct.RelativeStrengthIndex.html), the
+/// [Stochastic](../indicators/struct.SlowStochastic.html), forwards every input to it and
+/// classifies its output against two levels:
+///
+/// * _Overbought_ - the value is above _upper_
+/// * _Oversold_ - the value is below _lower_
+/// * _Neutral_ - otherwise, the levels themselves included
+/// * _Warmup_ - the value is NaN
+///
+/// # Parameters
+///
+/// * _indicator_ - the wrapped oscillator
+/// * _lower_ - oversold level (finite number)
+/// * _upper_ - overbought level (finite number greater than _lower_)
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::RelativeStrengthIndex;
+/// use tam::signals::{BandState, Bands};
+/// use tam::Next;
+///
+/// let rsi = RelativeStrengthIndex::new(2).unwrap();
+/// let mut bands = Bands::new(rsi, 30.0, 70.0).unwrap();
+///
+/// assert_eq!(bands.next(10.0), BandState::Warmup);
+/// assert_eq!(bands.next(11.0), BandState::Warmup);
+/// assert_eq!(bands.next(12.0), BandState::Overbought);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Bands<I> {
+    indicator: I,
+    lower: f64,
+    upper: f64,
+}
+
+impl<I> Bands<I> {
+    pub fn new(indicator: I, lower: f64, upper: f64) -> Result<Self> {
+        if !lower.is_finite() || !upper.is_finite() || lower >= upper {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            indicator,
+            lower,
+            upper,
+        })
+    }
+
+    pub fn lower(&self) -> f64 {
+        self.lower
+    }
+
+    pub fn upper(&self) -> f64 {
+        self.upper
+    }
+
+    pub fn inner(&self) -> &I {
+        &self.indicator
+    }
+
+    /// Zone of a value of the wrapped indicator.
+    pub fn classify(&self, value: f64) -> BandState {
+        if value.is_nan() {
+            BandState::Warmup
+        } else if value > self.upper {
+            BandState::Overbought
+        } else if value < self.lower {
+            BandState::Oversold
+        } else {
+            BandState::Neutral
+        }
+    }
+}
+
+impl<I: Period> Period for Bands<I> {
+    fn period(&self) -> usize {
+        self.indicator.period()
+    }
+}
+
+impl<I: Warmup> Warmup for Bands<I> {
+    fn lookback(&self) -> usize {
+        self.indicator.lookback()
+    }
+}
+
+impl<I, T> Next<T> for Bands<I>
+where
+    I: Next<T, Output = f64>,
+{
+    type Output = BandState;
+
+    fn next(&mut self, input: T) -> Self::Output {
+        let value = self.indicator.next(input);
+        self.classify(value)
+    }
+}
+
+impl<I: Reset> Reset for Bands<I> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for Bands<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "BANDS({}, {}, {})",
+            self.indicator, self.lower, self.upper
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::RelativeStrengthIndex as Rsi;
+    use crate::test_helper::*;
+
+    fn rsi_bands(period: usize) -> Bands<Rsi> {
+        Bands::new(Rsi::new(period).unwrap(), 30.0, 70.0).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        let rsi = Rsi::new(14).unwrap();
+        assert!(Bands::new(rsi.clone(), 70.0, 30.0).is_err());
+        assert!(Bands::new(rsi.clone(), 50.0, 50.0).is_err());
+        assert!(Bands::new(rsi.clone(), f64::NAN, 70.0).is_err());
+        assert!(Bands::new(rsi.clone(), 30.0, f64::INFINITY).is_err());
+        assert!(Bands::new(rsi, 30.0, 70.0).is_ok());
+    }
+
+    #[test]
+    fn test_rsi_zones() {
+        let mut bands = rsi_bands(3);
+        assert_eq!(bands.lookback(), 3);
+
+        // warmup, whatever the prices do
+        for &price in [10.0, 11.0, 12.0].iter() {
+            assert_eq!(bands.next(price), BandState::Warmup);
+        }
+
+        // only gains: RSI 100
+        assert_eq!(bands.next(13.0), BandState::Overbought);
+        assert_eq!(bands.next(&Bar::new().close(14.0)), BandState::Overbought);
+
+        // a mild pullback brings it back between the bands
+        assert_eq!(bands.next(13.0), BandState::Neutral);
+        assert_eq!(bands.next(12.0), BandState::Neutral);
+
+        // a sell-off
+        assert_eq!(bands.next(10.0), BandState::Oversold);
+        assert_eq!(bands.next(8.0), BandState::Oversold);
+    }
+
+    #[test]
+    fn test_matches_rsi() {
+        let prices: Vec<f64> = (0..60)
+            .map(|i| 100.0 + 10.0 * (i as f64 * 0.4).sin())
+            .collect();
+        let mut rsi = Rsi::new(5).unwrap();
+        let mut bands = rsi_bands(5);
+        let mut seen = Vec::new();
+        for &price in prices.iter() {
+            let value = rsi.next(price);
+            let state = bands.next(price);
+            assert_eq!(state, bands.classify(value));
+            if !seen.contains(&state) {
+                seen.push(state);
+            }
+        }
+        assert_eq!(seen.len(), 4);
+    }
+
+    #[test]
+    fn test_levels_are_neutral() {
+        let bands = rsi_bands(14);
+        assert_eq!(bands.classify(70.0), BandState::Neutral);
+        assert_eq!(bands.classify(30.0), BandState::Neutral);
+        assert_eq!(bands.classify(70.1), BandState::Overbought);
+        assert_eq!(bands.classify(29.9), BandState::Oversold);
+        assert_eq!(bands.classify(f64::NAN), BandState::Warmup);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut bands = rsi_bands(2);
+        for &price in [10.0, 11.0, 12.0].iter() {
+            bands.next(price);
+        }
+
+        bands.reset();
+        assert_eq!(bands.next(12.0), BandState::Warmup);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut bands = rsi_bands(3);
+        for &price in [10.0, 11.0, 10.5, 12.0].iter() {
+            bands.next(price);
+        }
+        let mut restored = serde_round_trip(&bands);
+        assert_eq!(restored, bands);
+        assert_eq!(restored.next(11.0), bands.next(11.0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", rsi_bands(14)), "BANDS(RSI(14), 30, 70)");
+    }
+}
@@ -0,0 +1,205 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::CenterOfGravity;
+use crate::{Close, Next, Period, Reset};
+use serde::{Deserialize, Serialize};
+
+/// Signal emitted by [CogCross](struct.CogCross.html).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CogCrossSignal {
+    /// The COG line did not cross its trigger on this bar.
+    None,
+    /// The COG line crossed above its trigger.
+    Buy,
+    /// The COG line crossed below its trigger.
+    Sell,
+}
+
+/// Turning point signal of the [Center of Gravity](../indicators/struct.CenterOfGravity.html)
+/// oscillator.
+///
+/// The trigger line is the COG value of the previous bar, so the COG crosses its trigger
+/// exactly when it turns. As the COG leads the price, the crossovers happen close to the
+/// turning points of the price:
+///
+/// * _Buy_ - the COG crosses above the trigger
+/// * _Sell_ - the COG crosses below the trigger
+///
+/// No signal is emitted until the COG and the trigger are known for two bars in a row
+/// (`period + 2` inputs).
+///
+/// # Parameters
+///
+/// * _period_ - period of the COG (integer greater than 0). Default is 10.
+///
+/// # Example
+///
+/// ```
+/// use tam::signals::{CogCross, CogCrossSignal};
+/// use tam::Next;
+///
+/// let mut cross = CogCross::new(2).unwrap();
+///
+/// let prices = [1.0, 1.0, 2.0, 3.0, 2.0, 2.0, 3.0];
+/// let signals: Vec<CogCrossSignal> = prices.iter().map(|&p| cross.next(p)).collect();
+/// assert_eq!(signals[3], CogCrossSignal::Sell);
+/// assert_eq!(signals[5], CogCrossSignal::Buy);
+/// ```
+///
+/// # Links
+///
+/// * [The CG Oscillator, John Ehlers](https://www.mesasoftware.com/papers/TheCGOscillator.pdf)
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CogCross {
+    cog: CenterOfGravity,
+    /// COG values of the last two bars, the most recent first.
+    prev: [f64; 2],
+}
+
+impl CogCross {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            cog: CenterOfGravity::new(period)?,
+            prev: [f64::NAN; 2],
+        })
+    }
+}
+
+impl Period for CogCross {
+    fn period(&self) -> usize {
+        self.cog.period()
+    }
+}
+
+impl Next<f64> for CogCross {
+    type Output = CogCrossSignal;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let cog = self.cog.next(input);
+        let [trigger, prev_trigger] = self.prev;
+        self.prev = [cog, trigger];
+
+        // NaN during warmup fails both comparisons
+        let prev_cog = trigger;
+        if cog > trigger && prev_cog <= prev_trigger {
+            CogCrossSignal::Buy
+        } else if cog < trigger && prev_cog >= prev_trigger {
+            CogCrossSignal::Sell
+        } else {
+            CogCrossSignal::None
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for CogCross {
+    type Output = CogCrossSignal;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for CogCross {
+    fn reset(&mut self) {
+        self.cog.reset();
+        self.prev = [f64::NAN; 2];
+    }
+}
+
+impl Default for CogCross {
+    fn default() -> Self {
+        Self::new(10).unwrap()
+    }
+}
+
+impl fmt::Display for CogCross {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "COGCROSS({})", self.cog.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use core::f64::consts::PI;
+
+    #[test]
+    fn test_new() {
+        assert!(CogCross::new(0).is_err());
+        assert!(CogCross::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_sine() {
+        // period of 20 bars: peaks at 5, 25, ..., troughs at 15, 35, ...
+        let mut cross = CogCross::new(10).unwrap();
+        let mut signals = Vec::new();
+        for i in 0..100 {
+            let price = 100.0 + 10.0 * (2.0 * PI * i as f64 / 20.0).sin();
+            match cross.next(price) {
+                CogCrossSignal::None => {}
+                signal => signals.push((i, signal)),
+            }
+        }
+
+        assert!(signals.len() >= 8);
+        for pair in signals.windows(2) {
+            assert_ne!(pair[0].1, pair[1].1);
+        }
+        for &(i, signal) in signals.iter() {
+            let turn: i32 = if signal == CogCrossSignal::Buy { 15 } else { 5 };
+            let distance = (i - turn).rem_euclid(20);
+            assert!(distance <= 1 || distance == 19, "{:?} at {}", signal, i);
+        }
+    }
+
+    #[test]
+    fn test_warmup() {
+        let mut cross = CogCross::new(2).unwrap();
+        // the COG jumps on the 3rd input, but there is no trigger history yet
+        for &price in [1.0, 1.0, 5.0].iter() {
+            assert_eq!(cross.next(price), CogCrossSignal::None);
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut cross = CogCross::new(2).unwrap();
+        let signals: Vec<CogCrossSignal> = [1.0, 1.0, 2.0, 3.0, 2.0]
+            .iter()
+            .map(|&p| cross.next(&Bar::new().close(p)))
+            .collect();
+        assert_eq!(signals[3], CogCrossSignal::Sell);
+    }
+
+    #[test]
+    fn test_reset() {
+        let prices = [1.0, 1.0, 2.0, 3.0, 2.0, 2.0, 3.0];
+        let mut cross = CogCross::new(2).unwrap();
+        let first: Vec<CogCrossSignal> = prices.iter().map(|&p| cross.next(p)).collect();
+
+        cross.reset();
+        let second: Vec<CogCrossSignal> = prices.iter().map(|&p| cross.next(p)).collect();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_serde() {
+        let json = serde_json::to_string(&CogCrossSignal::Buy).unwrap();
+        let signal: CogCrossSignal = serde_json::from_str(&json).unwrap();
+        assert_eq!(signal, CogCrossSignal::Buy);
+    }
+
+    #[test]
+    fn test_default() {
+        CogCross::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", CogCross::new(8).unwrap()), "COGCROSS(8)");
+    }
+}
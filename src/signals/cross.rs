@@ -0,0 +1,251 @@
+use core::fmt;
+
+use crate::{Next, Reset};
+use serde::{Deserialize, Serialize};
+
+/// Returns true if `a` crossed above `b` between the previous and the current `(a, b)` pairs.
+///
+/// `a` must be strictly above `b` now and at or below it before, so touching `b` is not a
+/// cross, but leaving it upwards after a touch is. A NaN in either pair is never a cross.
+///
+/// # Example
+///
+/// ```
+/// use tam::signals::crossed_above;
+///
+/// assert!(crossed_above((1.0, 2.0), (3.0, 2.0)));
+/// assert!(crossed_above((2.0, 2.0), (3.0, 2.0)));
+/// assert!(!crossed_above((1.0, 2.0), (2.0, 2.0)));
+/// assert!(!crossed_above((f64::NAN, 2.0), (3.0, 2.0)));
+/// ```
+pub fn crossed_above(prev: (f64, f64), curr: (f64, f64)) -> bool {
+    // comparisons with NaN are false
+    curr.0 > curr.1 && prev.0 <= prev.1
+}
+
+/// Returns true if `a` crossed below `b` between the previous and the current `(a, b)` pairs.
+///
+/// The mirror of [crossed_above](fn.crossed_above.html).
+pub fn crossed_below(prev: (f64, f64), curr: (f64, f64)) -> bool {
+    curr.0 < curr.1 && prev.0 >= prev.1
+}
+
+/// Signal emitted by [CrossTracker](struct.CrossTracker.html).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Crossing {
+    /// The series did not cross on this input.
+    None,
+    /// The first series crossed above the second one.
+    Above,
+    /// The first series crossed below the second one.
+    Below,
+}
+
+/// Crossovers of two arbitrary series.
+///
+/// Fed `(a, b)` pairs, it remembers the previous pair and reports whether `a` crossed `b`,
+/// with the rules of [crossed_above](fn.crossed_above.html) and
+/// [crossed_below](fn.crossed_below.html). The building block for crossover signals of
+/// indicators not covered by [MaCross](struct.MaCross.html).
+///
+/// The first pair, and a pair following one with a NaN (e.g. an indicator warming up), never
+/// cross.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::SimpleMovingAverage as Sma;
+/// use tam::signals::{CrossTracker, Crossing};
+/// use tam::Next;
+///
+/// // the price crossing its SMA
+/// let mut sma = Sma::new(2).unwrap();
+/// let mut cross = CrossTracker::new();
+///
+/// let prices = [10.0, 12.0, 11.0, 10.0, 12.0];
+/// let signals: Vec<Crossing> = prices.iter().map(|&p| cross.next((p, sma.next(p)))).collect();
+/// // SMA 10, 11, 11.5, 10.5, 11
+/// assert_eq!(
+///     signals,
+///     vec![Crossing::None, Crossing::Above, Crossing::Below, Crossing::None, Crossing::Above]
+/// );
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CrossTracker {
+    /// Previous pair, `None` if it contained a NaN.
+    prev: Option<(f64, f64)>,
+}
+
+impl CrossTracker {
+    pub fn new() -> Self {
+        Self { prev: None }
+    }
+
+    /// Previous pair, `None` at the start or if it contained a NaN.
+    pub fn prev(&self) -> Option<(f64, f64)> {
+        self.prev
+    }
+}
+
+impl Next<(f64, f64)> for CrossTracker {
+    type Output = Crossing;
+
+    fn next(&mut self, input: (f64, f64)) -> Self::Output {
+        let curr = if input.0.is_nan() || input.1.is_nan() {
+            None
+        } else {
+            Some(input)
+        };
+
+        match (core::mem::replace(&mut self.prev, curr), curr) {
+            (Some(prev), Some(curr)) if crossed_above(prev, curr) => Crossing::Above,
+            (Some(prev), Some(curr)) if crossed_below(prev, curr) => Crossing::Below,
+            _ => Crossing::None,
+        }
+    }
+}
+
+impl Reset for CrossTracker {
+    fn reset(&mut self) {
+        self.prev = None;
+    }
+}
+
+impl Default for CrossTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for CrossTracker {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CROSS")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_round_trip;
+    use alloc::{format, vec, vec::Vec};
+
+    #[test]
+    fn test_crossed_above() {
+        assert!(crossed_above((1.0, 2.0), (3.0, 2.0)));
+        assert!(crossed_above((1.0, 2.0), (3.5, 3.0)));
+        assert!(!crossed_above((3.0, 2.0), (4.0, 2.0)));
+        assert!(!crossed_above((1.0, 2.0), (1.5, 2.0)));
+        assert!(!crossed_above((3.0, 2.0), (1.0, 2.0)));
+    }
+
+    #[test]
+    fn test_crossed_below() {
+        assert!(crossed_below((3.0, 2.0), (1.0, 2.0)));
+        assert!(crossed_below((3.0, 2.0), (2.0, 2.5)));
+        assert!(!crossed_below((1.0, 2.0), (0.0, 2.0)));
+        assert!(!crossed_below((1.0, 2.0), (3.0, 2.0)));
+    }
+
+    #[test]
+    fn test_touch() {
+        // reaching the other series is not a cross
+        assert!(!crossed_above((1.0, 2.0), (2.0, 2.0)));
+        assert!(!crossed_below((3.0, 2.0), (2.0, 2.0)));
+        // leaving it is
+        assert!(crossed_above((2.0, 2.0), (3.0, 2.0)));
+        assert!(crossed_below((2.0, 2.0), (1.0, 2.0)));
+        // staying on it is not
+        assert!(!crossed_above((2.0, 2.0), (2.0, 2.0)));
+        assert!(!crossed_below((2.0, 2.0), (2.0, 2.0)));
+    }
+
+    #[test]
+    fn test_nan() {
+        let nan = f64::NAN;
+        for &(prev, curr) in [
+            ((nan, 2.0), (3.0, 2.0)),
+            ((1.0, nan), (3.0, 2.0)),
+            ((1.0, 2.0), (nan, 2.0)),
+            ((3.0, 2.0), (1.0, nan)),
+            ((nan, nan), (nan, nan)),
+        ]
+        .iter()
+        {
+            assert!(!crossed_above(prev, curr));
+            assert!(!crossed_below(prev, curr));
+        }
+    }
+
+    #[test]
+    fn test_tracker() {
+        let mut cross = CrossTracker::new();
+        let signals: Vec<Crossing> = [
+            (1.0, 2.0),
+            (3.0, 2.0),
+            (2.0, 2.0),
+            (1.0, 2.0),
+            (1.0, 0.5),
+            (1.0, 0.5),
+        ]
+        .iter()
+        .map(|&pair| cross.next(pair))
+        .collect();
+        assert_eq!(
+            signals,
+            vec![
+                Crossing::None,
+                Crossing::Above,
+                Crossing::None,
+                Crossing::Below,
+                Crossing::Above,
+                Crossing::None
+            ]
+        );
+        assert_eq!(cross.prev(), Some((1.0, 0.5)));
+    }
+
+    #[test]
+    fn test_tracker_nan() {
+        let mut cross = CrossTracker::new();
+        assert_eq!(cross.next((f64::NAN, 2.0)), Crossing::None);
+        assert_eq!(cross.prev(), None);
+        // no previous pair to compare with
+        assert_eq!(cross.next((3.0, 2.0)), Crossing::None);
+        assert_eq!(cross.next((1.0, f64::NAN)), Crossing::None);
+        assert_eq!(cross.next((1.0, 2.0)), Crossing::None);
+        assert_eq!(cross.next((3.0, 2.0)), Crossing::Above);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut cross = CrossTracker::new();
+        cross.next((1.0, 2.0));
+
+        cross.reset();
+        assert_eq!(cross.prev(), None);
+        assert_eq!(cross.next((3.0, 2.0)), Crossing::None);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut cross = CrossTracker::new();
+        cross.next((1.0, 2.0));
+        let mut restored = serde_round_trip(&cross);
+        assert_eq!(restored.next((3.0, 2.0)), cross.next((3.0, 2.0)));
+
+        // a NaN pair is not serialized
+        cross.next((f64::NAN, 2.0));
+        let restored = serde_round_trip(&cross);
+        assert_eq!(restored, cross);
+    }
+
+    #[test]
+    fn test_default() {
+        CrossTracker::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", CrossTracker::new()), "CROSS");
+    }
+}
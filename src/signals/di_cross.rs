@@ -0,0 +1,333 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::AverageDirectionalIndex;
+use crate::{Close, High, Low, Next, Period, Reset};
+use serde::{Deserialize, Serialize};
+
+/// Signal emitted by [DiCross](struct.DiCross.html).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DiCrossSignal {
+    /// No cross, or a cross without a strong enough trend.
+    None,
+    /// +DI crossed above -DI while the ADX is above the threshold.
+    Buy,
+    /// +DI crossed below -DI while the ADX is above the threshold.
+    Sell,
+}
+
+/// Crossover of the directional indicators of the
+/// [ADX](../indicators/struct.AverageDirectionalIndex.html), gated by the ADX.
+///
+/// Wilder's trading rule: the +DI and -DI tell the direction of the trend and the ADX its
+/// strength, so a cross of the DIs is only taken when the ADX shows a trend.
+///
+/// * _Buy_ - +DI crosses above -DI and the ADX is above the threshold
+/// * _Sell_ - +DI crosses below -DI and the ADX is above the threshold
+///
+/// # Warmup
+///
+/// The DIs are available _period_ bars before the ADX, and the DIs of the previous bar are
+/// tracked during the whole ADX warmup. A cross happening on the bar of the first ADX value is
+/// therefore signalled, while the crosses before it are not (the ADX is NaN).
+///
+/// # Parameters
+///
+/// * _adx_period_ - period of the ADX (integer greater than 1). Default is 14.
+/// * _adx_threshold_ - ADX level above which crosses are signalled (number in [0, 100]).
+///   Default is 25.
+///
+/// # Example
+///
+/// ```
+/// use tam::signals::{DiCross, DiCrossSignal};
+/// use tam::{DataItem, Next};
+///
+/// let bar = |close: f64| {
+///     DataItem::builder()
+///         .open(close).high(close + 1.0).low(close - 1.0).close(close).volume(1.0)
+///         .build().unwrap()
+/// };
+///
+/// let mut cross = DiCross::new(3, 20.0).unwrap();
+/// // a downtrend, then a rally
+/// let closes = (0..10).map(|i| 20.0 - i as f64).chain((0..5).map(|i| 12.0 + 2.0 * i as f64));
+/// let signals: Vec<DiCrossSignal> = closes.map(|close| cross.next(&bar(close))).collect();
+/// assert!(signals.contains(&DiCrossSignal::Buy));
+/// assert!(!signals.contains(&DiCrossSignal::Sell));
+/// ```
+///
+/// # Links
+///
+/// * [Average directional movement index, Wikipedia](https://en.wikipedia.org/wiki/Average_directional_movement_index)
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DiCross {
+    adx: AverageDirectionalIndex,
+    threshold: f64,
+    /// +DI minus -DI on the previous bar, once the DIs are available.
+    prev_diff: Option<f64>,
+}
+
+impl DiCross {
+    pub fn new(adx_period: usize, adx_threshold: f64) -> Result<Self> {
+        if !(0.0..=100.0).contains(&adx_threshold) {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            adx: AverageDirectionalIndex::new(adx_period)?,
+            threshold: adx_threshold,
+            prev_diff: None,
+        })
+    }
+
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+}
+
+impl Period for DiCross {
+    fn period(&self) -> usize {
+        self.adx.period()
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for DiCross {
+    type Output = DiCrossSignal;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let adx = self.adx.next(input);
+        let diff = match self.adx.di() {
+            Some((plus_di, minus_di)) => plus_di - minus_di,
+            None => return DiCrossSignal::None,
+        };
+        let prev_diff = match self.prev_diff.replace(diff) {
+            Some(prev_diff) => prev_diff,
+            None => return DiCrossSignal::None,
+        };
+
+        // NaN during the ADX warmup fails the comparison
+        let trending = adx > self.threshold;
+        if trending && diff > 0.0 && prev_diff <= 0.0 {
+            DiCrossSignal::Buy
+        } else if trending && diff < 0.0 && prev_diff >= 0.0 {
+            DiCrossSignal::Sell
+        } else {
+            DiCrossSignal::None
+        }
+    }
+}
+
+impl Reset for DiCross {
+    fn reset(&mut self) {
+        self.adx.reset();
+        self.prev_diff = None;
+    }
+}
+
+impl Default for DiCross {
+    fn default() -> Self {
+        Self::new(14, 25.0).unwrap()
+    }
+}
+
+impl fmt::Display for DiCross {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DICROSS({}, {})", self.adx.period(), self.threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    /// Period, bars and TA-Lib (+DI, -DI) of a dataset of the ADX ground truth.
+    #[cfg(feature = "std")]
+    type Fixture = (usize, Vec<Bar>, Vec<Option<(f64, f64)>>);
+
+    #[cfg(feature = "std")]
+    fn fixture_bars(period_name: &str) -> Option<Fixture> {
+        let file = std::fs::File::open("tests/data/adx_test_cases.json").ok()?;
+        let json: serde_json::Value =
+            serde_json::from_reader(std::io::BufReader::new(file)).unwrap();
+        let data = &json["realistic"][period_name];
+        let values = |name: &str| -> Vec<Option<f64>> {
+            data[name]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|v| v.as_f64())
+                .collect()
+        };
+        let (high, low, close) = (values("high"), values("low"), values("close"));
+        let (plus_di, minus_di) = (values("plus_di"), values("minus_di"));
+        let bars = (0..high.len())
+            .map(|i| {
+                Bar::new()
+                    .high(high[i].unwrap())
+                    .low(low[i].unwrap())
+                    .close(close[i].unwrap())
+            })
+            .collect();
+        let di = (0..high.len())
+            .map(|i| plus_di[i].zip(minus_di[i]))
+            .collect();
+        let period = data["timeperiod"].as_u64().unwrap() as usize;
+        Some((period, bars, di))
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(DiCross::new(1, 25.0).is_err());
+        assert!(DiCross::new(14, -1.0).is_err());
+        assert!(DiCross::new(14, 101.0).is_err());
+        assert!(DiCross::new(14, f64::NAN).is_err());
+        assert!(DiCross::new(2, 0.0).is_ok());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ground_truth_reversal() {
+        let (period, bars, expected_di) = match fixture_bars("period_7") {
+            Some(fixture) => fixture,
+            None => return,
+        };
+
+        // the DIs follow TA-Lib, with the tolerance of the ADX ground truth test
+        let mut adx = AverageDirectionalIndex::new(period).unwrap();
+        for (b, expected) in bars.iter().zip(expected_di.iter()) {
+            adx.next(b);
+            match (adx.di(), expected) {
+                (Some((plus, minus)), Some((plus_di, minus_di))) => {
+                    assert!((plus - plus_di).abs() < 2.0, "{} != {}", plus, plus_di);
+                    assert!((minus - minus_di).abs() < 2.0, "{} != {}", minus, minus_di);
+                }
+                (di, expected) => assert_eq!(di.is_none(), expected.is_none()),
+            }
+        }
+
+        let signals = |threshold: f64| -> Vec<(usize, DiCrossSignal)> {
+            let mut cross = DiCross::new(period, threshold).unwrap();
+            bars.iter()
+                .map(|b| cross.next(b))
+                .enumerate()
+                .filter(|&(_, s)| s != DiCrossSignal::None)
+                .collect()
+        };
+
+        // the uptrend reverses on the bar of the first ADX value (index 13): +DI 39.7 -> 25.5,
+        // -DI 23.8 -> 50.6, ADX 39.1
+        assert_eq!(signals(25.0), vec![(13, DiCrossSignal::Sell)]);
+
+        // the whipsaw of the following range is only signalled with a lower threshold, and
+        // stops when the ADX falls below 20 at index 35
+        assert_eq!(
+            signals(20.0),
+            vec![
+                (13, DiCrossSignal::Sell),
+                (29, DiCrossSignal::Buy),
+                (30, DiCrossSignal::Sell),
+                (31, DiCrossSignal::Buy),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_warmup() {
+        let mut cross = DiCross::new(3, 0.0).unwrap();
+        let mut adx = AverageDirectionalIndex::new(3).unwrap();
+        let signals: Vec<DiCrossSignal> = [10.0, 9.0, 8.0, 7.0, 12.0, 5.0]
+            .iter()
+            .map(|&close| {
+                adx.next(&Bar::new().high(close + 1.0).low(close - 1.0).close(close));
+                cross.next(&Bar::new().high(close + 1.0).low(close - 1.0).close(close))
+            })
+            .collect();
+
+        // the DIs (+DI, -DI) are (0, 50) at index 3 and (50, 20) at index 4: the cross
+        // happens while the ADX is NaN
+        assert_eq!(signals[..5], [DiCrossSignal::None; 5]);
+        // crossing back on the bar of the first ADX value
+        assert!(adx.di().unwrap().0 < adx.di().unwrap().1);
+        assert_eq!(signals[5], DiCrossSignal::Sell);
+    }
+
+    #[test]
+    fn test_threshold() {
+        let mut cross = DiCross::new(3, 100.0).unwrap();
+        for i in 0..10 {
+            cross.next(
+                &Bar::new()
+                    .high(21.0 - i as f64)
+                    .low(19.0 - i as f64)
+                    .close(20.0 - i as f64),
+            );
+        }
+        for i in 0..10 {
+            assert_eq!(
+                cross.next(
+                    &Bar::new()
+                        .high(13.0 + 2.0 * i as f64)
+                        .low(11.0 + 2.0 * i as f64)
+                        .close(12.0 + 2.0 * i as f64)
+                ),
+                DiCrossSignal::None
+            );
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut cross = DiCross::new(3, 0.0).unwrap();
+        for i in 0..10 {
+            cross.next(
+                &Bar::new()
+                    .high(21.0 - i as f64)
+                    .low(19.0 - i as f64)
+                    .close(20.0 - i as f64),
+            );
+        }
+        cross.reset();
+        assert_eq!(
+            cross.next(&Bar::new().high(31.0).low(29.0).close(30.0)),
+            DiCrossSignal::None
+        );
+        assert_eq!(cross, {
+            let mut fresh = DiCross::new(3, 0.0).unwrap();
+            fresh.next(&Bar::new().high(31.0).low(29.0).close(30.0));
+            fresh
+        });
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut cross = DiCross::new(3, 20.0).unwrap();
+        for i in 0..10 {
+            cross.next(
+                &Bar::new()
+                    .high(21.0 - i as f64)
+                    .low(19.0 - i as f64)
+                    .close(20.0 - i as f64),
+            );
+        }
+        let mut restored = serde_round_trip(&cross);
+        for i in 0..5 {
+            let b = Bar::new()
+                .high(13.0 + 2.0 * i as f64)
+                .low(11.0 + 2.0 * i as f64)
+                .close(12.0 + 2.0 * i as f64);
+            assert_eq!(restored.next(&b), cross.next(&b));
+        }
+    }
+
+    #[test]
+    fn test_default() {
+        DiCross::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", DiCross::default()), "DICROSS(14, 25)");
+    }
+}
@@ -0,0 +1,256 @@
+use alloc::collections::VecDeque;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, OutputHistory, Reset};
+use serde::{Deserialize, Serialize};
+
+/// Signal emitted by [Divergence](struct.Divergence.html).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DivergenceSignal {
+    /// No divergence was confirmed on this bar.
+    None,
+    /// Price made a lower low while the oscillator made a higher low.
+    Bullish,
+    /// Price made a higher high while the oscillator made a lower high.
+    Bearish,
+}
+
+/// Detects divergences between price and an oscillator (e.g. RSI).
+///
+/// Every input is passed to the wrapped oscillator. A swing high (low) is a bar whose price is
+/// strictly higher (lower) than the prices of `pivot_bars` bars on each side of it. When a new
+/// swing is confirmed it is compared with the previous swing of the same kind:
+///
+/// * _Bearish_ - price makes a higher high, but the oscillator makes a lower high
+/// * _Bullish_ - price makes a lower low, but the oscillator makes a higher low
+///
+/// Swings whose oscillator value is NaN (oscillator warm-up) are ignored.
+///
+/// # Lag
+///
+/// A swing can only be confirmed once `pivot_bars` bars after it are known, so the signal is
+/// emitted `pivot_bars` bars after the swing actually happened.
+///
+/// # Parameters
+///
+/// * _indicator_ - the oscillator, its output must be `f64`
+/// * _pivot_bars_ - number of bars on each side of a swing (integer greater than 0)
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::RelativeStrengthIndex;
+/// use tam::signals::{Divergence, DivergenceSignal};
+/// use tam::Next;
+///
+/// let rsi = RelativeStrengthIndex::new(3).unwrap();
+/// let mut divergence = Divergence::new(rsi, 2).unwrap();
+///
+/// let prices = [
+///     10.0, 9.0, 8.0, 7.0, 6.0, 5.0, 8.0, 11.0, 14.0, 13.0, 12.0, 11.0, 12.0, 13.0, 14.0,
+///     15.0, 14.5, 14.0,
+/// ];
+/// let signals: Vec<DivergenceSignal> = prices.iter().map(|&p| divergence.next(p)).collect();
+/// assert_eq!(signals[17], DivergenceSignal::Bearish);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Divergence<I> {
+    pivot_bars: usize,
+    oscillator: OutputHistory<I>,
+    prices: VecDeque<f64>,
+    last_high: Option<(f64, f64)>,
+    last_low: Option<(f64, f64)>,
+}
+
+impl<I> Divergence<I> {
+    pub fn new(indicator: I, pivot_bars: usize) -> Result<Self> {
+        match pivot_bars {
+            0 => Err(TaError::InvalidParameter),
+            _ => {
+                let window = 2 * pivot_bars + 1;
+                Ok(Self {
+                    pivot_bars,
+                    oscillator: OutputHistory::new(indicator, window)?,
+                    prices: VecDeque::with_capacity(window),
+                    last_high: None,
+                    last_low: None,
+                })
+            }
+        }
+    }
+
+    pub fn pivot_bars(&self) -> usize {
+        self.pivot_bars
+    }
+
+    fn is_pivot(&self, center: f64, is_high: bool) -> bool {
+        self.prices
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != self.pivot_bars)
+            .all(|(_, &p)| if is_high { center > p } else { center < p })
+    }
+}
+
+impl<I: Next<f64, Output = f64>> Next<f64> for Divergence<I> {
+    type Output = DivergenceSignal;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.oscillator.next(input);
+
+        if self.prices.len() == self.oscillator.capacity() {
+            self.prices.pop_front();
+        }
+        self.prices.push_back(input);
+
+        if self.prices.len() < self.oscillator.capacity() {
+            return DivergenceSignal::None;
+        }
+
+        let price = self.prices[self.pivot_bars];
+        let osc = match self.oscillator.get(self.pivot_bars) {
+            Some(osc) if !osc.is_nan() => osc,
+            _ => return DivergenceSignal::None,
+        };
+
+        let mut signal = DivergenceSignal::None;
+
+        if self.is_pivot(price, true) {
+            if let Some((prev_price, prev_osc)) = self.last_high {
+                if price > prev_price && osc < prev_osc {
+                    signal = DivergenceSignal::Bearish;
+                }
+            }
+            self.last_high = Some((price, osc));
+        } else if self.is_pivot(price, false) {
+            if let Some((prev_price, prev_osc)) = self.last_low {
+                if price < prev_price && osc > prev_osc {
+                    signal = DivergenceSignal::Bullish;
+                }
+            }
+            self.last_low = Some((price, osc));
+        }
+
+        signal
+    }
+}
+
+impl<I: Next<f64, Output = f64>, T: Close> Next<&T> for Divergence<I> {
+    type Output = DivergenceSignal;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl<I: Reset> Reset for Divergence<I> {
+    fn reset(&mut self) {
+        self.oscillator.reset();
+        self.prices.clear();
+        self.last_high = None;
+        self.last_low = None;
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for Divergence<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "DIVERGENCE({}, {})",
+            self.oscillator.inner(),
+            self.pivot_bars
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::RelativeStrengthIndex as Rsi;
+    use crate::test_helper::*;
+
+    fn divergence() -> Divergence<Rsi> {
+        Divergence::new(Rsi::new(3).unwrap(), 2).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Divergence::new(Rsi::new(3).unwrap(), 0).is_err());
+        assert!(Divergence::new(Rsi::new(3).unwrap(), 1).is_ok());
+    }
+
+    #[test]
+    fn test_bearish() {
+        // Swing highs at 14.0 (RSI 87.7) and 15.0 (RSI 85.6): higher high, lower RSI high.
+        let prices = [
+            10.0, 9.0, 8.0, 7.0, 6.0, 5.0, 8.0, 11.0, 14.0, 13.0, 12.0, 11.0, 12.0, 13.0, 14.0,
+            15.0, 14.5, 14.0, 13.5,
+        ];
+        let mut divergence = divergence();
+
+        for (i, &price) in prices.iter().enumerate() {
+            let expected = if i == 17 {
+                DivergenceSignal::Bearish
+            } else {
+                DivergenceSignal::None
+            };
+            assert_eq!(divergence.next(price), expected, "bar {}", i);
+        }
+    }
+
+    #[test]
+    fn test_bullish() {
+        // Mirror image of the bearish series: lower low in price, higher low in RSI.
+        let prices = [
+            10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 12.0, 9.0, 6.0, 7.0, 8.0, 9.0, 8.0, 7.0, 6.0, 5.0,
+            5.5, 6.0, 6.5,
+        ];
+        let mut divergence = divergence();
+
+        let signals: Vec<DivergenceSignal> = prices.iter().map(|&p| divergence.next(p)).collect();
+        assert_eq!(signals[17], DivergenceSignal::Bullish);
+        assert_eq!(
+            signals
+                .iter()
+                .filter(|&&s| s != DivergenceSignal::None)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut divergence = divergence();
+        assert_eq!(
+            divergence.next(&Bar::new().close(10.0)),
+            DivergenceSignal::None
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let prices = [
+            10.0, 9.0, 8.0, 7.0, 6.0, 5.0, 8.0, 11.0, 14.0, 13.0, 12.0, 11.0, 12.0, 13.0, 14.0,
+            15.0, 14.5, 14.0,
+        ];
+        let mut divergence = divergence();
+        let first: Vec<DivergenceSignal> = prices.iter().map(|&p| divergence.next(p)).collect();
+
+        divergence.reset();
+        let second: Vec<DivergenceSignal> = prices.iter().map(|&p| divergence.next(p)).collect();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_serde() {
+        let json = serde_json::to_string(&DivergenceSignal::Bearish).unwrap();
+        let signal: DivergenceSignal = serde_json::from_str(&json).unwrap();
+        assert_eq!(signal, DivergenceSignal::Bearish);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", divergence()), "DIVERGENCE(RSI(3), 2)");
+    }
+}
@@ -0,0 +1,252 @@
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{MaType, MovingAverage};
+use crate::{Close, Next, Period, Periods, Reset, Warmup};
+use serde::{Deserialize, Serialize};
+
+/// Signal emitted by [MaCross](struct.MaCross.html).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CrossSignal {
+    /// The moving averages did not cross on this bar.
+    None,
+    /// The fast moving average crossed above the slow one.
+    Golden,
+    /// The fast moving average crossed below the slow one.
+    Death,
+}
+
+/// Crossover signal of a fast and a slow moving average.
+///
+/// The most common trend following signal: the trend turns up when the fast moving average
+/// crosses above the slow one, and down when it crosses below.
+///
+/// * _Golden_ - the fast MA crosses above the slow MA
+/// * _Death_ - the fast MA crosses below the slow MA
+///
+/// A cross needs the two moving averages warmed up on two bars in a row, so no signal is
+/// emitted for the first `max(fast_period, slow_period)` inputs.
+///
+/// # Parameters
+///
+/// * _fast_type_ - kind of the fast moving average.
+/// * _fast_period_ - period of the fast moving average (integer greater than 0). Default is 50.
+/// * _slow_type_ - kind of the slow moving average.
+/// * _slow_period_ - period of the slow moving average (integer greater than 0). Default
+///   is 200.
+///
+/// Both moving averages are SMAs by default.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::MaType;
+/// use tam::signals::{CrossSignal, MaCross};
+/// use tam::Next;
+///
+/// let mut cross = MaCross::new(MaType::Sma, 1, MaType::Sma, 2).unwrap();
+///
+/// let prices = [3.0, 2.0, 1.0, 2.0, 3.0, 2.0];
+/// let signals: Vec<CrossSignal> = prices.iter().map(|&p| cross.next(p)).collect();
+/// assert_eq!(signals[3], CrossSignal::Golden);
+/// assert_eq!(signals[5], CrossSignal::Death);
+/// ```
+///
+/// # Links
+///
+/// * [Golden cross, Wikipedia](https://en.wikipedia.org/wiki/Golden_cross)
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MaCross {
+    fast: MovingAverage,
+    slow: MovingAverage,
+    count: usize,
+    /// Fast minus slow MA on the previous bar, once both are warmed up.
+    prev_diff: Option<f64>,
+}
+
+impl MaCross {
+    pub fn new(
+        fast_type: MaType,
+        fast_period: usize,
+        slow_type: MaType,
+        slow_period: usize,
+    ) -> Result<Self> {
+        Ok(Self {
+            fast: MovingAverage::new(fast_type, fast_period)?,
+            slow: MovingAverage::new(slow_type, slow_period)?,
+            count: 0,
+            prev_diff: None,
+        })
+    }
+}
+
+impl Periods for MaCross {
+    fn periods(&self) -> Vec<usize> {
+        vec![self.fast.period(), self.slow.period()]
+    }
+}
+
+impl Warmup for MaCross {
+    fn lookback(&self) -> usize {
+        self.fast.lookback().max(self.slow.lookback()) + 1
+    }
+}
+
+impl Next<f64> for MaCross {
+    type Output = CrossSignal;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let diff = self.fast.next(input) - self.slow.next(input);
+        if self.count + 1 < self.lookback() {
+            self.count += 1;
+            return CrossSignal::None;
+        }
+
+        let prev_diff = match self.prev_diff.replace(diff) {
+            Some(prev_diff) => prev_diff,
+            None => return CrossSignal::None,
+        };
+
+        if diff > 0.0 && prev_diff <= 0.0 {
+            CrossSignal::Golden
+        } else if diff < 0.0 && prev_diff >= 0.0 {
+            CrossSignal::Death
+        } else {
+            CrossSignal::None
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for MaCross {
+    type Output = CrossSignal;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for MaCross {
+    fn reset(&mut self) {
+        self.fast.reset();
+        self.slow.reset();
+        self.count = 0;
+        self.prev_diff = None;
+    }
+}
+
+impl Default for MaCross {
+    fn default() -> Self {
+        Self::new(MaType::Sma, 50, MaType::Sma, 200).unwrap()
+    }
+}
+
+impl fmt::Display for MaCross {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MACROSS({}, {})", self.fast, self.slow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(MaCross::new(MaType::Sma, 0, MaType::Sma, 2).is_err());
+        assert!(MaCross::new(MaType::Ema, 2, MaType::Wma, 0).is_err());
+        assert!(MaCross::new(MaType::Ema, 1, MaType::Wma, 1).is_ok());
+    }
+
+    #[test]
+    fn test_golden_cross() {
+        let mut cross = MaCross::new(MaType::Ema, 5, MaType::Sma, 20).unwrap();
+
+        // a downtrend, then a sharp rally
+        let prices: Vec<f64> = (0..40)
+            .map(|i| 100.0 - i as f64)
+            .chain((1..=20).map(|i| 61.0 + 3.0 * i as f64))
+            .collect();
+        let signals: Vec<CrossSignal> = prices
+            .iter()
+            .map(|&p| cross.next(&Bar::new().close(p)))
+            .collect();
+
+        let golden: Vec<usize> = (0..signals.len())
+            .filter(|&i| signals[i] == CrossSignal::Golden)
+            .collect();
+        assert_eq!(golden.len(), 1);
+        assert!(golden[0] > 40, "{:?}", golden);
+        assert!(!signals.contains(&CrossSignal::Death));
+    }
+
+    #[test]
+    fn test_death_cross() {
+        let mut cross = MaCross::new(MaType::Wma, 2, MaType::Sma, 3).unwrap();
+
+        // fast - slow once warmed up: 0.333, -0.333
+        let signals: Vec<CrossSignal> = [3.0, 3.0, 4.0, 2.0]
+            .iter()
+            .map(|&p| cross.next(p))
+            .collect();
+        assert_eq!(
+            signals,
+            vec![
+                CrossSignal::None,
+                CrossSignal::None,
+                CrossSignal::None,
+                CrossSignal::Death
+            ]
+        );
+    }
+
+    #[test]
+    fn test_warmup() {
+        // the partial averages cross twice before the SMA is warmed up on the fourth bar
+        let mut cross = MaCross::new(MaType::Ema, 2, MaType::Sma, 4).unwrap();
+        assert_eq!(cross.lookback(), 4);
+        for &price in [10.0, 12.0, 8.0, 7.0].iter() {
+            assert_eq!(cross.next(price), CrossSignal::None);
+        }
+        assert_eq!(cross.next(20.0), CrossSignal::Golden);
+    }
+
+    #[test]
+    fn test_reset() {
+        let prices = [3.0, 2.0, 1.0, 2.0, 3.0, 2.0, 1.0, 4.0];
+        let mut cross = MaCross::new(MaType::Sma, 1, MaType::Ema, 3).unwrap();
+        let first: Vec<CrossSignal> = prices.iter().map(|&p| cross.next(p)).collect();
+
+        cross.reset();
+        let second: Vec<CrossSignal> = prices.iter().map(|&p| cross.next(p)).collect();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_default() {
+        MaCross::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let cross = MaCross::new(MaType::Ema, 5, MaType::Sma, 20).unwrap();
+        assert_eq!(format!("{}", cross), "MACROSS(EMA(5), SMA(20))");
+    }
+
+    #[test]
+    fn test_serde() {
+        let ma_type: MaType =
+            serde_json::from_str(&serde_json::to_string(&MaType::Wma).unwrap()).unwrap();
+        assert_eq!(ma_type, MaType::Wma);
+
+        let mut cross = MaCross::new(MaType::Ema, 2, MaType::Sma, 3).unwrap();
+        for &price in [3.0, 2.0, 1.0, 2.0].iter() {
+            cross.next(price);
+        }
+        let mut restored = serde_round_trip(&cross);
+        assert_eq!(restored, cross);
+        assert_eq!(restored.next(5.0), cross.next(5.0));
+    }
+}
@@ -0,0 +1,23 @@
+mod divergence;
+pub use self::divergence::{Divergence, DivergenceSignal};
+
+mod cog_cross;
+pub use self::cog_cross::{CogCross, CogCrossSignal};
+
+mod regime_filter;
+pub use self::regime_filter::{Regime, RegimeFilter};
+
+mod spread_z_score;
+pub use self::spread_z_score::{PairSignal, SpreadZScore};
+
+mod ma_cross;
+pub use self::ma_cross::{CrossSignal, MaCross};
+
+mod bands;
+pub use self::bands::{BandState, Bands};
+
+mod di_cross;
+pub use self::di_cross::{DiCross, DiCrossSignal};
+
+mod cross;
+pub use self::cross::{crossed_above, crossed_below, CrossTracker, Crossing};
@@ -0,0 +1,201 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::AverageDirectionalIndex;
+use crate::{Close, High, Low, Next, Period, Reset};
+use serde::{Deserialize, Serialize};
+
+/// Market regime reported by [RegimeFilter](struct.RegimeFilter.html).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Regime {
+    /// The ADX is above the threshold.
+    Trending,
+    /// The ADX is at or below the threshold, or still warming up.
+    Ranging,
+}
+
+/// Classifies the market as trending or ranging (choppy) with the
+/// [ADX](../indicators/struct.AverageDirectionalIndex.html).
+///
+/// The ADX measures the strength of a trend regardless of its direction, so it is commonly
+/// used to gate entries of trend following strategies:
+///
+/// * _Trending_ - the ADX is above the threshold
+/// * _Ranging_ - the ADX is at or below the threshold
+///
+/// While the ADX is warming up (NaN) the regime is _Ranging_.
+///
+/// # Parameters
+///
+/// * _adx_period_ - period of the ADX (integer greater than 1). Default is 14.
+/// * _adx_threshold_ - ADX level above which the market is trending (number in [0, 100]).
+///   Default is 25.
+///
+/// # Example
+///
+/// ```
+/// use tam::signals::{Regime, RegimeFilter};
+/// use tam::{DataItem, Next};
+///
+/// let mut filter = RegimeFilter::new(3, 25.0).unwrap();
+///
+/// let mut regime = Regime::Ranging;
+/// for i in 0..10 {
+///     let close = 10.0 + i as f64;
+///     let bar = DataItem::builder()
+///         .open(close).high(close + 1.0).low(close - 1.0).close(close).volume(1.0)
+///         .build().unwrap();
+///     regime = filter.next(&bar);
+/// }
+/// assert_eq!(regime, Regime::Trending);
+/// ```
+///
+/// # Links
+///
+/// * [Average directional movement index, Wikipedia](https://en.wikipedia.org/wiki/Average_directional_movement_index)
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RegimeFilter {
+    adx: AverageDirectionalIndex,
+    threshold: f64,
+}
+
+impl RegimeFilter {
+    pub fn new(adx_period: usize, adx_threshold: f64) -> Result<Self> {
+        if !(0.0..=100.0).contains(&adx_threshold) {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            adx: AverageDirectionalIndex::new(adx_period)?,
+            threshold: adx_threshold,
+        })
+    }
+
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+}
+
+impl Period for RegimeFilter {
+    fn period(&self) -> usize {
+        self.adx.period()
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for RegimeFilter {
+    type Output = Regime;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        // NaN during warmup fails the comparison
+        if self.adx.next(input) > self.threshold {
+            Regime::Trending
+        } else {
+            Regime::Ranging
+        }
+    }
+}
+
+impl Reset for RegimeFilter {
+    fn reset(&mut self) {
+        self.adx.reset();
+    }
+}
+
+impl Default for RegimeFilter {
+    fn default() -> Self {
+        Self::new(14, 25.0).unwrap()
+    }
+}
+
+impl fmt::Display for RegimeFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "REGIME({}, {})", self.adx.period(), self.threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn trending(i: usize) -> Bar {
+        let close = 10.0 + i as f64;
+        Bar::new().high(close + 1.0).low(close - 1.0).close(close)
+    }
+
+    fn ranging(i: usize) -> Bar {
+        let close = 10.0 + (i % 2) as f64;
+        Bar::new().high(close + 0.5).low(close - 0.5).close(close)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(RegimeFilter::new(1, 25.0).is_err());
+        assert!(RegimeFilter::new(14, -1.0).is_err());
+        assert!(RegimeFilter::new(14, 101.0).is_err());
+        assert!(RegimeFilter::new(14, f64::NAN).is_err());
+        assert!(RegimeFilter::new(2, 0.0).is_ok());
+        assert!(RegimeFilter::new(14, 100.0).is_ok());
+    }
+
+    #[test]
+    fn test_trending() {
+        let mut filter = RegimeFilter::new(5, 25.0).unwrap();
+        let regimes: Vec<Regime> = (0..30).map(|i| filter.next(&trending(i))).collect();
+
+        // ranging during the ADX warmup, then trending
+        assert_eq!(regimes[0], Regime::Ranging);
+        assert!(regimes[15..].iter().all(|&r| r == Regime::Trending));
+    }
+
+    #[test]
+    fn test_ranging() {
+        let mut filter = RegimeFilter::new(5, 25.0).unwrap();
+        for i in 0..30 {
+            assert_eq!(filter.next(&ranging(i)), Regime::Ranging, "bar {}", i);
+        }
+    }
+
+    #[test]
+    fn test_regime_change() {
+        let mut filter = RegimeFilter::new(5, 25.0).unwrap();
+        let mut regime = Regime::Ranging;
+        for i in 0..30 {
+            regime = filter.next(&trending(i));
+        }
+        assert_eq!(regime, Regime::Trending);
+
+        for i in 0..60 {
+            regime = filter.next(&ranging(i));
+        }
+        assert_eq!(regime, Regime::Ranging);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut filter = RegimeFilter::new(5, 25.0).unwrap();
+        for i in 0..30 {
+            filter.next(&trending(i));
+        }
+        filter.reset();
+        assert_eq!(filter.next(&trending(0)), Regime::Ranging);
+    }
+
+    #[test]
+    fn test_serde() {
+        let json = serde_json::to_string(&Regime::Trending).unwrap();
+        assert_eq!(json, r#""Trending""#);
+        let regime: Regime = serde_json::from_str(&json).unwrap();
+        assert_eq!(regime, Regime::Trending);
+    }
+
+    #[test]
+    fn test_default() {
+        RegimeFilter::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", RegimeFilter::default()), "REGIME(14, 25)");
+    }
+}
@@ -0,0 +1,246 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{Beta, ZScore};
+use crate::{Next, PairIndicator, Period, Reset};
+use serde::{Deserialize, Serialize};
+
+/// Signal emitted by [SpreadZScore](struct.SpreadZScore.html).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum PairSignal {
+    /// Nothing to do on this bar.
+    None,
+    /// The spread is unusually low: buy _a_ and sell _hedge ratio_ units of _b_.
+    LongSpread,
+    /// The spread is unusually high: sell _a_ and buy _hedge ratio_ units of _b_.
+    ShortSpread,
+    /// The spread is back to normal: close the open position.
+    Close,
+}
+
+/// Pairs trading signal on the z-score of the spread between two prices.
+///
+/// The hedge ratio is the rolling [Beta](../indicators/struct.Beta.html) of _a_ against _b_,
+/// the spread is _a_ - hedge ratio * _b_ and its rolling [Z-Score](../indicators/struct.ZScore.html)
+/// tells how far it is from its mean. For two cointegrated prices the spread reverts to its
+/// mean, so:
+///
+/// * _ShortSpread_ - no position is open and the z-score rises above _entry_
+/// * _LongSpread_ - no position is open and the z-score falls below -_entry_
+/// * _Close_ - a position is open and the absolute z-score falls below _exit_
+///
+/// The input is the pair of prices (_a_, _b_). No signal is emitted until the beta and the
+/// z-score have warmed up (2 * _period_ - 1 inputs).
+///
+/// # Parameters
+///
+/// * _period_ - period of the beta and of the z-score (integer greater than 1). Default is 20.
+/// * _entry_ - absolute z-score opening a position (number greater than _exit_). Default is 2.
+/// * _exit_ - absolute z-score closing the position (number greater than or equal to 0).
+///   Default is 0.5.
+///
+/// # Example
+///
+/// ```
+/// use tam::signals::{PairSignal, SpreadZScore};
+/// use tam::Next;
+///
+/// let mut pair = SpreadZScore::new(5, 1.5, 0.5).unwrap();
+///
+/// let mut signals = Vec::new();
+/// for i in 0..40 {
+///     let b = 50.0 + i as f64;
+///     let a = 2.0 * b + (i as f64 * 0.8).sin();
+///     signals.push(pair.next((a, b)));
+/// }
+/// assert!(signals.contains(&PairSignal::ShortSpread));
+/// assert!(signals.contains(&PairSignal::Close));
+/// ```
+///
+/// # Links
+///
+/// * [Pairs trade, Wikipedia](https://en.wikipedia.org/wiki/Pairs_trade)
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SpreadZScore {
+    beta: Beta,
+    z_score: ZScore,
+    entry: f64,
+    exit: f64,
+    /// +1 with a long spread, -1 with a short spread, 0 without position.
+    position: i8,
+}
+
+impl SpreadZScore {
+    pub fn new(period: usize, entry: f64, exit: f64) -> Result<Self> {
+        if exit.is_nan() || exit < 0.0 || entry.is_nan() || entry <= exit {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            beta: Beta::new(period)?,
+            z_score: ZScore::new(period)?,
+            entry,
+            exit,
+            position: 0,
+        })
+    }
+}
+
+impl Period for SpreadZScore {
+    fn period(&self) -> usize {
+        self.beta.period()
+    }
+}
+
+impl Next<(f64, f64)> for SpreadZScore {
+    type Output = PairSignal;
+
+    fn next(&mut self, input: (f64, f64)) -> Self::Output {
+        let (a, b) = input;
+        let hedge_ratio = self.beta.next_pair(a, b);
+        if hedge_ratio.is_nan() {
+            return PairSignal::None;
+        }
+        let z = self.z_score.next(a - hedge_ratio * b);
+        if z.is_nan() {
+            return PairSignal::None;
+        }
+
+        if self.position == 0 {
+            if z > self.entry {
+                self.position = -1;
+                return PairSignal::ShortSpread;
+            }
+            if z < -self.entry {
+                self.position = 1;
+                return PairSignal::LongSpread;
+            }
+        } else if z.abs() < self.exit {
+            self.position = 0;
+            return PairSignal::Close;
+        }
+        PairSignal::None
+    }
+}
+
+impl Reset for SpreadZScore {
+    fn reset(&mut self) {
+        self.beta.reset();
+        self.z_score.reset();
+        self.position = 0;
+    }
+}
+
+impl Default for SpreadZScore {
+    fn default() -> Self {
+        Self::new(20, 2.0, 0.5).unwrap()
+    }
+}
+
+impl fmt::Display for SpreadZScore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SPREADZ({}, {}, {})",
+            self.beta.period(),
+            self.entry,
+            self.exit
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, vec::Vec};
+
+    /// Cointegrated prices: _b_ is a random walk, _a_ is 1.5 * _b_ plus a mean reverting
+    /// spread oscillating around 10.
+    fn cointegrated(n: usize) -> Vec<(f64, f64)> {
+        let mut seed = 7_u64;
+        let mut b = 100.0;
+        (0..n)
+            .map(|i| {
+                seed = seed
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                b += ((seed >> 33) as f64 / (1_u64 << 31) as f64 - 0.5) * 0.2;
+                let spread = 10.0 + 2.0 * (i as f64 * 0.3).sin();
+                (1.5 * b + spread, b)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(SpreadZScore::new(1, 2.0, 0.5).is_err());
+        assert!(SpreadZScore::new(20, 0.5, 0.5).is_err());
+        assert!(SpreadZScore::new(20, 2.0, -0.5).is_err());
+        assert!(SpreadZScore::new(20, f64::NAN, 0.5).is_err());
+        assert!(SpreadZScore::new(2, 1.0, 0.0).is_ok());
+    }
+
+    #[test]
+    fn test_warmup() {
+        let mut pair = SpreadZScore::new(5, 0.1, 0.0).unwrap();
+        let prices = cointegrated(10);
+        for &p in prices[..8].iter() {
+            assert_eq!(pair.next(p), PairSignal::None);
+        }
+    }
+
+    #[test]
+    fn test_cointegrated() {
+        let mut pair = SpreadZScore::new(20, 1.2, 0.3).unwrap();
+        let signals: Vec<PairSignal> = cointegrated(400)
+            .into_iter()
+            .map(|p| pair.next(p))
+            .collect();
+
+        let entries = signals
+            .iter()
+            .filter(|&&s| s == PairSignal::LongSpread || s == PairSignal::ShortSpread)
+            .count();
+        assert!(entries >= 5, "{} entries", entries);
+        assert!(signals.contains(&PairSignal::LongSpread));
+        assert!(signals.contains(&PairSignal::ShortSpread));
+
+        // every entry is closed before the next one
+        let mut open = false;
+        for signal in signals {
+            match signal {
+                PairSignal::LongSpread | PairSignal::ShortSpread => {
+                    assert!(!open);
+                    open = true;
+                }
+                PairSignal::Close => {
+                    assert!(open);
+                    open = false;
+                }
+                PairSignal::None => {}
+            }
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let prices = cointegrated(100);
+        let mut pair = SpreadZScore::new(10, 1.2, 0.3).unwrap();
+        let first: Vec<PairSignal> = prices.iter().map(|&p| pair.next(p)).collect();
+
+        pair.reset();
+        let second: Vec<PairSignal> = prices.iter().map(|&p| pair.next(p)).collect();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_default() {
+        SpreadZScore::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let pair = SpreadZScore::new(30, 2.5, 0.5).unwrap();
+        assert_eq!(format!("{}", pair), "SPREADZ(30, 2.5, 0.5)");
+    }
+}
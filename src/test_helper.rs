@@ -1,4 +1,9 @@
-use super::{Close, High, Low, Open, Volume};
+use super::{ClassifiedBar, Close, High, Low, Open, Volume};
+use serde::{de::DeserializeOwned, Serialize};
+
+// The test modules glob import this module, so these are in scope with or without `std`.
+pub use alloc::vec::Vec;
+pub use alloc::{format, vec};
 
 #[derive(Debug, PartialEq)]
 pub struct Bar {
@@ -7,6 +12,7 @@ pub struct Bar {
     low: f64,
     close: f64,
     volume: f64,
+    classified: Option<(f64, f64)>,
 }
 
 impl Bar {
@@ -17,13 +23,14 @@ impl Bar {
             low: 0.0,
             high: 0.0,
             volume: 0.0,
+            classified: None,
         }
     }
 
-    //pub fn open<T: Into<f64>>(mut self, val :T ) -> Self {
-    //    self.open = val.into();
-    //    self
-    //}
+    pub fn open<T: Into<f64>>(mut self, val: T) -> Self {
+        self.open = val.into();
+        self
+    }
 
     pub fn high<T: Into<f64>>(mut self, val: T) -> Self {
         self.high = val.into();
@@ -44,6 +51,11 @@ impl Bar {
         self.volume = val;
         self
     }
+
+    pub fn classified(mut self, buy_volume: f64, sell_volume: f64) -> Self {
+        self.classified = Some((buy_volume, sell_volume));
+        self
+    }
 }
 
 impl Open for Bar {
@@ -76,10 +88,30 @@ impl Volume for Bar {
     }
 }
 
+impl ClassifiedBar for Bar {
+    fn buy_volume(&self) -> Option<f64> {
+        self.classified.map(|(buy, _)| buy)
+    }
+
+    fn sell_volume(&self) -> Option<f64> {
+        self.classified.map(|(_, sell)| sell)
+    }
+}
+
 pub fn round(num: f64) -> f64 {
     (num * 1000.0).round() / 1000.00
 }
 
+/// Number of leading NaN outputs, i.e. the length of the warmup.
+pub fn warmup_nan_count(outputs: &[f64]) -> usize {
+    outputs.iter().take_while(|v| v.is_nan()).count()
+}
+
+/// Serializes `indicator` to JSON and deserializes it back.
+pub fn serde_round_trip<T: Serialize + DeserializeOwned>(indicator: &T) -> T {
+    serde_json::from_str(&serde_json::to_string(indicator).unwrap()).unwrap()
+}
+
 macro_rules! test_indicator {
     ($i:tt) => {
         #[test]
@@ -100,7 +132,7 @@ macro_rules! test_indicator {
             assert_eq!(indicator.next(12.3), first_output);
 
             // ensure Display is implemented
-            format!("{}", indicator);
+            let _ = format!("{}", indicator);
         }
     };
 }
@@ -1,6 +1,13 @@
 // Indicator traits
 //
 
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::any::Any;
+
+use crate::errors::Result;
+use serde::{Deserialize, Serialize};
+
 /// Resets an indicator to the initial state.
 pub trait Reset {
     fn reset(&mut self);
@@ -11,6 +18,130 @@ pub trait Period {
     fn period(&self) -> usize;
 }
 
+/// Return all the periods used by an indicator that is configured with several of them, e.g.
+/// `[fast, slow, signal]` for [MACD](indicators/struct.MovingAverageConvergenceDivergence.html).
+///
+/// The periods are returned in the order of the constructor arguments. Indicators with a single
+/// period implement [Period](trait.Period.html) instead.
+pub trait Periods {
+    fn periods(&self) -> Vec<usize>;
+}
+
+/// Number of inputs an indicator consumes before its first valid output.
+///
+/// The output for the input at index `lookback()` is the first valid one, so a series with
+/// at most `lookback()` values never produces one. The `calculate` batch functions return NaN
+/// for the first `lookback()` outputs and the `ready` constructors need `lookback()` values.
+pub trait Warmup {
+    fn lookback(&self) -> usize;
+
+    /// Index of the input, counted from 0 on a fresh indicator, whose output is the first
+    /// valid one.
+    ///
+    /// It is `lookback()` for a single indicator. Wrappers combine the lookbacks of their
+    /// parts, e.g. a [Pipeline](struct.Pipeline.html) adds them up, so this predicts when a
+    /// whole chain starts producing values.
+    fn ready_at(&self) -> usize {
+        self.lookback()
+    }
+}
+
+/// What an indicator outputs while it is warming up.
+///
+/// Set with `with_warmup_policy` on the indicators supporting it
+/// ([RSI](indicators/struct.RelativeStrengthIndex.html),
+/// [ADX](indicators/struct.AverageDirectionalIndex.html),
+/// [Correlation](indicators/struct.Correlation.html)). Some consumers, e.g. plotting
+/// libraries, don't accept NaN.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::RelativeStrengthIndex;
+/// use tam::{Next, Warmup, WarmupPolicy};
+///
+/// let prices = [10.0, 11.0, 10.5, 11.5];
+///
+/// let mut rsi = RelativeStrengthIndex::new(2)
+///     .unwrap()
+///     .with_warmup_policy(WarmupPolicy::Zero);
+/// let outputs: Vec<f64> = prices.iter().map(|&p| rsi.next(p)).collect();
+/// assert_eq!(&outputs[..2], &[0.0, 0.0]);
+///
+/// // backfilling needs the whole series
+/// let mut outputs = RelativeStrengthIndex::calculate(2, &prices).unwrap();
+/// WarmupPolicy::Repeat.apply(rsi.lookback(), &mut outputs);
+/// assert_eq!(outputs[0], outputs[2]);
+/// assert_eq!(outputs[1], outputs[2]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum WarmupPolicy {
+    /// NaN, telling the warmup apart from any actual value.
+    #[default]
+    Nan,
+    /// 0.
+    Zero,
+    /// The first valid output, repeated backwards over the warmup.
+    ///
+    /// A streaming indicator cannot know its first valid output in advance, so `next` still
+    /// returns NaN during the warmup; the backfill is done on a batch of outputs by
+    /// [apply](#method.apply).
+    Repeat,
+}
+
+impl WarmupPolicy {
+    /// Output of `next` during the warmup.
+    pub fn warmup_value(&self) -> f64 {
+        match self {
+            WarmupPolicy::Zero => 0.0,
+            WarmupPolicy::Nan | WarmupPolicy::Repeat => f64::NAN,
+        }
+    }
+
+    /// Rewrites the first `lookback` outputs of a series according to the policy.
+    ///
+    /// With `Repeat`, they are replaced with the output at index `lookback`, and left as they
+    /// are if the series is not longer than `lookback`.
+    pub fn apply(&self, lookback: usize, outputs: &mut [f64]) {
+        let warmup = lookback.min(outputs.len());
+        let value = match self {
+            WarmupPolicy::Nan => f64::NAN,
+            WarmupPolicy::Zero => 0.0,
+            WarmupPolicy::Repeat => match outputs.get(lookback) {
+                Some(&first) => first,
+                None => return,
+            },
+        };
+        outputs[..warmup].fill(value);
+    }
+}
+
+/// Reads the latest output of an indicator again, without feeding it a new input.
+///
+/// `current` returns what the last call of `next` returned, so callers polling an indicator
+/// between bars don't need to cache its outputs themselves. It is `None` while the indicator
+/// is warming up and after a [reset](trait.Reset.html).
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::RelativeStrengthIndex;
+/// use tam::{Current, Next};
+///
+/// let mut rsi = RelativeStrengthIndex::new(2).unwrap();
+/// rsi.next(10.0);
+/// rsi.next(11.0);
+/// assert_eq!(rsi.current(), None);
+///
+/// let value = rsi.next(12.0);
+/// assert_eq!(rsi.current(), Some(value));
+/// ```
+pub trait Current {
+    type Output;
+
+    fn current(&self) -> Option<Self::Output>;
+}
+
 /// Consumes a data item of type `T` and returns `Output`.
 ///
 /// Typically `T` can be `f64` or a struct similar to [DataItem](struct.DataItem.html), that implements
@@ -20,11 +151,229 @@ pub trait Period {
 /// [MACD](indicators/struct.MovingAverageConvergenceDivergence.html) it is `(f64, f64, f64)` since
 /// MACD returns 3 values.
 ///
+/// # Missing data
+///
+/// The indicators with an `f64` input and output also accept `Option<f64>`, to forward the
+/// gaps of a feed without making up a price. `Some(value)` is the same as `value`. `None`
+/// means "no new data": the state is frozen (nothing enters the window) and the output of the
+/// last `Some` is returned again, NaN if there was none yet. Only the outputs of `Some` inputs
+/// are remembered, so feed the whole stream as options.
+///
+/// ```
+/// use tam::indicators::SimpleMovingAverage;
+/// use tam::Next;
+///
+/// let mut sma = SimpleMovingAverage::new(2).unwrap();
+/// assert!(sma.next(None).is_nan());
+/// assert_eq!(sma.next(Some(4.0)), 4.0);
+/// assert_eq!(sma.next(None), 4.0);
+/// assert_eq!(sma.next(Some(6.0)), 5.0);
+/// ```
+///
 pub trait Next<T> {
     type Output;
     fn next(&mut self, input: T) -> Self::Output;
 }
 
+/// Object-safe interface of indicators that consume and produce `f64`.
+///
+/// Allows storing different indicators behind `Box<dyn Indicator>` and driving them
+/// uniformly. It is implemented automatically for every type implementing
+/// `Next<f64, Output = f64>` and [Reset](trait.Reset.html). The concrete indicator can be
+/// recovered with [as_any](#tymethod.as_any) and `downcast_ref`.
+///
+/// Since the method names overlap with `Next` and `Reset`, calling them on a concrete type
+/// with both traits in scope needs the fully qualified form, e.g. `Next::next(&mut sma, 1.0)`.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::{ExponentialMovingAverage, SimpleMovingAverage};
+/// use tam::Indicator;
+///
+/// let mut stack: Vec<Box<dyn Indicator>> = vec![
+///     Box::new(SimpleMovingAverage::new(2).unwrap()),
+///     Box::new(ExponentialMovingAverage::new(3).unwrap()),
+/// ];
+///
+/// for indicator in stack.iter_mut() {
+///     indicator.next(2.0);
+/// }
+/// let outputs: Vec<f64> = stack.iter_mut().map(|i| i.next(5.0)).collect();
+/// assert_eq!(outputs, vec![3.5, 3.5]);
+/// assert_eq!(stack[0].name(), "SimpleMovingAverage");
+///
+/// let sma = stack[0].as_any().downcast_ref::<SimpleMovingAverage>();
+/// assert!(sma.is_some());
+/// ```
+pub trait Indicator {
+    fn next(&mut self, input: f64) -> f64;
+
+    fn reset(&mut self);
+
+    /// Name of the indicator type, without the module path and generic parameters.
+    fn name(&self) -> &'static str;
+
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T> Indicator for T
+where
+    T: Next<f64, Output = f64> + Reset + 'static,
+{
+    fn next(&mut self, input: f64) -> f64 {
+        Next::next(self, input)
+    }
+
+    fn reset(&mut self) {
+        Reset::reset(self)
+    }
+
+    fn name(&self) -> &'static str {
+        let path = core::any::type_name::<T>();
+        let path = path.split('<').next().unwrap_or(path);
+        path.rsplit("::").next().unwrap_or(path)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Consumes a whole window of data items at once.
+///
+/// Complements [Next](trait.Next.html) for indicators whose output depends on a fixed span of
+/// bars (e.g. [Fractal](indicators/struct.Fractal.html)), for callers who already have the data
+/// as a slice. Only the last [window_len](#tymethod.window_len) items of `window` are used.
+///
+/// Returns `TaError::InsufficientData` if `window` is shorter than `window_len()`.
+pub trait NextWindow<T> {
+    type Output;
+
+    /// Number of items required by [next_window](#tymethod.next_window).
+    fn window_len(&self) -> usize;
+
+    fn next_window(&mut self, window: &[T]) -> Result<Self::Output>;
+}
+
+/// Indicator of two series, e.g. [Correlation](indicators/struct.Correlation.html).
+///
+/// Lets generic code accept any two-series indicator. Every `PairIndicator` also implements
+/// `Next<(f64, f64)>`, forwarding the tuple to [next_pair](#tymethod.next_pair).
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::{Correlation, ExponentialCorrelation};
+/// use tam::PairIndicator;
+///
+/// fn last_value<I: PairIndicator>(mut indicator: I, xs: &[f64], ys: &[f64]) -> f64 {
+///     let mut value = f64::NAN;
+///     for (&x, &y) in xs.iter().zip(ys) {
+///         value = indicator.next_pair(x, y);
+///     }
+///     value
+/// }
+///
+/// let (xs, ys) = ([1.0, 2.0, 3.0], [2.0, 4.0, 6.0]);
+/// assert_eq!(last_value(Correlation::new(3).unwrap(), &xs, &ys), 1.0);
+/// assert_eq!(last_value(ExponentialCorrelation::new(0.9).unwrap(), &xs, &ys), 1.0);
+/// ```
+pub trait PairIndicator {
+    fn next_pair(&mut self, x: f64, y: f64) -> f64;
+}
+
+impl<T: PairIndicator> Next<(f64, f64)> for T {
+    type Output = f64;
+
+    fn next(&mut self, input: (f64, f64)) -> Self::Output {
+        self.next_pair(input.0, input.1)
+    }
+}
+
+/// Compares two values within an absolute tolerance.
+///
+/// Implemented for `f64` and for the output structs of the indicators, which are compared
+/// field by field. NaN is considered equal to NaN, so outputs that are still warming up
+/// compare equal as well.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::BollingerBandsOutput;
+/// use tam::ApproxEq;
+///
+/// let a = BollingerBandsOutput { average: 2.0, upper: 3.0, lower: 1.0 };
+/// let b = BollingerBandsOutput { average: 2.0, upper: 3.0005, lower: 1.0 };
+///
+/// assert!(a.approx_eq(&b, 1e-3));
+/// assert!(!a.approx_eq(&b, 1e-4));
+/// ```
+pub trait ApproxEq {
+    fn approx_eq(&self, other: &Self, tol: f64) -> bool;
+}
+
+impl ApproxEq for f64 {
+    fn approx_eq(&self, other: &Self, tol: f64) -> bool {
+        self == other || (self.is_nan() && other.is_nan()) || (self - other).abs() <= tol
+    }
+}
+
+impl ApproxEq for bool {
+    fn approx_eq(&self, other: &Self, _tol: f64) -> bool {
+        self == other
+    }
+}
+
+impl ApproxEq for i8 {
+    fn approx_eq(&self, other: &Self, _tol: f64) -> bool {
+        self == other
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for Vec<T> {
+    fn approx_eq(&self, other: &Self, tol: f64) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a.approx_eq(b, tol))
+    }
+}
+
+impl<T: ApproxEq, const N: usize> ApproxEq for [T; N] {
+    fn approx_eq(&self, other: &Self, tol: f64) -> bool {
+        self.iter().zip(other).all(|(a, b)| a.approx_eq(b, tol))
+    }
+}
+
+/// Exports a multi-value output as named `f64` columns.
+///
+/// Useful for generic tabular export, e.g. building a columnar result without knowing the
+/// concrete output type. Implemented for the output structs with a fixed set of fields,
+/// `bool` fields are exported as 0.0 or 1.0.
+///
+/// # Example
+///
+/// ```
+/// use tam::indicators::BollingerBandsOutput;
+/// use tam::NamedOutput;
+///
+/// let out = BollingerBandsOutput { average: 2.0, upper: 3.0, lower: 1.0 };
+/// let map = out.to_named_f64();
+///
+/// assert_eq!(BollingerBandsOutput::field_names(), &["average", "upper", "lower"]);
+/// assert_eq!(map["upper"], 3.0);
+/// ```
+pub trait NamedOutput {
+    /// Names of the fields, in declaration order.
+    fn field_names() -> &'static [&'static str];
+
+    fn to_named_f64(&self) -> BTreeMap<&'static str, f64>;
+}
+
 /// Open price of a particular period.
 pub trait Open {
     fn open(&self) -> f64;
@@ -49,3 +398,144 @@ pub trait High {
 pub trait Volume {
     fn volume(&self) -> f64;
 }
+
+/// Volume of a particular period split into buyer and seller initiated trades (tick rule).
+///
+/// The `next_classified` methods of [OBV](indicators/struct.OnBalanceVolume.html) and
+/// [MFI](indicators/struct.MoneyFlowIndex.html) use the split when both values are known and
+/// fall back to comparing prices otherwise, like their `next`. Both methods return `None` by
+/// default.
+pub trait ClassifiedBar {
+    /// Volume of the trades printed at the ask.
+    fn buy_volume(&self) -> Option<f64> {
+        None
+    }
+
+    /// Volume of the trades printed at the bid.
+    fn sell_volume(&self) -> Option<f64> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::boxed::Box;
+    use alloc::{vec, vec::Vec};
+
+    /// Generic over any two-series indicator.
+    fn run_pairs<I: PairIndicator>(indicator: &mut I, pairs: &[(f64, f64)]) -> Vec<f64> {
+        pairs
+            .iter()
+            .map(|&(x, y)| indicator.next_pair(x, y))
+            .collect()
+    }
+
+    #[test]
+    fn test_pair_indicator() {
+        use crate::indicators::{Correlation, ExponentialCorrelation};
+
+        let pairs = [(2.0, 3.0), (3.0, 2.0), (6.0, 1.0), (5.0, 2.0)];
+
+        let mut corr = Correlation::new(3).unwrap();
+        let values = run_pairs(&mut corr, &pairs);
+        assert_eq!(values[1], -1.0);
+        assert_eq!(values[3], -0.7559289460184537);
+
+        // the tuple input forwards to next_pair
+        let mut corr = Correlation::new(3).unwrap();
+        let tuples: Vec<f64> = pairs.iter().map(|&pair| corr.next(pair)).collect();
+        assert_eq!(tuples, values);
+
+        let mut ewcorr = ExponentialCorrelation::new(0.9).unwrap();
+        let values = run_pairs(&mut ewcorr, &pairs);
+        assert_eq!(values[0], 0.0);
+        assert_eq!(values[1], -1.0);
+        assert!(values[3] < 0.0);
+    }
+
+    #[test]
+    fn test_warmup_policy_apply() {
+        let outputs = [f64::NAN, f64::NAN, 3.0, 4.0];
+
+        let mut zero = outputs;
+        WarmupPolicy::Zero.apply(2, &mut zero);
+        assert_eq!(zero, [0.0, 0.0, 3.0, 4.0]);
+
+        let mut repeat = outputs;
+        WarmupPolicy::Repeat.apply(2, &mut repeat);
+        assert_eq!(repeat, [3.0, 3.0, 3.0, 4.0]);
+
+        let mut nan = [0.0, 0.0, 3.0];
+        WarmupPolicy::Nan.apply(2, &mut nan);
+        assert!(nan[0].is_nan() && nan[1].is_nan());
+
+        // too short to know the first valid output
+        let mut short = [f64::NAN, f64::NAN];
+        WarmupPolicy::Repeat.apply(2, &mut short);
+        assert!(short.iter().all(|v| v.is_nan()));
+        WarmupPolicy::Zero.apply(5, &mut short);
+        assert_eq!(short, [0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_approx_eq_f64() {
+        assert!(1.0.approx_eq(&1.0005, 1e-3));
+        assert!(!1.0.approx_eq(&1.005, 1e-3));
+        assert!(f64::NAN.approx_eq(&f64::NAN, 0.0));
+        assert!(!f64::NAN.approx_eq(&1.0, 1e-3));
+        assert!(f64::INFINITY.approx_eq(&f64::INFINITY, 0.0));
+        assert!(!f64::INFINITY.approx_eq(&f64::NEG_INFINITY, 1e-3));
+    }
+
+    #[test]
+    fn test_indicator() {
+        use crate::indicators::{ExponentialMovingAverage, SimpleMovingAverage};
+        use crate::OutputHistory;
+
+        let mut stack: Vec<Box<dyn Indicator>> = vec![
+            Box::new(SimpleMovingAverage::new(2).unwrap()),
+            Box::new(ExponentialMovingAverage::new(3).unwrap()),
+            Box::new(OutputHistory::new(SimpleMovingAverage::new(1).unwrap(), 2).unwrap()),
+        ];
+
+        let names: Vec<&str> = stack.iter().map(|i| i.name()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "SimpleMovingAverage",
+                "ExponentialMovingAverage",
+                "OutputHistory"
+            ]
+        );
+
+        let outputs: Vec<Vec<f64>> = stack
+            .iter_mut()
+            .map(|i| [2.0, 5.0, 1.0].iter().map(|&x| i.next(x)).collect())
+            .collect();
+        assert_eq!(outputs[0], vec![2.0, 3.5, 3.0]);
+        assert_eq!(outputs[1], vec![2.0, 3.5, 2.25]);
+        assert_eq!(outputs[2], vec![2.0, 5.0, 1.0]);
+
+        for indicator in stack.iter_mut() {
+            indicator.reset();
+            assert_eq!(indicator.next(4.0), 4.0);
+        }
+
+        let ema = stack[1]
+            .as_any_mut()
+            .downcast_mut::<ExponentialMovingAverage>()
+            .unwrap();
+        assert_eq!(Next::next(ema, 4.0), 4.0);
+        assert!(stack[0]
+            .as_any()
+            .downcast_ref::<ExponentialMovingAverage>()
+            .is_none());
+    }
+
+    #[test]
+    fn test_approx_eq_vec() {
+        assert!(vec![1.0, f64::NAN].approx_eq(&vec![1.0001, f64::NAN], 1e-3));
+        assert!(!vec![1.0].approx_eq(&vec![1.0, 2.0], 1e-3));
+    }
+}